@@ -51,7 +51,9 @@ impl<'a> ReconstructingReducerStatement<'a> for DeadCodeElimination {
     }
 
     fn reduce_block(&mut self, input: BlockStatement<'a>, mut statements: Vec<&'a Statement<'a>>) -> Statement<'a> {
-        let first_return = statements.iter().position(|x| matches!(x, Statement::Return(_)));
+        let first_return = statements
+            .iter()
+            .position(|x| matches!(x, Statement::Return(_) | Statement::Break(_) | Statement::Continue(_)));
         if let Some(first_return) = first_return {
             statements.truncate(first_return + 1);
         }
@@ -83,12 +83,7 @@ impl<'a> MonoidalReducerStatement<'a, BoolAnd> for ReturnPathReducer {
         if_true: BoolAnd,
         if_false: Option<BoolAnd>,
     ) -> BoolAnd {
-        if if_false.as_ref().map(|x| x.0).unwrap_or(false) != if_true.0 {
-            self.record_error(
-                input.span(),
-                "cannot have asymmetrical return in if statement".to_string(),
-            );
-        }
+        // a branch that does not return falls through to the statements after the conditional
         if_true.append(if_false.unwrap_or(BoolAnd(false)))
     }
 
@@ -119,6 +114,10 @@ impl<'a> MonoidalReducerStatement<'a, BoolAnd> for ReturnPathReducer {
         BoolAnd(false)
     }
 
+    fn reduce_match(&mut self, input: &MatchStatement, value: BoolAnd, arms: Vec<BoolAnd>) -> BoolAnd {
+        BoolAnd(!arms.is_empty() && arms.iter().all(|arm| arm.0))
+    }
+
     fn reduce_return(&mut self, input: &ReturnStatement, value: BoolAnd) -> BoolAnd {
         BoolAnd(true)
     }
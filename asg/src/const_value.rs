@@ -16,8 +16,12 @@
 
 use crate::{AsgConvertError, IntegerType, Span, Type};
 
-use num_bigint::BigInt;
-use std::{convert::TryInto, fmt};
+use num_bigint::{BigInt, Sign};
+use std::{
+    cmp::Ordering,
+    convert::{TryFrom, TryInto},
+    fmt,
+};
 use tendril::StrTendril;
 
 /// Constant integer values in a program.
@@ -110,14 +114,54 @@ pub enum CharValue {
 
 impl From<leo_ast::CharValue> for CharValue {
     fn from(other: leo_ast::CharValue) -> Self {
+        other.character.into()
+    }
+}
+
+impl From<leo_ast::Char> for CharValue {
+    fn from(other: leo_ast::Char) -> Self {
         use leo_ast::Char::*;
-        match other.character {
+        match other {
             Scalar(value) => CharValue::Scalar(value),
             NonScalar(value) => CharValue::NonScalar(value),
         }
     }
 }
 
+/// The largest Unicode code point.
+const MAX_CHAR_CODE: u32 = 0x10FFFF;
+
+impl CharValue {
+    /// Returns the character with the given code point, or `None` if it is above the Unicode range.
+    pub fn from_code(code: u32) -> Option<CharValue> {
+        if code > MAX_CHAR_CODE {
+            return None;
+        }
+
+        Some(match std::char::from_u32(code) {
+            Some(scalar) => CharValue::Scalar(scalar),
+            None => CharValue::NonScalar(code),
+        })
+    }
+
+    /// Returns the code point of the character.
+    pub fn code(&self) -> u32 {
+        match self {
+            CharValue::Scalar(scalar) => *scalar as u32,
+            CharValue::NonScalar(code) => *code,
+        }
+    }
+}
+
+impl Into<leo_ast::Char> for &CharValue {
+    fn into(self) -> leo_ast::Char {
+        match self {
+            CharValue::Scalar(scalar) => leo_ast::Char::Scalar(*scalar),
+            CharValue::NonScalar(code) => leo_ast::Char::NonScalar(*code),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum ConstValue {
     Int(ConstInt),
@@ -132,6 +176,27 @@ pub enum ConstValue {
     Array(Vec<ConstValue>),
 }
 
+/// Field constants with fewer bits than this are smaller than the modulus of any supported field,
+/// so they are already in canonical form and can be ordered as plain integers.
+const CANONICAL_FIELD_BITS: u64 = 252;
+
+/// Orders two constant field values by their canonical representatives.
+/// Returns `None` if either value may need to be reduced first, in which case the comparison is left to the circuit.
+pub fn compare_fields(left: &BigInt, right: &BigInt) -> Option<Ordering> {
+    let is_canonical = |value: &BigInt| value.sign() != Sign::Minus && value.bits() <= CANONICAL_FIELD_BITS;
+    if is_canonical(left) && is_canonical(right) {
+        Some(left.cmp(right))
+    } else {
+        None
+    }
+}
+
+/// Returns `true` if the constant field value is small enough to denote the same element without being reduced,
+/// so its integer value is known.
+pub fn is_small_field(value: &BigInt) -> bool {
+    value.bits() <= CANONICAL_FIELD_BITS
+}
+
 macro_rules! const_int_op {
     ($name: ident, $retType: ty, $x: ident, $transform: expr) => {
         pub fn $name(&self) -> $retType {
@@ -210,6 +275,25 @@ macro_rules! const_int_bimap {
     };
 }
 
+macro_rules! const_int_shift {
+    ($name: ident, $x: ident, $y: ident, $transform: expr) => {
+        pub fn $name(&self, $y: u32) -> Option<ConstInt> {
+            Some(match self {
+                ConstInt::I8($x) => ConstInt::I8($transform),
+                ConstInt::I16($x) => ConstInt::I16($transform),
+                ConstInt::I32($x) => ConstInt::I32($transform),
+                ConstInt::I64($x) => ConstInt::I64($transform),
+                ConstInt::I128($x) => ConstInt::I128($transform),
+                ConstInt::U8($x) => ConstInt::U8($transform),
+                ConstInt::U16($x) => ConstInt::U16($transform),
+                ConstInt::U32($x) => ConstInt::U32($transform),
+                ConstInt::U64($x) => ConstInt::U64($transform),
+                ConstInt::U128($x) => ConstInt::U128($transform),
+            })
+        }
+    };
+}
+
 #[allow(clippy::useless_conversion)]
 impl ConstInt {
     const_int_op!(raw_value, String, x, format!("{}", x));
@@ -250,6 +334,17 @@ impl ConstInt {
 
     const_int_bimap!(value_div, x, y, x.checked_div(*y)?);
 
+    const_int_bimap!(value_rem, x, y, x.checked_rem(*y)?);
+
+    const_int_bimap!(value_bit_and, x, y, x & y);
+
+    const_int_bimap!(value_bit_or, x, y, x | y);
+
+    const_int_bimap!(value_bit_xor, x, y, x ^ y);
+
+    // shifting by the bit width or more discards every bit
+    const_int_shift!(value_shl, x, y, x.checked_shl(y).unwrap_or(0));
+
     // TODO: limited to 32 bit exponents
     const_int_bimap!(value_pow, x, y, x.checked_pow((*y).try_into().ok()?)?);
 
@@ -261,6 +356,35 @@ impl ConstInt {
 
     const_int_biop!(value_ge, bool, x, y, Some(x >= y));
 
+    /// Logical right shift, the vacated high bits are always zero.
+    pub fn value_shr(&self, y: u32) -> Option<ConstInt> {
+        Some(match self {
+            ConstInt::I8(x) => ConstInt::I8((*x as u8).checked_shr(y).unwrap_or(0) as i8),
+            ConstInt::I16(x) => ConstInt::I16((*x as u16).checked_shr(y).unwrap_or(0) as i16),
+            ConstInt::I32(x) => ConstInt::I32((*x as u32).checked_shr(y).unwrap_or(0) as i32),
+            ConstInt::I64(x) => ConstInt::I64((*x as u64).checked_shr(y).unwrap_or(0) as i64),
+            ConstInt::I128(x) => ConstInt::I128((*x as u128).checked_shr(y).unwrap_or(0) as i128),
+            ConstInt::U8(x) => ConstInt::U8(x.checked_shr(y).unwrap_or(0)),
+            ConstInt::U16(x) => ConstInt::U16(x.checked_shr(y).unwrap_or(0)),
+            ConstInt::U32(x) => ConstInt::U32(x.checked_shr(y).unwrap_or(0)),
+            ConstInt::U64(x) => ConstInt::U64(x.checked_shr(y).unwrap_or(0)),
+            ConstInt::U128(x) => ConstInt::U128(x.checked_shr(y).unwrap_or(0)),
+        })
+    }
+
+    /// Arithmetic right shift, the vacated high bits are copies of the sign bit.
+    /// Only defined for signed integers.
+    pub fn value_shr_signed(&self, y: u32) -> Option<ConstInt> {
+        Some(match self {
+            ConstInt::I8(x) => ConstInt::I8(x >> y.min(7)),
+            ConstInt::I16(x) => ConstInt::I16(x >> y.min(15)),
+            ConstInt::I32(x) => ConstInt::I32(x >> y.min(31)),
+            ConstInt::I64(x) => ConstInt::I64(x >> y.min(63)),
+            ConstInt::I128(x) => ConstInt::I128(x >> y.min(127)),
+            _ => return None,
+        })
+    }
+
     pub fn get_int_type(&self) -> IntegerType {
         match self {
             ConstInt::I8(_) => IntegerType::I8,
@@ -276,6 +400,10 @@ impl ConstInt {
         }
     }
 
+    ///
+    /// Casts the integer to `target`, keeping its low bits and reinterpreting them.
+    /// This is the value-changing cast of RFC 004, sign extending signed values.
+    ///
     pub fn cast_to(&self, target: &IntegerType) -> ConstInt {
         match target {
             IntegerType::I8 => ConstInt::I8(self.to_i8()),
@@ -291,6 +419,41 @@ impl ConstInt {
         }
     }
 
+    ///
+    /// Casts the integer to `target` if its value is representable in it, the value-preserving cast of RFC 004.
+    ///
+    pub fn checked_cast_to(&self, target: &IntegerType) -> Option<ConstInt> {
+        let cast = self.cast_to(target);
+
+        // Casting back restores the value unless bits were dropped, the sign catches reinterpreted bits.
+        if cast.cast_to(&self.get_int_type()) == *self && cast.is_negative() == self.is_negative() {
+            Some(cast)
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Returns the integer of type `target` with the value of a small constant field, see [`is_small_field`].
+    /// Negative constants stand for the field elements close to the modulus, which the circuit reads as negative too.
+    ///
+    pub fn from_field(value: &BigInt, target: &IntegerType) -> Option<ConstInt> {
+        ConstInt::parse(target, &value.to_string(), &Span::default()).ok()
+    }
+
+    ///
+    /// Returns the low bits of a small constant field as an integer of type `target`, see [`is_small_field`].
+    /// Negative constants keep the low bits of their two's complement, as the circuit does for elements close to the modulus.
+    ///
+    pub fn wrapping_from_field(value: &BigInt, target: &IntegerType) -> ConstInt {
+        let low_bits = u128::try_from(value & BigInt::from(u128::MAX)).unwrap_or_default();
+        ConstInt::U128(low_bits).cast_to(target)
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.get_int_type().is_signed() && self.to_i128() < 0
+    }
+
     pub fn get_type<'a>(&self) -> Type<'a> {
         Type::Integer(self.get_int_type())
     }
@@ -327,6 +490,75 @@ impl ConstValue {
         })
     }
 
+    ///
+    /// Returns the value cast to `target`, or `None` if the cast does not preserve the value.
+    /// Arrays are cast element-wise.
+    ///
+    pub fn cast_to(&self, target: &Type) -> Option<ConstValue> {
+        Some(match (self, target) {
+            (ConstValue::Int(int), Type::Integer(target)) => ConstValue::Int(int.checked_cast_to(target)?),
+            (ConstValue::Int(int), Type::Field) => ConstValue::Field(int.raw_value().parse().ok()?),
+            (ConstValue::Int(int), Type::Char) => {
+                ConstValue::Char(CharValue::from_code(int.checked_cast_to(&IntegerType::U32)?.to_u32())?)
+            }
+            (ConstValue::Field(field), Type::Integer(target)) if is_small_field(field) => {
+                ConstValue::Int(ConstInt::from_field(field, target)?)
+            }
+            (ConstValue::Field(field), Type::Field) => ConstValue::Field(field.clone()),
+            (ConstValue::Char(char), Type::Integer(target)) => {
+                ConstValue::Int(ConstInt::parse(target, &char.code().to_string(), &Span::default()).ok()?)
+            }
+            (ConstValue::Char(char), Type::Char) => ConstValue::Char(char.clone()),
+            (ConstValue::Array(items), Type::Array(item, len)) if items.len() == *len => ConstValue::Array(
+                items
+                    .iter()
+                    .map(|value| value.cast_to(item))
+                    .collect::<Option<Vec<_>>>()?,
+            ),
+            _ => return None,
+        })
+    }
+
+    ///
+    /// Returns the value cast to `target` keeping its low bits, the value-changing cast of RFC 004,
+    /// or `None` if the value is only known after reducing it in the circuit.
+    /// Arrays are cast element-wise.
+    ///
+    pub fn wrapping_cast_to(&self, target: &Type) -> Option<ConstValue> {
+        Some(match (self, target) {
+            (ConstValue::Int(int), Type::Integer(target)) => ConstValue::Int(int.cast_to(target)),
+            (ConstValue::Field(field), Type::Integer(target)) if is_small_field(field) => {
+                ConstValue::Int(ConstInt::wrapping_from_field(field, target))
+            }
+            (ConstValue::Char(char), Type::Integer(target)) => {
+                ConstValue::Int(ConstInt::U32(char.code()).cast_to(target))
+            }
+            (ConstValue::Array(items), Type::Array(item, len)) if items.len() == *len => ConstValue::Array(
+                items
+                    .iter()
+                    .map(|value| value.wrapping_cast_to(item))
+                    .collect::<Option<Vec<_>>>()?,
+            ),
+            (_, Type::Field) => self.cast_to(target)?,
+            _ => return None,
+        })
+    }
+
+    /// Returns the code points of a character or a string.
+    pub fn char_codes(&self) -> Option<Vec<u32>> {
+        match self {
+            ConstValue::Char(char) => Some(vec![char.code()]),
+            ConstValue::Array(items) => items
+                .iter()
+                .map(|item| match item {
+                    ConstValue::Char(char) => Some(char.code()),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+
     pub fn int(&self) -> Option<&ConstInt> {
         match self {
             ConstValue::Int(x) => Some(x),
@@ -14,15 +14,40 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::{cell::Cell, unimplemented};
+use std::{
+    cell::{Cell, RefCell},
+    unimplemented,
+};
 
+use indexmap::IndexMap;
 use typed_arena::Arena;
 
-use crate::{ArenaNode, Circuit, Expression, Function, Scope, Statement, Variable};
+use crate::{
+    ArenaNode,
+    AsgConvertError,
+    Circuit,
+    CoreCircuitDeclaration,
+    CoreModules,
+    Enum,
+    Expression,
+    Function,
+    Program,
+    Scope,
+    Span,
+    Statement,
+    Variable,
+};
 
 pub struct AsgContextInner<'a> {
     pub arena: &'a Arena<ArenaNode<'a>>,
     pub next_id: Cell<u32>,
+    /// The circuits that programs in this context can import from `core`.
+    pub core_modules: RefCell<CoreModules>,
+    /// The core modules loaded so far, by module.
+    pub(crate) core_programs: RefCell<IndexMap<String, Program<'a>>>,
+    /// The instances of generic functions whose bodies are yet to be converted, with their declarations
+    /// and the spans they were instantiated at.
+    pending_instances: RefCell<Vec<(&'a Function<'a>, leo_ast::Function, Span)>>,
 }
 
 impl<'a> AsgContextInner<'a> {
@@ -30,12 +55,50 @@ impl<'a> AsgContextInner<'a> {
         match arena.alloc(ArenaNode::Inner(AsgContextInner {
             arena,
             next_id: Cell::new(0),
+            core_modules: RefCell::new(CoreModules::default()),
+            core_programs: RefCell::new(IndexMap::new()),
+            pending_instances: RefCell::new(Vec::new()),
         })) {
             ArenaNode::Inner(x) => x,
             _ => unimplemented!(),
         }
     }
 
+    ///
+    /// Declares a circuit that programs in this context can import from `core`.
+    ///
+    /// A module that was already loaded keeps its previous declaration for the programs that imported it.
+    ///
+    pub fn register_core_module(&self, declaration: CoreCircuitDeclaration) {
+        self.core_programs.borrow_mut().remove(&declaration.module);
+        self.core_modules.borrow_mut().register(declaration);
+    }
+
+    ///
+    /// Defers converting the body of a generic instance until every declaration it may refer to is loaded.
+    ///
+    pub(crate) fn defer_instance(&self, function: &'a Function<'a>, declaration: leo_ast::Function, span: Span) {
+        self.pending_instances.borrow_mut().push((function, declaration, span));
+    }
+
+    ///
+    /// Converts the bodies of the deferred generic instances, including those they instantiate in turn.
+    ///
+    pub(crate) fn fill_instances(&self) -> Result<(), AsgConvertError> {
+        loop {
+            let pending = self.pending_instances.borrow_mut().pop();
+            let (function, declaration, span) = match pending {
+                Some(pending) => pending,
+                None => return Ok(()),
+            };
+            if let Err(error) = function.fill_from_ast(&declaration) {
+                self.pending_instances.borrow_mut().clear();
+                let name = function.name.borrow().name.to_string();
+                return Err(AsgConvertError::generic_instantiation(&name, error, &span));
+            }
+        }
+    }
+
     pub fn get_id(&self) -> u32 {
         let next_id = self.next_id.get();
         self.next_id.replace(next_id + 1);
@@ -82,6 +145,14 @@ impl<'a> AsgContextInner<'a> {
         }
     }
 
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_enum(&'a self, enum_: Enum<'a>) -> &'a Enum<'a> {
+        match self.arena.alloc(ArenaNode::Enum(enum_)) {
+            ArenaNode::Enum(e) => e,
+            _ => unimplemented!(),
+        }
+    }
+
     #[allow(clippy::mut_from_ref)]
     pub fn alloc_function(&'a self, function: Function<'a>) -> &'a Function<'a> {
         match self.arena.alloc(ArenaNode::Function(function)) {
@@ -50,6 +50,16 @@ impl AsgConvertError {
         Self::new_from_span(format!("failed to resolve import: '{}'", name), span)
     }
 
+    pub fn private_import(module: &str, name: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!(
+                "'{}' is private to module '{}', declare it with 'pub' to import it",
+                name, module
+            ),
+            span,
+        )
+    }
+
     pub fn unresolved_circuit_member(circuit_name: &str, name: &str, span: &Span) -> Self {
         Self::new_from_span(
             format!(
@@ -100,6 +110,65 @@ impl AsgConvertError {
         )
     }
 
+    pub fn unresolved_enum_variant(enum_name: &str, name: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!(
+                "illegal reference to non-existant variant '{}' of enum '{}'",
+                name, enum_name
+            ),
+            span,
+        )
+    }
+
+    pub fn redefined_enum_variant(enum_name: &str, name: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!("cannot redefine variant '{}' of enum '{}'", name, enum_name),
+            span,
+        )
+    }
+
+    pub fn too_many_enum_variants(enum_name: &str, max: usize, span: &Span) -> Self {
+        Self::new_from_span(
+            format!("enum '{}' has more than the maximum of {} variants", enum_name, max),
+            span,
+        )
+    }
+
+    pub fn duplicate_type_definition(name: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!("a circuit or enum named \"{}\" already exists in this scope", name),
+            span,
+        )
+    }
+
+    pub fn unexpected_enum_payload_count(variant: &str, expected: usize, got: usize, span: &Span) -> Self {
+        Self::new_from_span(
+            format!("enum variant '{}' carries {} values, got {}", variant, expected, got),
+            span,
+        )
+    }
+
+    pub fn duplicate_match_arm(variant: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!("variant '{}' is already matched by a previous arm", variant),
+            span,
+        )
+    }
+
+    pub fn unreachable_match_arm(span: &Span) -> Self {
+        Self::new_from_span(
+            "unreachable match arm, every variant is matched by a previous arm".to_string(),
+            span,
+        )
+    }
+
+    pub fn non_exhaustive_match(missing: &[String], span: &Span) -> Self {
+        Self::new_from_span(
+            format!("non-exhaustive match, missing variants: {}", missing.join(", ")),
+            span,
+        )
+    }
+
     pub fn illegal_function_assign(name: &str, span: &Span) -> Self {
         Self::new_from_span(format!("attempt to assign to function '{}'", name), span)
     }
@@ -201,6 +270,16 @@ impl AsgConvertError {
         Self::new_from_span(format!("array index out of bounds: '{}'", index), span)
     }
 
+    pub fn array_slice_too_long(length: usize, array_length: usize, span: &Span) -> Self {
+        Self::new_from_span(
+            format!(
+                "slice of length {} is longer than the array of length {}",
+                length, array_length
+            ),
+            span,
+        )
+    }
+
     pub fn unknown_array_size(span: &Span) -> Self {
         Self::new_from_span("array size cannot be inferred, add explicit types".to_string(), span)
     }
@@ -254,6 +333,33 @@ impl AsgConvertError {
         Self::new_from_span(format!("failed to parse int value '{}'", value), span)
     }
 
+    pub fn invalid_cast(from: &str, to: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!(
+                "cannot cast '{}' to '{}', casts are only defined between integers, fields and chars",
+                from, to
+            ),
+            span,
+        )
+    }
+
+    pub fn invalid_wrapping_cast(from: &str, to: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!(
+                "cannot cast '{}' to '{}' with wrapping, wrapping casts are only defined to integers and fields",
+                from, to
+            ),
+            span,
+        )
+    }
+
+    pub fn cast_out_of_range(value: &str, to: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!("cannot cast '{}' to '{}', the value is out of range", value, to),
+            span,
+        )
+    }
+
     pub fn unsigned_negation(span: &Span) -> Self {
         Self::new_from_span("cannot negate unsigned integer".to_string(), span)
     }
@@ -295,6 +401,71 @@ impl AsgConvertError {
         Self::new_from_span("cannot have test function as member of circuit".to_string(), span)
     }
 
+    pub fn unresolved_const_parameter(name: &str, span: &Span) -> Self {
+        Self::new_from_span(format!("failed to resolve const parameter: '{}'", name), span)
+    }
+
+    pub fn redefined_const_parameter(name: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!("cannot declare const parameter '{}' more than once", name),
+            span,
+        )
+    }
+
+    pub fn invalid_const_parameter_type(name: &str, type_: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!("const parameter '{}' must have type 'u32', found '{}'", name, type_),
+            span,
+        )
+    }
+
+    pub fn unexpected_const_argument_count(name: &str, expected: usize, got: usize, span: &Span) -> Self {
+        Self::new_from_span(
+            format!("'{}' expected {} const arguments, got {}", name, expected, got),
+            span,
+        )
+    }
+
+    pub fn unresolved_const_argument(parameter: &str, name: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!(
+                "could not infer const parameter '{}' of '{}' from the types at this call",
+                parameter, name
+            ),
+            span,
+        )
+    }
+
+    pub fn conflicting_const_argument(parameter: &str, first: u32, second: u32, span: &Span) -> Self {
+        Self::new_from_span(
+            format!(
+                "conflicting values {} and {} inferred for const parameter '{}'",
+                first, second, parameter
+            ),
+            span,
+        )
+    }
+
+    pub fn generic_instantiation(name: &str, error: AsgConvertError, span: &Span) -> Self {
+        let message = match error {
+            AsgConvertError::Error(error) => error.message,
+            error => error.to_string(),
+        };
+        Self::new_from_span(format!("failed to instantiate '{}': {}", name, message), span)
+    }
+
+    pub fn generic_entry_point(name: &str, span: &Span) -> Self {
+        Self::new_from_span(format!("function '{}' cannot have const parameters", name), span)
+    }
+
+    pub fn loop_control_outside_loop(keyword: &str, span: &Span) -> Self {
+        Self::new_from_span(format!("cannot use '{}' outside of a loop", keyword), span)
+    }
+
+    pub fn unreachable_statement(keyword: &str, span: &Span) -> Self {
+        Self::new_from_span(format!("unreachable statement after '{}'", keyword), span)
+    }
+
     pub fn parse_index_error() -> Self {
         AsgConvertError::InternalError("failed to parse index".to_string())
     }
@@ -84,11 +84,7 @@ impl<'a> FromAst<'a, leo_ast::ArrayInitExpression> for ArrayInitExpression<'a> {
             .dimensions
             .0
             .iter()
-            .map(|x| {
-                x.value
-                    .parse::<usize>()
-                    .map_err(|_| AsgConvertError::parse_dimension_error())
-            })
+            .map(|x| scope.resolve_dimension(x))
             .collect::<Result<Vec<_>, AsgConvertError>>()?;
 
         let len = *dimensions.get(0).ok_or_else(AsgConvertError::parse_dimension_error)?;
@@ -151,9 +147,9 @@ impl<'a> Into<leo_ast::ArrayInitExpression> for &ArrayInitExpression<'a> {
     fn into(self) -> leo_ast::ArrayInitExpression {
         leo_ast::ArrayInitExpression {
             element: Box::new(self.element.get().into()),
-            dimensions: leo_ast::ArrayDimensions(vec![leo_ast::PositiveNumber {
+            dimensions: leo_ast::ArrayDimensions(vec![leo_ast::Dimension::Number(leo_ast::PositiveNumber {
                 value: self.len.to_string().into(),
-            }]),
+            })]),
             span: self.span.clone().unwrap_or_default(),
         }
     }
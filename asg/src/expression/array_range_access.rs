@@ -14,7 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AsgConvertError, ConstValue, Expression, ExpressionNode, FromAst, Node, PartialType, Scope, Span, Type};
+use crate::{
+    AsgConvertError,
+    BinaryOperation,
+    ConstValue,
+    Expression,
+    ExpressionNode,
+    FromAst,
+    Node,
+    PartialType,
+    Scope,
+    Span,
+    Type,
+};
 use leo_ast::IntegerType;
 
 use std::cell::Cell;
@@ -97,6 +109,25 @@ impl<'a> ExpressionNode<'a> for ArrayRangeAccessExpression<'a> {
     }
 }
 
+/// Returns `N` if `right` is `left + N`, for a variable `left` and a constant `N`.
+/// The length of such a slice is known even if its start is only known at runtime.
+fn static_offset<'a>(left: Option<&'a Expression<'a>>, right: Option<&'a Expression<'a>>) -> Option<usize> {
+    let (start, end) = match (left?, right?) {
+        (Expression::VariableRef(start), Expression::Binary(end)) if end.operation == BinaryOperation::Add => {
+            (start, end)
+        }
+        _ => return None,
+    };
+    match end.left.get() {
+        Expression::VariableRef(variable) if std::ptr::eq(variable.variable, start.variable) => (),
+        _ => return None,
+    }
+    match end.right.get().const_value() {
+        Some(ConstValue::Int(offset)) => offset.to_usize(),
+        _ => None,
+    }
+}
+
 impl<'a> FromAst<'a, leo_ast::ArrayRangeAccessExpression> for ArrayRangeAccessExpression<'a> {
     fn from_ast(
         scope: &'a Scope<'a>,
@@ -147,6 +178,19 @@ impl<'a> FromAst<'a, leo_ast::ArrayRangeAccessExpression> for ArrayRangeAccessEx
             None => Some(0),
             _ => None,
         };
+        // A start known only at runtime is compared against the positions of the windows as a `u32`
+        if let (Some(left), None) = (left, const_left) {
+            match left.get_type() {
+                Some(Type::Integer(IntegerType::U32)) => (),
+                type_ => {
+                    return Err(AsgConvertError::unexpected_type(
+                        "u32",
+                        type_.map(|x| x.to_string()).as_deref(),
+                        &left.span().cloned().unwrap_or_default(),
+                    ));
+                }
+            }
+        }
         let const_right = match right.map(|x| x.const_value()) {
             Some(Some(ConstValue::Int(value))) => {
                 let value = value.to_usize();
@@ -174,7 +218,7 @@ impl<'a> FromAst<'a, leo_ast::ArrayRangeAccessExpression> for ArrayRangeAccessEx
         let mut length = if let (Some(left), Some(right)) = (const_left, const_right) {
             Some(right - left)
         } else {
-            None
+            static_offset(left, right)
         };
         if let Some(expected_len) = expected_len {
             if let Some(length) = length {
@@ -197,8 +241,12 @@ impl<'a> FromAst<'a, leo_ast::ArrayRangeAccessExpression> for ArrayRangeAccessEx
             }
             length = Some(expected_len);
         }
-        if length.is_none() {
-            return Err(AsgConvertError::unknown_array_size(&value.span));
+        match length {
+            None => return Err(AsgConvertError::unknown_array_size(&value.span)),
+            Some(length) if length > parent_size => {
+                return Err(AsgConvertError::array_slice_too_long(length, parent_size, &value.span));
+            }
+            Some(_) => (),
         }
 
         Ok(ArrayRangeAccessExpression {
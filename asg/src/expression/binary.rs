@@ -14,10 +14,23 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AsgConvertError, ConstValue, Expression, ExpressionNode, FromAst, Node, PartialType, Scope, Span, Type};
+use crate::{
+    compare_fields,
+    AsgConvertError,
+    ConstValue,
+    Expression,
+    ExpressionNode,
+    FromAst,
+    IntegerType,
+    Node,
+    PartialType,
+    Scope,
+    Span,
+    Type,
+};
 pub use leo_ast::{BinaryOperation, BinaryOperationClass};
 
-use std::cell::Cell;
+use std::{cell::Cell, cmp::Ordering};
 
 #[derive(Clone)]
 pub struct BinaryExpression<'a> {
@@ -71,6 +84,13 @@ impl<'a> ExpressionNode<'a> for BinaryExpression<'a> {
                 Mul => ConstValue::Int(left.value_mul(&right)?),
                 Div => ConstValue::Int(left.value_div(&right)?),
                 Pow => ConstValue::Int(left.value_pow(&right)?),
+                Mod => ConstValue::Int(left.value_rem(&right)?),
+                BitAnd => ConstValue::Int(left.value_bit_and(&right)?),
+                BitOr => ConstValue::Int(left.value_bit_or(&right)?),
+                BitXor => ConstValue::Int(left.value_bit_xor(&right)?),
+                Shl => ConstValue::Int(left.value_shl(right.to_u32())?),
+                Shr => ConstValue::Int(left.value_shr(right.to_u32())?),
+                ShrSigned => ConstValue::Int(left.value_shr_signed(right.to_u32())?),
                 Eq => ConstValue::Boolean(left == right),
                 Ne => ConstValue::Boolean(left != right),
                 Ge => ConstValue::Boolean(left.value_ge(&right)?),
@@ -79,17 +99,18 @@ impl<'a> ExpressionNode<'a> for BinaryExpression<'a> {
                 Lt => ConstValue::Boolean(left.value_lt(&right)?),
                 _ => return None,
             }),
-            // (ConstValue::Field(left), ConstValue::Field(right)) => {
-            //     Some(match self.operation {
-            //         Add => ConstValue::Field(left.checked_add(&right)?),
-            //         Sub => ConstValue::Field(left.checked_sub(&right)?),
-            //         Mul => ConstValue::Field(left.checked_mul(&right)?),
-            //         Div => ConstValue::Field(left.checked_div(&right)?),
-            //         Eq => ConstValue::Boolean(left == right),
-            //         Ne => ConstValue::Boolean(left != right),
-            //         _ => return None,
-            //     })
-            // },
+            (ConstValue::Field(left), ConstValue::Field(right)) => {
+                let ordering = compare_fields(&left, &right);
+                Some(match self.operation {
+                    Eq => ConstValue::Boolean(left == right),
+                    Ne => ConstValue::Boolean(left != right),
+                    Ge => ConstValue::Boolean(ordering? != Ordering::Less),
+                    Gt => ConstValue::Boolean(ordering? == Ordering::Greater),
+                    Le => ConstValue::Boolean(ordering? != Ordering::Greater),
+                    Lt => ConstValue::Boolean(ordering? == Ordering::Less),
+                    _ => return None,
+                })
+            }
             (ConstValue::Boolean(left), ConstValue::Boolean(right)) => Some(match self.operation {
                 Eq => ConstValue::Boolean(left == right),
                 Ne => ConstValue::Boolean(left != right),
@@ -98,11 +119,22 @@ impl<'a> ExpressionNode<'a> for BinaryExpression<'a> {
                 _ => return None,
             }),
             //todo: group?
-            (left, right) => Some(match self.operation {
-                Eq => ConstValue::Boolean(left == right),
-                Ne => ConstValue::Boolean(left != right),
-                _ => return None,
-            }),
+            (left, right) => {
+                // characters and strings are ordered by their code points
+                let ordering = left
+                    .char_codes()
+                    .zip(right.char_codes())
+                    .map(|(left, right)| left.cmp(&right));
+                Some(match self.operation {
+                    Eq => ConstValue::Boolean(left == right),
+                    Ne => ConstValue::Boolean(left != right),
+                    Ge => ConstValue::Boolean(ordering? != Ordering::Less),
+                    Gt => ConstValue::Boolean(ordering? == Ordering::Greater),
+                    Le => ConstValue::Boolean(ordering? != Ordering::Greater),
+                    Lt => ConstValue::Boolean(ordering? == Ordering::Less),
+                    _ => return None,
+                })
+            }
         }
     }
 
@@ -144,13 +176,40 @@ impl<'a> FromAst<'a, leo_ast::BinaryExpression> for BinaryExpression<'a> {
             },
         };
 
+        let is_shift = matches!(
+            value.op,
+            BinaryOperation::Shl | BinaryOperation::Shr | BinaryOperation::ShrSigned
+        );
+
         // left
-        let (left, right) = match <&Expression<'a>>::from_ast(scope, &*value.left, expected_type.clone()) {
-            Ok(left) => {
-                if let Some(left_type) = left.get_type() {
-                    let right = <&Expression<'a>>::from_ast(scope, &*value.right, Some(left_type.partial()))?;
-                    (left, right)
-                } else {
+        let (left, right) = if is_shift {
+            // the shift amount is always a u32, independent of the shifted type
+            let left = <&Expression<'a>>::from_ast(scope, &*value.left, expected_type)?;
+            let right = <&Expression<'a>>::from_ast(
+                scope,
+                &*value.right,
+                Some(PartialType::Integer(Some(IntegerType::U32), None)),
+            )?;
+            (left, right)
+        } else {
+            match <&Expression<'a>>::from_ast(scope, &*value.left, expected_type.clone()) {
+                Ok(left) => {
+                    if let Some(left_type) = left.get_type() {
+                        let right = <&Expression<'a>>::from_ast(scope, &*value.right, Some(left_type.partial()))?;
+                        (left, right)
+                    } else {
+                        let right = <&Expression<'a>>::from_ast(scope, &*value.right, expected_type)?;
+                        if let Some(right_type) = right.get_type() {
+                            (
+                                <&Expression<'a>>::from_ast(scope, &*value.left, Some(right_type.partial()))?,
+                                right,
+                            )
+                        } else {
+                            (left, right)
+                        }
+                    }
+                }
+                Err(e) => {
                     let right = <&Expression<'a>>::from_ast(scope, &*value.right, expected_type)?;
                     if let Some(right_type) = right.get_type() {
                         (
@@ -158,27 +217,25 @@ impl<'a> FromAst<'a, leo_ast::BinaryExpression> for BinaryExpression<'a> {
                             right,
                         )
                     } else {
-                        (left, right)
+                        return Err(e);
                     }
                 }
             }
-            Err(e) => {
-                let right = <&Expression<'a>>::from_ast(scope, &*value.right, expected_type)?;
-                if let Some(right_type) = right.get_type() {
-                    (
-                        <&Expression<'a>>::from_ast(scope, &*value.left, Some(right_type.partial()))?,
-                        right,
-                    )
-                } else {
-                    return Err(e);
-                }
-            }
         };
 
         let left_type = left.get_type();
         #[allow(clippy::unused_unit)]
         match class {
             BinaryOperationClass::Numeric => match left_type {
+                Some(Type::Integer(integer_type))
+                    if value.op == BinaryOperation::ShrSigned && !integer_type.is_signed() =>
+                {
+                    return Err(AsgConvertError::unexpected_type(
+                        "signed integer",
+                        Some(&*integer_type.to_string()),
+                        &value.span,
+                    ));
+                }
                 Some(Type::Integer(_)) => (),
                 Some(Type::Group) | Some(Type::Field)
                     if value.op == BinaryOperation::Add || value.op == BinaryOperation::Sub =>
@@ -207,11 +264,12 @@ impl<'a> FromAst<'a, leo_ast::BinaryExpression> for BinaryExpression<'a> {
                 },
                 BinaryOperation::Eq | BinaryOperation::Ne => (), // all types allowed
                 _ => match left_type {
-                    Some(Type::Integer(_)) | None => (),
+                    Some(Type::Integer(_)) | Some(Type::Field) | Some(Type::Char) | None => (),
+                    Some(ref x) if x.string_len().is_some() => (),
                     Some(x) => {
                         return Err(AsgConvertError::unexpected_type(
                             &x.to_string(),
-                            Some("integer"),
+                            Some("integer, field, char or string"),
                             &value.span,
                         ));
                     }
@@ -222,6 +280,7 @@ impl<'a> FromAst<'a, leo_ast::BinaryExpression> for BinaryExpression<'a> {
         let right_type = right.get_type();
 
         match (left_type, right_type) {
+            (Some(_), Some(_)) if is_shift => (),
             (Some(left_type), Some(right_type)) => {
                 if !left_type.is_assignable_from(&right_type) {
                     return Err(AsgConvertError::unexpected_type(
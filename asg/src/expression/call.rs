@@ -17,6 +17,7 @@
 use crate::{
     AsgConvertError,
     CircuitMember,
+    ConstBindings,
     ConstValue,
     Expression,
     ExpressionNode,
@@ -90,6 +91,8 @@ impl<'a> FromAst<'a, leo_ast::CallExpression> for CallExpression<'a> {
         value: &leo_ast::CallExpression,
         expected_type: Option<PartialType<'a>>,
     ) -> Result<CallExpression<'a>, AsgConvertError> {
+        // The arguments of a call to a generic function are converted while inferring its const arguments.
+        let mut inferred = None;
         let (target, function) = match &*value.function {
             leo_ast::Expression::Identifier(name) => (
                 None,
@@ -145,13 +148,42 @@ impl<'a> FromAst<'a, leo_ast::CallExpression> for CallExpression<'a> {
                 name,
                 span,
             }) => {
-                let circuit = if let leo_ast::Expression::Identifier(circuit_name) = &**ast_circuit {
+                let mut circuit = if let leo_ast::Expression::Identifier(circuit_name) = &**ast_circuit {
                     scope
                         .resolve_circuit(&circuit_name.name)
                         .ok_or_else(|| AsgConvertError::unresolved_circuit(&circuit_name.name, &circuit_name.span))?
                 } else {
                     return Err(AsgConvertError::unexpected_type("circuit", None, span));
                 };
+                if let Some(template) = &circuit.template {
+                    // The const arguments of the circuit are inferred along with those of the function.
+                    let declaration = template
+                        .members
+                        .iter()
+                        .find_map(|member| match member {
+                            leo_ast::CircuitMember::CircuitFunction(function) if function.identifier == *name => {
+                                Some(function)
+                            }
+                            _ => None,
+                        })
+                        .ok_or_else(|| {
+                            AsgConvertError::unresolved_circuit_member(&template.circuit_name.name, &name.name, span)
+                        })?;
+                    let mut parameters = template.const_parameters.clone();
+                    parameters.extend(declaration.const_parameters.iter().cloned());
+                    let (arguments, bindings) = infer_const_arguments(
+                        scope,
+                        circuit.scope,
+                        declaration,
+                        ConstBindings::new(parameters),
+                        value,
+                        expected_type.as_ref(),
+                    )?;
+                    let circuit_arguments =
+                        bindings.arguments(&template.const_parameters, &template.circuit_name.name, &value.span)?;
+                    circuit = circuit.instantiate(circuit_arguments, &value.span)?;
+                    inferred = Some((arguments, bindings));
+                }
                 let circuit_name = circuit.name.borrow().name.clone();
 
                 let member = circuit.members.borrow();
@@ -180,6 +212,26 @@ impl<'a> FromAst<'a, leo_ast::CallExpression> for CallExpression<'a> {
                 ));
             }
         };
+        let function = match &function.template {
+            Some(template) => {
+                let (arguments, bindings) = match inferred.take() {
+                    Some(inferred) => inferred,
+                    None => infer_const_arguments(
+                        scope,
+                        function.scope,
+                        template,
+                        ConstBindings::new(template.const_parameters.clone()),
+                        value,
+                        expected_type.as_ref(),
+                    )?,
+                };
+                let function_arguments =
+                    bindings.arguments(&template.const_parameters, &template.identifier.name, &value.span)?;
+                inferred = Some((arguments, bindings));
+                function.instantiate(function_arguments, &value.span)?
+            }
+            None => function,
+        };
         if let Some(expected) = expected_type {
             let output: Type = function.output.clone();
             if !expected.matches(&output) {
@@ -198,14 +250,32 @@ impl<'a> FromAst<'a, leo_ast::CallExpression> for CallExpression<'a> {
             ));
         }
 
+        let mut converted_arguments = inferred.map(|(arguments, _)| arguments.into_iter());
         let arguments = value
             .arguments
             .iter()
             .zip(function.arguments.iter())
             .map(|(expr, (_, argument))| {
-                let argument = argument.get().borrow();
-                let converted = <&Expression<'a>>::from_ast(scope, expr, Some(argument.type_.clone().partial()))?;
-                if argument.const_ && !converted.is_consty() {
+                // release the borrow before converting, a recursive call may reference this very parameter
+                let (type_, const_) = {
+                    let argument = argument.get().borrow();
+                    (argument.type_.clone(), argument.const_)
+                };
+                let converted = match converted_arguments.as_mut().and_then(|arguments| arguments.next()) {
+                    Some(converted) => {
+                        let converted_type = converted.get_type();
+                        if converted_type.as_ref() != Some(&type_) {
+                            return Err(AsgConvertError::unexpected_type(
+                                &type_.to_string(),
+                                converted_type.map(|x| x.to_string()).as_deref(),
+                                expr.span(),
+                            ));
+                        }
+                        converted
+                    }
+                    None => <&Expression<'a>>::from_ast(scope, expr, Some(type_.partial()))?,
+                };
+                if const_ && !converted.is_consty() {
                     return Err(AsgConvertError::unexpected_nonconst(expr.span()));
                 }
                 Ok(Cell::new(converted))
@@ -225,6 +295,52 @@ impl<'a> FromAst<'a, leo_ast::CallExpression> for CallExpression<'a> {
     }
 }
 
+///
+/// Converts the arguments of a call to a generic function, binding the const parameters used in the
+/// declared types to the lengths found in the types of the arguments and the expected output.
+///
+/// Declared types are resolved in `declaration_scope`, the arguments in the scope of the call.
+///
+fn infer_const_arguments<'a>(
+    scope: &'a Scope<'a>,
+    declaration_scope: &'a Scope<'a>,
+    declaration: &leo_ast::Function,
+    mut bindings: ConstBindings,
+    value: &leo_ast::CallExpression,
+    expected_type: Option<&PartialType<'a>>,
+) -> Result<(Vec<&'a Expression<'a>>, ConstBindings), AsgConvertError> {
+    if let (Some(output), Some(expected)) = (&declaration.output, expected_type.cloned().and_then(|x| x.full())) {
+        bindings.bind(declaration_scope, output, &expected, &value.span)?;
+    }
+
+    let inputs = declaration
+        .filter_self_inputs()
+        .filter_map(|input| match input {
+            leo_ast::FunctionInput::Variable(variable) => Some(&variable.type_),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if value.arguments.len() != inputs.len() {
+        return Err(AsgConvertError::unexpected_call_argument_count(
+            inputs.len(),
+            value.arguments.len(),
+            &value.span,
+        ));
+    }
+
+    let mut arguments = vec![];
+    for (expr, type_) in value.arguments.iter().zip(inputs) {
+        let expected = bindings.expected_type(declaration_scope, type_)?;
+        let converted = <&Expression<'a>>::from_ast(scope, expr, expected)?;
+        if let Some(converted_type) = converted.get_type() {
+            bindings.bind(declaration_scope, type_, &converted_type, &value.span)?;
+        }
+        arguments.push(converted);
+    }
+
+    Ok((arguments, bindings))
+}
+
 impl<'a> Into<leo_ast::CallExpression> for &CallExpression<'a> {
     fn into(self) -> leo_ast::CallExpression {
         let target_function = if let Some(target) = self.target.get() {
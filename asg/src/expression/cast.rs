@@ -14,7 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AsgConvertError, ConstValue, Expression, ExpressionNode, FromAst, Node, PartialType, Scope, Span, Type};
+use crate::{
+    is_small_field,
+    AsgConvertError,
+    ConstValue,
+    Expression,
+    ExpressionNode,
+    FromAst,
+    Node,
+    PartialType,
+    Scope,
+    Span,
+    Type,
+};
 pub use leo_ast::UnaryOperation;
 
 use std::cell::Cell;
@@ -25,6 +37,8 @@ pub struct CastExpression<'a> {
     pub span: Option<Span>,
     pub inner: Cell<&'a Expression<'a>>,
     pub target_type: Type<'a>,
+    /// Whether the cast keeps the low bits of the value instead of requiring the value to fit.
+    pub wrapping: bool,
 }
 
 impl<'a> Node for CastExpression<'a> {
@@ -56,12 +70,10 @@ impl<'a> ExpressionNode<'a> for CastExpression<'a> {
 
     fn const_value(&self) -> Option<ConstValue> {
         let value = self.inner.get().const_value()?;
-        match value {
-            ConstValue::Int(int) => match &self.target_type {
-                Type::Integer(target) => Some(ConstValue::Int(int.cast_to(target))),
-                _ => None,
-            },
-            _ => None,
+        if self.wrapping {
+            value.wrapping_cast_to(&self.target_type)
+        } else {
+            value.cast_to(&self.target_type)
         }
     }
 
@@ -87,14 +99,65 @@ impl<'a> FromAst<'a, leo_ast::CastExpression> for CastExpression<'a> {
             }
         }
 
-        let inner = <&Expression<'a>>::from_ast(scope, &*value.inner, None)?;
+        // Untyped literals take the type they are cast to.
+        let inner_type = match (&*value.inner, &target_type) {
+            (leo_ast::Expression::Value(leo_ast::ValueExpression::Implicit(..)), Type::Integer(target)) => {
+                Some(PartialType::Integer(None, Some(target.clone())))
+            }
+            (leo_ast::Expression::Value(leo_ast::ValueExpression::Implicit(..)), Type::Field) => {
+                Some(PartialType::Type(Type::Field))
+            }
+            _ => None,
+        };
+        let inner = <&Expression<'a>>::from_ast(scope, &*value.inner, inner_type)?;
+
+        match inner.get_type() {
+            Some(type_) if value.wrapping && !type_.can_wrapping_cast_to(&target_type) => {
+                return Err(AsgConvertError::invalid_wrapping_cast(
+                    &type_.to_string(),
+                    &target_type.to_string(),
+                    &value.span,
+                ));
+            }
+            Some(type_) if type_.can_cast_to(&target_type) => (),
+            type_ => {
+                return Err(AsgConvertError::invalid_cast(
+                    &type_
+                        .map(|type_| type_.to_string())
+                        .unwrap_or_else(|| "unknown".to_string()),
+                    &target_type.to_string(),
+                    &value.span,
+                ));
+            }
+        }
 
-        Ok(CastExpression {
+        let cast = CastExpression {
             parent: Cell::new(None),
             span: Some(value.span.clone()),
             inner: Cell::new(inner),
             target_type,
-        })
+            wrapping: value.wrapping,
+        };
+
+        // Constants are checked right away, arrays and field constants that still need reducing are left to the circuit.
+        // Wrapping casts never fail, those of constants that are not folded here are evaluated by the circuit.
+        if let (Some(inner_value), None, false) = (inner.const_value(), cast.const_value(), cast.wrapping) {
+            let out_of_range = match &inner_value {
+                ConstValue::Int(int) => Some(int.raw_value()),
+                ConstValue::Char(char) => Some(char.code().to_string()),
+                ConstValue::Field(field) if is_small_field(field) => Some(field.to_string()),
+                _ => None,
+            };
+            if let Some(inner_value) = out_of_range {
+                return Err(AsgConvertError::cast_out_of_range(
+                    &inner_value,
+                    &cast.target_type.to_string(),
+                    &value.span,
+                ));
+            }
+        }
+
+        Ok(cast)
     }
 }
 
@@ -103,6 +166,7 @@ impl<'a> Into<leo_ast::CastExpression> for &CastExpression<'a> {
         leo_ast::CastExpression {
             target_type: (&self.target_type).into(),
             inner: Box::new(self.inner.get().into()),
+            wrapping: self.wrapping,
             span: self.span.clone().unwrap_or_default(),
         }
     }
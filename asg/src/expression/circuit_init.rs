@@ -18,6 +18,7 @@ use crate::{
     AsgConvertError,
     Circuit,
     CircuitMember,
+    ConstBindings,
     ConstValue,
     Expression,
     ExpressionNode,
@@ -85,9 +86,46 @@ impl<'a> FromAst<'a, leo_ast::CircuitInitExpression> for CircuitInitExpression<'
         value: &leo_ast::CircuitInitExpression,
         expected_type: Option<PartialType<'a>>,
     ) -> Result<CircuitInitExpression<'a>, AsgConvertError> {
-        let circuit = scope
+        let mut circuit = scope
             .resolve_circuit(&value.name.name)
             .ok_or_else(|| AsgConvertError::unresolved_circuit(&value.name.name, &value.name.span))?;
+
+        // The values of a generic circuit are converted while inferring its const arguments.
+        let mut converted: IndexMap<&str, &'a Expression<'a>> = IndexMap::new();
+        if let Some(template) = &circuit.template {
+            let expected_instance = match &expected_type {
+                Some(PartialType::Type(Type::Circuit(expected_circuit))) => {
+                    circuit.instance_arguments(expected_circuit).map(|_| *expected_circuit)
+                }
+                _ => None,
+            };
+            circuit = match expected_instance {
+                Some(instance) => instance,
+                None => {
+                    let mut bindings = ConstBindings::new(template.const_parameters.clone());
+                    for member in template.members.iter() {
+                        if let leo_ast::CircuitMember::CircuitVariable(name, type_) = member {
+                            let member = value.members.iter().find(|x| x.identifier.name == name.name);
+                            if let Some(member) = member {
+                                let receiver = member
+                                    .expression
+                                    .clone()
+                                    .unwrap_or_else(|| leo_ast::Expression::Identifier(member.identifier.clone()));
+                                let expected = bindings.expected_type(circuit.scope, type_)?;
+                                let received = <&Expression<'a>>::from_ast(scope, &receiver, expected)?;
+                                if let Some(received_type) = received.get_type() {
+                                    bindings.bind(circuit.scope, type_, &received_type, &value.span)?;
+                                }
+                                converted.insert(member.identifier.name.as_ref(), received);
+                            }
+                        }
+                    }
+                    let arguments =
+                        bindings.arguments(&template.const_parameters, &template.circuit_name.name, &value.span)?;
+                    circuit.instantiate(arguments, &value.span)?
+                }
+            };
+        }
         match expected_type {
             Some(PartialType::Type(Type::Circuit(expected_circuit))) if expected_circuit == circuit => (),
             None => (),
@@ -125,7 +163,17 @@ impl<'a> FromAst<'a, leo_ast::CircuitInitExpression> for CircuitInitExpression<'
                     continue;
                 };
                 if let Some((identifier, receiver)) = members.get(&**name) {
-                    let received = if let Some(receiver) = *receiver {
+                    let received = if let Some(received) = converted.get(&**name) {
+                        let received_type = received.get_type();
+                        if received_type.as_ref() != Some(&type_) {
+                            return Err(AsgConvertError::unexpected_type(
+                                &type_.to_string(),
+                                received_type.map(|x| x.to_string()).as_deref(),
+                                &identifier.span,
+                            ));
+                        }
+                        *received
+                    } else if let Some(receiver) = *receiver {
                         <&Expression<'a>>::from_ast(scope, receiver, Some(type_.partial()))?
                     } else {
                         <&Expression<'a>>::from_ast(
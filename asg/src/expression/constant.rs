@@ -36,7 +36,7 @@ use std::cell::Cell;
 pub struct Constant<'a> {
     pub parent: Cell<Option<&'a Expression<'a>>>,
     pub span: Option<Span>,
-    pub value: ConstValue, // should not be compound constants
+    pub value: ConstValue, // the only compound constants are strings
 }
 
 impl<'a> Node for Constant<'a> {
@@ -221,8 +221,28 @@ impl<'a> FromAst<'a, leo_ast::ValueExpression> for Constant<'a> {
                     value: ConstValue::Int(ConstInt::parse(int_type, value, span)?),
                 }
             }
-            String(_str_type, _value) => {
-                unimplemented!("strings do not exist on ASG level")
+            String(value, span) => {
+                let type_ = Type::Array(Box::new(Type::Char), value.len());
+                match expected_type {
+                    Some(expected) if !expected.matches(&type_) => {
+                        return Err(AsgConvertError::unexpected_type(
+                            &expected.to_string(),
+                            Some(&*type_.to_string()),
+                            span,
+                        ));
+                    }
+                    _ => (),
+                }
+                Constant {
+                    parent: Cell::new(None),
+                    span: Some(span.clone()),
+                    value: ConstValue::Array(
+                        value
+                            .iter()
+                            .map(|character| ConstValue::Char(character.clone().into()))
+                            .collect(),
+                    ),
+                }
             }
         })
     }
@@ -266,7 +286,16 @@ impl<'a> Into<leo_ast::ValueExpression> for &Constant<'a> {
                 self.span.clone().unwrap_or_default(),
             ),
             ConstValue::Tuple(_) => unimplemented!(),
-            ConstValue::Array(_) => unimplemented!(),
+            ConstValue::Array(items) => leo_ast::ValueExpression::String(
+                items
+                    .iter()
+                    .map(|item| match item {
+                        ConstValue::Char(character) => character.into(),
+                        _ => unimplemented!("only strings are array constants on AST level"),
+                    })
+                    .collect(),
+                self.span.clone().unwrap_or_default(),
+            ),
         }
     }
 }
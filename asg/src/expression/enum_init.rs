@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    AsgConvertError,
+    ConstValue,
+    Enum,
+    Expression,
+    ExpressionNode,
+    FromAst,
+    Identifier,
+    Node,
+    PartialType,
+    Scope,
+    Span,
+    Type,
+};
+
+use std::cell::Cell;
+
+#[derive(Clone)]
+pub struct EnumInitExpression<'a> {
+    pub parent: Cell<Option<&'a Expression<'a>>>,
+    pub span: Option<Span>,
+    pub enum_: Cell<&'a Enum<'a>>,
+    pub variant: Identifier,
+    pub values: Vec<Cell<&'a Expression<'a>>>,
+}
+
+impl<'a> EnumInitExpression<'a> {
+    /// Returns the tag of the initialized variant.
+    pub fn variant_index(&self) -> usize {
+        self.enum_
+            .get()
+            .variant_index(&self.variant.name)
+            .expect("enum initialized with an unresolved variant")
+    }
+}
+
+impl<'a> Node for EnumInitExpression<'a> {
+    fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+}
+
+impl<'a> ExpressionNode<'a> for EnumInitExpression<'a> {
+    fn set_parent(&self, parent: &'a Expression<'a>) {
+        self.parent.replace(Some(parent));
+    }
+
+    fn get_parent(&self) -> Option<&'a Expression<'a>> {
+        self.parent.get()
+    }
+
+    fn enforce_parents(&self, expr: &'a Expression<'a>) {
+        self.values.iter().for_each(|element| {
+            element.get().set_parent(expr);
+        })
+    }
+
+    fn get_type(&self) -> Option<Type<'a>> {
+        Some(Type::Enum(self.enum_.get()))
+    }
+
+    fn is_mut_ref(&self) -> bool {
+        true
+    }
+
+    fn const_value(&self) -> Option<ConstValue> {
+        None
+    }
+
+    fn is_consty(&self) -> bool {
+        self.values.iter().all(|value| value.get().is_consty())
+    }
+}
+
+///
+/// Resolves `Enum::Variant` or `Enum::Variant(values)` to the initialization of an enum variant.
+///
+/// Returns `None` if `access` does not name an enum, or if a circuit shadows the enum name.
+///
+pub(crate) fn enum_init_from_ast<'a>(
+    scope: &'a Scope<'a>,
+    access: &leo_ast::CircuitStaticFunctionAccessExpression,
+    arguments: &[leo_ast::Expression],
+    span: &Span,
+    expected_type: Option<PartialType<'a>>,
+) -> Result<Option<&'a Expression<'a>>, AsgConvertError> {
+    let enum_ = match &*access.circuit {
+        leo_ast::Expression::Identifier(name) if scope.resolve_circuit(&name.name).is_none() => {
+            match scope.resolve_enum(&name.name) {
+                Some(enum_) => enum_,
+                None => return Ok(None),
+            }
+        }
+        _ => return Ok(None),
+    };
+
+    match expected_type {
+        Some(PartialType::Type(Type::Enum(expected_enum))) if expected_enum == enum_ => (),
+        None => (),
+        Some(x) => {
+            return Err(AsgConvertError::unexpected_type(
+                &x.to_string(),
+                Some(&enum_.name.borrow().name),
+                span,
+            ));
+        }
+    }
+
+    let payload = match enum_.variants.borrow().get(access.name.name.as_ref()) {
+        Some(variant) => variant.payload.clone(),
+        None => {
+            return Err(AsgConvertError::unresolved_enum_variant(
+                &enum_.name.borrow().name,
+                &access.name.name,
+                &access.name.span,
+            ));
+        }
+    };
+    if arguments.len() != payload.len() {
+        return Err(AsgConvertError::unexpected_enum_payload_count(
+            &access.name.name,
+            payload.len(),
+            arguments.len(),
+            span,
+        ));
+    }
+
+    let values = arguments
+        .iter()
+        .zip(payload)
+        .map(|(argument, type_)| {
+            Ok(Cell::new(<&Expression<'a>>::from_ast(
+                scope,
+                argument,
+                Some(type_.partial()),
+            )?))
+        })
+        .collect::<Result<Vec<_>, AsgConvertError>>()?;
+
+    Ok(Some(scope.context.alloc_expression(Expression::EnumInit(
+        EnumInitExpression {
+            parent: Cell::new(None),
+            span: Some(span.clone()),
+            enum_: Cell::new(enum_),
+            variant: access.name.clone(),
+            values,
+        },
+    ))))
+}
+
+impl<'a> Into<leo_ast::Expression> for &EnumInitExpression<'a> {
+    fn into(self) -> leo_ast::Expression {
+        let span = self.span.clone().unwrap_or_default();
+        let access = leo_ast::Expression::CircuitStaticFunctionAccess(leo_ast::CircuitStaticFunctionAccessExpression {
+            circuit: Box::new(leo_ast::Expression::Identifier(self.enum_.get().name.borrow().clone())),
+            name: self.variant.clone(),
+            span: span.clone(),
+        });
+        if self.values.is_empty() {
+            return access;
+        }
+        leo_ast::Expression::Call(leo_ast::CallExpression {
+            function: Box::new(access),
+            arguments: self.values.iter().map(|value| value.get().into()).collect(),
+            span,
+        })
+    }
+}
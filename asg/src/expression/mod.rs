@@ -44,6 +44,9 @@ pub use circuit_access::*;
 mod circuit_init;
 pub use circuit_init::*;
 
+mod enum_init;
+pub use enum_init::*;
+
 mod constant;
 pub use constant::*;
 
@@ -65,6 +68,9 @@ pub use variable_ref::*;
 mod cast;
 pub use cast::*;
 
+mod string;
+pub use string::*;
+
 use crate::{AsgConvertError, ConstValue, FromAst, Node, PartialType, Scope, Span, Type};
 
 #[derive(Clone)]
@@ -87,6 +93,8 @@ pub enum Expression<'a> {
     CircuitInit(CircuitInitExpression<'a>),
     CircuitAccess(CircuitAccessExpression<'a>),
 
+    EnumInit(EnumInitExpression<'a>),
+
     Call(CallExpression<'a>),
 }
 
@@ -114,6 +122,7 @@ impl<'a> Node for Expression<'a> {
             TupleAccess(x) => x.span(),
             CircuitInit(x) => x.span(),
             CircuitAccess(x) => x.span(),
+            EnumInit(x) => x.span(),
             Call(x) => x.span(),
         }
     }
@@ -148,6 +157,7 @@ impl<'a> ExpressionNode<'a> for Expression<'a> {
             TupleAccess(x) => x.set_parent(parent),
             CircuitInit(x) => x.set_parent(parent),
             CircuitAccess(x) => x.set_parent(parent),
+            EnumInit(x) => x.set_parent(parent),
             Call(x) => x.set_parent(parent),
         }
     }
@@ -169,6 +179,7 @@ impl<'a> ExpressionNode<'a> for Expression<'a> {
             TupleAccess(x) => x.get_parent(),
             CircuitInit(x) => x.get_parent(),
             CircuitAccess(x) => x.get_parent(),
+            EnumInit(x) => x.get_parent(),
             Call(x) => x.get_parent(),
         }
     }
@@ -190,6 +201,7 @@ impl<'a> ExpressionNode<'a> for Expression<'a> {
             TupleAccess(x) => x.enforce_parents(expr),
             CircuitInit(x) => x.enforce_parents(expr),
             CircuitAccess(x) => x.enforce_parents(expr),
+            EnumInit(x) => x.enforce_parents(expr),
             Call(x) => x.enforce_parents(expr),
         }
     }
@@ -211,6 +223,7 @@ impl<'a> ExpressionNode<'a> for Expression<'a> {
             TupleAccess(x) => x.get_type(),
             CircuitInit(x) => x.get_type(),
             CircuitAccess(x) => x.get_type(),
+            EnumInit(x) => x.get_type(),
             Call(x) => x.get_type(),
         }
     }
@@ -232,6 +245,7 @@ impl<'a> ExpressionNode<'a> for Expression<'a> {
             TupleAccess(x) => x.is_mut_ref(),
             CircuitInit(x) => x.is_mut_ref(),
             CircuitAccess(x) => x.is_mut_ref(),
+            EnumInit(x) => x.is_mut_ref(),
             Call(x) => x.is_mut_ref(),
         }
     }
@@ -253,6 +267,7 @@ impl<'a> ExpressionNode<'a> for Expression<'a> {
             TupleAccess(x) => x.const_value(),
             CircuitInit(x) => x.const_value(),
             CircuitAccess(x) => x.const_value(),
+            EnumInit(x) => x.const_value(),
             Call(x) => x.const_value(),
         }
     }
@@ -274,6 +289,7 @@ impl<'a> ExpressionNode<'a> for Expression<'a> {
             TupleAccess(x) => x.is_consty(),
             CircuitInit(x) => x.is_consty(),
             CircuitAccess(x) => x.is_consty(),
+            EnumInit(x) => x.is_consty(),
             Call(x) => x.is_consty(),
         }
     }
@@ -332,14 +348,32 @@ impl<'a> FromAst<'a, leo_ast::Expression> for &'a Expression<'a> {
                 CircuitAccessExpression::from_ast(scope, circuit_member, expected_type)
                     .map(Expression::CircuitAccess)?,
             ),
-            CircuitStaticFunctionAccess(circuit_member) => scope.context.alloc_expression(
-                CircuitAccessExpression::from_ast(scope, circuit_member, expected_type)
-                    .map(Expression::CircuitAccess)?,
-            ),
-
-            Call(call) => scope
-                .context
-                .alloc_expression(CallExpression::from_ast(scope, call, expected_type).map(Expression::Call)?),
+            CircuitStaticFunctionAccess(circuit_member) => {
+                match enum_init_from_ast(scope, circuit_member, &[], &circuit_member.span, expected_type.clone())? {
+                    Some(expression) => expression,
+                    None => scope.context.alloc_expression(
+                        CircuitAccessExpression::from_ast(scope, circuit_member, expected_type)
+                            .map(Expression::CircuitAccess)?,
+                    ),
+                }
+            }
+
+            Call(call) => match string_function_from_ast(scope, call, expected_type.clone())? {
+                Some(expression) => expression,
+                None => match &*call.function {
+                    CircuitStaticFunctionAccess(access) => {
+                        match enum_init_from_ast(scope, access, &call.arguments, &call.span, expected_type.clone())? {
+                            Some(expression) => expression,
+                            None => scope.context.alloc_expression(
+                                CallExpression::from_ast(scope, call, expected_type).map(Expression::Call)?,
+                            ),
+                        }
+                    }
+                    _ => scope
+                        .context
+                        .alloc_expression(CallExpression::from_ast(scope, call, expected_type).map(Expression::Call)?),
+                },
+            },
         };
         expression.enforce_parents(&expression);
         Ok(expression)
@@ -364,6 +398,7 @@ impl<'a> Into<leo_ast::Expression> for &Expression<'a> {
             TupleAccess(x) => leo_ast::Expression::TupleAccess(x.into()),
             CircuitInit(x) => leo_ast::Expression::CircuitInit(x.into()),
             CircuitAccess(x) => x.into(),
+            EnumInit(x) => x.into(),
             Call(x) => leo_ast::Expression::Call(x.into()),
         }
     }
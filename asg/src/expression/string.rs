@@ -0,0 +1,185 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Built-in string functions, the static members of the `String` circuit type of RFC 001.
+//!
+//! Strings are arrays of characters `[char; N]`, so each function call resolves to an existing expression.
+
+use crate::{
+    ArrayInlineExpression,
+    AsgConvertError,
+    CastExpression,
+    ConstInt,
+    ConstValue,
+    Constant,
+    Expression,
+    ExpressionNode,
+    FromAst,
+    IntegerType,
+    PartialType,
+    Scope,
+    Type,
+};
+
+use leo_ast::Node as AstNode;
+
+use std::cell::Cell;
+
+/// The name of the built-in circuit type of the string functions.
+pub const STRING_CIRCUIT: &str = "String";
+
+/// The functions of the built-in `String` circuit type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StringFunction {
+    /// `String::len(s: [char; N]) -> u32`
+    Len,
+    /// `String::concat(a: [char; N], b: [char; M]) -> [char; N + M]`
+    Concat,
+    /// `String::to_bytes(s: [char; N]) -> [u8; N]`, every character must be below 256.
+    ToBytes,
+    /// `String::from_bytes(bytes: [u8; N]) -> [char; N]`
+    FromBytes,
+}
+
+impl StringFunction {
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "len" => StringFunction::Len,
+            "concat" => StringFunction::Concat,
+            "to_bytes" => StringFunction::ToBytes,
+            "from_bytes" => StringFunction::FromBytes,
+            _ => return None,
+        })
+    }
+
+    fn argument_count(self) -> usize {
+        match self {
+            StringFunction::Concat => 2,
+            _ => 1,
+        }
+    }
+
+    fn argument_type<'a>(self) -> PartialType<'a> {
+        let item = match self {
+            StringFunction::FromBytes => PartialType::Integer(Some(IntegerType::U8), None),
+            _ => PartialType::Type(Type::Char),
+        };
+        PartialType::Array(Some(Box::new(item)), None)
+    }
+}
+
+///
+/// Resolves a call of a `String` function to the expression computing it.
+///
+/// Returns `None` if the call is not a `String` function call, or if the program declares its own `String` circuit.
+///
+pub(crate) fn string_function_from_ast<'a>(
+    scope: &'a Scope<'a>,
+    value: &leo_ast::CallExpression,
+    expected_type: Option<PartialType<'a>>,
+) -> Result<Option<&'a Expression<'a>>, AsgConvertError> {
+    let name = match &*value.function {
+        leo_ast::Expression::CircuitStaticFunctionAccess(leo_ast::CircuitStaticFunctionAccessExpression {
+            circuit,
+            name,
+            ..
+        }) => match &**circuit {
+            leo_ast::Expression::Identifier(circuit)
+                if circuit.name.as_ref() == STRING_CIRCUIT && scope.resolve_circuit(STRING_CIRCUIT).is_none() =>
+            {
+                name
+            }
+            _ => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+
+    let function = StringFunction::from_name(&name.name)
+        .ok_or_else(|| AsgConvertError::unresolved_circuit_member(STRING_CIRCUIT, &name.name, &name.span))?;
+    if value.arguments.len() != function.argument_count() {
+        return Err(AsgConvertError::unexpected_call_argument_count(
+            function.argument_count(),
+            value.arguments.len(),
+            &value.span,
+        ));
+    }
+
+    let arguments = value
+        .arguments
+        .iter()
+        .map(|argument| {
+            let expression = <&Expression<'a>>::from_ast(scope, argument, Some(function.argument_type()))?;
+            let type_ = expression.get_type();
+            match type_.as_ref().map(|type_| (type_, type_.string_len())) {
+                Some((_, Some(len))) if function != StringFunction::FromBytes => Ok((expression, len)),
+                Some((Type::Array(item, len), _))
+                    if function == StringFunction::FromBytes && **item == Type::Integer(IntegerType::U8) =>
+                {
+                    Ok((expression, *len))
+                }
+                _ => Err(AsgConvertError::unexpected_type(
+                    &function.argument_type().to_string(),
+                    type_.map(|type_| type_.to_string()).as_deref(),
+                    argument.span(),
+                )),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let span = Some(value.span.clone());
+    let (first, len) = arguments[0];
+    let expression = match function {
+        StringFunction::Len => Expression::Constant(Constant {
+            parent: Cell::new(None),
+            span,
+            value: ConstValue::Int(ConstInt::U32(len as u32)),
+        }),
+        StringFunction::Concat => Expression::ArrayInline(ArrayInlineExpression {
+            parent: Cell::new(None),
+            span,
+            elements: arguments
+                .iter()
+                .map(|(argument, _)| (Cell::new(*argument), true))
+                .collect(),
+        }),
+        StringFunction::ToBytes | StringFunction::FromBytes => {
+            let item = if function == StringFunction::ToBytes {
+                Type::Integer(IntegerType::U8)
+            } else {
+                Type::Char
+            };
+            Expression::Cast(CastExpression {
+                parent: Cell::new(None),
+                span,
+                inner: Cell::new(first),
+                target_type: Type::Array(Box::new(item), len),
+                wrapping: false,
+            })
+        }
+    };
+
+    if let (Some(expected), Some(type_)) = (expected_type, expression.get_type()) {
+        if !expected.matches(&type_) {
+            return Err(AsgConvertError::unexpected_type(
+                &expected.to_string(),
+                Some(&*type_.to_string()),
+                &value.span,
+            ));
+        }
+    }
+
+    Ok(Some(scope.context.alloc_expression(expression)))
+}
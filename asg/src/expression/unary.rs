@@ -140,6 +140,18 @@ impl<'a> FromAst<'a, leo_ast::UnaryExpression> for UnaryExpression<'a> {
                 return Err(AsgConvertError::unsigned_negation(&value.span));
             }
         }
+        if matches!(value.op, UnaryOperation::BitNot) {
+            match expr.get_type() {
+                Some(Type::Integer(_)) | None => (),
+                Some(type_) => {
+                    return Err(AsgConvertError::unexpected_type(
+                        "integer",
+                        Some(&*type_.to_string()),
+                        &value.span,
+                    ));
+                }
+            }
+        }
         Ok(UnaryExpression {
             parent: Cell::new(None),
             span: Some(value.span.clone()),
@@ -16,12 +16,14 @@
 
 use crate::{
     AsgConvertError,
+    ConstInt,
     ConstValue,
     Constant,
     DefinitionStatement,
     Expression,
     ExpressionNode,
     FromAst,
+    IntegerType,
     Node,
     PartialType,
     Scope,
@@ -125,6 +127,7 @@ impl<'a> ExpressionNode<'a> for VariableRef<'a> {
                 }
             }
             Statement::Iteration(_) => true,
+            Statement::Match(statement) => statement.value.get().is_consty(),
             _ => false,
         }
     }
@@ -148,6 +151,23 @@ impl<'a> FromAst<'a, leo_ast::Identifier> for &'a Expression<'a> {
             match scope.resolve_variable(&value.name) {
                 Some(v) => v,
                 None => {
+                    if let Some(const_value) = scope.resolve_const_parameter(&value.name) {
+                        let type_ = Type::Integer(IntegerType::U32);
+                        if let Some(expected_type) = expected_type {
+                            if !expected_type.matches(&type_) {
+                                return Err(AsgConvertError::unexpected_type(
+                                    &expected_type.to_string(),
+                                    Some(&*type_.to_string()),
+                                    &value.span,
+                                ));
+                            }
+                        }
+                        return Ok(scope.context.alloc_expression(Expression::Constant(Constant {
+                            parent: Cell::new(None),
+                            span: Some(value.span.clone()),
+                            value: ConstValue::Int(ConstInt::U32(const_value)),
+                        })));
+                    }
                     if value.name.starts_with("aleo1") {
                         return Ok(scope.context.alloc_expression(Expression::Constant(Constant {
                             parent: Cell::new(None),
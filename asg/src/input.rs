@@ -45,6 +45,8 @@ impl<'a> Input<'a> {
             core_mapping: RefCell::new(None),
             scope,
             span: Default::default(),
+            template: None,
+            instances: RefCell::new(IndexMap::new()),
         })
     }
 
@@ -74,6 +76,8 @@ impl<'a> Input<'a> {
             core_mapping: RefCell::new(None),
             scope: input_scope,
             span: Default::default(),
+            template: None,
+            instances: RefCell::new(IndexMap::new()),
         });
 
         Input {
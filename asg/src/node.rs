@@ -18,6 +18,7 @@ use crate::{
     AsgContextInner,
     AsgConvertError,
     Circuit,
+    Enum,
     Expression,
     Function,
     PartialType,
@@ -48,6 +49,7 @@ pub enum ArenaNode<'a> {
     Statement(Statement<'a>),
     Variable(Variable<'a>),
     Circuit(Circuit<'a>),
+    Enum(Enum<'a>),
     Function(Function<'a>),
     Inner(AsgContextInner<'a>),
 }
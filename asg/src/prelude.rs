@@ -14,30 +14,268 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-// TODO (protryon): We should merge this with core
+//! The circuits of the `core` library, declared by their Leo signatures.
 
-use crate::{AsgContext, AsgConvertError, Program};
+use crate::{
+    AsgContext,
+    AsgConvertError,
+    Circuit,
+    CircuitMember,
+    Function,
+    FunctionQualifier,
+    Identifier,
+    InnerVariable,
+    Program,
+    VariableDeclaration,
+};
+use leo_ast::{ArrayDimensions, Dimension, IntegerType, PositiveNumber, Type as AstType};
 
-// TODO (protryon): Make asg deep copy so we can cache resolved core modules
-// TODO (protryon): Figure out how to do headers without bogus returns
+use indexmap::IndexMap;
+use std::cell::{Cell, RefCell};
 
-pub fn resolve_core_module<'a>(context: AsgContext<'a>, module: &str) -> Result<Option<Program<'a>>, AsgConvertError> {
-    match module {
-        "unstable.blake2s" => {
-            let asg = crate::load_asg(
-                context,
-                r#"
-                circuit Blake2s {
-                    function hash(seed: [u8; 32], message: [u8; 32]) -> [u8; 32] {
-                        return [0; 32];
-                    }
-                }
-                "#,
-                &mut crate::NullImportResolver,
-            )?;
-            asg.set_core_mapping("blake2s");
-            Ok(Some(asg))
+/// An argument of a core circuit function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoreArgument {
+    pub name: String,
+    pub type_: AstType,
+    pub const_: bool,
+}
+
+/// The signature of a core circuit function, the compiler provides its implementation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoreFunction {
+    pub name: String,
+    pub arguments: Vec<CoreArgument>,
+    pub output: AstType,
+}
+
+impl CoreFunction {
+    pub fn new(name: &str, output: AstType) -> Self {
+        Self {
+            name: name.to_string(),
+            arguments: vec![],
+            output,
+        }
+    }
+
+    /// Appends an argument that takes any value of the given type.
+    pub fn argument(mut self, name: &str, type_: AstType) -> Self {
+        self.arguments.push(CoreArgument {
+            name: name.to_string(),
+            type_,
+            const_: false,
+        });
+        self
+    }
+
+    /// Appends an argument that only takes values known at compile time.
+    pub fn const_argument(mut self, name: &str, type_: AstType) -> Self {
+        self.arguments.push(CoreArgument {
+            name: name.to_string(),
+            type_,
+            const_: true,
+        });
+        self
+    }
+}
+
+///
+/// A circuit of the `core` library.
+///
+/// The circuit is imported as `core.<module>.<name>`, and calls to its functions are resolved by the
+/// compiler through its `mapping`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoreCircuitDeclaration {
+    pub module: String,
+    pub name: String,
+    pub mapping: String,
+    pub functions: Vec<CoreFunction>,
+}
+
+impl CoreCircuitDeclaration {
+    pub fn new(module: &str, name: &str, mapping: &str) -> Self {
+        Self {
+            module: module.to_string(),
+            name: name.to_string(),
+            mapping: mapping.to_string(),
+            functions: vec![],
+        }
+    }
+
+    /// Appends a static function to the circuit.
+    pub fn function(mut self, function: CoreFunction) -> Self {
+        self.functions.push(function);
+        self
+    }
+
+    pub fn blake2s() -> Self {
+        Self::new("unstable.blake2s", "Blake2s", "blake2s").function(
+            CoreFunction::new("hash", array_type(u8_type(), 32))
+                .argument("seed", array_type(u8_type(), 32))
+                .argument("message", array_type(u8_type(), 32)),
+        )
+    }
+
+    pub fn pedersen() -> Self {
+        Self::new("unstable.pedersen", "Pedersen", "pedersen")
+            .function(CoreFunction::new("hash", AstType::Group).argument("message", array_type(AstType::Boolean, 256)))
+            .function(
+                CoreFunction::new("commit", AstType::Group)
+                    .argument("message", array_type(AstType::Boolean, 256))
+                    .argument("randomness", array_type(AstType::Boolean, 256)),
+            )
+    }
+
+    pub fn poseidon() -> Self {
+        Self::new("unstable.poseidon", "Poseidon", "poseidon")
+            .function(CoreFunction::new("hash", AstType::Field).argument("message", array_type(AstType::Field, 2)))
+    }
+
+    pub fn sha256() -> Self {
+        Self::new("unstable.sha256", "Sha256", "sha256").function(
+            CoreFunction::new("hash", array_type(u8_type(), 32)).argument("message", array_type(u8_type(), 32)),
+        )
+    }
+
+    /// Returns a program that only declares the circuit, its functions have no bodies.
+    fn load<'a>(&self, context: AsgContext<'a>) -> Result<Program<'a>, AsgConvertError> {
+        let mut program = Program::new(
+            context,
+            &leo_ast::Program::new(format!("core.{}", self.module)),
+            &mut crate::NullImportResolver,
+        )?;
+        let scope = program.scope.make_subscope();
+
+        let circuit = context.alloc_circuit(Circuit {
+            id: context.get_id(),
+            name: RefCell::new(Identifier::new(self.name.as_str().into())),
+            core_mapping: RefCell::new(Some(self.mapping.clone())),
+            scope,
+            span: None,
+            members: RefCell::new(IndexMap::new()),
+            template: None,
+            instances: RefCell::new(IndexMap::new()),
+        });
+        scope.circuit_self.replace(Some(circuit));
+
+        for declared in self.functions.iter() {
+            let function_scope = scope.make_subscope();
+            let mut arguments = IndexMap::new();
+            for argument in declared.arguments.iter() {
+                let variable = context.alloc_variable(RefCell::new(InnerVariable {
+                    id: context.get_id(),
+                    name: Identifier::new(argument.name.as_str().into()),
+                    type_: scope.resolve_ast_type(&argument.type_)?,
+                    mutable: false,
+                    const_: argument.const_,
+                    declaration: VariableDeclaration::Parameter,
+                    references: vec![],
+                    assignments: vec![],
+                }));
+                arguments.insert(argument.name.clone(), Cell::new(variable));
+            }
+
+            let function = context.alloc_function(Function {
+                id: context.get_id(),
+                name: RefCell::new(Identifier::new(declared.name.as_str().into())),
+                output: scope.resolve_ast_type(&declared.output)?,
+                arguments,
+                circuit: Cell::new(Some(circuit)),
+                span: None,
+                body: Cell::new(None),
+                scope: function_scope,
+                qualifier: FunctionQualifier::Static,
+                annotations: vec![],
+                template: None,
+                instances: RefCell::new(IndexMap::new()),
+            });
+            function_scope.function.replace(Some(function));
+
+            circuit
+                .members
+                .borrow_mut()
+                .insert(declared.name.clone(), CircuitMember::Function(function));
         }
-        _ => Ok(None),
+
+        program.scope.circuits.borrow_mut().insert(self.name.clone(), circuit);
+        program.circuits.insert(self.name.clone(), circuit);
+
+        Ok(program)
+    }
+}
+
+fn u8_type() -> AstType {
+    AstType::IntegerType(IntegerType::U8)
+}
+
+/// Returns the type of an array with the given length.
+pub fn array_type(element: AstType, length: usize) -> AstType {
+    AstType::Array(
+        Box::new(element),
+        ArrayDimensions(vec![Dimension::Number(PositiveNumber {
+            value: length.to_string().into(),
+        })]),
+    )
+}
+
+///
+/// The circuits that can be imported from `core`, by module.
+///
+/// The default registry declares the circuits implemented by the compiler.
+///
+#[derive(Clone, Debug)]
+pub struct CoreModules {
+    modules: IndexMap<String, CoreCircuitDeclaration>,
+}
+
+impl Default for CoreModules {
+    fn default() -> Self {
+        let mut core_modules = Self {
+            modules: IndexMap::new(),
+        };
+        core_modules.register(CoreCircuitDeclaration::blake2s());
+        core_modules.register(CoreCircuitDeclaration::pedersen());
+        core_modules.register(CoreCircuitDeclaration::poseidon());
+        core_modules.register(CoreCircuitDeclaration::sha256());
+        core_modules
+    }
+}
+
+impl CoreModules {
+    /// Declares a circuit, replacing any circuit previously declared in the same module.
+    pub fn register(&mut self, declaration: CoreCircuitDeclaration) {
+        self.modules.insert(declaration.module.clone(), declaration);
+    }
+
+    pub fn get(&self, module: &str) -> Option<&CoreCircuitDeclaration> {
+        self.modules.get(module)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &CoreCircuitDeclaration> {
+        self.modules.values()
     }
 }
+
+///
+/// Returns the program of a core module, or `None` if no circuit is declared in it.
+///
+/// A module is loaded once per context and shared by every program that imports it.
+///
+pub fn resolve_core_module<'a>(context: AsgContext<'a>, module: &str) -> Result<Option<Program<'a>>, AsgConvertError> {
+    if let Some(program) = context.core_programs.borrow().get(module) {
+        return Ok(Some(program.clone()));
+    }
+
+    let declaration = match context.core_modules.borrow().get(module) {
+        Some(declaration) => declaration.clone(),
+        None => return Ok(None),
+    };
+    let program = declaration.load(context)?;
+    context
+        .core_programs
+        .borrow_mut()
+        .insert(module.to_string(), program.clone());
+
+    Ok(Some(program))
+}
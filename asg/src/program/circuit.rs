@@ -14,7 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AsgConvertError, Function, Identifier, Node, Scope, Span, Type};
+use crate::{
+    check_const_parameters,
+    instance_name,
+    instance_scope,
+    AsgConvertError,
+    Function,
+    Identifier,
+    Node,
+    Scope,
+    Span,
+    Type,
+};
 
 use indexmap::IndexMap;
 use std::cell::RefCell;
@@ -33,6 +44,12 @@ pub struct Circuit<'a> {
     pub scope: &'a Scope<'a>,
     pub span: Option<Span>,
     pub members: RefCell<IndexMap<String, CircuitMember<'a>>>,
+
+    /// The declaration of a generic circuit, which is converted again for each set of const arguments.
+    pub template: Option<leo_ast::Circuit>,
+
+    /// Maps const arguments => instance of a generic circuit.
+    pub instances: RefCell<IndexMap<Vec<u32>, &'a Circuit<'a>>>,
 }
 
 impl<'a> PartialEq for Circuit<'a> {
@@ -63,9 +80,17 @@ impl<'a> Circuit<'a> {
             core_mapping: RefCell::new(None),
             span: Some(value.circuit_name.span.clone()),
             scope: new_scope,
+            template: if value.is_generic() { Some(value.clone()) } else { None },
+            instances: RefCell::new(IndexMap::new()),
         });
         new_scope.circuit_self.replace(Some(circuit));
 
+        // The members of a generic circuit are only resolved by its instances.
+        if circuit.is_generic() {
+            check_const_parameters(&value.const_parameters)?;
+            return Ok(circuit);
+        }
+
         let mut members = circuit.members.borrow_mut();
         for member in value.members.iter() {
             if let leo_ast::CircuitMember::CircuitVariable(name, type_) = member {
@@ -90,10 +115,25 @@ impl<'a> Circuit<'a> {
         scope: &'a Scope<'a>,
         value: &leo_ast::Circuit,
     ) -> Result<&'a Circuit<'a>, AsgConvertError> {
-        let new_scope = scope.make_subscope();
-        let circuits = scope.circuits.borrow();
+        let circuit = *scope.circuits.borrow().get(value.circuit_name.name.as_ref()).unwrap();
+        circuit.init_functions(scope, value)?;
 
-        let circuit = circuits.get(value.circuit_name.name.as_ref()).unwrap();
+        Ok(circuit)
+    }
+
+    ///
+    /// Resolves the headers of the member functions of the circuit.
+    ///
+    fn init_functions(
+        self: &'a Circuit<'a>,
+        scope: &'a Scope<'a>,
+        value: &leo_ast::Circuit,
+    ) -> Result<(), AsgConvertError> {
+        if self.is_generic() {
+            return Ok(());
+        }
+        let circuit = self;
+        let new_scope = scope.make_subscope();
         new_scope.circuit_self.replace(Some(circuit));
 
         let mut members = circuit.members.borrow_mut();
@@ -118,10 +158,91 @@ impl<'a> Circuit<'a> {
             }
         }
 
-        Ok(circuit)
+        Ok(())
+    }
+
+    ///
+    /// Returns the instance of a generic circuit for the given const arguments.
+    ///
+    /// The bodies of the member functions of a new instance are converted once the program is loaded.
+    /// Errors are reported at the span of the instantiation.
+    ///
+    pub(crate) fn instantiate(
+        self: &'a Circuit<'a>,
+        arguments: Vec<u32>,
+        span: &Span,
+    ) -> Result<&'a Circuit<'a>, AsgConvertError> {
+        if let Some(instance) = self.instances.borrow().get(&arguments) {
+            return Ok(*instance);
+        }
+        let template = self
+            .template
+            .as_ref()
+            .expect("instantiated a circuit without const parameters");
+        let name = instance_name(&template.circuit_name.name, &arguments);
+        let scope = instance_scope(
+            self.scope
+                .parent_scope
+                .get()
+                .expect("generic circuit without a parent scope"),
+            &template.const_parameters,
+            &arguments,
+        );
+
+        let mut declaration = template.clone();
+        declaration.const_parameters.clear();
+        declaration.circuit_name.name = name.clone().into();
+        let instance = Circuit::init(scope, &declaration)
+            .map_err(|error| AsgConvertError::generic_instantiation(&name, error, span))?;
+        self.instances.borrow_mut().insert(arguments, instance);
+
+        instance
+            .init_functions(scope, &declaration)
+            .map_err(|error| AsgConvertError::generic_instantiation(&name, error, span))?;
+        for member in declaration.members.iter() {
+            if let leo_ast::CircuitMember::CircuitFunction(function) = member {
+                if let Some(CircuitMember::Function(asg_function)) =
+                    instance.members.borrow().get(function.identifier.name.as_ref())
+                {
+                    scope
+                        .context
+                        .defer_instance(asg_function, function.clone(), span.clone());
+                }
+            }
+        }
+
+        Ok(instance)
+    }
+
+    ///
+    /// Returns the const arguments of the given instance of this generic circuit.
+    ///
+    pub fn instance_arguments(&self, instance: &Circuit<'a>) -> Option<Vec<u32>> {
+        self.instances
+            .borrow()
+            .iter()
+            .find(|(_, x)| **x == instance)
+            .map(|(arguments, _)| arguments.clone())
+    }
+
+    pub fn is_generic(&self) -> bool {
+        self.template.is_some()
+    }
+
+    ///
+    /// Returns the const parameters of a generic circuit.
+    ///
+    pub fn const_parameters(&self) -> &[leo_ast::ConstParameter] {
+        self.template
+            .as_ref()
+            .map(|template| &template.const_parameters[..])
+            .unwrap_or_default()
     }
 
     pub(super) fn fill_from_ast(self: &'a Circuit<'a>, value: &leo_ast::Circuit) -> Result<(), AsgConvertError> {
+        if self.is_generic() {
+            return Ok(());
+        }
         for member in value.members.iter() {
             match member {
                 leo_ast::CircuitMember::CircuitVariable(..) => {}
@@ -145,6 +266,9 @@ impl<'a> Circuit<'a> {
 
 impl<'a> Into<leo_ast::Circuit> for &Circuit<'a> {
     fn into(self) -> leo_ast::Circuit {
+        if let Some(template) = &self.template {
+            return template.clone();
+        }
         let members = self
             .members
             .borrow()
@@ -158,6 +282,7 @@ impl<'a> Into<leo_ast::Circuit> for &Circuit<'a> {
             .collect();
         leo_ast::Circuit {
             circuit_name: self.name.borrow().clone(),
+            const_parameters: vec![],
             members,
         }
     }
@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AsgConvertError, Identifier, Node, Scope, Span, Type};
+
+use indexmap::IndexMap;
+use std::cell::RefCell;
+
+/// The most variants an enum can declare, so that its tag fits in a `u8`.
+pub const MAX_ENUM_VARIANTS: usize = 256;
+
+#[derive(Clone)]
+pub struct EnumVariant<'a> {
+    pub name: Identifier,
+    pub payload: Vec<Type<'a>>,
+}
+
+#[derive(Clone)]
+pub struct Enum<'a> {
+    pub id: u32,
+    pub name: RefCell<Identifier>,
+    pub span: Option<Span>,
+    /// Maps variant name => variant, in declaration order. The position of a variant is its tag.
+    pub variants: RefCell<IndexMap<String, EnumVariant<'a>>>,
+}
+
+impl<'a> PartialEq for Enum<'a> {
+    fn eq(&self, other: &Enum) -> bool {
+        if self.name != other.name {
+            return false;
+        }
+        self.id == other.id
+    }
+}
+
+impl<'a> Eq for Enum<'a> {}
+
+impl<'a> Node for Enum<'a> {
+    fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+}
+
+impl<'a> Enum<'a> {
+    pub(super) fn init(scope: &'a Scope<'a>, value: &leo_ast::Enum) -> Result<&'a Enum<'a>, AsgConvertError> {
+        if value.variants.len() > MAX_ENUM_VARIANTS {
+            return Err(AsgConvertError::too_many_enum_variants(
+                &value.enum_name.name,
+                MAX_ENUM_VARIANTS,
+                &value.enum_name.span,
+            ));
+        }
+
+        Ok(scope.context.alloc_enum(Enum {
+            id: scope.context.get_id(),
+            name: RefCell::new(value.enum_name.clone()),
+            span: Some(value.enum_name.span.clone()),
+            variants: RefCell::new(IndexMap::new()),
+        }))
+    }
+
+    ///
+    /// Resolves the payload types of the variants, once every circuit and enum of the program is declared.
+    ///
+    pub(super) fn init_variants(
+        self: &'a Enum<'a>,
+        scope: &'a Scope<'a>,
+        value: &leo_ast::Enum,
+    ) -> Result<(), AsgConvertError> {
+        let mut variants = self.variants.borrow_mut();
+        for variant in value.variants.iter() {
+            if variants.contains_key(variant.name.name.as_ref()) {
+                return Err(AsgConvertError::redefined_enum_variant(
+                    &value.enum_name.name,
+                    &variant.name.name,
+                    &variant.name.span,
+                ));
+            }
+            let payload = variant
+                .payload
+                .iter()
+                .map(|type_| scope.resolve_ast_type(type_))
+                .collect::<Result<Vec<_>, AsgConvertError>>()?;
+            variants.insert(variant.name.name.to_string(), EnumVariant {
+                name: variant.name.clone(),
+                payload,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the tag of the variant `name`, which is its position in the declaration.
+    pub fn variant_index(&self, name: &str) -> Option<usize> {
+        self.variants.borrow().get_index_of(name)
+    }
+}
+
+impl<'a> Into<leo_ast::Enum> for &Enum<'a> {
+    fn into(self) -> leo_ast::Enum {
+        leo_ast::Enum {
+            enum_name: self.name.borrow().clone(),
+            variants: self
+                .variants
+                .borrow()
+                .values()
+                .map(|variant| leo_ast::EnumVariant {
+                    name: variant.name.clone(),
+                    payload: variant.payload.iter().map(Into::into).collect(),
+                })
+                .collect(),
+        }
+    }
+}
@@ -15,6 +15,9 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    check_const_parameters,
+    instance_name,
+    instance_scope,
     AsgConvertError,
     BlockStatement,
     Circuit,
@@ -54,6 +57,12 @@ pub struct Function<'a> {
     pub scope: &'a Scope<'a>,
     pub qualifier: FunctionQualifier,
     pub annotations: Vec<Annotation>,
+
+    /// The declaration of a generic function, which is converted again for each set of const arguments.
+    pub template: Option<leo_ast::Function>,
+
+    /// Maps const arguments => instance of a generic function.
+    pub instances: RefCell<IndexMap<Vec<u32>, &'a Function<'a>>>,
 }
 
 impl<'a> PartialEq for Function<'a> {
@@ -69,6 +78,9 @@ impl<'a> Eq for Function<'a> {}
 
 impl<'a> Function<'a> {
     pub(crate) fn init(scope: &'a Scope<'a>, value: &leo_ast::Function) -> Result<&'a Function<'a>, AsgConvertError> {
+        if value.is_generic() {
+            return Function::init_template(scope, value);
+        }
         let output: Type<'a> = value
             .output
             .as_ref()
@@ -127,13 +139,109 @@ impl<'a> Function<'a> {
             scope: new_scope,
             span: Some(value.span.clone()),
             annotations: value.annotations.clone(),
+            template: None,
+            instances: RefCell::new(IndexMap::new()),
+        });
+        function.scope.function.replace(Some(function));
+
+        Ok(function)
+    }
+
+    ///
+    /// Returns a generic function, whose types and body are only resolved by its instances.
+    ///
+    fn init_template(scope: &'a Scope<'a>, value: &leo_ast::Function) -> Result<&'a Function<'a>, AsgConvertError> {
+        check_const_parameters(&value.const_parameters)?;
+        let mut qualifier = FunctionQualifier::Static;
+        for input in value.input.iter() {
+            match input {
+                FunctionInput::SelfKeyword(_) => qualifier = FunctionQualifier::SelfRef,
+                FunctionInput::ConstSelfKeyword(_) => qualifier = FunctionQualifier::ConstSelfRef,
+                FunctionInput::MutSelfKeyword(_) => qualifier = FunctionQualifier::MutSelfRef,
+                FunctionInput::Variable(_) => (),
+            }
+        }
+        if qualifier != FunctionQualifier::Static && scope.circuit_self.get().is_none() {
+            return Err(AsgConvertError::invalid_self_in_global(&value.span));
+        }
+        let function = scope.context.alloc_function(Function {
+            id: scope.context.get_id(),
+            name: RefCell::new(value.identifier.clone()),
+            output: Type::Tuple(vec![]),
+            arguments: IndexMap::new(),
+            circuit: Cell::new(None),
+            body: Cell::new(None),
+            qualifier,
+            scope: scope.make_subscope(),
+            span: Some(value.span.clone()),
+            annotations: value.annotations.clone(),
+            template: Some(value.clone()),
+            instances: RefCell::new(IndexMap::new()),
         });
         function.scope.function.replace(Some(function));
 
         Ok(function)
     }
 
-    pub(super) fn fill_from_ast(self: &'a Function<'a>, value: &leo_ast::Function) -> Result<(), AsgConvertError> {
+    ///
+    /// Returns the instance of a generic function for the given const arguments.
+    ///
+    /// The header of a new instance is resolved right away, its body once the program is loaded.
+    /// Errors are reported at the span of the instantiation.
+    ///
+    pub(crate) fn instantiate(
+        self: &'a Function<'a>,
+        arguments: Vec<u32>,
+        span: &Span,
+    ) -> Result<&'a Function<'a>, AsgConvertError> {
+        if let Some(instance) = self.instances.borrow().get(&arguments) {
+            return Ok(*instance);
+        }
+        let template = self
+            .template
+            .as_ref()
+            .expect("instantiated a function without const parameters");
+        let name = instance_name(&template.identifier.name, &arguments);
+        let scope = instance_scope(
+            self.scope
+                .parent_scope
+                .get()
+                .expect("generic function without a parent scope"),
+            &template.const_parameters,
+            &arguments,
+        );
+
+        let mut declaration = template.clone();
+        declaration.const_parameters.clear();
+        declaration.identifier.name = name.clone().into();
+        let instance = Function::init(scope, &declaration)
+            .map_err(|error| AsgConvertError::generic_instantiation(&name, error, span))?;
+        instance.circuit.replace(self.circuit.get());
+
+        self.instances.borrow_mut().insert(arguments, instance);
+        self.scope.context.defer_instance(instance, declaration, span.clone());
+
+        Ok(instance)
+    }
+
+    pub fn is_generic(&self) -> bool {
+        self.template.is_some()
+    }
+
+    ///
+    /// Returns the const parameters of a generic function.
+    ///
+    pub fn const_parameters(&self) -> &[leo_ast::ConstParameter] {
+        self.template
+            .as_ref()
+            .map(|template| &template.const_parameters[..])
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn fill_from_ast(self: &'a Function<'a>, value: &leo_ast::Function) -> Result<(), AsgConvertError> {
+        if self.is_generic() {
+            return Ok(());
+        }
         if self.qualifier != FunctionQualifier::Static {
             let circuit = self.circuit.get();
             let self_variable = self.scope.context.alloc_variable(RefCell::new(crate::InnerVariable {
@@ -186,6 +294,9 @@ impl<'a> Function<'a> {
 
 impl<'a> Into<leo_ast::Function> for &Function<'a> {
     fn into(self) -> leo_ast::Function {
+        if let Some(template) = &self.template {
+            return template.clone();
+        }
         let input = self
             .arguments
             .iter()
@@ -214,6 +325,7 @@ impl<'a> Into<leo_ast::Function> for &Function<'a> {
         let output: Type = self.output.clone();
         leo_ast::Function {
             identifier: self.name.borrow().clone(),
+            const_parameters: vec![],
             input,
             block: body,
             output: Some((&output).into()),
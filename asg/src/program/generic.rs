@@ -0,0 +1,213 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Helpers for generic functions and circuits, which are instantiated once per set of const arguments.
+
+use crate::{AsgConvertError, PartialType, Scope, Span, Type};
+use leo_ast::{ConstParameter, Dimension, IntegerType};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// Returns an error if the const parameters are not distinct or do not have type `u32`.
+pub(crate) fn check_const_parameters(parameters: &[ConstParameter]) -> Result<(), AsgConvertError> {
+    let mut names = IndexSet::new();
+    for parameter in parameters.iter() {
+        if parameter.type_ != leo_ast::Type::IntegerType(IntegerType::U32) {
+            return Err(AsgConvertError::invalid_const_parameter_type(
+                &parameter.identifier.name,
+                &parameter.type_.to_string(),
+                &parameter.span,
+            ));
+        }
+        if !names.insert(parameter.identifier.name.as_ref()) {
+            return Err(AsgConvertError::redefined_const_parameter(
+                &parameter.identifier.name,
+                &parameter.identifier.span,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Returns a new scope where the const parameters are bound to the given arguments.
+pub(crate) fn instance_scope<'a>(
+    scope: &'a Scope<'a>,
+    parameters: &[ConstParameter],
+    arguments: &[u32],
+) -> &'a Scope<'a> {
+    let new_scope = scope.make_subscope();
+    new_scope.circuit_self.replace(scope.circuit_self.get());
+    new_scope.const_parameters.replace(
+        parameters
+            .iter()
+            .zip(arguments.iter())
+            .map(|(parameter, argument)| (parameter.identifier.name.to_string(), *argument))
+            .collect(),
+    );
+    new_scope
+}
+
+/// Returns the name of an instance of a generic function or circuit, as in `hash<32>`.
+pub(crate) fn instance_name(name: &str, arguments: &[u32]) -> String {
+    let arguments = arguments.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+    format!("{}<{}>", name, arguments)
+}
+
+/// The values a call site infers for the const parameters of a generic function or circuit.
+pub(crate) struct ConstBindings {
+    parameters: Vec<ConstParameter>,
+    values: IndexMap<String, u32>,
+}
+
+impl ConstBindings {
+    pub fn new(parameters: Vec<ConstParameter>) -> Self {
+        Self {
+            parameters,
+            values: IndexMap::new(),
+        }
+    }
+
+    fn is_parameter(&self, dimension: &Dimension) -> bool {
+        match dimension {
+            Dimension::Parameter(name) => self
+                .parameters
+                .iter()
+                .any(|parameter| parameter.identifier.name == name.name),
+            Dimension::Number(_) => false,
+        }
+    }
+
+    ///
+    /// Returns the type expected for a value of the declared type, with unknown lengths wherever a
+    /// const parameter is not bound yet, or `None` if nothing is known about it.
+    ///
+    pub fn expected_type<'a>(
+        &self,
+        scope: &'a Scope<'a>,
+        type_: &leo_ast::Type,
+    ) -> Result<Option<PartialType<'a>>, AsgConvertError> {
+        Ok(match type_ {
+            leo_ast::Type::Array(element, dimensions) => {
+                let mut expected = self.expected_type(scope, element)?;
+                for dimension in dimensions.0.iter().rev() {
+                    let len = match dimension {
+                        Dimension::Parameter(name) if self.is_parameter(dimension) => {
+                            self.values.get(name.name.as_ref()).map(|value| *value as usize)
+                        }
+                        dimension => Some(scope.resolve_dimension(dimension)?),
+                    };
+                    expected = Some(PartialType::Array(expected.map(Box::new), len));
+                }
+                expected
+            }
+            leo_ast::Type::Tuple(types) => Some(PartialType::Tuple(
+                types
+                    .iter()
+                    .map(|type_| self.expected_type(scope, type_))
+                    .collect::<Result<Vec<_>, AsgConvertError>>()?,
+            )),
+            leo_ast::Type::GenericCircuit(_, arguments) if arguments.iter().any(|x| self.is_parameter(x)) => None,
+            type_ => Some(scope.resolve_ast_type(type_)?.partial()),
+        })
+    }
+
+    ///
+    /// Infers the const parameters used by the declared type from the type of a value.
+    ///
+    /// Mismatched types are left to the type checking of the instance.
+    ///
+    pub fn bind<'a>(
+        &mut self,
+        scope: &'a Scope<'a>,
+        type_: &leo_ast::Type,
+        actual: &Type<'a>,
+        span: &Span,
+    ) -> Result<(), AsgConvertError> {
+        match (type_, actual) {
+            (leo_ast::Type::Array(element, dimensions), _) => {
+                let mut actual = actual;
+                for dimension in dimensions.0.iter() {
+                    match actual {
+                        Type::Array(item, len) => {
+                            self.bind_dimension(dimension, *len as u32, span)?;
+                            actual = item;
+                        }
+                        _ => return Ok(()),
+                    }
+                }
+                self.bind(scope, element, actual, span)
+            }
+            (leo_ast::Type::Tuple(types), Type::Tuple(actuals)) => {
+                for (type_, actual) in types.iter().zip(actuals.iter()) {
+                    self.bind(scope, type_, actual, span)?;
+                }
+                Ok(())
+            }
+            (leo_ast::Type::GenericCircuit(name, arguments), Type::Circuit(circuit)) => {
+                let values = scope
+                    .resolve_circuit(&name.name)
+                    .and_then(|generic| generic.instance_arguments(circuit));
+                if let Some(values) = values {
+                    for (argument, value) in arguments.iter().zip(values) {
+                        self.bind_dimension(argument, value, span)?;
+                    }
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn bind_dimension(&mut self, dimension: &Dimension, value: u32, span: &Span) -> Result<(), AsgConvertError> {
+        if let Dimension::Parameter(name) = dimension {
+            if !self.is_parameter(dimension) {
+                return Ok(());
+            }
+            match self.values.get(name.name.as_ref()) {
+                Some(bound) if *bound != value => {
+                    return Err(AsgConvertError::conflicting_const_argument(
+                        &name.name, *bound, value, span,
+                    ));
+                }
+                Some(_) => (),
+                None => {
+                    self.values.insert(name.name.to_string(), value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the values inferred for the given const parameters of the generic `name`.
+    ///
+    pub fn arguments(
+        &self,
+        parameters: &[ConstParameter],
+        name: &str,
+        span: &Span,
+    ) -> Result<Vec<u32>, AsgConvertError> {
+        parameters
+            .iter()
+            .map(|parameter| {
+                self.values
+                    .get(parameter.identifier.name.as_ref())
+                    .copied()
+                    .ok_or_else(|| AsgConvertError::unresolved_const_argument(&parameter.identifier.name, name, span))
+            })
+            .collect()
+    }
+}
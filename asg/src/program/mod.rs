@@ -21,9 +21,15 @@
 mod circuit;
 pub use circuit::*;
 
+mod enum_;
+pub use enum_::*;
+
 mod function;
 pub use function::*;
 
+mod generic;
+pub(crate) use generic::*;
+
 use crate::{
     node::FromAst,
     ArenaNode,
@@ -37,7 +43,7 @@ use crate::{
 };
 use leo_ast::{Identifier, PackageAccess, PackageOrPackages, Span};
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use std::cell::{Cell, RefCell};
 
 /// Stores the Leo program abstract semantic graph (ASG).
@@ -64,6 +70,13 @@ pub struct Program<'a> {
     /// Maps circuit name => circuit code block.
     pub circuits: IndexMap<String, &'a Circuit<'a>>,
 
+    /// Maps enum name => enum declaration.
+    pub enums: IndexMap<String, &'a Enum<'a>>,
+
+    /// The names other programs may import, or `None` if every item can be imported.
+    /// Only the modules of a package restrict their exports.
+    pub exports: Option<IndexSet<String>>,
+
     pub scope: &'a Scope<'a>,
 }
 
@@ -180,6 +193,7 @@ impl<'a> Program<'a> {
 
         let mut imported_functions: IndexMap<String, &'a Function<'a>> = IndexMap::new();
         let mut imported_circuits: IndexMap<String, &'a Circuit<'a>> = IndexMap::new();
+        let mut imported_enums: IndexMap<String, &'a Enum<'a>> = IndexMap::new();
         let mut imported_global_consts: IndexMap<String, &'a DefinitionStatement<'a>> = IndexMap::new();
 
         // Prepare locally relevant scope of imports.
@@ -189,17 +203,49 @@ impl<'a> Program<'a> {
             let resolved_package = resolved_packages
                 .get(&package)
                 .expect("could not find preloaded package");
+            if let ImportSymbol::Direct(name) | ImportSymbol::Alias(name, _) = &symbol {
+                if resolved_package.is_private(name) {
+                    return Err(AsgConvertError::private_import(&pretty_package, name, &span));
+                }
+            }
             match symbol {
                 ImportSymbol::All => {
-                    imported_functions.extend(resolved_package.functions.clone().into_iter());
-                    imported_circuits.extend(resolved_package.circuits.clone().into_iter());
-                    imported_global_consts.extend(resolved_package.global_consts.clone().into_iter());
+                    imported_functions.extend(
+                        resolved_package
+                            .functions
+                            .iter()
+                            .filter(|(name, _)| resolved_package.is_exported(name))
+                            .map(|(name, function)| (name.clone(), *function)),
+                    );
+                    imported_circuits.extend(
+                        resolved_package
+                            .circuits
+                            .iter()
+                            .filter(|(name, _)| resolved_package.is_exported(name))
+                            .map(|(name, circuit)| (name.clone(), *circuit)),
+                    );
+                    imported_enums.extend(
+                        resolved_package
+                            .enums
+                            .iter()
+                            .filter(|(name, _)| resolved_package.is_exported(name))
+                            .map(|(name, enum_)| (name.clone(), *enum_)),
+                    );
+                    imported_global_consts.extend(
+                        resolved_package
+                            .global_consts
+                            .iter()
+                            .filter(|(name, _)| resolved_package.is_exported(name))
+                            .map(|(name, global_const)| (name.clone(), *global_const)),
+                    );
                 }
                 ImportSymbol::Direct(name) => {
                     if let Some(function) = resolved_package.functions.get(&name) {
                         imported_functions.insert(name.clone(), *function);
                     } else if let Some(circuit) = resolved_package.circuits.get(&name) {
                         imported_circuits.insert(name.clone(), *circuit);
+                    } else if let Some(enum_) = resolved_package.enums.get(&name) {
+                        imported_enums.insert(name.clone(), *enum_);
                     } else if let Some(global_const) = resolved_package.global_consts.get(&name) {
                         imported_global_consts.insert(name.clone(), *global_const);
                     } else {
@@ -214,6 +260,8 @@ impl<'a> Program<'a> {
                         imported_functions.insert(alias.clone(), *function);
                     } else if let Some(circuit) = resolved_package.circuits.get(&name) {
                         imported_circuits.insert(alias.clone(), *circuit);
+                    } else if let Some(enum_) = resolved_package.enums.get(&name) {
+                        imported_enums.insert(alias.clone(), *enum_);
                     } else if let Some(global_const) = resolved_package.global_consts.get(&name) {
                         imported_global_consts.insert(alias.clone(), *global_const);
                     } else {
@@ -226,17 +274,35 @@ impl<'a> Program<'a> {
             }
         }
 
+        // Imported global constants are referenced through their variables.
+        let mut imported_variables = IndexMap::new();
+        for (name, global_const) in imported_global_consts.iter() {
+            match &global_const.variables[..] {
+                [variable] => {
+                    imported_variables.insert(name.clone(), *variable);
+                }
+                variables => {
+                    for variable in variables.iter() {
+                        imported_variables.insert(variable.borrow().name.name.to_string(), *variable);
+                    }
+                }
+            }
+        }
+
         let import_scope = match context.arena.alloc(ArenaNode::Scope(Box::new(Scope {
             context,
             id: context.get_id(),
             parent_scope: Cell::new(None),
             circuit_self: Cell::new(None),
-            variables: RefCell::new(IndexMap::new()),
+            variables: RefCell::new(imported_variables),
             functions: RefCell::new(imported_functions),
             global_consts: RefCell::new(imported_global_consts),
             circuits: RefCell::new(imported_circuits),
+            enums: RefCell::new(imported_enums),
+            const_parameters: RefCell::new(IndexMap::new()),
             function: Cell::new(None),
             input: Cell::new(None),
+            is_loop: Cell::new(false),
         }))) {
             ArenaNode::Scope(c) => c,
             _ => unimplemented!(),
@@ -252,10 +318,23 @@ impl<'a> Program<'a> {
             functions: RefCell::new(IndexMap::new()),
             global_consts: RefCell::new(IndexMap::new()),
             circuits: RefCell::new(IndexMap::new()),
+            enums: RefCell::new(IndexMap::new()),
+            const_parameters: RefCell::new(IndexMap::new()),
             function: Cell::new(None),
+            is_loop: Cell::new(false),
         });
 
         // Prepare header-like scope entries.
+        for (name, enum_) in program.enums.iter() {
+            assert_eq!(name.name, enum_.enum_name.name);
+            if program.circuits.contains_key(name) {
+                return Err(AsgConvertError::duplicate_type_definition(&name.name, &name.span));
+            }
+            let asg_enum = Enum::init(scope, enum_)?;
+
+            scope.enums.borrow_mut().insert(name.name.to_string(), asg_enum);
+        }
+
         for (name, circuit) in program.circuits.iter() {
             assert_eq!(name.name, circuit.circuit_name.name);
             let asg_circuit = Circuit::init(scope, circuit)?;
@@ -263,6 +342,13 @@ impl<'a> Program<'a> {
             scope.circuits.borrow_mut().insert(name.name.to_string(), asg_circuit);
         }
 
+        // Enum payloads can refer to any circuit or enum.
+        for (name, enum_) in program.enums.iter() {
+            let asg_enum = *scope.enums.borrow().get(name.name.as_ref()).unwrap();
+
+            asg_enum.init_variants(scope, enum_)?;
+        }
+
         // Second pass for circuit members.
         for (name, circuit) in program.circuits.iter() {
             assert_eq!(name.name, circuit.circuit_name.name);
@@ -273,9 +359,12 @@ impl<'a> Program<'a> {
 
         for (name, function) in program.functions.iter() {
             assert_eq!(name.name, function.identifier.name);
-            let function = Function::init(scope, function)?;
+            let asg_function = Function::init(scope, function)?;
+            if asg_function.is_generic() && (name.name.as_ref() == "main" || asg_function.is_test()) {
+                return Err(AsgConvertError::generic_entry_point(&name.name, &function.span));
+            }
 
-            scope.functions.borrow_mut().insert(name.name.to_string(), function);
+            scope.functions.borrow_mut().insert(name.name.to_string(), asg_function);
         }
 
         for (name, global_const) in program.global_consts.iter() {
@@ -327,6 +416,20 @@ impl<'a> Program<'a> {
             circuits.insert(name.name.to_string(), asg_circuit);
         }
 
+        // Convert the bodies of the generic instances now that every declaration is loaded.
+        context.fill_instances()?;
+
+        let enums = program
+            .enums
+            .iter()
+            .map(|(name, _)| {
+                (
+                    name.name.to_string(),
+                    *scope.enums.borrow().get(name.name.as_ref()).unwrap(),
+                )
+            })
+            .collect();
+
         Ok(Program {
             context,
             id: context.get_id(),
@@ -334,19 +437,32 @@ impl<'a> Program<'a> {
             functions,
             global_consts,
             circuits,
+            enums,
             imported_modules: resolved_packages
                 .into_iter()
                 .map(|(package, program)| (package.join("."), program))
                 .collect(),
+            exports: None,
             scope,
         })
     }
 
-    pub(crate) fn set_core_mapping(&self, mapping: &str) {
-        for (_, circuit) in self.circuits.iter() {
-            circuit.core_mapping.replace(Some(mapping.to_string()));
+    /// Returns `true` if the item `name` can be imported from this program.
+    pub fn is_exported(&self, name: &str) -> bool {
+        match &self.exports {
+            Some(exports) => exports.contains(name),
+            None => true,
         }
     }
+
+    /// Returns `true` if this program declares the item `name` but does not export it.
+    pub fn is_private(&self, name: &str) -> bool {
+        !self.is_exported(name)
+            && (self.functions.contains_key(name)
+                || self.circuits.contains_key(name)
+                || self.enums.contains_key(name)
+                || self.global_consts.contains_key(name))
+    }
 }
 
 struct InternalIdentifierGenerator {
@@ -382,6 +498,7 @@ pub fn reform_ast<'a>(program: &Program<'a>) -> leo_ast::Program {
     all_programs.retain(|module, _| !module.starts_with("core."));
 
     let mut all_circuits: IndexMap<String, &'a Circuit<'a>> = IndexMap::new();
+    let mut all_enums: IndexMap<String, &'a Enum<'a>> = IndexMap::new();
     let mut all_functions: IndexMap<String, &'a Function<'a>> = IndexMap::new();
     let mut all_global_consts: IndexMap<String, &'a DefinitionStatement<'a>> = IndexMap::new();
     let mut identifiers = InternalIdentifierGenerator { next: 0 };
@@ -391,6 +508,11 @@ pub fn reform_ast<'a>(program: &Program<'a>) -> leo_ast::Program {
             circuit.name.borrow_mut().name = identifier.clone().into();
             all_circuits.insert(identifier, *circuit);
         }
+        for (name, enum_) in program.enums.iter() {
+            let identifier = format!("{}{}", identifiers.next().unwrap(), name);
+            enum_.name.borrow_mut().name = identifier.clone().into();
+            all_enums.insert(identifier, *enum_);
+        }
         for (name, function) in program.functions.iter() {
             let identifier = if name == "main" {
                 "main".to_string()
@@ -420,6 +542,8 @@ pub fn reform_ast<'a>(program: &Program<'a>) -> leo_ast::Program {
                 span: Span::default(),
             })
             .collect(),
+        modules: vec![],
+        public: Default::default(),
         expected_input: vec![],
         functions: all_functions
             .into_iter()
@@ -429,6 +553,10 @@ pub fn reform_ast<'a>(program: &Program<'a>) -> leo_ast::Program {
             .into_iter()
             .map(|(_, circuit)| (circuit.name.borrow().clone(), circuit.into()))
             .collect(),
+        enums: all_enums
+            .into_iter()
+            .map(|(_, enum_)| (enum_.name.borrow().clone(), enum_.into()))
+            .collect(),
         global_consts: all_global_consts
             .into_iter()
             .map(|(_, global_const)| {
@@ -451,12 +579,19 @@ impl<'a> Into<leo_ast::Program> for &Program<'a> {
         leo_ast::Program {
             name: self.name.clone(),
             imports: vec![],
+            modules: vec![],
+            public: self.exports.clone().unwrap_or_default(),
             expected_input: vec![],
             circuits: self
                 .circuits
                 .iter()
                 .map(|(_, circuit)| (circuit.name.borrow().clone(), (*circuit).into()))
                 .collect(),
+            enums: self
+                .enums
+                .iter()
+                .map(|(_, enum_)| (enum_.name.borrow().clone(), (*enum_).into()))
+                .collect(),
             functions: self
                 .functions
                 .iter()
@@ -46,6 +46,7 @@ impl<'a, T: Monoid, R: MonoidalReducerExpression<'a, T>> MonoidalDirector<'a, T,
             Expression::Call(e) => self.reduce_call(e),
             Expression::CircuitAccess(e) => self.reduce_circuit_access(e),
             Expression::CircuitInit(e) => self.reduce_circuit_init(e),
+            Expression::EnumInit(e) => self.reduce_enum_init(e),
             Expression::Ternary(e) => self.reduce_ternary_expression(e),
             Expression::Cast(e) => self.reduce_cast_expression(e),
             Expression::Constant(e) => self.reduce_constant(e),
@@ -123,6 +124,12 @@ impl<'a, T: Monoid, R: MonoidalReducerExpression<'a, T>> MonoidalDirector<'a, T,
         self.reducer.reduce_circuit_init(input, values)
     }
 
+    pub fn reduce_enum_init(&mut self, input: &EnumInitExpression<'a>) -> T {
+        let values = input.values.iter().map(|e| self.reduce_expression(e.get())).collect();
+
+        self.reducer.reduce_enum_init(input, values)
+    }
+
     pub fn reduce_ternary_expression(&mut self, input: &TernaryExpression<'a>) -> T {
         let condition = self.reduce_expression(input.condition.get());
         let if_true = self.reduce_expression(input.if_true.get());
@@ -175,8 +182,9 @@ impl<'a, T: Monoid, R: MonoidalReducerStatement<'a, T>> MonoidalDirector<'a, T,
             Statement::Definition(s) => self.reduce_definition(s),
             Statement::Expression(s) => self.reduce_expression_statement(s),
             Statement::Iteration(s) => self.reduce_iteration(s),
+            Statement::Match(s) => self.reduce_match(s),
             Statement::Return(s) => self.reduce_return(s),
-            Statement::Empty(_) => T::default(),
+            Statement::Break(_) | Statement::Continue(_) | Statement::Empty(_) => T::default(),
         };
 
         self.reducer.reduce_statement(input, value)
@@ -266,6 +274,17 @@ impl<'a, T: Monoid, R: MonoidalReducerStatement<'a, T>> MonoidalDirector<'a, T,
         self.reducer.reduce_iteration(input, start, stop, body)
     }
 
+    pub fn reduce_match(&mut self, input: &MatchStatement<'a>) -> T {
+        let value = self.reduce_expression(input.value.get());
+        let arms = input
+            .arms
+            .iter()
+            .map(|arm| self.reduce_statement(arm.body.get()))
+            .collect();
+
+        self.reducer.reduce_match(input, value, arms)
+    }
+
     pub fn reduce_return(&mut self, input: &ReturnStatement<'a>) -> T {
         let value = self.reduce_expression(input.expression.get());
 
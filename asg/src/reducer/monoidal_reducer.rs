@@ -60,6 +60,10 @@ pub trait MonoidalReducerExpression<'a, T: Monoid> {
         T::default().append_all(values.into_iter())
     }
 
+    fn reduce_enum_init(&mut self, input: &EnumInitExpression<'a>, values: Vec<T>) -> T {
+        T::default().append_all(values.into_iter())
+    }
+
     fn reduce_ternary_expression(&mut self, input: &TernaryExpression<'a>, condition: T, if_true: T, if_false: T) -> T {
         condition.append(if_true).append(if_false)
     }
@@ -138,6 +142,10 @@ pub trait MonoidalReducerStatement<'a, T: Monoid>: MonoidalReducerExpression<'a,
         start.append(stop).append(body)
     }
 
+    fn reduce_match(&mut self, input: &MatchStatement<'a>, value: T, arms: Vec<T>) -> T {
+        value.append_all(arms.into_iter())
+    }
+
     fn reduce_return(&mut self, input: &ReturnStatement<'a>, value: T) -> T {
         value
     }
@@ -46,6 +46,7 @@ impl<'a, R: ReconstructingReducerExpression<'a>> ReconstructingDirector<'a, R> {
             Expression::Call(e) => self.reduce_call(e),
             Expression::CircuitAccess(e) => self.reduce_circuit_access(e),
             Expression::CircuitInit(e) => self.reduce_circuit_init(e),
+            Expression::EnumInit(e) => self.reduce_enum_init(e),
             Expression::Ternary(e) => self.reduce_ternary_expression(e),
             Expression::Cast(e) => self.reduce_cast_expression(e),
             Expression::Constant(e) => self.reduce_constant(e),
@@ -139,6 +140,12 @@ impl<'a, R: ReconstructingReducerExpression<'a>> ReconstructingDirector<'a, R> {
         self.reducer.reduce_circuit_init(input, values)
     }
 
+    pub fn reduce_enum_init(&mut self, input: EnumInitExpression<'a>) -> Expression<'a> {
+        let values = input.values.iter().map(|e| self.reduce_expression(e.get())).collect();
+
+        self.reducer.reduce_enum_init(input, values)
+    }
+
     pub fn reduce_ternary_expression(&mut self, input: TernaryExpression<'a>) -> Expression<'a> {
         let condition = self.reduce_expression(input.condition.get());
         let if_true = self.reduce_expression(input.if_true.get());
@@ -191,8 +198,9 @@ impl<'a, R: ReconstructingReducerStatement<'a>> ReconstructingDirector<'a, R> {
             Statement::Definition(s) => self.reduce_definition(s),
             Statement::Expression(s) => self.reduce_expression_statement(s),
             Statement::Iteration(s) => self.reduce_iteration(s),
+            Statement::Match(s) => self.reduce_match(s),
             Statement::Return(s) => self.reduce_return(s),
-            x @ Statement::Empty(_) => x,
+            x @ (Statement::Break(_) | Statement::Continue(_) | Statement::Empty(_)) => x,
         };
 
         self.reducer.reduce_statement_alloc(self.context, input, value)
@@ -286,6 +294,17 @@ impl<'a, R: ReconstructingReducerStatement<'a>> ReconstructingDirector<'a, R> {
         self.reducer.reduce_iteration(input, start, stop, body)
     }
 
+    pub fn reduce_match(&mut self, input: MatchStatement<'a>) -> Statement<'a> {
+        let value = self.reduce_expression(input.value.get());
+        let arms = input
+            .arms
+            .iter()
+            .map(|arm| self.reduce_statement(arm.body.get()))
+            .collect();
+
+        self.reducer.reduce_match(input, value, arms)
+    }
+
     pub fn reduce_return(&mut self, input: ReturnStatement<'a>) -> Statement<'a> {
         let value = self.reduce_expression(input.expression.get());
 
@@ -296,6 +315,10 @@ impl<'a, R: ReconstructingReducerStatement<'a>> ReconstructingDirector<'a, R> {
 #[allow(dead_code)]
 impl<'a, R: ReconstructingReducerProgram<'a>> ReconstructingDirector<'a, R> {
     fn reduce_function(&mut self, input: &'a Function<'a>) -> &'a Function<'a> {
+        // Instances are reduced in place, as calls refer to them directly.
+        for (_, instance) in input.instances.borrow().iter() {
+            self.reduce_function(instance);
+        }
         let body = input.body.get().map(|s| self.reduce_statement(s));
 
         self.reducer.reduce_function(input, body)
@@ -312,6 +335,9 @@ impl<'a, R: ReconstructingReducerProgram<'a>> ReconstructingDirector<'a, R> {
     }
 
     pub fn reduce_circuit(&mut self, input: &'a Circuit<'a>) -> &'a Circuit<'a> {
+        for (_, instance) in input.instances.borrow().iter() {
+            self.reduce_circuit(instance);
+        }
         let members = input
             .members
             .borrow()
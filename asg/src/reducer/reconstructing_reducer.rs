@@ -135,6 +135,16 @@ pub trait ReconstructingReducerExpression<'a> {
         })
     }
 
+    fn reduce_enum_init(&mut self, input: EnumInitExpression<'a>, values: Vec<&'a Expression<'a>>) -> Expression<'a> {
+        Expression::EnumInit(EnumInitExpression {
+            parent: input.parent,
+            enum_: input.enum_,
+            variant: input.variant,
+            values: values.into_iter().map(Cell::new).collect(),
+            span: input.span,
+        })
+    }
+
     fn reduce_ternary_expression(
         &mut self,
         input: TernaryExpression<'a>,
@@ -156,6 +166,7 @@ pub trait ReconstructingReducerExpression<'a> {
             parent: input.parent,
             inner: Cell::new(inner),
             target_type: input.target_type,
+            wrapping: input.wrapping,
             span: input.span,
         })
     }
@@ -345,6 +356,31 @@ pub trait ReconstructingReducerStatement<'a>: ReconstructingReducerExpression<'a
         })
     }
 
+    fn reduce_match(
+        &mut self,
+        input: MatchStatement<'a>,
+        value: &'a Expression<'a>,
+        arms: Vec<&'a Statement<'a>>,
+    ) -> Statement<'a> {
+        Statement::Match(MatchStatement {
+            parent: input.parent,
+            span: input.span,
+            value: Cell::new(value),
+            enum_: input.enum_,
+            arms: input
+                .arms
+                .into_iter()
+                .zip(arms)
+                .map(|(arm, body)| MatchArm {
+                    span: arm.span,
+                    pattern: arm.pattern,
+                    variants: arm.variants,
+                    body: Cell::new(body),
+                })
+                .collect(),
+        })
+    }
+
     fn reduce_return(&mut self, input: ReturnStatement<'a>, value: &'a Expression<'a>) -> Statement<'a> {
         Statement::Return(ReturnStatement {
             parent: input.parent,
@@ -407,6 +443,8 @@ pub trait ReconstructingReducerProgram<'a>: ReconstructingReducerStatement<'a> {
             imported_modules: imported_modules.into_iter().collect(),
             functions: functions.into_iter().collect(),
             circuits: circuits.into_iter().collect(),
+            enums: input.enums,
+            exports: input.exports,
             scope: input.scope,
             global_consts: global_consts.into_iter().collect(),
         }
@@ -68,6 +68,10 @@ pub trait ExpressionVisitor<'a> {
         Default::default()
     }
 
+    fn visit_enum_init(&mut self, input: &EnumInitExpression<'a>) -> VisitResult {
+        Default::default()
+    }
+
     fn visit_ternary_expression(&mut self, input: &TernaryExpression<'a>) -> VisitResult {
         Default::default()
     }
@@ -140,6 +144,10 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
         Default::default()
     }
 
+    fn visit_match(&mut self, input: &MatchStatement<'a>) -> VisitResult {
+        Default::default()
+    }
+
     fn visit_return(&mut self, input: &ReturnStatement<'a>) -> VisitResult {
         Default::default()
     }
@@ -59,6 +59,7 @@ impl<'a, R: ExpressionVisitor<'a>> VisitorDirector<'a, R> {
                 Expression::Call(e) => self.visit_call(e),
                 Expression::CircuitAccess(e) => self.visit_circuit_access(e),
                 Expression::CircuitInit(e) => self.visit_circuit_init(e),
+                Expression::EnumInit(e) => self.visit_enum_init(e),
                 Expression::Ternary(e) => self.visit_ternary_expression(e),
                 Expression::Cast(e) => self.visit_cast_expression(e),
                 Expression::Constant(e) => self.visit_constant(e),
@@ -173,6 +174,18 @@ impl<'a, R: ExpressionVisitor<'a>> VisitorDirector<'a, R> {
         }
     }
 
+    pub fn visit_enum_init(&mut self, input: &EnumInitExpression<'a>) -> ConcreteVisitResult {
+        match self.visitor.visit_enum_init(input) {
+            VisitResult::VisitChildren => {
+                for argument in input.values.iter() {
+                    self.visit_expression(argument)?;
+                }
+                Ok(())
+            }
+            x => x.into(),
+        }
+    }
+
     pub fn visit_ternary_expression(&mut self, input: &TernaryExpression<'a>) -> ConcreteVisitResult {
         match self.visitor.visit_ternary_expression(input) {
             VisitResult::VisitChildren => {
@@ -247,8 +260,9 @@ impl<'a, R: StatementVisitor<'a>> VisitorDirector<'a, R> {
                 Statement::Definition(s) => self.visit_definition(s),
                 Statement::Expression(s) => self.visit_expression_statement(s),
                 Statement::Iteration(s) => self.visit_iteration(s),
+                Statement::Match(s) => self.visit_match(s),
                 Statement::Return(s) => self.visit_return(s),
-                Statement::Empty(_) => Ok(()),
+                Statement::Break(_) | Statement::Continue(_) | Statement::Empty(_) => Ok(()),
             },
             x => x.into(),
         }
@@ -378,6 +392,19 @@ impl<'a, R: StatementVisitor<'a>> VisitorDirector<'a, R> {
         }
     }
 
+    pub fn visit_match(&mut self, input: &MatchStatement<'a>) -> ConcreteVisitResult {
+        match self.visitor.visit_match(input) {
+            VisitResult::VisitChildren => {
+                self.visit_expression(&input.value)?;
+                for arm in input.arms.iter() {
+                    self.visit_statement(&arm.body)?;
+                }
+                Ok(())
+            }
+            x => x.into(),
+        }
+    }
+
     pub fn visit_return(&mut self, input: &ReturnStatement<'a>) -> ConcreteVisitResult {
         match self.visitor.visit_return(input) {
             VisitResult::VisitChildren => {
@@ -394,6 +421,9 @@ impl<'a, R: ProgramVisitor<'a>> VisitorDirector<'a, R> {
         match self.visitor.visit_function(input) {
             VisitResult::VisitChildren => {
                 self.visit_opt_statement(&input.body)?;
+                for (_, instance) in input.instances.borrow().iter() {
+                    self.visit_function(instance)?;
+                }
                 Ok(())
             }
             x => x.into(),
@@ -418,6 +448,9 @@ impl<'a, R: ProgramVisitor<'a>> VisitorDirector<'a, R> {
                 for (_, member) in input.members.borrow().iter() {
                     self.visit_circuit_member(member)?;
                 }
+                for (_, instance) in input.instances.borrow().iter() {
+                    self.visit_circuit(instance)?;
+                }
                 Ok(())
             }
             x => x.into(),
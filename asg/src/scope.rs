@@ -14,12 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AsgContext, AsgConvertError, Circuit, DefinitionStatement, Function, Input, Type, Variable};
+use crate::{AsgContext, AsgConvertError, Circuit, DefinitionStatement, Enum, Function, Input, Type, Variable};
 
 use indexmap::IndexMap;
 use std::cell::{Cell, RefCell};
 
-/// An abstract data type that track the current bindings for variables, functions, and circuits.
+/// An abstract data type that track the current bindings for variables, functions, circuits, and enums.
 #[derive(Clone)]
 pub struct Scope<'a> {
     pub context: AsgContext<'a>,
@@ -48,8 +48,17 @@ pub struct Scope<'a> {
     /// Maps circuit name => circuit.
     pub circuits: RefCell<IndexMap<String, &'a Circuit<'a>>>,
 
+    /// Maps enum name => enum.
+    pub enums: RefCell<IndexMap<String, &'a Enum<'a>>>,
+
+    /// Maps const parameter name => the value it is bound to in a generic instance.
+    pub const_parameters: RefCell<IndexMap<String, u32>>,
+
     /// The main input to the program.
     pub input: Cell<Option<Input<'a>>>,
+
+    /// Whether this scope is the body of a loop, which `break` and `continue` statements exit.
+    pub is_loop: Cell<bool>,
 }
 
 #[allow(clippy::mut_from_ref)]
@@ -136,6 +145,70 @@ impl<'a> Scope<'a> {
         }
     }
 
+    ///
+    /// Returns a reference to the enum corresponding to the name.
+    ///
+    /// If the current scope did not have this name present, then the parent scope is checked.
+    /// If there is no parent scope, then `None` is returned.
+    ///
+    pub fn resolve_enum(&self, name: &str) -> Option<&'a Enum<'a>> {
+        if let Some(resolved) = self.enums.borrow().get(name) {
+            Some(*resolved)
+        } else if let Some(resolved) = self.parent_scope.get() {
+            resolved.resolve_enum(name)
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Returns `true` if the current scope is inside the body of a loop.
+    ///
+    /// If the current scope is not a loop body, then the parent scope is checked.
+    /// If there is no parent scope, then `false` is returned.
+    ///
+    pub fn is_in_loop(&self) -> bool {
+        if self.is_loop.get() {
+            true
+        } else if let Some(resolved) = self.parent_scope.get() {
+            resolved.is_in_loop()
+        } else {
+            false
+        }
+    }
+
+    ///
+    /// Returns the value of the const parameter corresponding to the name.
+    ///
+    /// If the current scope did not have this name present, then the parent scope is checked.
+    /// If there is no parent scope, then `None` is returned.
+    ///
+    pub fn resolve_const_parameter(&self, name: &str) -> Option<u32> {
+        if let Some(resolved) = self.const_parameters.borrow().get(name) {
+            Some(*resolved)
+        } else if let Some(resolved) = self.parent_scope.get() {
+            resolved.resolve_const_parameter(name)
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Returns the length given by an array dimension or const argument.
+    ///
+    pub fn resolve_dimension(&self, dimension: &leo_ast::Dimension) -> Result<usize, AsgConvertError> {
+        match dimension {
+            leo_ast::Dimension::Number(number) => number
+                .value
+                .parse::<usize>()
+                .map_err(|_| AsgConvertError::parse_dimension_error()),
+            leo_ast::Dimension::Parameter(name) => self
+                .resolve_const_parameter(&name.name)
+                .map(|value| value as usize)
+                .ok_or_else(|| AsgConvertError::unresolved_const_parameter(&name.name, &name.span)),
+        }
+    }
+
     ///
     /// Returns a reference to the current circuit.
     ///
@@ -164,9 +237,12 @@ impl<'a> Scope<'a> {
             variables: RefCell::new(IndexMap::new()),
             functions: RefCell::new(IndexMap::new()),
             circuits: RefCell::new(IndexMap::new()),
+            enums: RefCell::new(IndexMap::new()),
+            const_parameters: RefCell::new(IndexMap::new()),
             global_consts: RefCell::new(IndexMap::new()),
             function: Cell::new(None),
             input: Cell::new(None),
+            is_loop: Cell::new(false),
         })
     }
 
@@ -185,10 +261,7 @@ impl<'a> Scope<'a> {
             Array(sub_type, dimensions) => {
                 let mut item = Box::new(self.resolve_ast_type(&*sub_type)?);
                 for dimension in dimensions.0.iter().rev() {
-                    let dimension = dimension
-                        .value
-                        .parse::<usize>()
-                        .map_err(|_| AsgConvertError::parse_index_error())?;
+                    let dimension = self.resolve_dimension(dimension)?;
                     item = Box::new(Type::Array(item, dimension));
                 }
                 *item
@@ -207,10 +280,39 @@ impl<'a> Scope<'a> {
                 self.resolve_circuit_self()
                     .ok_or_else(AsgConvertError::reference_self_outside_circuit)?,
             ),
-            Circuit(name) => Type::Circuit(
-                self.resolve_circuit(&name.name)
-                    .ok_or_else(|| AsgConvertError::unresolved_circuit(&name.name, &name.span))?,
-            ),
+            Circuit(name) => match self.resolve_circuit(&name.name) {
+                Some(circuit) if circuit.is_generic() => {
+                    return Err(AsgConvertError::unexpected_const_argument_count(
+                        &name.name,
+                        circuit.const_parameters().len(),
+                        0,
+                        &name.span,
+                    ));
+                }
+                Some(circuit) => Type::Circuit(circuit),
+                None => Type::Enum(
+                    self.resolve_enum(&name.name)
+                        .ok_or_else(|| AsgConvertError::unresolved_circuit(&name.name, &name.span))?,
+                ),
+            },
+            GenericCircuit(name, arguments) => {
+                let circuit = self
+                    .resolve_circuit(&name.name)
+                    .ok_or_else(|| AsgConvertError::unresolved_circuit(&name.name, &name.span))?;
+                if circuit.const_parameters().len() != arguments.len() {
+                    return Err(AsgConvertError::unexpected_const_argument_count(
+                        &name.name,
+                        circuit.const_parameters().len(),
+                        arguments.len(),
+                        &name.span,
+                    ));
+                }
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| self.resolve_dimension(argument).map(|value| value as u32))
+                    .collect::<Result<Vec<_>, AsgConvertError>>()?;
+                Type::Circuit(circuit.instantiate(arguments, &name.span)?)
+            }
         })
     }
 }
@@ -208,6 +208,13 @@ impl<'a> FromAst<'a, leo_ast::AssignStatement> for &'a Statement<'a> {
                 }
             });
         }
+        if matches!(
+            statement.operation,
+            AssignOperation::Shl | AssignOperation::Shr | AssignOperation::ShrSigned
+        ) {
+            // the shift amount is always a u32, independent of the shifted type
+            target_type = Some(PartialType::Integer(Some(IntegerType::U32), None));
+        }
         let value = <&Expression<'a>>::from_ast(scope, &statement.value, target_type)?;
 
         let statement = scope.context.alloc_statement(Statement::Assign(AssignStatement {
@@ -15,6 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{AsgConvertError, FromAst, Node, PartialType, Scope, Span, Statement};
+use leo_ast::Node as AstNode;
 
 use std::cell::Cell;
 
@@ -42,6 +43,15 @@ impl<'a> FromAst<'a, leo_ast::Block> for BlockStatement<'a> {
 
         let mut output = vec![];
         for item in statement.statements.iter() {
+            // Nothing after a `break` or `continue` in the same block is ever executed.
+            let keyword = match output.last().map(|statement: &Cell<&Statement>| statement.get()) {
+                Some(Statement::Break(_)) => Some("break"),
+                Some(Statement::Continue(_)) => Some("continue"),
+                _ => None,
+            };
+            if let Some(keyword) = keyword {
+                return Err(AsgConvertError::unreachable_statement(keyword, item.span()));
+            }
             output.push(Cell::new(<&'a Statement<'a>>::from_ast(&new_scope, item, None)?));
         }
         Ok(BlockStatement {
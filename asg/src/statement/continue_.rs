@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AsgConvertError, FromAst, Node, PartialType, Scope, Span, Statement};
+
+use std::cell::Cell;
+
+#[derive(Clone)]
+pub struct ContinueStatement<'a> {
+    pub parent: Cell<Option<&'a Statement<'a>>>,
+    pub span: Option<Span>,
+}
+
+impl<'a> Node for ContinueStatement<'a> {
+    fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+}
+
+impl<'a> FromAst<'a, leo_ast::ContinueStatement> for ContinueStatement<'a> {
+    fn from_ast(
+        scope: &'a Scope<'a>,
+        statement: &leo_ast::ContinueStatement,
+        _expected_type: Option<PartialType<'a>>,
+    ) -> Result<Self, AsgConvertError> {
+        if !scope.is_in_loop() {
+            return Err(AsgConvertError::loop_control_outside_loop("continue", &statement.span));
+        }
+
+        Ok(ContinueStatement {
+            parent: Cell::new(None),
+            span: Some(statement.span.clone()),
+        })
+    }
+}
+
+impl<'a> Into<leo_ast::ContinueStatement> for &ContinueStatement<'a> {
+    fn into(self) -> leo_ast::ContinueStatement {
+        leo_ast::ContinueStatement {
+            span: self.span.clone().unwrap_or_default(),
+        }
+    }
+}
@@ -87,6 +87,9 @@ impl<'a> FromAst<'a, leo_ast::IterationStatement> for &'a Statement<'a> {
             .borrow_mut()
             .insert(statement.variable.name.to_string(), variable);
 
+        let body_scope = scope.make_subscope();
+        body_scope.is_loop.set(true);
+
         let statement = scope.context.alloc_statement(Statement::Iteration(IterationStatement {
             parent: Cell::new(None),
             span: Some(statement.span.clone()),
@@ -97,7 +100,7 @@ impl<'a> FromAst<'a, leo_ast::IterationStatement> for &'a Statement<'a> {
                 scope
                     .context
                     .alloc_statement(Statement::Block(crate::BlockStatement::from_ast(
-                        scope,
+                        body_scope,
                         &statement.block,
                         None,
                     )?)),
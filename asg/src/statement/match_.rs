@@ -0,0 +1,258 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    AsgConvertError,
+    BlockStatement,
+    Enum,
+    Expression,
+    ExpressionNode,
+    FromAst,
+    Identifier,
+    InnerVariable,
+    Node,
+    PartialType,
+    Scope,
+    Span,
+    Statement,
+    Type,
+    Variable,
+};
+
+use std::cell::{Cell, RefCell};
+
+#[derive(Clone)]
+pub enum MatchPattern<'a> {
+    /// Matches one variant, binding the values it carries. A `None` binding ignores its value.
+    Variant {
+        variant: Identifier,
+        bindings: Vec<Option<&'a Variable<'a>>>,
+    },
+    /// Matches the variants not matched by a previous arm.
+    Wildcard,
+}
+
+#[derive(Clone)]
+pub struct MatchArm<'a> {
+    pub span: Option<Span>,
+    pub pattern: MatchPattern<'a>,
+    /// The tags of the variants this arm runs for.
+    pub variants: Vec<usize>,
+    pub body: Cell<&'a Statement<'a>>,
+}
+
+#[derive(Clone)]
+pub struct MatchStatement<'a> {
+    pub parent: Cell<Option<&'a Statement<'a>>>,
+    pub span: Option<Span>,
+    pub value: Cell<&'a Expression<'a>>,
+    pub enum_: &'a Enum<'a>,
+    pub arms: Vec<MatchArm<'a>>,
+}
+
+impl<'a> Node for MatchStatement<'a> {
+    fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+}
+
+impl<'a> FromAst<'a, leo_ast::MatchStatement> for &'a Statement<'a> {
+    fn from_ast(
+        scope: &'a Scope<'a>,
+        statement: &leo_ast::MatchStatement,
+        _expected_type: Option<PartialType<'a>>,
+    ) -> Result<Self, AsgConvertError> {
+        let value = <&Expression<'a>>::from_ast(scope, &statement.value, None)?;
+        let enum_ = match value.get_type() {
+            Some(Type::Enum(enum_)) => enum_,
+            type_ => {
+                return Err(AsgConvertError::unexpected_type(
+                    "enum",
+                    type_.map(|x| x.to_string()).as_deref(),
+                    &statement.span,
+                ));
+            }
+        };
+        let enum_name = enum_.name.borrow().name.to_string();
+        let variant_count = enum_.variants.borrow().len();
+
+        let mut matched = vec![false; variant_count];
+        let mut arms = Vec::with_capacity(statement.arms.len());
+        for arm in statement.arms.iter() {
+            if matched.iter().all(|x| *x) {
+                return Err(AsgConvertError::unreachable_match_arm(&arm.span));
+            }
+
+            let arm_scope = scope.make_subscope();
+            let (pattern, variants) = match &arm.pattern {
+                leo_ast::MatchPattern::Variant {
+                    enum_name: pattern_enum,
+                    variant,
+                    bindings,
+                    span,
+                } => {
+                    if scope.resolve_enum(&pattern_enum.name) != Some(enum_) {
+                        return Err(AsgConvertError::unexpected_type(
+                            &enum_name,
+                            Some(&pattern_enum.name),
+                            &pattern_enum.span,
+                        ));
+                    }
+                    let (index, payload) = match enum_.variants.borrow().get_full(variant.name.as_ref()) {
+                        Some((index, _, found)) => (index, found.payload.clone()),
+                        None => {
+                            return Err(AsgConvertError::unresolved_enum_variant(
+                                &enum_name,
+                                &variant.name,
+                                &variant.span,
+                            ));
+                        }
+                    };
+                    if matched[index] {
+                        return Err(AsgConvertError::duplicate_match_arm(&variant.name, span));
+                    }
+                    if bindings.len() != payload.len() {
+                        return Err(AsgConvertError::unexpected_enum_payload_count(
+                            &variant.name,
+                            payload.len(),
+                            bindings.len(),
+                            span,
+                        ));
+                    }
+                    matched[index] = true;
+
+                    let bindings = bindings
+                        .iter()
+                        .zip(payload)
+                        .map(|(binding, type_)| {
+                            if binding.name.as_ref() == "_" {
+                                return None;
+                            }
+                            let variable = scope.context.alloc_variable(RefCell::new(InnerVariable {
+                                id: scope.context.get_id(),
+                                name: binding.clone(),
+                                type_,
+                                mutable: false,
+                                const_: false,
+                                declaration: crate::VariableDeclaration::MatchBinding,
+                                references: vec![],
+                                assignments: vec![],
+                            }));
+                            arm_scope
+                                .variables
+                                .borrow_mut()
+                                .insert(binding.name.to_string(), variable);
+                            Some(variable)
+                        })
+                        .collect();
+
+                    (
+                        MatchPattern::Variant {
+                            variant: variant.clone(),
+                            bindings,
+                        },
+                        vec![index],
+                    )
+                }
+                leo_ast::MatchPattern::Wildcard(_) => {
+                    let variants = (0..variant_count).filter(|index| !matched[*index]).collect();
+                    matched.iter_mut().for_each(|x| *x = true);
+                    (MatchPattern::Wildcard, variants)
+                }
+            };
+
+            let body = scope
+                .context
+                .alloc_statement(Statement::Block(BlockStatement::from_ast(arm_scope, &arm.block, None)?));
+
+            arms.push(MatchArm {
+                span: Some(arm.span.clone()),
+                pattern,
+                variants,
+                body: Cell::new(body),
+            });
+        }
+
+        let missing = enum_
+            .variants
+            .borrow()
+            .keys()
+            .zip(matched.iter())
+            .filter(|(_, matched)| !**matched)
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(AsgConvertError::non_exhaustive_match(&missing, &statement.span));
+        }
+
+        let statement = scope.context.alloc_statement(Statement::Match(MatchStatement {
+            parent: Cell::new(None),
+            span: Some(statement.span.clone()),
+            value: Cell::new(value),
+            enum_,
+            arms,
+        }));
+        if let Statement::Match(match_statement) = statement {
+            for arm in match_statement.arms.iter() {
+                if let MatchPattern::Variant { bindings, .. } = &arm.pattern {
+                    for variable in bindings.iter().flatten() {
+                        variable.borrow_mut().assignments.push(statement);
+                    }
+                }
+            }
+        }
+        Ok(statement)
+    }
+}
+
+impl<'a> Into<leo_ast::MatchStatement> for &MatchStatement<'a> {
+    fn into(self) -> leo_ast::MatchStatement {
+        let enum_name = self.enum_.name.borrow().clone();
+        leo_ast::MatchStatement {
+            value: self.value.get().into(),
+            arms: self
+                .arms
+                .iter()
+                .map(|arm| {
+                    let span = arm.span.clone().unwrap_or_default();
+                    leo_ast::MatchArm {
+                        pattern: match &arm.pattern {
+                            MatchPattern::Variant { variant, bindings } => leo_ast::MatchPattern::Variant {
+                                enum_name: enum_name.clone(),
+                                variant: variant.clone(),
+                                bindings: bindings
+                                    .iter()
+                                    .map(|binding| match binding {
+                                        Some(variable) => variable.borrow().name.clone(),
+                                        None => Identifier::new("_".into()),
+                                    })
+                                    .collect(),
+                                span: span.clone(),
+                            },
+                            MatchPattern::Wildcard => leo_ast::MatchPattern::Wildcard(span.clone()),
+                        },
+                        block: match arm.body.get() {
+                            Statement::Block(block) => block.into(),
+                            _ => unimplemented!(),
+                        },
+                        span,
+                    }
+                })
+                .collect(),
+            span: self.span.clone().unwrap_or_default(),
+        }
+    }
+}
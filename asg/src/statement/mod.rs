@@ -24,12 +24,18 @@ pub use assign::*;
 mod block;
 pub use block::*;
 
+mod break_;
+pub use break_::*;
+
 mod conditional;
 pub use conditional::*;
 
 mod console;
 pub use console::*;
 
+mod continue_;
+pub use continue_::*;
+
 mod definition;
 pub use definition::*;
 
@@ -39,6 +45,9 @@ pub use expression::*;
 mod iteration;
 pub use iteration::*;
 
+mod match_;
+pub use match_::*;
+
 mod return_;
 pub use return_::*;
 
@@ -51,9 +60,12 @@ pub enum Statement<'a> {
     Assign(AssignStatement<'a>),
     Conditional(ConditionalStatement<'a>),
     Iteration(IterationStatement<'a>),
+    Break(BreakStatement<'a>),
+    Continue(ContinueStatement<'a>),
     Console(ConsoleStatement<'a>),
     Expression(ExpressionStatement<'a>),
     Block(BlockStatement<'a>),
+    Match(MatchStatement<'a>),
     Empty(Option<Span>),
 }
 
@@ -66,9 +78,12 @@ impl<'a> Node for Statement<'a> {
             Assign(s) => s.span(),
             Conditional(s) => s.span(),
             Iteration(s) => s.span(),
+            Break(s) => s.span(),
+            Continue(s) => s.span(),
             Console(s) => s.span(),
             Expression(s) => s.span(),
             Block(s) => s.span(),
+            Match(s) => s.span(),
             Empty(s) => s.as_ref(),
         }
     }
@@ -95,6 +110,14 @@ impl<'a> FromAst<'a, leo_ast::Statement> for &'a Statement<'a> {
                     )?))
             }
             Iteration(statement) => Self::from_ast(scope, statement, None)?,
+            Break(statement) => scope
+                .context
+                .alloc_statement(Statement::Break(BreakStatement::from_ast(scope, statement, None)?)),
+            Continue(statement) => scope
+                .context
+                .alloc_statement(Statement::Continue(ContinueStatement::from_ast(
+                    scope, statement, None,
+                )?)),
             Console(statement) => scope
                 .context
                 .alloc_statement(Statement::Console(ConsoleStatement::from_ast(scope, statement, None)?)),
@@ -108,6 +131,7 @@ impl<'a> FromAst<'a, leo_ast::Statement> for &'a Statement<'a> {
             Block(statement) => scope
                 .context
                 .alloc_statement(Statement::Block(BlockStatement::from_ast(scope, statement, None)?)),
+            Match(statement) => Self::from_ast(scope, statement, None)?,
         })
     }
 }
@@ -121,9 +145,12 @@ impl<'a> Into<leo_ast::Statement> for &Statement<'a> {
             Assign(statement) => leo_ast::Statement::Assign(statement.into()),
             Conditional(statement) => leo_ast::Statement::Conditional(statement.into()),
             Iteration(statement) => leo_ast::Statement::Iteration(statement.into()),
+            Break(statement) => leo_ast::Statement::Break(statement.into()),
+            Continue(statement) => leo_ast::Statement::Continue(statement.into()),
             Console(statement) => leo_ast::Statement::Console(statement.into()),
             Expression(statement) => leo_ast::Statement::Expression(statement.into()),
             Block(statement) => leo_ast::Statement::Block(statement.into()),
+            Match(statement) => leo_ast::Statement::Match(statement.into()),
             Empty(_) => unimplemented!(),
         }
     }
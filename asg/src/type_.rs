@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::Circuit;
+use crate::{Circuit, Enum};
 pub use leo_ast::IntegerType;
 
 use std::fmt;
@@ -34,6 +34,7 @@ pub enum Type<'a> {
     Array(Box<Type<'a>>, usize),
     Tuple(Vec<Type<'a>>),
     Circuit(&'a Circuit<'a>),
+    Enum(&'a Enum<'a>),
 }
 
 #[derive(Clone, PartialEq)]
@@ -125,8 +126,39 @@ impl<'a> Type<'a> {
         matches!(self, Type::Tuple(t) if t.is_empty())
     }
 
+    /// Returns `true` if values of this type can be cast to the given type.
+    /// Arrays are cast element-wise, so their lengths must agree.
     pub fn can_cast_to(&self, to: &Type<'a>) -> bool {
-        matches!(self, Type::Integer(_)) && matches!(to, Type::Integer(_))
+        match (self, to) {
+            (Type::Integer(_), Type::Integer(_))
+            | (Type::Integer(_), Type::Field)
+            | (Type::Field, Type::Integer(_))
+            | (Type::Field, Type::Field)
+            | (Type::Integer(_), Type::Char)
+            | (Type::Char, Type::Integer(_))
+            | (Type::Char, Type::Char) => true,
+            (Type::Array(from, from_len), Type::Array(to, to_len)) => from_len == to_len && from.can_cast_to(to),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if values of this type can be cast to `to` with a wrapping cast, which only yields integers and fields.
+    pub fn can_wrapping_cast_to(&self, to: &Type<'a>) -> bool {
+        match (self, to) {
+            (_, Type::Char) => false,
+            (Type::Array(from, from_len), Type::Array(to, to_len)) => {
+                from_len == to_len && from.can_wrapping_cast_to(to)
+            }
+            _ => self.can_cast_to(to),
+        }
+    }
+
+    /// Returns the length of a string, which is an array of characters `[char; N]`.
+    pub fn string_len(&self) -> Option<usize> {
+        match self {
+            Type::Array(item, len) if **item == Type::Char => Some(*len),
+            _ => None,
+        }
     }
 }
 
@@ -151,6 +183,7 @@ impl<'a> fmt::Display for Type<'a> {
                 write!(f, ")")
             }
             Type::Circuit(circuit) => write!(f, "{}", &circuit.name.borrow().name),
+            Type::Enum(enum_) => write!(f, "{}", &enum_.name.borrow().name),
         }
     }
 }
@@ -207,12 +240,13 @@ impl<'a> Into<leo_ast::Type> for &Type<'a> {
             Integer(int_type) => leo_ast::Type::IntegerType(int_type.clone()),
             Array(type_, len) => leo_ast::Type::Array(
                 Box::new(type_.as_ref().into()),
-                leo_ast::ArrayDimensions(vec![leo_ast::PositiveNumber {
+                leo_ast::ArrayDimensions(vec![leo_ast::Dimension::Number(leo_ast::PositiveNumber {
                     value: len.to_string().into(),
-                }]),
+                })]),
             ),
             Tuple(subtypes) => leo_ast::Type::Tuple(subtypes.iter().map(Into::into).collect()),
             Circuit(circuit) => leo_ast::Type::Circuit(circuit.name.borrow().clone()),
+            Enum(enum_) => leo_ast::Type::Circuit(enum_.name.borrow().clone()),
         }
     }
 }
@@ -24,6 +24,7 @@ use leo_ast::Identifier;
 pub enum VariableDeclaration {
     Definition,
     IterationDefinition,
+    MatchBinding,
     Parameter,
     Input,
 }
@@ -28,4 +28,5 @@ pub mod import;
 pub mod integers;
 pub mod mutability;
 pub mod statements;
+pub mod string;
 pub mod tuples;
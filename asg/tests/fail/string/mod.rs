@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::load_asg;
+
+#[test]
+fn test_string_length_mismatch() {
+    let program_string = r#"
+    function main() {
+        const s: [char; 2] = "Leo";
+    }
+    "#;
+    load_asg(program_string).err().unwrap();
+}
+
+#[test]
+fn test_unknown_string_function() {
+    let program_string = r#"
+    function main() {
+        const s = String::reverse("Leo");
+    }
+    "#;
+    load_asg(program_string).err().unwrap();
+}
+
+#[test]
+fn test_concat_argument_count() {
+    let program_string = r#"
+    function main() {
+        const s = String::concat("Leo");
+    }
+    "#;
+    load_asg(program_string).err().unwrap();
+}
+
+#[test]
+fn test_from_bytes_of_string() {
+    let program_string = r#"
+    function main() {
+        const s = String::from_bytes("Leo");
+    }
+    "#;
+    load_asg(program_string).err().unwrap();
+}
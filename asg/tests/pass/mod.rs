@@ -30,4 +30,5 @@ pub mod input_files;
 pub mod integers;
 pub mod mutability;
 pub mod statements;
+pub mod string;
 pub mod tuples;
@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::load_asg;
+
+#[test]
+fn test_string_literal() {
+    let program_string = r#"
+    function main() {
+        const s: [char; 3] = "Leo";
+        console.assert(s[0] == 'L');
+    }
+    "#;
+    load_asg(program_string).unwrap();
+}
+
+#[test]
+fn test_string_functions() {
+    let program_string = r#"
+    function main(s: [char; 4]) {
+        let t: [char; 7] = String::concat("Leo", s);
+        let b: [u8; 7] = String::to_bytes(t);
+        let u: [char; 7] = String::from_bytes(b);
+        console.assert(String::len(u) == 7);
+    }
+    "#;
+    load_asg(program_string).unwrap();
+}
+
+#[test]
+fn test_string_ordering() {
+    let program_string = r#"
+    function main(s: [char; 3]) {
+        console.assert(s < "Leo" || 'a' >= s[0]);
+    }
+    "#;
+    load_asg(program_string).unwrap();
+}
+
+#[test]
+fn test_user_string_circuit() {
+    let program_string = r#"
+    circuit String {
+        function len() -> u8 {
+            return 0;
+        }
+    }
+
+    function main() {
+        console.assert(String::len() == 0);
+    }
+    "#;
+    load_asg(program_string).unwrap();
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CircuitMember, Identifier};
+use crate::{CircuitMember, ConstParameter, Identifier};
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -22,12 +22,32 @@ use std::fmt;
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Circuit {
     pub circuit_name: Identifier,
+    /// The const parameters of a generic circuit, as in `circuit Buffer<const N: u32>`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub const_parameters: Vec<ConstParameter>,
     pub members: Vec<CircuitMember>,
 }
 
 impl Circuit {
+    ///
+    /// Returns `true` if the circuit has const parameters.
+    ///
+    pub fn is_generic(&self) -> bool {
+        !self.const_parameters.is_empty()
+    }
+
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "circuit {} {{ ", self.circuit_name)?;
+        write!(f, "circuit {}", self.circuit_name)?;
+        if self.is_generic() {
+            let const_parameters = self
+                .const_parameters
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, "<{}>", const_parameters)?;
+        }
+        writeln!(f, " {{ ")?;
         for field in self.members.iter() {
             writeln!(f, "    {}", field)?;
         }
@@ -14,16 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::PositiveNumber;
+use crate::{Dimension, PositiveNumber};
 use leo_input::types::ArrayDimensions as InputArrayDimensions;
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-/// A vector of positive numbers that represent array dimensions.
+/// A vector of dimensions that represent array dimensions.
 /// Can be used in an array [`Type`] or an array initializer [`Expression`].
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Default, Hash)]
-pub struct ArrayDimensions(pub Vec<PositiveNumber>);
+pub struct ArrayDimensions(pub Vec<Dimension>);
 
 impl ArrayDimensions {
     ///
@@ -53,23 +53,23 @@ impl ArrayDimensions {
     /// Returns `true` if there is an array dimension equal to zero.
     ///
     pub fn is_zero(&self) -> bool {
-        self.0.iter().any(|number| number.is_zero())
+        self.0.iter().any(|dimension| dimension.is_zero())
     }
 
     ///
     /// Returns the first dimension of the array.
     ///
-    pub fn first(&self) -> Option<&PositiveNumber> {
+    pub fn first(&self) -> Option<&Dimension> {
         self.0.first()
     }
 
     ///
     /// Attempts to remove the first dimension from the array.
     ///
-    /// If the first dimension exists, then remove and return `Some(PositiveNumber)`.
+    /// If the first dimension exists, then remove and return `Some(Dimension)`.
     /// If the first dimension does not exist, then return `None`.
     ///
-    pub fn remove_first(&mut self) -> Option<PositiveNumber> {
+    pub fn remove_first(&mut self) -> Option<Dimension> {
         // If there are no dimensions in the array, then return None.
         self.0.first()?;
 
@@ -83,10 +83,10 @@ impl ArrayDimensions {
     ///
     /// Attempts to remove the last dimension from the array.
     ///
-    /// If the last dimension exists, then remove and return `Some(PositiveNumber)`.
+    /// If the last dimension exists, then remove and return `Some(Dimension)`.
     /// If the last dimension does not exist, then return `None`.
     ///
-    pub fn remove_last(&mut self) -> Option<PositiveNumber> {
+    pub fn remove_last(&mut self) -> Option<Dimension> {
         self.0.pop()
     }
 }
@@ -95,10 +95,12 @@ impl ArrayDimensions {
 impl<'ast> From<InputArrayDimensions<'ast>> for ArrayDimensions {
     fn from(dimensions: InputArrayDimensions<'ast>) -> Self {
         Self(match dimensions {
-            InputArrayDimensions::Single(single) => vec![PositiveNumber::from(single.number)],
-            InputArrayDimensions::Multiple(multiple) => {
-                multiple.numbers.into_iter().map(PositiveNumber::from).collect()
-            }
+            InputArrayDimensions::Single(single) => vec![PositiveNumber::from(single.number).into()],
+            InputArrayDimensions::Multiple(multiple) => multiple
+                .numbers
+                .into_iter()
+                .map(|number| PositiveNumber::from(number).into())
+                .collect(),
         })
     }
 }
@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, PositiveNumber};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An array dimension or const argument.
+/// Either a number literal or the name of a const parameter, as in `[u8; N]`.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+pub enum Dimension {
+    Number(PositiveNumber),
+    Parameter(Identifier),
+}
+
+impl Dimension {
+    ///
+    /// Returns `true` if this dimension is the number zero.
+    ///
+    pub fn is_zero(&self) -> bool {
+        match self {
+            Dimension::Number(number) => number.is_zero(),
+            Dimension::Parameter(_) => false,
+        }
+    }
+}
+
+impl From<PositiveNumber> for Dimension {
+    fn from(number: PositiveNumber) -> Self {
+        Dimension::Number(number)
+    }
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Dimension::Number(number) => number.fmt(f),
+            Dimension::Parameter(identifier) => identifier.fmt(f),
+        }
+    }
+}
@@ -17,9 +17,15 @@
 pub mod array_dimensions;
 pub use array_dimensions::*;
 
+pub mod const_parameter;
+pub use const_parameter::*;
+
 pub mod const_self_keyword;
 pub use const_self_keyword::*;
 
+pub mod dimension;
+pub use dimension::*;
+
 pub mod identifier;
 pub use identifier::*;
 
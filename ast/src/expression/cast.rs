@@ -22,12 +22,19 @@ use super::*;
 pub struct CastExpression {
     pub inner: Box<Expression>,
     pub target_type: Type,
+    /// Whether the cast keeps the low bits of the value instead of requiring the value to fit, see RFC 004.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub wrapping: bool,
     pub span: Span,
 }
 
 impl fmt::Display for CastExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} as {}", self.inner, self.target_type)
+        if self.wrapping {
+            write!(f, "{} as wrapping {}", self.inner, self.target_type)
+        } else {
+            write!(f, "{} as {}", self.inner, self.target_type)
+        }
     }
 }
 
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Annotation, Block, FunctionInput, Identifier, Node, Span, Type};
+use crate::{Annotation, Block, ConstParameter, FunctionInput, Identifier, Node, Span, Type};
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -23,6 +23,9 @@ use std::fmt;
 pub struct Function {
     pub annotations: Vec<Annotation>,
     pub identifier: Identifier,
+    /// The const parameters of a generic function, as in `function hash<const N: u32>`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub const_parameters: Vec<ConstParameter>,
     pub input: Vec<FunctionInput>,
     pub output: Option<Type>,
     pub block: Block,
@@ -42,6 +45,13 @@ impl Function {
         &self.identifier.name
     }
 
+    ///
+    /// Returns `true` if the function has const parameters.
+    ///
+    pub fn is_generic(&self) -> bool {
+        !self.const_parameters.is_empty()
+    }
+
     ///
     /// Returns `true` if the function has input `self` or `mut self`.
     /// Returns `false` otherwise.
@@ -67,6 +77,15 @@ impl Function {
 
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "function {}", self.identifier)?;
+        if self.is_generic() {
+            let const_parameters = self
+                .const_parameters
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, "<{}>", const_parameters)?;
+        }
 
         let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
         let returns = self.output.as_ref().map(|type_| type_.to_string());
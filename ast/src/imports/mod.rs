@@ -31,3 +31,6 @@ pub use package_or_packages::*;
 
 pub mod package_access;
 pub use package_access::*;
+
+pub mod module;
+pub use module::*;
@@ -0,0 +1,38 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, Span};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Represents a `mod name;` declaration of another file of the same package.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ModuleDeclaration {
+    pub name: Identifier,
+    /// Whether the module can be imported from outside the module that declares it.
+    pub public: bool,
+    pub span: Span,
+}
+
+impl fmt::Display for ModuleDeclaration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.public {
+            write!(f, "pub ")?;
+        }
+        write!(f, "mod {};", self.name)
+    }
+}
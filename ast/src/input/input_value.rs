@@ -17,8 +17,15 @@
 use crate::{ArrayDimensions, Char, CharValue, GroupValue, Span as AstSpan};
 use leo_input::{
     errors::InputParserError,
-    expressions::{ArrayInitializerExpression, ArrayInlineExpression, Expression, StringExpression, TupleExpression},
-    types::{ArrayType, CharType, DataType, IntegerType, TupleType, Type},
+    expressions::{
+        ArrayInitializerExpression,
+        ArrayInlineExpression,
+        EnumExpression,
+        Expression,
+        StringExpression,
+        TupleExpression,
+    },
+    types::{ArrayType, CharType, DataType, EnumType, IntegerType, TupleType, Type},
     values::{
         Address,
         AddressValue,
@@ -45,6 +52,8 @@ pub enum InputValue {
     Integer(IntegerType, String),
     Array(Vec<InputValue>),
     Tuple(Vec<InputValue>),
+    /// An enum value given by the enum name, the variant name and the values it carries.
+    Enum(String, String, Vec<InputValue>),
 }
 
 impl InputValue {
@@ -151,6 +160,7 @@ impl InputValue {
                 InputValue::from_string(array_type, string)
             }
             (Type::Tuple(tuple_type), Expression::Tuple(tuple)) => InputValue::from_tuple(tuple_type, tuple),
+            (Type::Enum(enum_type), Expression::Enum(enum_)) => InputValue::from_enum(enum_type, enum_),
             (type_, expression) => Err(InputParserError::expression_type_mismatch(type_, expression)),
         }
     }
@@ -345,6 +355,72 @@ impl InputValue {
 
         Ok(InputValue::Tuple(values))
     }
+
+    ///
+    /// Returns a new `InputValue` from the given `EnumType` and `EnumExpression`.
+    ///
+    /// The payload types are declared in the program, so the values of a variant must carry their types.
+    ///
+    pub(crate) fn from_enum(enum_type: EnumType, enum_: EnumExpression) -> Result<Self, InputParserError> {
+        if enum_type.identifier.value != enum_.enum_name.value {
+            return Err(InputParserError::enum_name_mismatch(
+                enum_type.identifier.value,
+                enum_.enum_name.value,
+                &enum_.span,
+            ));
+        }
+
+        let mut values = Vec::with_capacity(enum_.expressions.len());
+        for expression in enum_.expressions.into_iter() {
+            values.push(InputValue::from_typed_expression(expression)?);
+        }
+
+        Ok(InputValue::Enum(enum_.enum_name.value, enum_.variant.value, values))
+    }
+
+    ///
+    /// Returns a new `InputValue` from an expression whose values all have an explicit type.
+    ///
+    fn from_typed_expression(expression: Expression) -> Result<Self, InputParserError> {
+        match expression {
+            Expression::Value(Value::Address(address)) => Ok(InputValue::from_address_value(address)),
+            Expression::Value(Value::Boolean(boolean)) => InputValue::from_boolean(boolean),
+            Expression::Value(Value::Char(character)) => InputValue::from_char(character),
+            Expression::Value(Value::Field(field)) => Ok(InputValue::from_field(field)),
+            Expression::Value(Value::Group(group)) => Ok(InputValue::from_group(group)),
+            Expression::Value(Value::Integer(IntegerValue::Signed(integer))) => Ok(InputValue::from_number(
+                IntegerType::Signed(integer.type_.clone()),
+                integer.to_string(),
+            )),
+            Expression::Value(Value::Integer(IntegerValue::Unsigned(integer))) => Ok(InputValue::from_number(
+                IntegerType::Unsigned(integer.type_.clone()),
+                integer.to_string(),
+            )),
+            Expression::ArrayInline(inline) => Ok(InputValue::Array(
+                inline
+                    .expressions
+                    .into_iter()
+                    .map(InputValue::from_typed_expression)
+                    .collect::<Result<Vec<_>, _>>()?,
+            )),
+            Expression::Tuple(tuple) => Ok(InputValue::Tuple(
+                tuple
+                    .expressions
+                    .into_iter()
+                    .map(InputValue::from_typed_expression)
+                    .collect::<Result<Vec<_>, _>>()?,
+            )),
+            Expression::Enum(enum_) => {
+                let mut values = Vec::with_capacity(enum_.expressions.len());
+                for expression in enum_.expressions.into_iter() {
+                    values.push(InputValue::from_typed_expression(expression)?);
+                }
+
+                Ok(InputValue::Enum(enum_.enum_name.value, enum_.variant.value, values))
+            }
+            expression => Err(InputParserError::enum_value_type(expression)),
+        }
+    }
 }
 
 ///
@@ -415,6 +491,15 @@ impl fmt::Display for InputValue {
 
                 write!(f, "({})", values)
             }
+            InputValue::Enum(ref enum_, ref variant, ref values) => {
+                write!(f, "{}::{}", enum_, variant)?;
+                if !values.is_empty() {
+                    let values = values.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+
+                    write!(f, "({})", values)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -35,6 +35,9 @@ pub use self::chars::*;
 pub mod common;
 pub use self::common::*;
 
+pub mod enums;
+pub use self::enums::*;
+
 pub mod errors;
 pub use self::errors::*;
 
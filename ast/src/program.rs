@@ -17,9 +17,9 @@
 //! A Leo program consists of import, circuit, and function definitions.
 //! Each defined type consists of ast statements and expressions.
 
-use crate::{Circuit, DefinitionStatement, Function, FunctionInput, Identifier, ImportStatement};
+use crate::{Circuit, DefinitionStatement, Enum, Function, FunctionInput, Identifier, ImportStatement, ModuleDeclaration};
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -29,7 +29,15 @@ pub struct Program {
     pub name: String,
     pub expected_input: Vec<FunctionInput>,
     pub imports: Vec<ImportStatement>,
+    /// The modules of the package declared by this file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modules: Vec<ModuleDeclaration>,
+    /// The names of the circuits, functions and global constants declared `pub`.
+    #[serde(default, skip_serializing_if = "IndexSet::is_empty")]
+    pub public: IndexSet<String>,
     pub circuits: IndexMap<Identifier, Circuit>,
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub enums: IndexMap<Identifier, Enum>,
     pub global_consts: IndexMap<String, DefinitionStatement>,
     pub functions: IndexMap<Identifier, Function>,
 }
@@ -46,11 +54,19 @@ impl fmt::Display for Program {
             import.fmt(f)?;
             writeln!(f,)?;
         }
+        for module in self.modules.iter() {
+            module.fmt(f)?;
+            writeln!(f,)?;
+        }
         writeln!(f,)?;
         for (_, circuit) in self.circuits.iter() {
             circuit.fmt(f)?;
             writeln!(f,)?;
         }
+        for (_, enum_) in self.enums.iter() {
+            enum_.fmt(f)?;
+            writeln!(f,)?;
+        }
         writeln!(f,)?;
         for (_, function) in self.functions.iter() {
             function.fmt(f)?;
@@ -66,7 +82,10 @@ impl Program {
             name,
             expected_input: vec![],
             imports: vec![],
+            modules: vec![],
+            public: IndexSet::new(),
             circuits: IndexMap::new(),
+            enums: IndexMap::new(),
             global_consts: IndexMap::new(),
             functions: IndexMap::new(),
         }
@@ -25,6 +25,8 @@ use crate::*;
 pub struct Canonicalizer {
     // If we are in a circuit keep track of the circuit name.
     circuit_name: Option<Identifier>,
+    // If we are in a generic circuit keep track of its const parameters.
+    circuit_const_parameters: Vec<ConstParameter>,
     in_circuit: bool,
 }
 
@@ -32,6 +34,7 @@ impl Default for Canonicalizer {
     fn default() -> Self {
         Self {
             circuit_name: None,
+            circuit_const_parameters: vec![],
             in_circuit: false,
         }
     }
@@ -110,6 +113,25 @@ impl Canonicalizer {
         matches!(type_option, Some(Type::SelfType))
     }
 
+    ///
+    /// Returns the type that `Self` stands for in the enclosing circuit.
+    ///
+    /// A generic circuit is applied to its own const parameters, as in `Buffer<N>`.
+    ///
+    fn self_type(&self) -> Type {
+        let circuit_name = self.circuit_name.as_ref().unwrap().clone();
+        if self.circuit_const_parameters.is_empty() {
+            Type::Circuit(circuit_name)
+        } else {
+            let arguments = self
+                .circuit_const_parameters
+                .iter()
+                .map(|parameter| Dimension::Parameter(parameter.identifier.clone()))
+                .collect();
+            Type::GenericCircuit(circuit_name, arguments)
+        }
+    }
+
     fn canonicalize_expression(&mut self, expression: &Expression) -> Expression {
         match expression {
             Expression::Unary(unary) => {
@@ -150,12 +172,13 @@ impl Canonicalizer {
                 let mut target_type = cast.target_type.clone();
 
                 if matches!(target_type, Type::SelfType) {
-                    target_type = Type::Circuit(self.circuit_name.as_ref().unwrap().clone());
+                    target_type = self.self_type();
                 }
 
                 return Expression::Cast(CastExpression {
                     inner,
                     target_type,
+                    wrapping: cast.wrapping,
                     span: cast.span.clone(),
                 });
             }
@@ -336,7 +359,7 @@ impl Canonicalizer {
                 let mut type_ = definition.type_.clone();
 
                 if self.is_self_type(type_.as_ref()) {
-                    type_ = Some(Type::Circuit(self.circuit_name.as_ref().unwrap().clone()));
+                    type_ = Some(self.self_type());
                 }
 
                 Statement::Definition(DefinitionStatement {
@@ -386,6 +409,25 @@ impl Canonicalizer {
                     span: iteration.span.clone(),
                 })
             }
+            Statement::Break(_) | Statement::Continue(_) => statement.clone(),
+            Statement::Match(match_statement) => {
+                let value = self.canonicalize_expression(&match_statement.value);
+                let arms = match_statement
+                    .arms
+                    .iter()
+                    .map(|arm| MatchArm {
+                        pattern: arm.pattern.clone(),
+                        block: self.canonicalize_block(&arm.block),
+                        span: arm.span.clone(),
+                    })
+                    .collect();
+
+                Statement::Match(MatchStatement {
+                    value,
+                    arms,
+                    span: match_statement.span.clone(),
+                })
+            }
             Statement::Console(console_function_call) => {
                 let function = match &console_function_call.function {
                     ConsoleFunction::Assert(expression) => {
@@ -435,12 +477,13 @@ impl Canonicalizer {
                 let block = self.canonicalize_block(&function.block);
 
                 if self.is_self_type(output.as_ref()) {
-                    output = Some(Type::Circuit(self.circuit_name.as_ref().unwrap().clone()));
+                    output = Some(self.self_type());
                 }
 
                 return CircuitMember::CircuitFunction(Function {
                     annotations: function.annotations.clone(),
                     identifier: function.identifier.clone(),
+                    const_parameters: function.const_parameters.clone(),
                     input,
                     output,
                     block,
@@ -649,6 +692,7 @@ impl ReconstructingReducer for Canonicalizer {
         Ok(Function {
             identifier,
             annotations,
+            const_parameters: function.const_parameters.clone(),
             input,
             output: new_output,
             block,
@@ -658,19 +702,22 @@ impl ReconstructingReducer for Canonicalizer {
 
     fn reduce_circuit(
         &mut self,
-        _circuit: &Circuit,
+        circuit: &Circuit,
         circuit_name: Identifier,
         members: Vec<CircuitMember>,
     ) -> Result<Circuit, ReducerError> {
         self.circuit_name = Some(circuit_name.clone());
+        self.circuit_const_parameters = circuit.const_parameters.clone();
         let circ = Circuit {
             circuit_name,
+            const_parameters: circuit.const_parameters.clone(),
             members: members
                 .iter()
                 .map(|member| self.canonicalize_circuit_member(member))
                 .collect(),
         };
         self.circuit_name = None;
+        self.circuit_const_parameters = vec![];
         Ok(circ)
     }
 }
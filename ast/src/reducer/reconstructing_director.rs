@@ -41,6 +41,9 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
                 Type::Tuple(reduced_types)
             }
             Type::Circuit(identifier) => Type::Circuit(self.reduce_identifier(identifier)?),
+            Type::GenericCircuit(identifier, arguments) => {
+                Type::GenericCircuit(self.reduce_identifier(identifier)?, arguments.clone())
+            }
             _ => type_.clone(),
         };
 
@@ -289,6 +292,9 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
             Statement::Assign(assign) => Statement::Assign(self.reduce_assign(&assign)?),
             Statement::Conditional(conditional) => Statement::Conditional(self.reduce_conditional(&conditional)?),
             Statement::Iteration(iteration) => Statement::Iteration(self.reduce_iteration(&iteration)?),
+            Statement::Break(break_statement) => Statement::Break(break_statement.clone()),
+            Statement::Continue(continue_statement) => Statement::Continue(continue_statement.clone()),
+            Statement::Match(match_statement) => Statement::Match(self.reduce_match(&match_statement)?),
             Statement::Console(console) => Statement::Console(self.reduce_console(&console)?),
             Statement::Expression(expression) => Statement::Expression(self.reduce_expression_statement(&expression)?),
             Statement::Block(block) => Statement::Block(self.reduce_block(&block)?),
@@ -384,6 +390,40 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
         self.reducer.reduce_iteration(iteration, variable, start, stop, block)
     }
 
+    pub fn reduce_match_arm(&mut self, arm: &MatchArm) -> Result<MatchArm, ReducerError> {
+        let pattern = match &arm.pattern {
+            MatchPattern::Variant {
+                enum_name,
+                variant,
+                bindings,
+                span,
+            } => MatchPattern::Variant {
+                enum_name: self.reduce_identifier(enum_name)?,
+                variant: self.reduce_identifier(variant)?,
+                bindings: bindings
+                    .iter()
+                    .map(|binding| self.reduce_identifier(binding))
+                    .collect::<Result<Vec<_>, _>>()?,
+                span: span.clone(),
+            },
+            MatchPattern::Wildcard(span) => MatchPattern::Wildcard(span.clone()),
+        };
+        let block = self.reduce_block(&arm.block)?;
+
+        self.reducer.reduce_match_arm(arm, pattern, block)
+    }
+
+    pub fn reduce_match(&mut self, match_statement: &MatchStatement) -> Result<MatchStatement, ReducerError> {
+        let value = self.reduce_expression(&match_statement.value)?;
+
+        let mut arms = vec![];
+        for arm in match_statement.arms.iter() {
+            arms.push(self.reduce_match_arm(arm)?);
+        }
+
+        self.reducer.reduce_match(match_statement, value, arms)
+    }
+
     pub fn reduce_console(
         &mut self,
         console_function_call: &ConsoleStatement,
@@ -450,6 +490,11 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
         }
         self.reducer.swap_in_circuit();
 
+        let mut enums = IndexMap::new();
+        for (identifier, enum_) in program.enums.iter() {
+            enums.insert(self.reduce_identifier(identifier)?, self.reduce_enum(enum_)?);
+        }
+
         let mut functions = IndexMap::new();
         for (identifier, function) in program.functions.iter() {
             functions.insert(self.reduce_identifier(identifier)?, self.reduce_function(function)?);
@@ -461,7 +506,7 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
         }
 
         self.reducer
-            .reduce_program(program, inputs, imports, circuits, functions, global_consts)
+            .reduce_program(program, inputs, imports, circuits, enums, functions, global_consts)
     }
 
     pub fn reduce_function_input_variable(
@@ -511,6 +556,28 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
         self.reducer.reduce_import(import, package_or_packages)
     }
 
+    pub fn reduce_enum_variant(&mut self, variant: &EnumVariant) -> Result<EnumVariant, ReducerError> {
+        let name = self.reduce_identifier(&variant.name)?;
+
+        let mut payload = vec![];
+        for type_ in variant.payload.iter() {
+            payload.push(self.reduce_type(type_, &variant.name.span)?);
+        }
+
+        self.reducer.reduce_enum_variant(variant, name, payload)
+    }
+
+    pub fn reduce_enum(&mut self, enum_: &Enum) -> Result<Enum, ReducerError> {
+        let enum_name = self.reduce_identifier(&enum_.enum_name)?;
+
+        let mut variants = vec![];
+        for variant in enum_.variants.iter() {
+            variants.push(self.reduce_enum_variant(variant)?);
+        }
+
+        self.reducer.reduce_enum(enum_, enum_name, variants)
+    }
+
     pub fn reduce_circuit_member(&mut self, circuit_member: &CircuitMember) -> Result<CircuitMember, ReducerError> {
         let new = match circuit_member {
             CircuitMember::CircuitVariable(identifier, type_) => CircuitMember::CircuitVariable(
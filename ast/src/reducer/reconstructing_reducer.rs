@@ -114,6 +114,7 @@ pub trait ReconstructingReducer {
         Ok(CastExpression {
             inner: Box::new(inner),
             target_type,
+            wrapping: cast.wrapping,
             span: cast.span.clone(),
         })
     }
@@ -364,6 +365,32 @@ pub trait ReconstructingReducer {
         })
     }
 
+    fn reduce_match_arm(
+        &mut self,
+        arm: &MatchArm,
+        pattern: MatchPattern,
+        block: Block,
+    ) -> Result<MatchArm, ReducerError> {
+        Ok(MatchArm {
+            pattern,
+            block,
+            span: arm.span.clone(),
+        })
+    }
+
+    fn reduce_match(
+        &mut self,
+        match_statement: &MatchStatement,
+        value: Expression,
+        arms: Vec<MatchArm>,
+    ) -> Result<MatchStatement, ReducerError> {
+        Ok(MatchStatement {
+            value,
+            arms,
+            span: match_statement.span.clone(),
+        })
+    }
+
     fn reduce_console(
         &mut self,
         console: &ConsoleStatement,
@@ -400,6 +427,7 @@ pub trait ReconstructingReducer {
         expected_input: Vec<FunctionInput>,
         imports: Vec<ImportStatement>,
         circuits: IndexMap<Identifier, Circuit>,
+        enums: IndexMap<Identifier, Enum>,
         functions: IndexMap<Identifier, Function>,
         global_consts: IndexMap<String, DefinitionStatement>,
     ) -> Result<Program, ReducerError> {
@@ -407,7 +435,10 @@ pub trait ReconstructingReducer {
             name: program.name.clone(),
             expected_input,
             imports,
+            modules: program.modules.clone(),
+            public: program.public.clone(),
             circuits,
+            enums,
             functions,
             global_consts,
         })
@@ -465,11 +496,33 @@ pub trait ReconstructingReducer {
 
     fn reduce_circuit(
         &mut self,
-        _circuit: &Circuit,
+        circuit: &Circuit,
         circuit_name: Identifier,
         members: Vec<CircuitMember>,
     ) -> Result<Circuit, ReducerError> {
-        Ok(Circuit { circuit_name, members })
+        Ok(Circuit {
+            circuit_name,
+            const_parameters: circuit.const_parameters.clone(),
+            members,
+        })
+    }
+
+    fn reduce_enum_variant(
+        &mut self,
+        _variant: &EnumVariant,
+        name: Identifier,
+        payload: Vec<Type>,
+    ) -> Result<EnumVariant, ReducerError> {
+        Ok(EnumVariant { name, payload })
+    }
+
+    fn reduce_enum(
+        &mut self,
+        _enum_: &Enum,
+        enum_name: Identifier,
+        variants: Vec<EnumVariant>,
+    ) -> Result<Enum, ReducerError> {
+        Ok(Enum { enum_name, variants })
     }
 
     fn reduce_annotation(&mut self, annotation: &Annotation, name: Identifier) -> Result<Annotation, ReducerError> {
@@ -493,6 +546,7 @@ pub trait ReconstructingReducer {
         Ok(Function {
             identifier,
             annotations,
+            const_parameters: function.const_parameters.clone(),
             input,
             output,
             block,
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Block, Expression, Identifier, Node, Span};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// What an arm of a `match` statement matches against.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum MatchPattern {
+    /// `Enum::Variant` or `Enum::Variant(a, _)`, binding the values the variant carries.
+    /// A binding named `_` ignores its value.
+    Variant {
+        enum_name: Identifier,
+        variant: Identifier,
+        bindings: Vec<Identifier>,
+        span: Span,
+    },
+    /// `_`, matching every variant not matched by a previous arm.
+    Wildcard(Span),
+}
+
+impl fmt::Display for MatchPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatchPattern::Variant {
+                enum_name,
+                variant,
+                bindings,
+                ..
+            } => {
+                write!(f, "{}::{}", enum_name, variant)?;
+                if !bindings.is_empty() {
+                    let bindings = bindings.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+                    write!(f, "({})", bindings)?;
+                }
+                Ok(())
+            }
+            MatchPattern::Wildcard(_) => write!(f, "_"),
+        }
+    }
+}
+
+impl Node for MatchPattern {
+    fn span(&self) -> &Span {
+        match self {
+            MatchPattern::Variant { span, .. } => span,
+            MatchPattern::Wildcard(span) => span,
+        }
+    }
+
+    fn set_span(&mut self, new_span: Span) {
+        match self {
+            MatchPattern::Variant { span, .. } => *span = new_span,
+            MatchPattern::Wildcard(span) => *span = new_span,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct MatchArm {
+    pub pattern: MatchPattern,
+    pub block: Block,
+    pub span: Span,
+}
+
+impl fmt::Display for MatchArm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} => {}", self.pattern, self.block)
+    }
+}
+
+impl Node for MatchArm {
+    fn span(&self) -> &Span {
+        &self.span
+    }
+
+    fn set_span(&mut self, span: Span) {
+        self.span = span;
+    }
+}
+
+/// `match value { ... }`, running the block of the arm whose pattern matches the enum `value`.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct MatchStatement {
+    pub value: Expression,
+    pub arms: Vec<MatchArm>,
+    pub span: Span,
+}
+
+impl fmt::Display for MatchStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "match {} {{", self.value)?;
+        for arm in self.arms.iter() {
+            writeln!(f, "\t{}", arm)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl Node for MatchStatement {
+    fn span(&self) -> &Span {
+        &self.span
+    }
+
+    fn set_span(&mut self, span: Span) {
+        self.span = span;
+    }
+}
@@ -26,9 +26,18 @@ pub use block::*;
 pub mod return_statement;
 pub use return_statement::*;
 
+pub mod break_statement;
+pub use break_statement::*;
+
+pub mod continue_statement;
+pub use continue_statement::*;
+
 pub mod iteration;
 pub use iteration::*;
 
+pub mod match_statement;
+pub use match_statement::*;
+
 pub mod expression;
 pub use expression::*;
 
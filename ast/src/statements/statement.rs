@@ -28,6 +28,9 @@ pub enum Statement {
     Assign(AssignStatement),
     Conditional(ConditionalStatement),
     Iteration(IterationStatement),
+    Break(BreakStatement),
+    Continue(ContinueStatement),
+    Match(MatchStatement),
     Console(ConsoleStatement),
     Expression(ExpressionStatement),
     Block(Block),
@@ -41,6 +44,9 @@ impl fmt::Display for Statement {
             Statement::Assign(x) => x.fmt(f),
             Statement::Conditional(x) => x.fmt(f),
             Statement::Iteration(x) => x.fmt(f),
+            Statement::Break(x) => x.fmt(f),
+            Statement::Continue(x) => x.fmt(f),
+            Statement::Match(x) => x.fmt(f),
             Statement::Console(x) => x.fmt(f),
             Statement::Expression(x) => x.fmt(f),
             Statement::Block(x) => x.fmt(f),
@@ -57,6 +63,9 @@ impl Node for Statement {
             Assign(n) => n.span(),
             Conditional(n) => n.span(),
             Iteration(n) => n.span(),
+            Break(n) => n.span(),
+            Continue(n) => n.span(),
+            Match(n) => n.span(),
             Console(n) => n.span(),
             Expression(n) => n.span(),
             Block(n) => n.span(),
@@ -71,6 +80,9 @@ impl Node for Statement {
             Assign(n) => n.set_span(span),
             Conditional(n) => n.set_span(span),
             Iteration(n) => n.set_span(span),
+            Break(n) => n.set_span(span),
+            Continue(n) => n.set_span(span),
+            Match(n) => n.set_span(span),
             Console(n) => n.set_span(span),
             Expression(n) => n.set_span(span),
             Block(n) => n.set_span(span),
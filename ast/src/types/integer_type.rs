@@ -15,8 +15,18 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_input::types::{
+    I128Type,
+    I16Type,
+    I32Type,
+    I64Type,
+    I8Type,
     IntegerType as InputIntegerType,
     SignedIntegerType as InputSignedIntegerType,
+    U128Type,
+    U16Type,
+    U32Type,
+    U64Type,
+    U8Type,
     UnsignedIntegerType as InputUnsignedIntegerType,
 };
 
@@ -79,6 +89,25 @@ impl From<InputSignedIntegerType> for IntegerType {
     }
 }
 
+/// Explicit type -> input pest ast
+
+impl From<IntegerType> for InputIntegerType {
+    fn from(integer_type: IntegerType) -> Self {
+        match integer_type {
+            IntegerType::U8 => InputIntegerType::Unsigned(InputUnsignedIntegerType::U8Type(U8Type {})),
+            IntegerType::U16 => InputIntegerType::Unsigned(InputUnsignedIntegerType::U16Type(U16Type {})),
+            IntegerType::U32 => InputIntegerType::Unsigned(InputUnsignedIntegerType::U32Type(U32Type {})),
+            IntegerType::U64 => InputIntegerType::Unsigned(InputUnsignedIntegerType::U64Type(U64Type {})),
+            IntegerType::U128 => InputIntegerType::Unsigned(InputUnsignedIntegerType::U128Type(U128Type {})),
+            IntegerType::I8 => InputIntegerType::Signed(InputSignedIntegerType::I8Type(I8Type {})),
+            IntegerType::I16 => InputIntegerType::Signed(InputSignedIntegerType::I16Type(I16Type {})),
+            IntegerType::I32 => InputIntegerType::Signed(InputSignedIntegerType::I32Type(I32Type {})),
+            IntegerType::I64 => InputIntegerType::Signed(InputSignedIntegerType::I64Type(I64Type {})),
+            IntegerType::I128 => InputIntegerType::Signed(InputSignedIntegerType::I128Type(I128Type {})),
+        }
+    }
+}
+
 impl fmt::Display for IntegerType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
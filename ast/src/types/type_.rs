@@ -14,10 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ArrayDimensions, Identifier, IntegerType};
+use crate::{ArrayDimensions, Dimension, Identifier, IntegerType};
 use leo_input::types::{
     ArrayType as InputArrayType,
     DataType as InputDataType,
+    EnumType as InputEnumType,
     TupleType as InputTupleType,
     Type as InputType,
 };
@@ -40,6 +41,8 @@ pub enum Type {
     Array(Box<Type>, ArrayDimensions),
     Tuple(Vec<Type>),
     Circuit(Identifier),
+    /// A generic circuit applied to its const arguments, as in `Buffer<32>`.
+    GenericCircuit(Identifier, Vec<Dimension>),
     SelfType,
 }
 
@@ -55,7 +58,7 @@ impl Type {
     /// Returns `true` if the self `Type` is a `Circuit`.
     ///
     pub fn is_circuit(&self) -> bool {
-        matches!(self, Type::Circuit(_))
+        matches!(self, Type::Circuit(_) | Type::GenericCircuit(..))
     }
 
     ///
@@ -72,6 +75,9 @@ impl Type {
             (Type::Group, Type::Group) => true,
             (Type::IntegerType(left), Type::IntegerType(right)) => left.eq(&right),
             (Type::Circuit(left), Type::Circuit(right)) => left.eq(&right),
+            (Type::GenericCircuit(left, left_arguments), Type::GenericCircuit(right, right_arguments)) => {
+                left.eq(&right) && left_arguments.eq(right_arguments)
+            }
             (Type::SelfType, Type::SelfType) => true,
             (Type::Array(left_type, left_dim), Type::Array(right_type, right_dim)) => {
                 // Convert array dimensions to owned.
@@ -135,12 +141,20 @@ impl<'ast> From<InputTupleType<'ast>> for Type {
     }
 }
 
+impl<'ast> From<InputEnumType<'ast>> for Type {
+    fn from(enum_type: InputEnumType<'ast>) -> Self {
+        // Enums share the namespace of circuits and are resolved by name.
+        Type::Circuit(Identifier::from(enum_type.identifier))
+    }
+}
+
 impl<'ast> From<InputType<'ast>> for Type {
     fn from(type_: InputType<'ast>) -> Self {
         match type_ {
             InputType::Basic(type_) => Type::from(type_),
             InputType::Array(type_) => Type::from(type_),
             InputType::Tuple(type_) => Type::from(type_),
+            InputType::Enum(type_) => Type::from(type_),
         }
     }
 }
@@ -155,6 +169,11 @@ impl fmt::Display for Type {
             Type::Group => write!(f, "group"),
             Type::IntegerType(ref integer_type) => write!(f, "{}", integer_type),
             Type::Circuit(ref variable) => write!(f, "circuit {}", variable),
+            Type::GenericCircuit(ref variable, ref arguments) => {
+                let arguments = arguments.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+
+                write!(f, "circuit {}<{}>", variable, arguments)
+            }
             Type::SelfType => write!(f, "SelfType"),
             Type::Array(ref array, ref dimensions) => write!(f, "[{}; {}]", *array, dimensions),
             Type::Tuple(ref tuple) => {
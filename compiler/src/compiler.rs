@@ -16,18 +16,31 @@
 
 //! Compiles a Leo program from a file path.
 use crate::{
-    constraints::{generate_constraints, generate_test_constraints},
+    constraints::{
+        generate_checked_constraints,
+        generate_constraints,
+        generate_profiled_constraints,
+        generate_test_constraints,
+    },
     errors::CompilerError,
+    interpret_program,
+    interpret_tests,
     CompilerOptions,
+    CoreCircuit,
+    CoreCircuitRegistry,
     GroupType,
     Output,
     OutputFile,
+    Profile,
+    PublicInputRecorder,
+    PublicInputsFile,
     TheoremOptions,
     TypeInferencePhase,
 };
 pub use leo_asg::{new_context, AsgContext as Context, AsgContext};
-use leo_asg::{Asg, AsgPass, FormattedError, Program as AsgProgram};
+use leo_asg::{Asg, AsgConvertError, AsgPass, FormattedError, Program as AsgProgram};
 use leo_ast::{Input, MainInput, Program as AstProgram};
+use leo_imports::ImportsMap;
 use leo_input::LeoInputParser;
 use leo_package::inputs::InputPairs;
 use leo_parser::parse_ast;
@@ -56,18 +69,32 @@ pub fn thread_leaked_context() -> AsgContext<'static> {
     THREAD_GLOBAL_CONTEXT.with(|f| *f)
 }
 
+/// The size of a synthesized circuit, keys generated for one circuit cannot be used with another shape.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CircuitShape {
+    pub num_constraints: usize,
+    pub num_public_variables: usize,
+    pub num_private_variables: usize,
+}
+
 /// Stores information to compile a Leo program.
 #[derive(Clone)]
 pub struct Compiler<'a, F: PrimeField, G: GroupType<F>> {
     program_name: String,
     main_file_path: PathBuf,
     output_directory: PathBuf,
+    imports_map: ImportsMap,
     program: AstProgram,
     program_input: Input,
+    /// The contents of the input file and the state file, constant inputs are part of the circuit.
+    input_sources: Vec<String>,
+    /// The files parsed to resolve the imports and modules of the program.
+    imported_files: Vec<PathBuf>,
     context: AsgContext<'a>,
     asg: Option<AsgProgram<'a>>,
     options: CompilerOptions,
     proof_options: TheoremOptions,
+    core_circuits: CoreCircuitRegistry<F, G>,
     _engine: PhantomData<F>,
     _group: PhantomData<G>,
 }
@@ -80,6 +107,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
         package_name: String,
         main_file_path: PathBuf,
         output_directory: PathBuf,
+        imports_map: ImportsMap,
         context: AsgContext<'a>,
         options: Option<CompilerOptions>,
         proof_options: Option<TheoremOptions>,
@@ -88,12 +116,16 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
             program_name: package_name.clone(),
             main_file_path,
             output_directory,
+            imports_map,
             program: AstProgram::new(package_name),
             program_input: Input::new(),
+            input_sources: vec![],
+            imported_files: vec![],
             asg: None,
             context,
             options: options.unwrap_or_default(),
             proof_options: proof_options.unwrap_or_default(),
+            core_circuits: CoreCircuitRegistry::default(),
             _engine: PhantomData,
             _group: PhantomData,
         }
@@ -110,6 +142,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
         package_name: String,
         main_file_path: PathBuf,
         output_directory: PathBuf,
+        imports_map: ImportsMap,
         context: AsgContext<'a>,
         options: Option<CompilerOptions>,
         proof_options: Option<TheoremOptions>,
@@ -118,6 +151,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
             package_name,
             main_file_path,
             output_directory,
+            imports_map,
             context,
             options,
             proof_options,
@@ -132,6 +166,30 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
         self.options = options;
     }
 
+    ///
+    /// Adds a circuit that the program can import from `core`.
+    ///
+    /// The circuit must be registered before the program is parsed.
+    ///
+    pub fn register_core_circuit<C: CoreCircuit<F, G> + 'static>(&mut self, circuit: C) {
+        self.context.register_core_module(circuit.declaration());
+        self.core_circuits.register(circuit);
+    }
+
+    ///
+    /// Returns the parsed program AST.
+    ///
+    pub fn ast(&self) -> &AstProgram {
+        &self.program
+    }
+
+    ///
+    /// Returns the program ASG, if the program has been parsed.
+    ///
+    pub fn asg(&self) -> Option<&AsgProgram<'a>> {
+        self.asg.as_ref()
+    }
+
     ///
     /// Returns a new `Compiler` from the given main file path.
     ///
@@ -145,6 +203,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
         package_name: String,
         main_file_path: PathBuf,
         output_directory: PathBuf,
+        imports_map: ImportsMap,
         input_string: &str,
         input_path: &Path,
         state_string: &str,
@@ -157,6 +216,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
             package_name,
             main_file_path,
             output_directory,
+            imports_map,
             context,
             options,
             proof_options,
@@ -215,6 +275,8 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
             e
         })?;
 
+        self.input_sources = vec![input_string.to_string(), state_string.to_string()];
+
         Ok(())
     }
 
@@ -259,12 +321,17 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
 
         tracing::debug!("Program parsing complete\n{:#?}", self.program);
 
+        // Parse the modules declared by the main file.
+        let mut import_parser = leo_imports::ImportParser::new(self.main_file_path.clone(), self.imports_map.clone());
+        if let Some(directory) = self.main_file_path.parent() {
+            import_parser
+                .declare_modules(directory, &self.program)
+                .map_err(|error| -> AsgConvertError { error.into() })?;
+        }
+
         // Create a new symbol table from the program, imported_programs, and program_input.
-        let asg = Asg::new(
-            self.context,
-            &self.program,
-            &mut leo_imports::ImportParser::new(self.main_file_path.clone()),
-        )?;
+        let asg = Asg::new(self.context, &self.program, &mut import_parser)?;
+        self.imported_files = import_parser.resolved_files().cloned().collect();
 
         if self.proof_options.type_inferenced {
             let new_ast = TypeInferencePhase::default()
@@ -308,27 +375,128 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
     /// Synthesizes the circuit with program input to verify correctness.
     ///
     pub fn compile_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<Output, CompilerError> {
-        generate_constraints::<F, G, CS>(cs, &self.asg.as_ref().unwrap(), &self.program_input)
+        generate_constraints::<F, G, _>(
+            cs,
+            self.asg.as_ref().unwrap(),
+            &self.program_input,
+            &self.options,
+            &self.core_circuits,
+        )
+    }
+
+    ///
+    /// Synthesizes the circuit with program input and collects the values of its public inputs.
+    ///
+    /// The public inputs are returned in allocation order, as expected by the verifier.
+    /// Fails at the first constraint that the program input does not satisfy.
+    ///
+    pub fn compile_constraints_with_public_inputs<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+    ) -> Result<(Output, Vec<F>), CompilerError> {
+        let mut recorder = PublicInputRecorder::new(cs);
+        let output = generate_checked_constraints::<F, G, _>(
+            &mut recorder,
+            self.asg.as_ref().unwrap(),
+            &self.program_input,
+            &self.options,
+            &self.core_circuits,
+        )?;
+
+        Ok((output, recorder.into_values()))
+    }
+
+    ///
+    /// Synthesizes the circuit with program input and profiles which frames of the program produce it.
+    ///
+    pub fn compile_constraints_with_profile<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+    ) -> Result<(Output, Profile), CompilerError> {
+        generate_profiled_constraints::<F, G, _>(
+            cs,
+            self.asg.as_ref().unwrap(),
+            &self.program_input,
+            &self.options,
+            &self.core_circuits,
+        )
     }
 
     ///
     /// Synthesizes the circuit for test functions with program input.
     ///
     pub fn compile_test_constraints(self, input_pairs: InputPairs) -> Result<(u32, u32), CompilerError> {
-        generate_test_constraints::<F, G>(&self.asg.as_ref().unwrap(), input_pairs, &self.output_directory)
+        generate_test_constraints::<F, G>(
+            &self.asg.as_ref().unwrap(),
+            input_pairs,
+            &self.output_directory,
+            &self.options,
+            &self.core_circuits,
+        )
     }
 
     ///
-    /// Returns a SHA256 checksum of the program file.
+    /// Evaluates the program on its input without synthesizing the circuit.
     ///
-    pub fn checksum(&self) -> Result<String, CompilerError> {
-        // Read in the main file as string
-        let unparsed_file = fs::read_to_string(&self.main_file_path)
-            .map_err(|e| CompilerError::FileReadError(self.main_file_path.clone(), e))?;
+    pub fn interpret(&self) -> Result<Output, CompilerError> {
+        interpret_program::<F, G>(
+            self.asg.as_ref().unwrap(),
+            &self.program_input,
+            &self.options,
+            &self.core_circuits,
+        )
+    }
+
+    ///
+    /// Evaluates the test functions of the program without synthesizing their circuits.
+    ///
+    pub fn interpret_tests(self, input_pairs: InputPairs) -> Result<(u32, u32), CompilerError> {
+        interpret_tests::<F, G>(
+            self.asg.as_ref().unwrap(),
+            input_pairs,
+            &self.output_directory,
+            &self.options,
+            &self.core_circuits,
+        )
+    }
 
-        // Hash the file contents
+    ///
+    /// Returns a SHA256 checksum of everything the circuit of the program is built from.
+    ///
+    /// Covers the compiler version and options, the main file, every file resolved for its imports
+    /// and modules, the input files and, if given, the shape of the synthesized circuit.
+    ///
+    pub fn checksum(&self, circuit_shape: Option<&CircuitShape>) -> Result<String, CompilerError> {
         let mut hasher = Sha256::new();
-        hasher.update(unparsed_file.as_bytes());
+        let mut update = |bytes: &[u8]| {
+            hasher.update(&(bytes.len() as u64).to_le_bytes());
+            hasher.update(bytes);
+        };
+
+        update(env!("CARGO_PKG_VERSION").as_bytes());
+        update(&[
+            self.options.canonicalization_enabled as u8,
+            self.options.constant_folding_enabled as u8,
+            self.options.dead_code_elimination_enabled as u8,
+        ]);
+        update(&self.options.inline_limit.to_le_bytes());
+
+        // Hash the contents of the main file and of every imported file
+        for path in std::iter::once(&self.main_file_path).chain(self.imported_files.iter()) {
+            let unparsed_file = fs::read_to_string(path).map_err(|e| CompilerError::FileReadError(path.clone(), e))?;
+            update(unparsed_file.as_bytes());
+        }
+
+        for input_source in self.input_sources.iter() {
+            update(input_source.as_bytes());
+        }
+
+        if let Some(circuit_shape) = circuit_shape {
+            update(&(circuit_shape.num_constraints as u64).to_le_bytes());
+            update(&(circuit_shape.num_public_variables as u64).to_le_bytes());
+            update(&(circuit_shape.num_private_variables as u64).to_le_bytes());
+        }
+
         let hash = hasher.finalize();
 
         Ok(hex::encode(hash))
@@ -365,7 +533,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstraintSynthesizer<F> for Compiler<'
     fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
         let output_directory = self.output_directory.clone();
         let package_name = self.program_name.clone();
-        let result = self.compile_constraints(cs).map_err(|e| {
+        let (result, public_inputs) = self.compile_constraints_with_public_inputs(cs).map_err(|e| {
             tracing::error!("{}", e);
             SynthesisError::Unsatisfiable
         })?;
@@ -376,6 +544,10 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstraintSynthesizer<F> for Compiler<'
             .write(&output_directory, result.to_string().as_bytes())
             .unwrap();
 
+        // Write public inputs to file
+        let public_inputs_file = PublicInputsFile::new(&package_name);
+        public_inputs_file.write(&output_directory, &public_inputs).unwrap();
+
         Ok(())
     }
 }
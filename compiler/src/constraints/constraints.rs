@@ -16,8 +16,19 @@
 
 //! Generates R1CS constraints for a compiled Leo program.
 
-use crate::{errors::CompilerError, ConstrainedProgram, GroupType, Output, OutputFile};
-use leo_asg::Program;
+use crate::{
+    errors::CompilerError,
+    CompilerOptions,
+    ConstrainedProgram,
+    ConstraintTracker,
+    CoreCircuitRegistry,
+    GroupType,
+    Output,
+    OutputFile,
+    Profile,
+    ProfileFrame,
+};
+use leo_asg::{Function, Program};
 use leo_ast::Input;
 use leo_input::LeoInputParser;
 use leo_package::inputs::InputPairs;
@@ -30,9 +41,65 @@ pub fn generate_constraints<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSy
     cs: &mut CS,
     program: &Program<'a>,
     input: &Input,
+    options: &CompilerOptions,
+    core_circuits: &CoreCircuitRegistry<F, G>,
 ) -> Result<Output, CompilerError> {
-    let mut resolved_program = ConstrainedProgram::<F, G>::new(program.clone());
+    let mut resolved_program = ConstrainedProgram::<F, G>::new(program.clone())
+        .with_inline_limit(options.inline_limit)
+        .with_core_circuits(core_circuits.clone());
 
+    enforce_program(&mut resolved_program, cs, program, input)
+}
+
+///
+/// Generates the constraints of a program, recording which frames of the program produce them.
+///
+pub fn generate_profiled_constraints<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    program: &Program<'a>,
+    input: &Input,
+    options: &CompilerOptions,
+    core_circuits: &CoreCircuitRegistry<F, G>,
+) -> Result<(Output, Profile), CompilerError> {
+    let mut resolved_program = ConstrainedProgram::<F, G>::new(program.clone())
+        .with_inline_limit(options.inline_limit)
+        .with_core_circuits(core_circuits.clone())
+        .with_profiler();
+
+    let output = enforce_program(&mut resolved_program, cs, program, input)?;
+    let profile = resolved_program.into_profile().unwrap_or_default();
+
+    Ok((output, profile))
+}
+
+///
+/// Generates the constraints of a program, failing at the first constraint its input does not satisfy.
+///
+pub fn generate_checked_constraints<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    program: &Program<'a>,
+    input: &Input,
+    options: &CompilerOptions,
+    core_circuits: &CoreCircuitRegistry<F, G>,
+) -> Result<Output, CompilerError> {
+    let mut resolved_program = ConstrainedProgram::<F, G>::new(program.clone())
+        .with_inline_limit(options.inline_limit)
+        .with_core_circuits(core_circuits.clone());
+    resolved_program.trace_spans();
+
+    let mut tracker = ConstraintTracker::new(cs);
+    let output = enforce_program(&mut resolved_program, &mut tracker, program, input)?;
+    check_satisfied(&resolved_program, &tracker)?;
+
+    Ok(output)
+}
+
+fn enforce_program<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    resolved_program: &mut ConstrainedProgram<'a, F, G>,
+    cs: &mut CS,
+    program: &Program<'a>,
+    input: &Input,
+) -> Result<Output, CompilerError> {
     for (_, global_const) in program.global_consts.iter() {
         resolved_program.enforce_definition_statement(cs, global_const)?;
     }
@@ -44,24 +111,49 @@ pub fn generate_constraints<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSy
 
     match main {
         Some(function) => {
-            let result = resolved_program.enforce_main_function(cs, &function, input)?;
-            Ok(result)
+            resolved_program.enter_profile_frame(cs, || {
+                ProfileFrame::call("function", function, &function.span.clone().unwrap_or_default())
+            });
+            let result = resolved_program.enforce_main_function(cs, function, input);
+            resolved_program.exit_profile_frame(cs);
+
+            Ok(result?)
         }
         _ => Err(CompilerError::NoMainFunction),
     }
 }
 
+///
+/// Returns an error locating the first constraint of `tracker` that is not satisfied, if any.
+///
+fn check_satisfied<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    resolved_program: &ConstrainedProgram<'a, F, G>,
+    tracker: &ConstraintTracker<F, CS>,
+) -> Result<(), CompilerError> {
+    match tracker.unsatisfied() {
+        Some(unsatisfied) => {
+            let span = resolved_program
+                .traced_span(unsatisfied.index)
+                .cloned()
+                .unwrap_or_default();
+            Err(CompilerError::unsatisfied_constraint(&unsatisfied.path, &span))
+        }
+        None => Ok(()),
+    }
+}
+
 pub fn generate_test_constraints<'a, F: PrimeField, G: GroupType<F>>(
     program: &Program<'a>,
     input: InputPairs,
     output_directory: &Path,
+    options: &CompilerOptions,
+    core_circuits: &CoreCircuitRegistry<F, G>,
 ) -> Result<(u32, u32), CompilerError> {
-    let mut resolved_program = ConstrainedProgram::<F, G>::new(program.clone());
+    let mut resolved_program = ConstrainedProgram::<F, G>::new(program.clone())
+        .with_inline_limit(options.inline_limit)
+        .with_core_circuits(core_circuits.clone());
     let program_name = program.name.clone();
 
-    // Get default input
-    let default = input.pairs.get(&program_name);
-
     let tests = program
         .functions
         .iter()
@@ -75,56 +167,20 @@ pub fn generate_test_constraints<'a, F: PrimeField, G: GroupType<F>>(
 
     for (test_name, function) in tests.into_iter() {
         let cs = &mut TestConstraintSystem::<F>::new();
+        let mut tracker = ConstraintTracker::new(cs);
         let full_test_name = format!("{}::{}", program_name.clone(), test_name);
-        let mut output_file_name = program_name.clone();
-
-        let input_file = function
-            .annotations
-            .iter()
-            .find(|x| x.name.name.as_ref() == "test")
-            .unwrap()
-            .arguments
-            .get(0);
-        // get input file name from annotation or use test_name
-        let input_pair = match input_file {
-            Some(file_id) => {
-                let file_name = file_id.clone();
-                let file_name_kebab = file_name.to_string().replace("_", "-");
-
-                // transform "test_name" into "test-name"
-                output_file_name = file_name.to_string();
-
-                // searches for test_input (snake case) or for test-input (kebab case)
-                match input
-                    .pairs
-                    .get(&file_name_kebab)
-                    .or_else(|| input.pairs.get(&file_name_kebab))
-                {
-                    Some(pair) => pair.to_owned(),
-                    None => return Err(CompilerError::InvalidTestContext(file_name.to_string())),
-                }
-            }
-            None => default.ok_or(CompilerError::NoTestInput)?,
-        };
-
-        // parse input files to abstract syntax trees
-        let input_file = &input_pair.input_file;
-        let state_file = &input_pair.state_file;
-
-        let input_ast = LeoInputParser::parse_file(input_file)?;
-        let state_ast = LeoInputParser::parse_file(state_file)?;
-
-        // parse input files into input struct
-        let mut input = Input::new();
-        input.parse_input(input_ast)?;
-        input.parse_state(state_ast)?;
+        let (output_file_name, test_input) = parse_test_input(&program_name, function, &input)?;
 
         // run test function on new program with input
+        resolved_program.trace_spans();
         let result = resolved_program.enforce_main_function(
-            cs, function, &input, // pass program input into every test
+            &mut tracker,
+            function,
+            &test_input, // pass program input into every test
         );
+        let satisfied = check_satisfied(&resolved_program, &tracker);
 
-        match (result.is_ok(), cs.is_satisfied()) {
+        match (result.is_ok(), satisfied.is_ok()) {
             (true, true) => {
                 tracing::info!("{} ... ok\n", full_test_name);
 
@@ -140,7 +196,9 @@ pub fn generate_test_constraints<'a, F: PrimeField, G: GroupType<F>>(
                 passed += 1;
             }
             (true, false) => {
-                tracing::error!("{} constraint system not satisfied\n", full_test_name);
+                let error = satisfied.unwrap_err();
+
+                tracing::error!("{} constraint system not satisfied\n\n{}\n", full_test_name, error);
 
                 // increment failed tests
                 failed += 1;
@@ -159,3 +217,59 @@ pub fn generate_test_constraints<'a, F: PrimeField, G: GroupType<F>>(
 
     Ok((passed, failed))
 }
+
+///
+/// Returns the name of the output file and the parsed input of a test function.
+///
+/// The input is named by the `@test` annotation argument, or defaults to the program input.
+///
+pub(crate) fn parse_test_input(
+    program_name: &str,
+    function: &Function,
+    input: &InputPairs,
+) -> Result<(String, Input), CompilerError> {
+    let mut output_file_name = program_name.to_string();
+
+    let input_file = function
+        .annotations
+        .iter()
+        .find(|x| x.name.name.as_ref() == "test")
+        .unwrap()
+        .arguments
+        .get(0);
+    // get input file name from annotation or use test_name
+    let input_pair = match input_file {
+        Some(file_id) => {
+            let file_name = file_id.clone();
+            let file_name_kebab = file_name.to_string().replace("_", "-");
+
+            // transform "test_name" into "test-name"
+            output_file_name = file_name.to_string();
+
+            // searches for test_input (snake case) or for test-input (kebab case)
+            match input
+                .pairs
+                .get(&file_name_kebab)
+                .or_else(|| input.pairs.get(&file_name_kebab))
+            {
+                Some(pair) => pair.to_owned(),
+                None => return Err(CompilerError::InvalidTestContext(file_name.to_string())),
+            }
+        }
+        None => input.pairs.get(program_name).ok_or(CompilerError::NoTestInput)?,
+    };
+
+    // parse input files to abstract syntax trees
+    let input_file = &input_pair.input_file;
+    let state_file = &input_pair.state_file;
+
+    let input_ast = LeoInputParser::parse_file(input_file)?;
+    let state_ast = LeoInputParser::parse_file(state_file)?;
+
+    // parse input files into input struct
+    let mut input = Input::new();
+    input.parse_input(input_ast)?;
+    input.parse_state(state_ast)?;
+
+    Ok((output_file_name, input))
+}
@@ -0,0 +1,179 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A constraint system that hides the type of the constraint system it wraps.
+
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{ConstraintSystem, LinearCombination, SynthesisError, Variable};
+
+type Assignment<'f, F> = &'f mut dyn FnMut() -> Result<F, SynthesisError>;
+type Annotation<'f> = &'f mut dyn FnMut() -> String;
+type Combination<'f, F> = &'f mut dyn FnMut(LinearCombination<F>) -> LinearCombination<F>;
+
+///
+/// The operations of a constraint system, without generic methods so that it can be used as a trait object.
+///
+/// Every closure is called at most once.
+///
+pub trait DynConstraintSystem<F: Field> {
+    fn alloc(&mut self, annotation: Annotation, f: Assignment<F>) -> Result<Variable, SynthesisError>;
+
+    fn alloc_input(&mut self, annotation: Annotation, f: Assignment<F>) -> Result<Variable, SynthesisError>;
+
+    fn enforce(&mut self, annotation: Annotation, a: Combination<F>, b: Combination<F>, c: Combination<F>);
+
+    fn push_namespace(&mut self, annotation: Annotation);
+
+    fn pop_namespace(&mut self);
+
+    fn num_constraints(&self) -> usize;
+
+    fn num_public_variables(&self) -> usize;
+
+    fn num_private_variables(&self) -> usize;
+}
+
+impl<F: Field, CS: ConstraintSystem<F>> DynConstraintSystem<F> for CS {
+    fn alloc(&mut self, annotation: Annotation, f: Assignment<F>) -> Result<Variable, SynthesisError> {
+        ConstraintSystem::alloc(self, annotation, f)
+    }
+
+    fn alloc_input(&mut self, annotation: Annotation, f: Assignment<F>) -> Result<Variable, SynthesisError> {
+        ConstraintSystem::alloc_input(self, annotation, f)
+    }
+
+    fn enforce(&mut self, annotation: Annotation, a: Combination<F>, b: Combination<F>, c: Combination<F>) {
+        ConstraintSystem::enforce(self, annotation, a, b, c)
+    }
+
+    fn push_namespace(&mut self, annotation: Annotation) {
+        // Namespaces only forward to their root.
+        ConstraintSystem::push_namespace(self.get_root(), annotation)
+    }
+
+    fn pop_namespace(&mut self) {
+        ConstraintSystem::pop_namespace(self.get_root())
+    }
+
+    fn num_constraints(&self) -> usize {
+        ConstraintSystem::num_constraints(self)
+    }
+
+    fn num_public_variables(&self) -> usize {
+        ConstraintSystem::num_public_variables(self)
+    }
+
+    fn num_private_variables(&self) -> usize {
+        ConstraintSystem::num_private_variables(self)
+    }
+}
+
+///
+/// Synthesizes into any constraint system through [`DynConstraintSystem`].
+///
+/// Core circuits are called with this constraint system, so that they can be registered as trait objects.
+///
+pub struct ErasedConstraintSystem<'cs, F: Field> {
+    cs: &'cs mut dyn DynConstraintSystem<F>,
+}
+
+impl<'cs, F: Field> ErasedConstraintSystem<'cs, F> {
+    pub fn new(cs: &'cs mut dyn DynConstraintSystem<F>) -> Self {
+        Self { cs }
+    }
+}
+
+/// Turns a closure that is called at most once into one that can be passed as `FnMut`.
+fn once<T>(f: impl FnOnce() -> T) -> impl FnMut() -> T {
+    let mut f = Some(f);
+    move || (f.take().expect("closure called twice"))()
+}
+
+/// Turns a linear combination closure that is called at most once into one that can be passed as `FnMut`.
+fn once_lc<F: Field>(
+    f: impl FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+) -> impl FnMut(LinearCombination<F>) -> LinearCombination<F> {
+    let mut f = Some(f);
+    move |lc| (f.take().expect("closure called twice"))(lc)
+}
+
+impl<'cs, F: Field> ConstraintSystem<F> for ErasedConstraintSystem<'cs, F> {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.cs
+            .alloc(&mut once(|| annotation().as_ref().to_string()), &mut once(f))
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.cs
+            .alloc_input(&mut once(|| annotation().as_ref().to_string()), &mut once(f))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.cs.enforce(
+            &mut once(|| annotation().as_ref().to_string()),
+            &mut once_lc(a),
+            &mut once_lc(b),
+            &mut once_lc(c),
+        )
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        self.cs.push_namespace(&mut once(|| name_fn().as_ref().to_string()))
+    }
+
+    fn pop_namespace(&mut self) {
+        self.cs.pop_namespace()
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.cs.num_constraints()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.cs.num_public_variables()
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.cs.num_private_variables()
+    }
+}
@@ -18,3 +18,15 @@
 
 pub mod constraints;
 pub use self::constraints::*;
+
+pub mod public_inputs;
+pub use self::public_inputs::*;
+
+pub mod profile;
+pub use self::profile::*;
+
+pub mod tracker;
+pub use self::tracker::*;
+
+pub mod erased;
+pub use self::erased::*;
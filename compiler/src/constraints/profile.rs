@@ -0,0 +1,232 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Attributes the constraints and variables of a synthesized circuit to the parts of the program producing them.
+
+use leo_asg::{Function, Span};
+use snarkvm_fields::Field;
+use snarkvm_r1cs::ConstraintSystem;
+
+use indexmap::IndexMap;
+use std::{fmt, path::Path};
+
+///
+/// A function, loop iteration, statement or core circuit call that constraints are attributed to.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProfileFrame {
+    pub label: String,
+    pub location: String,
+}
+
+impl ProfileFrame {
+    pub fn new(label: String, span: &Span) -> Self {
+        let location = match Path::new(span.path.as_str()).file_name() {
+            Some(file_name) => format!("{}:{}:{}", file_name.to_string_lossy(), span.line_start, span.col_start),
+            None => format!("{}:{}", span.line_start, span.col_start),
+        };
+
+        Self { label, location }
+    }
+
+    /// Creates a frame for a call to `function`, naming its circuit if it is a member function.
+    pub(crate) fn call(kind: &str, function: &Function, span: &Span) -> Self {
+        let name = match function.circuit.get() {
+            Some(circuit) => format!("{}::{}", circuit.name.borrow().name, function.name.borrow().name),
+            None => function.name.borrow().name.to_string(),
+        };
+
+        Self::new(format!("{} {}", kind, name), span)
+    }
+}
+
+impl fmt::Display for ProfileFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.label, self.location)
+    }
+}
+
+///
+/// The number of constraints and variables produced by a frame.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProfileCount {
+    pub constraints: usize,
+    pub variables: usize,
+}
+
+impl ProfileCount {
+    fn of<F: Field, CS: ConstraintSystem<F>>(cs: &CS) -> Self {
+        Self {
+            constraints: cs.num_constraints(),
+            variables: cs.num_public_variables() + cs.num_private_variables(),
+        }
+    }
+
+    fn add(&mut self, other: ProfileCount) {
+        self.constraints += other.constraints;
+        self.variables += other.variables;
+    }
+
+    fn sub(&mut self, other: ProfileCount) {
+        self.constraints -= other.constraints;
+        self.variables -= other.variables;
+    }
+}
+
+///
+/// The constraints and variables of a circuit, keyed by the stack of frames that produced them.
+///
+/// Each stack only counts what its innermost frame produced itself, excluding the frames it entered.
+///
+#[derive(Clone, Debug, Default)]
+pub struct Profile {
+    pub stacks: IndexMap<Vec<ProfileFrame>, ProfileCount>,
+}
+
+impl Profile {
+    /// Returns the number of constraints and variables attributed to any frame.
+    pub fn total(&self) -> ProfileCount {
+        let mut total = ProfileCount::default();
+        for count in self.stacks.values() {
+            total.add(*count);
+        }
+        total
+    }
+
+    ///
+    /// Returns the counts of every frame, summed over the stacks it is innermost in.
+    ///
+    /// Frames are sorted by constraints, then variables, largest first.
+    ///
+    pub fn frames(&self) -> Vec<(&ProfileFrame, ProfileCount)> {
+        let mut frames: IndexMap<&ProfileFrame, ProfileCount> = IndexMap::new();
+        for (stack, count) in self.stacks.iter() {
+            if let Some(frame) = stack.last() {
+                frames.entry(frame).or_default().add(*count);
+            }
+        }
+
+        let mut frames = frames
+            .into_iter()
+            .filter(|(_, count)| *count != ProfileCount::default())
+            .collect::<Vec<_>>();
+        frames.sort_by(|(_, left), (_, right)| {
+            (right.constraints, right.variables).cmp(&(left.constraints, left.variables))
+        });
+        frames
+    }
+
+    ///
+    /// Returns a table of the counts of every frame, as sorted by [`Profile::frames`].
+    ///
+    pub fn to_table(&self) -> String {
+        let frames = self.frames();
+        let location_width = frames
+            .iter()
+            .map(|(frame, _)| frame.location.len())
+            .chain(std::iter::once("Location".len()))
+            .max()
+            .unwrap_or_default();
+
+        let mut table = format!(
+            "{:>12} {:>12}  {:<width$}  Frame\n",
+            "Constraints",
+            "Variables",
+            "Location",
+            width = location_width
+        );
+        for (frame, count) in frames {
+            table.push_str(&format!(
+                "{:>12} {:>12}  {:<width$}  {}\n",
+                count.constraints,
+                count.variables,
+                frame.location,
+                frame.label,
+                width = location_width
+            ));
+        }
+        table
+    }
+
+    ///
+    /// Returns the constraints of every stack in the folded stack format, one `frame;frame count` line per stack.
+    ///
+    /// The output can be rendered by flamegraph tools such as `inferno-flamegraph`.
+    ///
+    pub fn to_folded(&self) -> String {
+        let mut folded = String::new();
+        for (stack, count) in self.stacks.iter() {
+            if count.constraints == 0 {
+                continue;
+            }
+            let frames = stack
+                .iter()
+                .map(|frame| frame.to_string().replace(';', ","))
+                .collect::<Vec<_>>();
+            folded.push_str(&format!("{} {}\n", frames.join(";"), count.constraints));
+        }
+        folded
+    }
+}
+
+/// A frame that is being synthesized.
+struct OpenFrame {
+    frame: ProfileFrame,
+    /// The counts of the constraint system when the frame was entered.
+    start: ProfileCount,
+    /// The counts of the frames entered from this frame.
+    entered: ProfileCount,
+}
+
+///
+/// Records a [`Profile`] while a program is synthesized.
+///
+#[derive(Default)]
+pub(crate) struct Profiler {
+    profile: Profile,
+    stack: Vec<OpenFrame>,
+}
+
+impl Profiler {
+    pub(crate) fn enter<F: Field, CS: ConstraintSystem<F>>(&mut self, cs: &CS, frame: ProfileFrame) {
+        self.stack.push(OpenFrame {
+            frame,
+            start: ProfileCount::of(cs),
+            entered: ProfileCount::default(),
+        });
+    }
+
+    pub(crate) fn exit<F: Field, CS: ConstraintSystem<F>>(&mut self, cs: &CS) {
+        let open = self.stack.pop().expect("exited a profile frame that was never entered");
+
+        let mut produced = ProfileCount::of(cs);
+        produced.sub(open.start);
+        if let Some(parent) = self.stack.last_mut() {
+            parent.entered.add(produced);
+        }
+
+        let mut stack = self.stack.iter().map(|open| open.frame.clone()).collect::<Vec<_>>();
+        stack.push(open.frame);
+
+        produced.sub(open.entered);
+        self.profile.stacks.entry(stack).or_default().add(produced);
+    }
+
+    pub(crate) fn into_profile(self) -> Profile {
+        self.profile
+    }
+}
@@ -0,0 +1,189 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Constraint system wrappers used to allocate and collect the public inputs of a Leo program.
+
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{ConstraintSystem, LinearCombination, SynthesisError, Variable};
+
+use std::marker::PhantomData;
+
+///
+/// Wraps a constraint system and records the value of every public variable allocated through it.
+///
+/// The recorded values are exactly the public inputs a verifier needs, in allocation order.
+///
+pub struct PublicInputRecorder<'cs, F: Field, CS: ConstraintSystem<F>> {
+    cs: &'cs mut CS,
+    values: Vec<F>,
+}
+
+impl<'cs, F: Field, CS: ConstraintSystem<F>> PublicInputRecorder<'cs, F, CS> {
+    pub fn new(cs: &'cs mut CS) -> Self {
+        Self { cs, values: vec![] }
+    }
+
+    /// Returns the recorded public input values.
+    pub fn into_values(self) -> Vec<F> {
+        self.values
+    }
+}
+
+impl<'cs, F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for PublicInputRecorder<'cs, F, CS> {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.cs.alloc(annotation, f)
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let value = f();
+        if let Ok(value) = value {
+            self.values.push(value);
+        }
+
+        self.cs.alloc_input(annotation, || value)
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.cs.enforce(annotation, a, b, c)
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        self.cs.get_root().push_namespace(name_fn)
+    }
+
+    fn pop_namespace(&mut self) {
+        self.cs.get_root().pop_namespace()
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.cs.num_constraints()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.cs.num_public_variables()
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.cs.num_private_variables()
+    }
+}
+
+///
+/// Wraps a constraint system and allocates every variable requested through it as a public variable.
+///
+/// Used to expose values of any type as public inputs without a public counterpart for each gadget.
+///
+pub struct PublicAllocation<'cs, F: Field, CS: ConstraintSystem<F>> {
+    cs: &'cs mut CS,
+    _field: PhantomData<F>,
+}
+
+impl<'cs, F: Field, CS: ConstraintSystem<F>> PublicAllocation<'cs, F, CS> {
+    pub fn new(cs: &'cs mut CS) -> Self {
+        Self {
+            cs,
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<'cs, F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for PublicAllocation<'cs, F, CS> {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.cs.alloc_input(annotation, f)
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.cs.alloc_input(annotation, f)
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.cs.enforce(annotation, a, b, c)
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        self.cs.get_root().push_namespace(name_fn)
+    }
+
+    fn pop_namespace(&mut self) {
+        self.cs.get_root().pop_namespace()
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.cs.num_constraints()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.cs.num_public_variables()
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.cs.num_private_variables()
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Finds the constraints of a circuit that the program input does not satisfy.
+
+use leo_asg::Span;
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{ConstraintSystem, Index, LinearCombination, SynthesisError, Variable};
+
+use indexmap::IndexMap;
+
+///
+/// The first constraint of a circuit that is not satisfied by the values assigned to its variables.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsatisfiedConstraint {
+    /// The position of the constraint in the circuit.
+    pub index: usize,
+    /// The namespaces the constraint was enforced in, separated by `/`.
+    pub path: String,
+}
+
+///
+/// Wraps a constraint system and checks every constraint against the values of its variables as it is enforced.
+///
+/// Constraints using a variable without a value, as during a setup, are not checked.
+///
+pub struct ConstraintTracker<'cs, F: Field, CS: ConstraintSystem<F>> {
+    cs: &'cs mut CS,
+    values: IndexMap<Index, F>,
+    namespaces: Vec<String>,
+    unsatisfied: Option<UnsatisfiedConstraint>,
+}
+
+impl<'cs, F: Field, CS: ConstraintSystem<F>> ConstraintTracker<'cs, F, CS> {
+    pub fn new(cs: &'cs mut CS) -> Self {
+        // Constraint systems that allocate the constant one variable do so on creation.
+        let mut values = IndexMap::new();
+        if cs.num_public_variables() > 0 {
+            values.insert(CS::one().get_unchecked(), F::one());
+        }
+
+        Self {
+            cs,
+            values,
+            namespaces: vec![],
+            unsatisfied: None,
+        }
+    }
+
+    /// Returns the first constraint that is not satisfied, if any.
+    pub fn unsatisfied(&self) -> Option<&UnsatisfiedConstraint> {
+        self.unsatisfied.as_ref()
+    }
+
+    fn evaluate(&self, lc: &LinearCombination<F>) -> Option<F> {
+        let mut sum = F::zero();
+        for (variable, coefficient) in lc.0.iter() {
+            let mut term = *self.values.get(&variable.get_unchecked())?;
+            term.mul_assign(coefficient);
+            sum.add_assign(&term);
+        }
+        Some(sum)
+    }
+
+    /// Returns `false` if the values of the variables of `a * b = c` are known and do not satisfy it.
+    fn is_satisfied(&self, a: &LinearCombination<F>, b: &LinearCombination<F>, c: &LinearCombination<F>) -> bool {
+        match (self.evaluate(a), self.evaluate(b), self.evaluate(c)) {
+            (Some(mut a), Some(b), Some(c)) => {
+                a.mul_assign(&b);
+                a == c
+            }
+            _ => true,
+        }
+    }
+}
+
+impl<'cs, F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for ConstraintTracker<'cs, F, CS> {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        // The value is only computed if the wrapped constraint system asks for it.
+        let mut known = None;
+        let variable = self.cs.alloc(annotation, || {
+            let value = f();
+            known = value.as_ref().ok().copied();
+            value
+        })?;
+        if let Some(value) = known {
+            self.values.insert(variable.get_unchecked(), value);
+        }
+
+        Ok(variable)
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        // The value is only computed if the wrapped constraint system asks for it.
+        let mut known = None;
+        let variable = self.cs.alloc_input(annotation, || {
+            let value = f();
+            known = value.as_ref().ok().copied();
+            value
+        })?;
+        if let Some(value) = known {
+            self.values.insert(variable.get_unchecked(), value);
+        }
+
+        Ok(variable)
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        let a = a(LinearCombination::zero());
+        let b = b(LinearCombination::zero());
+        let c = c(LinearCombination::zero());
+
+        if self.unsatisfied.is_some() || self.is_satisfied(&a, &b, &c) {
+            return self.cs.enforce(annotation, |_| a, |_| b, |_| c);
+        }
+
+        let annotation = annotation();
+        let path = self
+            .namespaces
+            .iter()
+            .map(|namespace| namespace.as_str())
+            .chain(std::iter::once(annotation.as_ref()))
+            .collect::<Vec<_>>()
+            .join("/");
+        self.unsatisfied = Some(UnsatisfiedConstraint {
+            index: self.cs.num_constraints(),
+            path,
+        });
+
+        self.cs.enforce(|| annotation, |_| a, |_| b, |_| c)
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        let name = name_fn();
+        self.namespaces.push(name.as_ref().to_string());
+
+        self.cs.get_root().push_namespace(|| name)
+    }
+
+    fn pop_namespace(&mut self) {
+        self.namespaces.pop();
+
+        self.cs.get_root().pop_namespace()
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.cs.num_constraints()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.cs.num_public_variables()
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.cs.num_private_variables()
+    }
+}
+
+/// A statement or expression and the constraints enforced while evaluating it.
+struct TracedSpan {
+    span: Option<Span>,
+    start: usize,
+    /// The number of constraints when the evaluation ended, `None` while it is evaluated.
+    end: Option<usize>,
+}
+
+///
+/// Records which statements and expressions enforce the constraints of a circuit.
+///
+#[derive(Default)]
+pub(crate) struct SpanTracer {
+    spans: Vec<TracedSpan>,
+    open: Vec<usize>,
+}
+
+impl SpanTracer {
+    pub(crate) fn enter(&mut self, span: Option<&Span>, constraints: usize) {
+        self.open.push(self.spans.len());
+        self.spans.push(TracedSpan {
+            span: span.cloned(),
+            start: constraints,
+            end: None,
+        });
+    }
+
+    pub(crate) fn exit(&mut self, constraints: usize) {
+        let index = self.open.pop().expect("exited a traced span that was never entered");
+
+        // A span without constraints cannot contain any traced span that has constraints.
+        if self.spans[index].start == constraints {
+            self.spans.truncate(index);
+        } else {
+            self.spans[index].end = Some(constraints);
+        }
+    }
+
+    ///
+    /// Returns the span of the innermost statement or expression that enforced the constraint at `index`.
+    ///
+    /// Spans that contain the constraint are nested, so the innermost one is the last one entered.
+    ///
+    pub(crate) fn span_of(&self, index: usize) -> Option<&Span> {
+        self.spans
+            .iter()
+            .rev()
+            .filter(|traced| traced.start <= index && traced.end.map(|end| index < end).unwrap_or(true))
+            .find_map(|traced| traced.span.as_ref())
+    }
+}
@@ -14,8 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::errors::{ExpressionError, FunctionError, ImportError, StatementError};
-use leo_asg::{AsgConvertError, FormattedError};
+use crate::errors::{ExpressionError, FunctionError, ImportError, InterpreterError, StatementError};
+use leo_asg::{AsgConvertError, FormattedError, Span};
 use leo_ast::{AstError, LeoError};
 use leo_input::InputParserError;
 use leo_parser::SyntaxError;
@@ -40,6 +40,9 @@ pub enum CompilerError {
     #[error("{}", _0)]
     InputParserError(#[from] InputParserError),
 
+    #[error("{}", _0)]
+    InterpreterError(#[from] InterpreterError),
+
     #[error("Cannot find input files with context name `{}`", _0)]
     InvalidTestContext(String),
 
@@ -66,6 +69,17 @@ pub enum CompilerError {
 
     #[error("{}", _0)]
     StatementError(#[from] StatementError),
+
+    #[error("{}", _0)]
+    UnsatisfiedConstraint(FormattedError),
 }
 
 impl LeoError for CompilerError {}
+
+impl CompilerError {
+    pub fn unsatisfied_constraint(path: &str, span: &Span) -> Self {
+        let message = format!("the program input does not satisfy the constraint `{}`", path);
+
+        CompilerError::UnsatisfiedConstraint(FormattedError::new_from_span(message, span))
+    }
+}
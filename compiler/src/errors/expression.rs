@@ -73,6 +73,12 @@ impl ExpressionError {
         Self::new_from_span(message, span)
     }
 
+    pub fn undefined_core_circuit(mapping: &str, span: &Span) -> Self {
+        let message = format!("no implementation of the core circuit `{}` is registered", mapping);
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn cannot_evaluate(operation: String, span: &Span) -> Self {
         let message = format!("Mismatched types found for operation `{}`", operation);
 
@@ -91,6 +97,12 @@ impl ExpressionError {
         Self::new_from_span(message, span)
     }
 
+    pub fn invalid_cast(value: String, target: String, span: &Span) -> Self {
+        let message = format!("cannot cast `{}` to `{}`", value, target);
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn conditional_boolean(actual: String, span: &Span) -> Self {
         let message = format!("if, else conditional must resolve to a boolean, found `{}`", actual);
 
@@ -121,6 +133,15 @@ impl ExpressionError {
         Self::new_from_span(message, span)
     }
 
+    pub fn array_slice_out_of_bounds(start: String, length: usize, array_length: usize, span: &Span) -> Self {
+        let message = format!(
+            "cannot take a slice of length {} starting at {} from an array of length {}",
+            length, start, array_length
+        );
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn array_invalid_slice_length(span: &Span) -> Self {
         let message = "illegal length of slice".to_string();
 
@@ -126,12 +126,55 @@ impl FunctionError {
         Self::new_from_span(message, span)
     }
 
+    pub fn invalid_enum_variant(enum_: String, variant: String, span: &Span) -> Self {
+        let message = format!("Enum `{}` has no variant `{}`", enum_, variant);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn enum_variant_size_mismatch(variant: String, expected: usize, actual: usize, span: &Span) -> Self {
+        let message = format!(
+            "Enum variant `{}` carries {} values, found {} values",
+            variant, expected, actual
+        );
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn invalid_tuple(actual: String, span: &Span) -> Self {
         let message = format!("Expected function input tuple, found `{}`", actual);
 
         Self::new_from_span(message, span)
     }
 
+    pub fn inline_limit_exceeded(limit: u32, call_stack: String, span: &Span) -> Self {
+        let message = format!(
+            "function calls are nested deeper than the inline limit of {} ({}). \
+            If the recursion terminates, raise the limit with `--inline-limit` or `inline_limit` in the [compiler] section of Leo.toml",
+            limit, call_stack
+        );
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn circular_call(function: String, call_stack: String, span: &Span) -> Self {
+        let message = format!(
+            "function `{}` calls itself with the same constant arguments, inlining it would never terminate ({})",
+            function, call_stack
+        );
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn unbounded_runtime_recursion(function: String, call_stack: String, span: &Span) -> Self {
+        let message = format!(
+            "function `{}` calls itself with only runtime arguments, recursion on runtime values cannot be bounded ({})",
+            function, call_stack
+        );
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn input_not_found(expected: String, span: &Span) -> Self {
         let message = format!("main function input {} not found", expected);
 
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::{ConsoleError, ExpressionError, FunctionError, OutputBytesError, StatementError};
+use leo_asg::AsgConvertError;
+use leo_ast::{FormattedError, LeoError, Span};
+
+#[derive(Debug, Error)]
+pub enum InterpreterError {
+    #[error("{}", _0)]
+    AsgConvertError(#[from] AsgConvertError),
+
+    #[error("{}", _0)]
+    ConsoleError(#[from] ConsoleError),
+
+    #[error("{}", _0)]
+    Error(#[from] FormattedError),
+
+    #[error("{}", _0)]
+    ExpressionError(#[from] ExpressionError),
+
+    #[error("{}", _0)]
+    FunctionError(#[from] FunctionError),
+
+    #[error("{}", _0)]
+    OutputBytesError(#[from] OutputBytesError),
+
+    #[error("{}", _0)]
+    StatementError(#[from] StatementError),
+}
+
+impl LeoError for InterpreterError {}
+
+impl InterpreterError {
+    fn new_from_span(message: String, span: &Span) -> Self {
+        InterpreterError::Error(FormattedError::new_from_span(message, span))
+    }
+
+    pub fn overflow(operation: String, span: &Span) -> Self {
+        let message = format!("the result of `{}` does not fit in its integer type", operation);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn division_by_zero(operation: String, span: &Span) -> Self {
+        let message = format!("`{}` divides by zero", operation);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn unexpected_value(expected: &str, actual: String, span: &Span) -> Self {
+        let message = format!("expected a {} value, found `{}`", expected, actual);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn undefined_variable(name: String, span: &Span) -> Self {
+        let message = format!("variable `{}` is used before it is defined", name);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn unknown_value(value: String, span: &Span) -> Self {
+        let message = format!("the value of `{}` is not known without an input", value);
+
+        Self::new_from_span(message, span)
+    }
+}
@@ -26,6 +26,9 @@ pub use self::function::*;
 pub mod import;
 pub use self::import::*;
 
+pub mod interpreter;
+pub use self::interpreter::*;
+
 pub mod console;
 pub use self::console::*;
 
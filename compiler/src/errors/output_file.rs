@@ -27,6 +27,9 @@ pub enum OutputFileError {
     #[error("Cannot remove the provided file - {:?}", _0)]
     FileRemovalError(PathBuf),
 
+    #[error("Invalid public input value `{}`", _0)]
+    InvalidValue(String),
+
     #[error("writing: {}", _0)]
     Writing(io::Error),
 }
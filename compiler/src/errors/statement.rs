@@ -115,6 +115,12 @@ impl StatementError {
         Self::new_from_span(message, span)
     }
 
+    pub fn match_enum(actual: String, span: &Span) -> Self {
+        let message = format!("Match statement must resolve to an enum, found `{}`", actual);
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn multiple_definition(value: String, span: &Span) -> Self {
         let message = format!("cannot assign multiple variables to a single value: {}", value,);
 
@@ -16,6 +16,7 @@
 
 use crate::errors::FieldError;
 use leo_ast::{FormattedError, LeoError, Span};
+use snarkvm_r1cs::SynthesisError;
 
 #[derive(Debug, Error)]
 pub enum CharError {
@@ -38,4 +39,19 @@ impl CharError {
 
         Self::new_from_span(message, span)
     }
+
+    pub fn invalid_code(code: u32, span: &Span) -> Self {
+        let message = format!(
+            "cannot cast `{}` to `char`, the value is not a Unicode code point",
+            code
+        );
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn cannot_enforce(error: SynthesisError, span: &Span) -> Self {
+        let message = format!("char conversion failed due to synthesis error `{:?}`", error);
+
+        Self::new_from_span(message, span)
+    }
 }
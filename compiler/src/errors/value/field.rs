@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::{FormattedError, LeoError, Span};
+use leo_ast::{FormattedError, IntegerType, LeoError, Span};
 use snarkvm_r1cs::SynthesisError;
 
 #[derive(Debug, Error)]
@@ -57,6 +57,18 @@ impl FieldError {
         Self::new_from_span(message, span)
     }
 
+    pub fn cast(error: SynthesisError, span: &Span) -> Self {
+        let message = format!("field cast failed due to synthesis error `{:?}`", error);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn cast_out_of_range(field: String, target: &IntegerType, span: &Span) -> Self {
+        let message = format!("cannot cast `{}` to `{}`, the value is out of range", field, target);
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn no_inverse(field: String, span: &Span) -> Self {
         let message = format!("no multiplicative inverse found for field `{}`", field);
 
@@ -74,6 +74,12 @@ impl IntegerError {
         Self::new_from_span(message, span)
     }
 
+    pub fn cast_out_of_range(value: String, target: &IntegerType, span: &Span) -> Self {
+        let message = format!("cannot cast `{}` to `{}`, the value is out of range", value, target);
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn invalid_integer(actual: String, span: &Span) -> Self {
         let message = format!("failed to parse `{}` as expected integer type", actual);
 
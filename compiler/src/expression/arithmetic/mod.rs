@@ -34,5 +34,5 @@ pub use self::div::*;
 pub mod pow;
 pub use self::pow::*;
 
-pub mod bit_not;
-pub use self::bit_not::*;
+pub mod rem;
+pub use self::rem::*;
@@ -29,41 +29,10 @@ use crate::{
 use leo_asg::{ConstInt, Expression, Span};
 
 use snarkvm_fields::PrimeField;
-use snarkvm_gadgets::{
-    boolean::Boolean,
-    eq::{EqGadget, EvaluateEqGadget},
-    select::CondSelectGadget,
-};
+use snarkvm_gadgets::{boolean::Boolean, eq::EqGadget, select::CondSelectGadget};
 use snarkvm_r1cs::ConstraintSystem;
 
 impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
-    pub fn array_bounds_check<CS: ConstraintSystem<F>>(
-        &mut self,
-        cs: &mut CS,
-        index_resolved: &Integer,
-        array_len: u32,
-        span: &Span,
-    ) -> Result<(), ExpressionError> {
-        let bounds_check = evaluate_lt::<F, G, CS>(
-            cs,
-            ConstrainedValue::Integer(index_resolved.clone()),
-            ConstrainedValue::Integer(Integer::new(
-                &ConstInt::U32(array_len).cast_to(&index_resolved.get_type()),
-            )),
-            span,
-        )?;
-        let bounds_check = match bounds_check {
-            ConstrainedValue::Boolean(b) => b,
-            _ => unimplemented!("illegal non-Integer returned from lt"),
-        };
-        let namespace_string = format!("evaluate array access bounds {}:{}", span.line_start, span.col_start);
-        let mut unique_namespace = cs.ns(|| namespace_string);
-        bounds_check
-            .enforce_equal(&mut unique_namespace, &Boolean::Constant(true))
-            .map_err(|e| ExpressionError::cannot_enforce("array bounds check".to_string(), e, span))?;
-        Ok(())
-    }
-
     #[allow(clippy::too_many_arguments)]
     pub fn enforce_array_access<CS: ConstraintSystem<F>>(
         &mut self,
@@ -72,7 +41,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         index: &'a Expression<'a>,
         span: &Span,
     ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
-        let mut array = match self.enforce_expression(cs, array)? {
+        let array = match self.enforce_expression(cs, array)? {
             ConstrainedValue::Array(array) => array,
             value => return Err(ExpressionError::undefined_array(value.to_string(), span)),
         };
@@ -87,31 +56,16 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             if array.is_empty() {
                 return Err(ExpressionError::array_index_out_of_bounds(0, span));
             }
-            {
-                let array_len: u32 = array
-                    .len()
-                    .try_into()
-                    .map_err(|_| ExpressionError::array_length_out_of_bounds(span))?;
-                self.array_bounds_check(cs, &&index_resolved, array_len, span)?;
-            }
-
-            let mut current_value = array.pop().unwrap();
-            for (i, item) in array.into_iter().enumerate() {
-                let namespace_string = format!("evaluate array access eq {} {}:{}", i, span.line_start, span.col_start);
-                let eq_namespace = cs.ns(|| namespace_string);
-
-                let index_bounded = i
-                    .try_into()
-                    .map_err(|_| ExpressionError::array_index_out_of_legal_bounds(span))?;
-                let const_index = ConstInt::U32(index_bounded).cast_to(&index_resolved.get_type());
-                let index_comparison = index_resolved
-                    .evaluate_equal(eq_namespace, &Integer::new(&const_index))
-                    .map_err(|_| ExpressionError::cannot_evaluate("==".to_string(), span))?;
+            let selector = self.enforce_index_selector(cs, &index_resolved, array.len(), span)?;
 
+            // The first element is the result unless another one is selected
+            let mut items = array.into_iter().zip(selector.iter()).enumerate();
+            let mut current_value = items.next().map(|(_, (item, _))| item).unwrap();
+            for (i, (item, is_selected)) in items {
                 let unique_namespace =
                     cs.ns(|| format!("select array access {} {}:{}", i, span.line_start, span.col_start));
                 let value =
-                    ConstrainedValue::conditionally_select(unique_namespace, &index_comparison, &item, &current_value)
+                    ConstrainedValue::conditionally_select(unique_namespace, is_selected, &item, &current_value)
                         .map_err(|e| ExpressionError::cannot_enforce("conditional select".to_string(), e, span))?;
                 current_value = value;
             }
@@ -148,91 +102,124 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                 Integer::new(&ConstInt::U32(index_bounded))
             } // Array slice ends at array length
         };
-        let const_dimensions = match (from_resolved.to_usize(), to_resolved.to_usize()) {
-            (Some(from), Some(to)) => Some((from, to)),
-            (Some(from), None) => Some((from, from + length)),
-            (None, Some(to)) => Some((to - length, to)),
-            (None, None) => None,
-        };
-        Ok(if let Some((left, right)) = const_dimensions {
-            if right - left != length {
+        if let (Some(from), Some(to)) = (from_resolved.to_usize(), to_resolved.to_usize()) {
+            if to.checked_sub(from) != Some(length) {
                 return Err(ExpressionError::array_invalid_slice_length(span));
             }
-            if right > array.len() {
-                return Err(ExpressionError::array_index_out_of_bounds(right, span));
-            }
-            ConstrainedValue::Array(array[left..right].to_owned())
-        } else {
-            {
-                let calc_len = enforce_sub::<F, G, _>(
-                    cs,
-                    ConstrainedValue::Integer(to_resolved.clone()),
-                    ConstrainedValue::Integer(from_resolved.clone()),
+            if to > array.len() {
+                return Err(ExpressionError::array_slice_out_of_bounds(
+                    from.to_string(),
+                    length,
+                    array.len(),
                     span,
-                )?;
-                let calc_len = match calc_len {
-                    ConstrainedValue::Integer(i) => i,
-                    _ => unimplemented!("illegal non-Integer returned from sub"),
-                };
-                let namespace_string = format!(
-                    "evaluate array range access length check {}:{}",
-                    span.line_start, span.col_start
-                );
-                let mut unique_namespace = cs.ns(|| namespace_string);
-                calc_len
-                    .enforce_equal(&mut unique_namespace, &Integer::new(&ConstInt::U32(length as u32)))
-                    .map_err(|e| ExpressionError::cannot_enforce("array length check".to_string(), e, span))?;
+                ));
             }
-            {
-                let bounds_check = evaluate_le::<F, G, _>(
-                    cs,
-                    ConstrainedValue::Integer(to_resolved),
-                    ConstrainedValue::Integer(Integer::new(&ConstInt::U32(array.len() as u32))),
+            return Ok(ConstrainedValue::Array(array[from..to].to_owned()));
+        }
+        if length > array.len() {
+            return Err(ExpressionError::array_invalid_slice_length(span));
+        }
+
+        // The end of the slice must be `length` past its start
+        {
+            let calc_len = enforce_sub::<F, G, _>(
+                cs,
+                ConstrainedValue::Integer(to_resolved),
+                ConstrainedValue::Integer(from_resolved.clone()),
+                span,
+            )?;
+            let calc_len = match calc_len {
+                ConstrainedValue::Integer(i) => i,
+                _ => unimplemented!("illegal non-Integer returned from sub"),
+            };
+            let namespace_string = format!(
+                "evaluate array range access length check {}:{}",
+                span.line_start, span.col_start
+            );
+            let mut unique_namespace = cs.ns(|| namespace_string);
+            calc_len
+                .enforce_equal(&mut unique_namespace, &Integer::new(&ConstInt::U32(length as u32)))
+                .map_err(|e| ExpressionError::cannot_enforce("array length check".to_string(), e, span))?;
+        }
+
+        // Only the starts that leave `length` elements in the array are valid
+        let last_start = array.len() - length;
+        if let Some(from) = from_resolved.to_usize() {
+            if from > last_start {
+                return Err(ExpressionError::array_slice_out_of_bounds(
+                    from.to_string(),
+                    length,
+                    array.len(),
                     span,
-                )?;
-                let bounds_check = match bounds_check {
-                    ConstrainedValue::Boolean(b) => b,
-                    _ => unimplemented!("illegal non-Integer returned from le"),
-                };
-                let namespace_string = format!(
-                    "evaluate array range access bounds {}:{}",
-                    span.line_start, span.col_start
-                );
-                let mut unique_namespace = cs.ns(|| namespace_string);
-                bounds_check
-                    .enforce_equal(&mut unique_namespace, &Boolean::Constant(true))
-                    .map_err(|e| ExpressionError::cannot_enforce("array bounds check".to_string(), e, span))?;
+                ));
             }
-            let mut windows = array.windows(length);
-            let mut result = ConstrainedValue::Array(vec![]);
-
-            for i in 0..length {
-                let window = if let Some(window) = windows.next() {
-                    window
-                } else {
-                    break;
-                };
-                let array_value = ConstrainedValue::Array(window.to_vec());
-                let mut unique_namespace =
-                    cs.ns(|| format!("array index eq-check {} {}:{}", i, span.line_start, span.col_start));
-
-                let equality = evaluate_eq::<F, G, _>(
-                    &mut unique_namespace,
-                    ConstrainedValue::Integer(from_resolved.clone()),
-                    ConstrainedValue::Integer(Integer::new(&ConstInt::U32(i as u32))),
-                    span,
-                )?;
-                let equality = match equality {
-                    ConstrainedValue::Boolean(b) => b,
-                    _ => unimplemented!("unexpected non-Boolean for evaluate_eq"),
-                };
+            return Ok(ConstrainedValue::Array(array[from..from + length].to_owned()));
+        }
+        let out_of_bounds = from_resolved
+            .get_value()
+            .and_then(|from| from.parse::<usize>().ok())
+            .filter(|from| *from > last_start);
+        if let Some(from) = out_of_bounds {
+            return Err(ExpressionError::array_slice_out_of_bounds(
+                from.to_string(),
+                length,
+                array.len(),
+                span,
+            ));
+        }
+        {
+            let bounds_check = evaluate_le::<F, G, _>(
+                cs,
+                ConstrainedValue::Integer(from_resolved.clone()),
+                ConstrainedValue::Integer(Integer::new(&ConstInt::U32(last_start as u32))),
+                span,
+            )?;
+            let bounds_check = match bounds_check {
+                ConstrainedValue::Boolean(b) => b,
+                _ => unimplemented!("illegal non-Integer returned from le"),
+            };
+            let namespace_string = format!(
+                "evaluate array range access bounds {}:{}",
+                span.line_start, span.col_start
+            );
+            let mut unique_namespace = cs.ns(|| namespace_string);
+            bounds_check
+                .enforce_equal(&mut unique_namespace, &Boolean::Constant(true))
+                .map_err(|e| ExpressionError::cannot_enforce("array bounds check".to_string(), e, span))?;
+        }
 
-                let unique_namespace =
-                    unique_namespace.ns(|| format!("array index {} {}:{}", i, span.line_start, span.col_start));
-                result = ConstrainedValue::conditionally_select(unique_namespace, &equality, &array_value, &result)
-                    .map_err(|e| ExpressionError::cannot_enforce("conditional select".to_string(), e, span))?;
-            }
-            result
-        })
+        // An empty slice is the same at every start within bounds
+        if length == 0 {
+            return Ok(ConstrainedValue::Array(vec![]));
+        }
+
+        // Select the window that starts at `from`, the first one if no other does
+        let mut windows = array.windows(length).enumerate();
+        let mut result = match windows.next() {
+            Some((_, window)) => ConstrainedValue::Array(window.to_vec()),
+            None => ConstrainedValue::Array(vec![]),
+        };
+        for (i, window) in windows {
+            let array_value = ConstrainedValue::Array(window.to_vec());
+            let mut unique_namespace =
+                cs.ns(|| format!("array index eq-check {} {}:{}", i, span.line_start, span.col_start));
+
+            let equality = evaluate_eq::<F, G, _>(
+                &mut unique_namespace,
+                ConstrainedValue::Integer(from_resolved.clone()),
+                ConstrainedValue::Integer(Integer::new(&ConstInt::U32(i as u32))),
+                span,
+            )?;
+            let equality = match equality {
+                ConstrainedValue::Boolean(b) => b,
+                _ => unimplemented!("unexpected non-Boolean for evaluate_eq"),
+            };
+
+            let unique_namespace =
+                unique_namespace.ns(|| format!("array index {} {}:{}", i, span.line_start, span.col_start));
+            result = ConstrainedValue::conditionally_select(unique_namespace, &equality, &array_value, &result)
+                .map_err(|e| ExpressionError::cannot_enforce("conditional select".to_string(), e, span))?;
+        }
+        Ok(result)
     }
 }
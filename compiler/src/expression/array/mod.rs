@@ -24,3 +24,6 @@ pub use self::access::*;
 
 pub mod index;
 pub use self::index::*;
+
+pub mod selector;
+pub use self::selector::*;
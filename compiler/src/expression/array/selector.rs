@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Decomposes a variable array index into a one-hot selector in a compiled Leo program.
+
+use std::convert::TryInto;
+
+use crate::{errors::ExpressionError, program::ConstrainedProgram, GroupType, Integer};
+use leo_asg::{ConstInt, Span};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::{boolean::Boolean, eq::EvaluateEqGadget};
+use snarkvm_r1cs::{ConstraintSystem, Variable};
+
+///
+/// Identifies the bits of an index and the length of the array it selects from.
+///
+/// Two indices with the same key are the same circuit value, so they share a selector.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct IndexSelectorKey {
+    bits: Vec<(Option<Variable>, bool)>,
+    array_len: usize,
+}
+
+impl IndexSelectorKey {
+    fn new(index: &Integer, array_len: usize) -> Self {
+        let bits = index
+            .get_bits()
+            .iter()
+            .map(|bit| match bit {
+                Boolean::Constant(value) => (None, *value),
+                Boolean::Is(bit) => (Some(bit.get_variable()), false),
+                Boolean::Not(bit) => (Some(bit.get_variable()), true),
+            })
+            .collect();
+
+        Self { bits, array_len }
+    }
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
+    ///
+    /// Returns one boolean per element of an array of length `array_len`, set only for the element at `index`.
+    ///
+    /// Exactly one of the booleans must be set, which also enforces that `index` is in bounds.
+    /// The selector is enforced once per index and array length and reused by later accesses.
+    ///
+    pub(crate) fn enforce_index_selector<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        index: &Integer,
+        array_len: usize,
+        span: &Span,
+    ) -> Result<Vec<Boolean>, ExpressionError> {
+        let key = IndexSelectorKey::new(index, array_len);
+        if let Some(selector) = self.index_selectors.get(&key) {
+            return Ok(selector.clone());
+        }
+
+        let mut selector = Vec::with_capacity(array_len);
+        for i in 0..array_len {
+            let namespace_string = format!(
+                "evaluate array index selector {} {}:{}",
+                i, span.line_start, span.col_start
+            );
+            let eq_namespace = cs.ns(|| namespace_string);
+
+            let index_bounded = i
+                .try_into()
+                .map_err(|_| ExpressionError::array_index_out_of_legal_bounds(span))?;
+            let const_index = ConstInt::U32(index_bounded).cast_to(&index.get_type());
+            let is_selected = index
+                .evaluate_equal(eq_namespace, &Integer::new(&const_index))
+                .map_err(|_| ExpressionError::cannot_evaluate("==".to_string(), span))?;
+            selector.push(is_selected);
+        }
+
+        // An index past the end of the array selects no element
+        cs.enforce(
+            || format!("enforce array index bounds {}:{}", span.line_start, span.col_start),
+            |lc| {
+                selector
+                    .iter()
+                    .fold(lc, |lc, is_selected| lc + &is_selected.lc(CS::one(), F::one()))
+            },
+            |lc| lc + CS::one(),
+            |lc| lc + CS::one(),
+        );
+
+        self.index_selectors.insert(key, selector.clone());
+        Ok(selector)
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-//! Enforces a logical `!` operator in a resolved Leo program.
+//! Enforces a bitwise `~` operator in a resolved Leo program.
 
 use crate::{errors::IntegerError, value::ConstrainedValue, GroupType};
 use leo_asg::Span;
@@ -25,5 +25,8 @@ pub fn evaluate_bit_not<'a, F: PrimeField, G: GroupType<F>>(
     value: ConstrainedValue<'a, F, G>,
     span: &Span,
 ) -> Result<ConstrainedValue<'a, F, G>, IntegerError> {
-    Err(IntegerError::cannot_evaluate(format!("!{}", value), span))
+    match value {
+        ConstrainedValue::Integer(integer) => Ok(ConstrainedValue::Integer(integer.bit_not())),
+        value => Err(IntegerError::cannot_evaluate(format!("~{}", value), span)),
+    }
 }
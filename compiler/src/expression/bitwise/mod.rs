@@ -0,0 +1,38 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Methods to enforce bitwise and shift expressions in a compiled Leo program.
+
+pub mod bit_and;
+pub use self::bit_and::*;
+
+pub mod bit_not;
+pub use self::bit_not::*;
+
+pub mod bit_or;
+pub use self::bit_or::*;
+
+pub mod bit_xor;
+pub use self::bit_xor::*;
+
+pub mod shl;
+pub use self::shl::*;
+
+pub mod shr;
+pub use self::shr::*;
+
+pub mod shr_signed;
+pub use self::shr_signed::*;
@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces a cast expression in a compiled Leo program.
+
+use crate::{
+    errors::ExpressionError,
+    program::ConstrainedProgram,
+    value::{Char, ConstrainedValue},
+    FieldType,
+    GroupType,
+    Integer,
+};
+use leo_asg::{Expression, IntegerType, Span, Type};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::ConstraintSystem;
+
+impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
+    /// Enforce a cast between integer, field and char types, element-wise for arrays.
+    /// Wrapping casts keep the low bits of the value, the others require the value to fit in the target type.
+    pub fn enforce_cast<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        inner: &'a Expression<'a>,
+        target_type: &Type<'a>,
+        wrapping: bool,
+        span: &Span,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        let value = self.enforce_expression(cs, inner)?;
+        let unique_namespace = format!(
+            "cast {} as {} {}:{}",
+            value, target_type, span.line_start, span.col_start
+        );
+
+        cast_value(&mut cs.ns(|| unique_namespace), value, target_type, wrapping, span)
+    }
+}
+
+pub(crate) fn cast_value<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    value: ConstrainedValue<'a, F, G>,
+    target_type: &Type<'a>,
+    wrapping: bool,
+    span: &Span,
+) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+    Ok(match (value, target_type) {
+        (ConstrainedValue::Integer(integer), Type::Integer(target)) if wrapping => {
+            ConstrainedValue::Integer(integer.wrapping_cast(target))
+        }
+        (ConstrainedValue::Integer(integer), Type::Integer(target)) => {
+            ConstrainedValue::Integer(integer.cast(cs, target, span)?)
+        }
+        (ConstrainedValue::Integer(integer), Type::Field) => {
+            let signed = integer.get_type().is_signed();
+            ConstrainedValue::Field(FieldType::from_integer_bits(
+                cs.ns(|| "field"),
+                &integer.get_bits(),
+                signed,
+                span,
+            )?)
+        }
+        (ConstrainedValue::Integer(integer), Type::Char) => {
+            let code = integer.cast(cs, &IntegerType::U32, span)?;
+            ConstrainedValue::Char(Char::from_code_bits(cs.ns(|| "char"), &code.get_bits(), span)?)
+        }
+        (ConstrainedValue::Field(field), Type::Integer(target)) => {
+            let bits = if wrapping {
+                field.to_wrapped_integer_bits(cs.ns(|| "integer"), target, span)?
+            } else {
+                field.to_integer_bits(cs.ns(|| "integer"), target, span)?
+            };
+            ConstrainedValue::Integer(Integer::from_bits_le(target, &bits))
+        }
+        (ConstrainedValue::Char(character), Type::Integer(target)) => {
+            let bits = if wrapping {
+                character
+                    .field
+                    .to_wrapped_integer_bits(cs.ns(|| "integer"), target, span)?
+            } else {
+                character.field.to_integer_bits(cs.ns(|| "integer"), target, span)?
+            };
+            ConstrainedValue::Integer(Integer::from_bits_le(target, &bits))
+        }
+        (value @ ConstrainedValue::Field(_), Type::Field) | (value @ ConstrainedValue::Char(_), Type::Char) => value,
+        (ConstrainedValue::Array(items), Type::Array(item_type, len)) if items.len() == *len => {
+            ConstrainedValue::Array(
+                items
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, item)| {
+                        cast_value(&mut cs.ns(|| format!("element {}", i)), item, item_type, wrapping, span)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+            )
+        }
+        (value, _) => {
+            return Err(ExpressionError::invalid_cast(
+                value.to_string(),
+                target_type.to_string(),
+                span,
+            ))
+        }
+    })
+}
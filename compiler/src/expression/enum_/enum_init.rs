@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces an enum variant initialization in a compiled Leo program.
+
+use crate::{errors::ExpressionError, program::ConstrainedProgram, value::ConstrainedValue, GroupType, Integer};
+use leo_asg::{ConstInt, EnumInitExpression};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::ConstraintSystem;
+
+impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
+    pub fn enforce_enum_init<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        expr: &EnumInitExpression<'a>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        let enum_ = expr.enum_.get();
+        let variant = expr.variant_index();
+
+        let mut values = Vec::with_capacity(expr.values.len());
+        for value in expr.values.iter() {
+            values.push(self.enforce_expression(cs, value.get())?);
+        }
+
+        // Only the initialized variant carries values.
+        let mut payloads = vec![None; enum_.variants.borrow().len()];
+        payloads[variant] = Some(values);
+
+        Ok(ConstrainedValue::Enum(
+            enum_,
+            Integer::new(&ConstInt::U8(variant as u8)),
+            payloads,
+        ))
+    }
+}
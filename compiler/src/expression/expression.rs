@@ -18,11 +18,11 @@
 
 use crate::{
     arithmetic::*,
+    bitwise::*,
     errors::ExpressionError,
     logical::*,
     program::ConstrainedProgram,
     relational::*,
-    resolve_core_circuit,
     value::{Address, Char, CharType, ConstrainedValue, Integer},
     FieldType,
     GroupType,
@@ -82,11 +82,28 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         &mut self,
         cs: &mut CS,
         expression: &'a Expression<'a>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        self.enter_traced_span(cs, expression.span());
+        let value = self.enforce_expression_kind(cs, expression);
+        self.exit_traced_span(cs);
+
+        value
+    }
+
+    fn enforce_expression_kind<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        expression: &'a Expression<'a>,
     ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
         let span = &expression.span().cloned().unwrap_or_default();
         match expression {
             // Cast
-            Expression::Cast(_) => unimplemented!("casts not implemented"),
+            Expression::Cast(CastExpression {
+                inner,
+                target_type,
+                wrapping,
+                ..
+            }) => self.enforce_cast(cs, inner.get(), target_type, *wrapping, span),
 
             // Variables
             Expression::VariableRef(variable_ref) => self.evaluate_ref(variable_ref),
@@ -119,7 +136,13 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                     BinaryOperation::Gt => evaluate_gt(cs, resolved_left, resolved_right, span),
                     BinaryOperation::Le => evaluate_le(cs, resolved_left, resolved_right, span),
                     BinaryOperation::Lt => evaluate_lt(cs, resolved_left, resolved_right, span),
-                    _ => unimplemented!("unimplemented binary operator"),
+                    BinaryOperation::BitOr => enforce_bit_or(cs, resolved_left, resolved_right, span),
+                    BinaryOperation::BitAnd => enforce_bit_and(cs, resolved_left, resolved_right, span),
+                    BinaryOperation::BitXor => enforce_bit_xor(cs, resolved_left, resolved_right, span),
+                    BinaryOperation::Shr => enforce_shr(cs, resolved_left, resolved_right, span),
+                    BinaryOperation::ShrSigned => enforce_shr_signed(cs, resolved_left, resolved_right, span),
+                    BinaryOperation::Shl => enforce_shl(cs, resolved_left, resolved_right, span),
+                    BinaryOperation::Mod => enforce_rem(cs, resolved_left, resolved_right, span),
                 }
             }
 
@@ -130,7 +153,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                     enforce_negate(cs, resolved_inner, span)
                 }
                 UnaryOperation::Not => Ok(evaluate_not(self.enforce_expression(cs, inner.get())?, span)?),
-                _ => unimplemented!("unimplemented unary operator"),
+                UnaryOperation::BitNot => Ok(evaluate_bit_not(self.enforce_expression(cs, inner.get())?, span)?),
             },
 
             Expression::Ternary(TernaryExpression {
@@ -168,6 +191,9 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             Expression::CircuitInit(expr) => self.enforce_circuit(cs, expr, span),
             Expression::CircuitAccess(expr) => self.enforce_circuit_access(cs, expr),
 
+            // Enums
+            Expression::EnumInit(expr) => self.enforce_enum_init(cs, expr),
+
             // Functions
             Expression::Call(CallExpression {
                 function,
@@ -178,10 +204,13 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                 if let Some(circuit) = function.get().circuit.get() {
                     let core_mapping = circuit.core_mapping.borrow();
                     if let Some(core_mapping) = core_mapping.as_deref() {
-                        let core_circuit = resolve_core_circuit::<F, G>(core_mapping);
+                        let core_circuit = self
+                            .core_circuits
+                            .get(core_mapping)
+                            .ok_or_else(|| ExpressionError::undefined_core_circuit(core_mapping, span))?;
                         return self.enforce_core_circuit_call_expression(
                             cs,
-                            &core_circuit,
+                            &*core_circuit,
                             function.get(),
                             target.get(),
                             &arguments[..],
@@ -16,7 +16,14 @@
 
 use std::cell::Cell;
 
-use crate::{program::ConstrainedProgram, value::ConstrainedValue, CoreCircuit, GroupType};
+use crate::{
+    program::ConstrainedProgram,
+    value::ConstrainedValue,
+    CoreCircuit,
+    ErasedConstraintSystem,
+    GroupType,
+    ProfileFrame,
+};
 
 use crate::errors::ExpressionError;
 use leo_asg::{Expression, Function, Span};
@@ -26,7 +33,7 @@ use snarkvm_r1cs::ConstraintSystem;
 impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
     /// Call a default core circuit function with arguments
     #[allow(clippy::too_many_arguments)]
-    pub fn enforce_core_circuit_call_expression<CS: ConstraintSystem<F>, C: CoreCircuit<'a, F, G>>(
+    pub fn enforce_core_circuit_call_expression<CS: ConstraintSystem<F>, C: CoreCircuit<F, G> + ?Sized>(
         &mut self,
         cs: &mut CS,
         core_circuit: &C,
@@ -44,8 +51,16 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             .collect::<Result<Vec<_>, _>>()?;
 
         // Call the core function
-        let return_value = core_circuit.call_function(cs, function, span, target_value, arguments)?;
+        self.enter_profile_frame(cs, || ProfileFrame::call("core", function, span));
+        let return_value = core_circuit.call_function(
+            &mut ErasedConstraintSystem::new(cs),
+            function,
+            span,
+            target_value,
+            arguments,
+        );
+        self.exit_profile_frame(cs);
 
-        Ok(return_value)
+        return_value
     }
 }
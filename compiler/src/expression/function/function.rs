@@ -18,7 +18,7 @@
 
 use std::cell::Cell;
 
-use crate::{errors::ExpressionError, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
+use crate::{errors::ExpressionError, program::ConstrainedProgram, value::ConstrainedValue, GroupType, ProfileFrame};
 use leo_asg::{Expression, Function, Span};
 
 use snarkvm_fields::PrimeField;
@@ -43,10 +43,13 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             )
         };
 
-        let return_value = self
-            .enforce_function(&mut cs.ns(name_unique), &function, target, arguments)
-            .map_err(|error| ExpressionError::from(Box::new(error)))?;
+        let cs = &mut cs.ns(name_unique);
+        self.enter_profile_frame(cs, || {
+            ProfileFrame::call("function", function, &function.span.clone().unwrap_or_default())
+        });
+        let return_value = self.enforce_function(cs, function, target, arguments, span);
+        self.exit_profile_frame(cs);
 
-        Ok(return_value)
+        return_value.map_err(|error| ExpressionError::from(Box::new(error)))
     }
 }
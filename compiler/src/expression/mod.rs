@@ -25,12 +25,21 @@ pub use self::array::*;
 pub mod binary;
 pub use self::binary::*;
 
+pub mod bitwise;
+pub use self::bitwise::*;
+
+pub mod cast;
+pub use self::cast::*;
+
 pub mod circuit;
 pub use self::circuit::*;
 
 pub mod conditional;
 pub use self::conditional::*;
 
+pub mod enum_;
+pub use self::enum_::*;
+
 pub mod expression;
 pub use self::expression::*;
 
@@ -16,7 +16,7 @@
 
 //! Enforces a relational `>=` operator in a resolved Leo program.
 
-use crate::{errors::ExpressionError, value::ConstrainedValue, GroupType};
+use crate::{errors::ExpressionError, string_less_than, value::ConstrainedValue, GroupType};
 use leo_asg::Span;
 
 use snarkvm_fields::PrimeField;
@@ -34,6 +34,15 @@ pub fn evaluate_ge<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
         (ConstrainedValue::Integer(num_1), ConstrainedValue::Integer(num_2)) => {
             num_1.greater_than_or_equal(unique_namespace, &num_2)
         }
+        (ConstrainedValue::Field(field_1), ConstrainedValue::Field(field_2)) => {
+            field_1.greater_than_or_equal(unique_namespace, &field_2)
+        }
+        (ConstrainedValue::Char(char_1), ConstrainedValue::Char(char_2)) => {
+            char_1.greater_than_or_equal(unique_namespace, &char_2)
+        }
+        (ConstrainedValue::Array(string_1), ConstrainedValue::Array(string_2)) => {
+            string_less_than(unique_namespace, &string_2, &string_1, true)
+        }
         (val_1, val_2) => {
             return Err(ExpressionError::incompatible_types(
                 format!("{} >= {}", val_1, val_2),
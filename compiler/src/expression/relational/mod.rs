@@ -30,3 +30,6 @@ pub use self::le::*;
 
 pub mod lt;
 pub use self::lt::*;
+
+pub mod string;
+pub use self::string::*;
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces the lexicographic ordering of strings in a resolved Leo program.
+
+use crate::{value::ConstrainedValue, GroupType};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::{
+    boolean::Boolean,
+    traits::{bits::EvaluateLtGadget, eq::EvaluateEqGadget},
+};
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+
+///
+/// Returns `left < right`, or `left <= right` if `or_equal`, for two strings of the same length.
+///
+/// Strings are ordered lexicographically by the code points of their characters.
+///
+pub fn string_less_than<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    left: &[ConstrainedValue<'a, F, G>],
+    right: &[ConstrainedValue<'a, F, G>],
+    or_equal: bool,
+) -> Result<Boolean, SynthesisError> {
+    if left.len() != right.len() {
+        return Err(SynthesisError::Unsatisfiable);
+    }
+
+    // Walking back from the last character, each character decides the order unless it is equal.
+    let mut result = Boolean::constant(or_equal);
+    for (i, pair) in left.iter().zip(right.iter()).enumerate().rev() {
+        let (left, right) = match pair {
+            (ConstrainedValue::Char(left), ConstrainedValue::Char(right)) => (left, right),
+            _ => return Err(SynthesisError::Unsatisfiable),
+        };
+
+        let less = left.less_than(cs.ns(|| format!("char {} less", i)), right)?;
+        let equal = left.evaluate_equal(cs.ns(|| format!("char {} equal", i)), right)?;
+        let rest = Boolean::and(cs.ns(|| format!("char {} rest", i)), &equal, &result)?;
+        result = Boolean::or(cs.ns(|| format!("char {} result", i)), &less, &rest)?;
+    }
+
+    Ok(result)
+}
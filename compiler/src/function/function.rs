@@ -16,9 +16,14 @@
 
 //! Enforces constraints on a function in a compiled Leo program.
 
-use crate::{errors::FunctionError, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
-
-use leo_asg::{Expression, Function, FunctionQualifier};
+use crate::{
+    errors::FunctionError,
+    program::{CallFrame, ConstrainedProgram},
+    value::ConstrainedValue,
+    GroupType,
+};
+
+use leo_asg::{Expression, Function, FunctionQualifier, Span};
 use std::cell::Cell;
 
 use snarkvm_fields::PrimeField;
@@ -32,9 +37,25 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         function: &'a Function<'a>,
         target: Option<&'a Expression<'a>>,
         arguments: &[Cell<&'a Expression<'a>>],
+        span: &Span,
     ) -> Result<ConstrainedValue<'a, F, G>, FunctionError> {
         let target_value = target.map(|target| self.enforce_expression(cs, target)).transpose()?;
 
+        if function.arguments.len() != arguments.len() {
+            return Err(FunctionError::input_not_found(
+                "arguments length invalid".to_string(),
+                &function.span.clone().unwrap_or_default(),
+            ));
+        }
+
+        // Evaluate every argument before binding any, a recursive call may read the caller's parameters
+        let mut input_values = Vec::with_capacity(arguments.len());
+        for input_expression in arguments.iter() {
+            input_values.push(self.enforce_expression(cs, input_expression.get())?);
+        }
+
+        self.push_call_frame(function, target_value.as_ref(), &input_values, span)?;
+
         let self_var = if let Some(target) = &target_value {
             let self_var = function
                 .scope
@@ -46,16 +67,8 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             None
         };
 
-        if function.arguments.len() != arguments.len() {
-            return Err(FunctionError::input_not_found(
-                "arguments length invalid".to_string(),
-                &function.span.clone().unwrap_or_default(),
-            ));
-        }
-
         // Store input values as new variables in resolved program
-        for ((_, variable), input_expression) in function.arguments.iter().zip(arguments.iter()) {
-            let input_value = self.enforce_expression(cs, input_expression.get())?;
+        for ((_, variable), input_value) in function.arguments.iter().zip(input_values) {
             let variable = variable.get().borrow();
 
             self.store(variable.id, input_value);
@@ -75,16 +88,21 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
 
         results.append(&mut result);
 
-        if function.qualifier == FunctionQualifier::MutSelfRef {
-            if let (Some(self_var), Some(target)) = (self_var, target) {
-                let new_self = self
-                    .get(self_var.borrow().id)
+        let new_self = match (function.qualifier == FunctionQualifier::MutSelfRef, self_var) {
+            (true, Some(self_var)) => Some(
+                self.get(self_var.borrow().id)
                     .expect("no self variable found in mut self context")
-                    .clone();
+                    .clone(),
+            ),
+            _ => None,
+        };
 
-                if !self.resolve_mut_ref(cs, target, new_self, &indicator)? {
-                    // todo: we should report a warning for calling a mutable function on an effectively copied self (i.e. wasn't assignable `tempStruct {x: 5}.myMutSelfFunction()`)
-                }
+        // The caller's variables must be visible again before `self` is written back
+        self.pop_call_frame();
+
+        if let (Some(new_self), Some(target)) = (new_self, target) {
+            if !self.resolve_mut_ref(cs, target, new_self, &indicator)? {
+                // todo: we should report a warning for calling a mutable function on an effectively copied self (i.e. wasn't assignable `tempStruct {x: 5}.myMutSelfFunction()`)
             }
         }
 
@@ -92,4 +110,86 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         Self::conditionally_select_result(cs, &output, results, &function.span.clone().unwrap_or_default())
             .map_err(FunctionError::StatementError)
     }
+
+    ///
+    /// Enters a call to `function`, enforcing the inline limit of RFC 002.
+    ///
+    /// Calls that repeat a pending call of the same function with the same constant arguments
+    /// can never reach a base case, so they are rejected before the limit is reached. So are calls
+    /// whose arguments are all runtime values, as nothing known while inlining can bound them.
+    ///
+    fn push_call_frame(
+        &mut self,
+        function: &'a Function<'a>,
+        target: Option<&ConstrainedValue<'a, F, G>>,
+        arguments: &[ConstrainedValue<'a, F, G>],
+        span: &Span,
+    ) -> Result<(), FunctionError> {
+        let constant_arguments = target
+            .into_iter()
+            .chain(arguments.iter())
+            .map(|value| {
+                if value.is_constant() {
+                    Some(value.to_string())
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let call_stack = || {
+            describe_call_stack(
+                self.call_stack
+                    .iter()
+                    .map(|frame| frame.function)
+                    .chain(std::iter::once(function)),
+            )
+        };
+
+        // `main` is at depth 0
+        if self.call_stack.len() > self.inline_limit as usize {
+            return Err(FunctionError::inline_limit_exceeded(
+                self.inline_limit,
+                call_stack(),
+                span,
+            ));
+        }
+
+        if self
+            .call_stack
+            .iter()
+            .any(|frame| frame.function.id == function.id && frame.constant_arguments == constant_arguments)
+        {
+            let name = function.name.borrow().name.to_string();
+            if !constant_arguments.is_empty() && constant_arguments.iter().all(Option::is_none) {
+                return Err(FunctionError::unbounded_runtime_recursion(name, call_stack(), span));
+            }
+            return Err(FunctionError::circular_call(name, call_stack(), span));
+        }
+
+        self.call_stack.push(CallFrame::new(function, constant_arguments));
+
+        Ok(())
+    }
+}
+
+///
+/// Describes a stack of pending function calls, collapsing direct recursion into a single entry.
+///
+pub(crate) fn describe_call_stack<'a>(functions: impl Iterator<Item = &'a Function<'a>>) -> String {
+    let mut calls: Vec<(String, usize)> = vec![];
+    for name in functions.map(|function| function.name.borrow().name.to_string()) {
+        match calls.last_mut() {
+            Some((last, count)) if *last == name => *count += 1,
+            _ => calls.push((name, 1)),
+        }
+    }
+    calls
+        .into_iter()
+        .map(|(name, count)| match count {
+            1 => name,
+            count => format!("{} ({} times)", name, count),
+        })
+        .collect::<Vec<_>>()
+        .join(" -> ")
 }
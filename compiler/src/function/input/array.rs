@@ -29,7 +29,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         &mut self,
         cs: &mut CS,
         name: &str,
-        array_type: &Type,
+        array_type: &Type<'a>,
         array_len: usize,
         input_value: Option<InputValue>,
         span: &Span,
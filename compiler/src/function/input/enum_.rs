@@ -0,0 +1,175 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Allocates an enum as a main function input parameter in a compiled Leo program.
+
+use crate::{errors::FunctionError, program::ConstrainedProgram, value::ConstrainedValue, GroupType, Integer};
+
+use leo_asg::{ConstInt, Enum, IntegerType, Type};
+use leo_ast::{Char, CharValue, GroupValue, InputValue, Span};
+
+use snarkvm_dpc::{account::AccountAddress, testnet1::instantiated::Components};
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::{
+    boolean::Boolean,
+    traits::{bits::EvaluateLtGadget, eq::EqGadget},
+};
+use snarkvm_r1cs::ConstraintSystem;
+
+impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
+    ///
+    /// Allocates an enum input as a `u8` tag and the values of every variant.
+    ///
+    /// The variants that are not given by the input are allocated with default values,
+    /// so the shape of the circuit does not depend on the input.
+    ///
+    pub fn allocate_enum<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        name: &str,
+        enum_: &'a Enum<'a>,
+        input_value: Option<InputValue>,
+        span: &Span,
+    ) -> Result<ConstrainedValue<'a, F, G>, FunctionError> {
+        let (index, mut values) = match input_value {
+            Some(InputValue::Enum(enum_name, variant, values)) => {
+                let (index, _) = self.enum_input_variant(enum_, name, &enum_name, &variant, &values, span)?;
+                (Some(index), Some(values))
+            }
+            None => (None, None),
+            Some(input) => {
+                return Err(FunctionError::input_type_mismatch(
+                    enum_.name.borrow().name.to_string(),
+                    input.to_string(),
+                    name.to_string(),
+                    span,
+                ));
+            }
+        };
+
+        let tag = Integer::from_input(
+            cs,
+            &IntegerType::U8,
+            &format!("{}_tag", name),
+            index.map(|index| InputValue::Integer(IntegerType::U8.into(), index.to_string())),
+            span,
+        )?;
+
+        // Restrict the tag to the declared variants.
+        let variants = enum_.variants.borrow();
+        if variants.len() <= u8::MAX as usize {
+            let is_valid = tag
+                .less_than(
+                    cs.ns(|| format!("`{}` tag is valid {}:{}", name, span.line_start, span.col_start)),
+                    &Integer::new(&ConstInt::U8(variants.len() as u8)),
+                )
+                .map_err(|error| crate::errors::IntegerError::synthesis(error, span))?;
+            is_valid
+                .enforce_equal(
+                    cs.ns(|| format!("`{}` tag in range {}:{}", name, span.line_start, span.col_start)),
+                    &Boolean::constant(true),
+                )
+                .map_err(|error| crate::errors::IntegerError::synthesis(error, span))?;
+        }
+
+        let mut payloads = Vec::with_capacity(variants.len());
+        for (i, variant) in variants.values().enumerate() {
+            let inputs = match (index, values.as_mut()) {
+                (Some(index), Some(values)) if index == i => std::mem::take(values).into_iter().map(Some).collect(),
+                (Some(_), _) => variant
+                    .payload
+                    .iter()
+                    .map(|type_| Some(default_input_value(type_)))
+                    .collect(),
+                (None, _) => vec![None; variant.payload.len()],
+            };
+
+            let mut payload = Vec::with_capacity(inputs.len());
+            for (j, (type_, input)) in variant.payload.iter().zip(inputs).enumerate() {
+                let value_name = format!("{}_{}_{}", name, variant.name.name, j);
+                payload.push(self.allocate_main_function_input(cs, type_, &value_name, input, span)?);
+            }
+            payloads.push(Some(payload));
+        }
+
+        Ok(ConstrainedValue::Enum(enum_, tag, payloads))
+    }
+
+    ///
+    /// Returns the index and the value types of the variant named by an enum input.
+    ///
+    pub(crate) fn enum_input_variant(
+        &self,
+        enum_: &'a Enum<'a>,
+        name: &str,
+        enum_name: &str,
+        variant: &str,
+        values: &[InputValue],
+        span: &Span,
+    ) -> Result<(usize, Vec<Type<'a>>), FunctionError> {
+        let expected_name = enum_.name.borrow().name.to_string();
+        if expected_name != enum_name {
+            return Err(FunctionError::input_type_mismatch(
+                expected_name,
+                enum_name.to_string(),
+                name.to_string(),
+                span,
+            ));
+        }
+
+        let variants = enum_.variants.borrow();
+        let (index, _, found) = variants
+            .get_full(variant)
+            .ok_or_else(|| FunctionError::invalid_enum_variant(expected_name, variant.to_string(), span))?;
+        if found.payload.len() != values.len() {
+            return Err(FunctionError::enum_variant_size_mismatch(
+                variant.to_string(),
+                found.payload.len(),
+                values.len(),
+                span,
+            ));
+        }
+
+        Ok((index, found.payload.clone()))
+    }
+}
+
+/// Returns the value witnessed for an input of the given type that is not in use.
+fn default_input_value(type_: &Type) -> InputValue {
+    match type_ {
+        Type::Address => InputValue::Address(AccountAddress::<Components>::default().to_string()),
+        Type::Boolean => InputValue::Boolean(false),
+        Type::Char => InputValue::Char(CharValue {
+            character: Char::Scalar('\0'),
+            span: Span::default(),
+        }),
+        Type::Field => InputValue::Field("0".to_string()),
+        Type::Group => InputValue::Group(GroupValue::Single("0".into(), Span::default())),
+        Type::Integer(integer_type) => InputValue::Integer(integer_type.clone().into(), "0".to_string()),
+        Type::Array(type_, len) => InputValue::Array(vec![default_input_value(type_); *len]),
+        Type::Tuple(types) => InputValue::Tuple(types.iter().map(default_input_value).collect()),
+        Type::Enum(enum_) => {
+            let variants = enum_.variants.borrow();
+            let (variant, found) = variants.get_index(0).expect("enums have at least one variant");
+            InputValue::Enum(
+                enum_.name.borrow().name.to_string(),
+                variant.clone(),
+                found.payload.iter().map(default_input_value).collect(),
+            )
+        }
+        Type::Circuit(_) => unimplemented!("main function input not implemented for type {}", type_), // Should not happen.
+    }
+}
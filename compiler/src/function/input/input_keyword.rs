@@ -14,7 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::FunctionError, ConstrainedCircuitMember, ConstrainedProgram, ConstrainedValue, GroupType};
+use crate::{
+    errors::FunctionError,
+    ConstrainedCircuitMember,
+    ConstrainedProgram,
+    ConstrainedValue,
+    GroupType,
+    PublicAllocation,
+};
 use leo_asg::{Circuit, CircuitMember, Type};
 use leo_ast::{Identifier, Input, Span};
 
@@ -79,7 +86,13 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             };
 
             let member_name = name.clone();
-            let member_value = self.allocate_input_section(cs, name, sub_circuit, values)?;
+
+            // Register values are the public inputs of the program
+            let member_value = if name.name.as_ref() == REGISTERS_VARIABLE_NAME {
+                self.allocate_input_section(&mut PublicAllocation::new(cs), name, sub_circuit, values)?
+            } else {
+                self.allocate_input_section(cs, name, sub_circuit, values)?
+            };
 
             let member = ConstrainedCircuitMember(member_name, member_value);
 
@@ -43,7 +43,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
     pub fn allocate_main_function_input<CS: ConstraintSystem<F>>(
         &mut self,
         cs: &mut CS,
-        type_: &Type,
+        type_: &Type<'a>,
         name: &str,
         input_option: Option<InputValue>,
         span: &Span,
@@ -63,6 +63,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             )?)),
             Type::Array(type_, len) => self.allocate_array(cs, name, &*type_, *len, input_option, span),
             Type::Tuple(types) => self.allocate_tuple(cs, &name, types, input_option, span),
+            Type::Enum(enum_) => self.allocate_enum(cs, &name, enum_, input_option, span),
             _ => unimplemented!("main function input not implemented for type {}", type_), // Should not happen.
         }
     }
@@ -73,7 +74,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
     pub fn constant_main_function_input<CS: ConstraintSystem<F>>(
         &mut self,
         cs: &mut CS,
-        type_: &Type,
+        type_: &Type<'a>,
         name: &str,
         input_option: Option<InputValue>,
         span: &Span,
@@ -135,6 +136,24 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                         .collect::<Result<Vec<_>, _>>()?,
                 ))
             }
+            (Type::Enum(enum_), InputValue::Enum(enum_name, variant, values)) => {
+                let (index, payload) = self.enum_input_variant(enum_, name, &enum_name, &variant, &values, span)?;
+
+                let mut payloads = vec![None; enum_.variants.borrow().len()];
+                payloads[index] = Some(
+                    values
+                        .into_iter()
+                        .zip(payload.iter())
+                        .map(|(value, type_)| self.constant_main_function_input(cs, type_, name, Some(value), span))
+                        .collect::<Result<Vec<_>, _>>()?,
+                );
+
+                Ok(ConstrainedValue::Enum(
+                    enum_,
+                    Integer::new(&ConstInt::U8(index as u8)),
+                    payloads,
+                ))
+            }
             (Type::Circuit(_), _) => unimplemented!("main function input not implemented for type {}", type_), // Should not happen.
 
             // Return an error if the input type and input value do not match.
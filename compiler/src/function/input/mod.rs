@@ -19,6 +19,9 @@
 pub mod array;
 pub use self::array::*;
 
+pub mod enum_;
+pub use self::enum_::*;
+
 pub mod main_function_input;
 pub use self::main_function_input::*;
 
@@ -34,6 +34,9 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
     ) -> Result<Output, FunctionError> {
         let registers = input.get_registers();
 
+        // Selectors from a previous run refer to the variables of another constraint system
+        self.index_selectors.clear();
+
         // Iterate over main function input variables and allocate new values
         let asg_input = function.scope.resolve_input();
 
@@ -122,7 +125,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         }
 
         let span = function.span.clone().unwrap_or_default();
-        let result_value = self.enforce_function(cs, function, None, &arguments)?;
+        let result_value = self.enforce_function(cs, function, None, &arguments, &span)?;
         let output = Output::new(&self.asg, registers, result_value, &span)?;
 
         Ok(output)
@@ -42,11 +42,11 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         span: &Span,
     ) -> Result<ConstrainedValue<'a, F, G>, StatementError> {
         // Initialize empty return value.
-        let mut return_value = None;
+        let mut return_value: Option<ConstrainedValue<'a, F, G>> = None;
+        let mut returned = false;
 
         // Find the return value
-        let mut ignored = vec![];
-        for (indicator, result) in results.into_iter() {
+        for (i, (indicator, result)) in results.into_iter().enumerate() {
             // Error if a statement returned a result with an incorrect type
             let result_type = result.to_type(span)?;
             if !expected_return.is_assignable_from(&result_type) {
@@ -57,38 +57,29 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                 );
             }
 
+            // Error if we already have a return value.
             if get_indicator_value(&indicator) {
-                // Error if we already have a return value.
-                if return_value.is_some() {
+                if returned {
                     return Err(StatementError::multiple_returns(span));
-                } else {
-                    // Set the function return value.
-                    return_value = Some(result);
                 }
-            } else {
-                // Ignore a possible function return value.
-                ignored.push((indicator, result))
+                returned = true;
             }
-        }
 
-        // Conditionally select out the ignored results in the circuit.
-        //
-        // If there are branches in the function we need to use the `ConditionalSelectGadget` to parse through and select the correct one.
-        // This can be thought of as de-multiplexing all previous wires that may have returned results into one.
-        for (i, (indicator, result)) in ignored.into_iter().enumerate() {
-            if let Some(value) = &return_value {
-                return_value = Some(
-                    ConstrainedValue::conditionally_select(
-                        cs.ns(|| format!("select result {} {}:{}", i, span.line_start, span.col_start)),
-                        &indicator,
-                        &result,
-                        &value,
-                    )
-                    .map_err(|_| StatementError::select_fail(result.to_string(), value.to_string(), span))?,
-                );
-            } else {
-                return_value = Some(result); // we ignore indicator for default -- questionable
-            }
+            // Conditionally select the results in the circuit.
+            //
+            // If there are branches in the function we need to use the `ConditionalSelectGadget` to parse through and select the correct one.
+            // This can be thought of as de-multiplexing all previous wires that may have returned results into one.
+            // The results are selected in the order of the statements, so the circuit is the same whichever one is returned.
+            return_value = Some(match return_value {
+                Some(value) => ConstrainedValue::conditionally_select(
+                    cs.ns(|| format!("select result {} {}:{}", i, span.line_start, span.col_start)),
+                    &indicator,
+                    &result,
+                    &value,
+                )
+                .map_err(|_| StatementError::select_fail(result.to_string(), value.to_string(), span))?,
+                None => result, // we ignore indicator for default -- questionable
+            });
         }
 
         if expected_return.is_unit() {
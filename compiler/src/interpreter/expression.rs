@@ -0,0 +1,538 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Evaluates an expression in an interpreted Leo program.
+
+use crate::{
+    cast_value,
+    errors::{ExpressionError, IntegerError, InterpreterError},
+    ErasedConstraintSystem,
+    FieldType,
+    GroupType,
+    Interpreter,
+    Value,
+};
+use leo_asg::{expression::*, CircuitMember, ConstInt, ConstValue, Expression, IntegerType, Node, Span};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::TestConstraintSystem;
+
+impl<'a, F: PrimeField, G: GroupType<F>> Interpreter<'a, F, G> {
+    pub(crate) fn evaluate_const_value(
+        &self,
+        value: &ConstValue,
+        span: &Span,
+    ) -> Result<Value<'a, F, G>, InterpreterError> {
+        Ok(match value {
+            ConstValue::Address(value) => Value::Address(value.to_string()),
+            ConstValue::Boolean(value) => Value::Boolean(*value),
+            ConstValue::Char(value) => Value::Char(value.clone()),
+            ConstValue::Field(value) => {
+                let field = FieldType::<F>::constant(TestConstraintSystem::<F>::new(), value.to_string(), span)
+                    .map_err(ExpressionError::from)?;
+                Value::Field(field.get_value().expect("constant field without a value"))
+            }
+            ConstValue::Group(value) => Value::Group(G::constant(value, span).map_err(ExpressionError::from)?),
+            ConstValue::Int(value) => Value::Integer(value.clone()),
+            ConstValue::Tuple(values) => Value::Tuple(
+                values
+                    .iter()
+                    .map(|x| self.evaluate_const_value(x, span))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            ConstValue::Array(values) => Value::Array(
+                values
+                    .iter()
+                    .map(|x| self.evaluate_const_value(x, span))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        })
+    }
+
+    pub(crate) fn evaluate_expression(
+        &mut self,
+        expression: &'a Expression<'a>,
+    ) -> Result<Value<'a, F, G>, InterpreterError> {
+        let span = &expression.span().cloned().unwrap_or_default();
+        match expression {
+            // Cast
+            Expression::Cast(CastExpression {
+                inner,
+                target_type,
+                wrapping,
+                ..
+            }) => {
+                let value = self.evaluate_expression(inner.get())?.to_constrained(span)?;
+                let value = cast_value(
+                    &mut TestConstraintSystem::<F>::new(),
+                    value,
+                    target_type,
+                    *wrapping,
+                    span,
+                )?;
+
+                Value::from_constrained(value, span)
+            }
+
+            // Variables
+            Expression::VariableRef(variable_ref) => Ok(self.get(variable_ref.variable, span)?.clone()),
+
+            // Values
+            Expression::Constant(Constant { value, .. }) => self.evaluate_const_value(value, span),
+
+            // Binary operations
+            Expression::Binary(BinaryExpression {
+                left, right, operation, ..
+            }) => {
+                let left = self.evaluate_expression(left.get())?;
+                let right = self.evaluate_expression(right.get())?;
+
+                evaluate_binary(operation, left, right, span)
+            }
+
+            // Unary operations
+            Expression::Unary(UnaryExpression { inner, operation, .. }) => {
+                let inner = self.evaluate_expression(inner.get())?;
+
+                evaluate_unary(operation, inner, span)
+            }
+
+            // Only the branch that is taken is evaluated
+            Expression::Ternary(TernaryExpression {
+                condition,
+                if_true,
+                if_false,
+                ..
+            }) => match self.evaluate_expression(condition.get())? {
+                Value::Boolean(true) => self.evaluate_expression(if_true.get()),
+                Value::Boolean(false) => self.evaluate_expression(if_false.get()),
+                value => Err(ExpressionError::conditional_boolean(value.to_string(), span).into()),
+            },
+
+            // Arrays
+            Expression::ArrayInline(ArrayInlineExpression { elements, .. }) => {
+                let mut result = vec![];
+                for (element, is_spread) in elements.iter() {
+                    match (self.evaluate_expression(element.get())?, is_spread) {
+                        (Value::Array(values), true) => result.extend(values),
+                        (value, false) => result.push(value),
+                        (value, true) => return Err(ExpressionError::undefined_array(value.to_string(), span).into()),
+                    }
+                }
+
+                Ok(Value::Array(result))
+            }
+            Expression::ArrayInit(ArrayInitExpression { element, len, .. }) => {
+                let value = self.evaluate_expression(element.get())?;
+
+                Ok(Value::Array(vec![value; *len]))
+            }
+            Expression::ArrayAccess(ArrayAccessExpression { array, index, .. }) => {
+                let array = match self.evaluate_expression(array.get())? {
+                    Value::Array(array) => array,
+                    value => return Err(ExpressionError::undefined_array(value.to_string(), span).into()),
+                };
+                let index = self.evaluate_index(index.get(), span)?;
+
+                array
+                    .into_iter()
+                    .nth(index)
+                    .ok_or_else(|| ExpressionError::array_index_out_of_bounds(index, span).into())
+            }
+            Expression::ArrayRangeAccess(ArrayRangeAccessExpression {
+                array,
+                left,
+                right,
+                length,
+                ..
+            }) => {
+                let array = match self.evaluate_expression(array.get())? {
+                    Value::Array(array) => array,
+                    value => return Err(ExpressionError::undefined_array(value.to_string(), span).into()),
+                };
+                let left = match left.get() {
+                    Some(left) => self.evaluate_index(left, span)?,
+                    None => 0,
+                };
+                let right = match right.get() {
+                    Some(right) => self.evaluate_index(right, span)?,
+                    None => array.len(),
+                };
+
+                if right.checked_sub(left) != Some(*length) {
+                    return Err(ExpressionError::array_invalid_slice_length(span).into());
+                }
+                if right > array.len() {
+                    return Err(ExpressionError::array_slice_out_of_bounds(
+                        left.to_string(),
+                        *length,
+                        array.len(),
+                        span,
+                    )
+                    .into());
+                }
+
+                Ok(Value::Array(array[left..right].to_vec()))
+            }
+
+            // Tuples
+            Expression::TupleInit(TupleInitExpression { elements, .. }) => Ok(Value::Tuple(
+                elements
+                    .iter()
+                    .map(|element| self.evaluate_expression(element.get()))
+                    .collect::<Result<Vec<_>, _>>()?,
+            )),
+            Expression::TupleAccess(TupleAccessExpression { tuple_ref, index, .. }) => {
+                match self.evaluate_expression(tuple_ref.get())? {
+                    Value::Tuple(tuple) => tuple
+                        .into_iter()
+                        .nth(*index)
+                        .ok_or_else(|| ExpressionError::tuple_index_out_of_bounds(*index, span).into()),
+                    value => Err(ExpressionError::undefined_array(value.to_string(), span).into()),
+                }
+            }
+
+            // Circuits
+            Expression::CircuitInit(CircuitInitExpression { circuit, values, .. }) => {
+                let circuit = circuit.get();
+
+                let mut members = Vec::with_capacity(values.len());
+                for (name, inner) in values.iter() {
+                    match circuit.members.borrow().get(name.name.as_ref()) {
+                        Some(CircuitMember::Variable(_)) => (),
+                        _ => return Err(ExpressionError::expected_circuit_member(name.to_string(), span).into()),
+                    }
+                    members.push((name.clone(), self.evaluate_expression(inner.get())?));
+                }
+
+                Ok(Value::Circuit(circuit, members))
+            }
+            Expression::EnumInit(expr) => {
+                let mut values = Vec::with_capacity(expr.values.len());
+                for value in expr.values.iter() {
+                    values.push(self.evaluate_expression(value.get())?);
+                }
+
+                Ok(Value::Enum(expr.enum_.get(), expr.variant_index(), values))
+            }
+            Expression::CircuitAccess(CircuitAccessExpression {
+                circuit,
+                target,
+                member,
+                ..
+            }) => {
+                let target = match target.get() {
+                    Some(target) => target,
+                    None => return Err(ExpressionError::invalid_static_access(member.to_string(), &member.span).into()),
+                };
+                match self.evaluate_expression(target)? {
+                    Value::Circuit(_, members) => members
+                        .into_iter()
+                        .find(|(name, _)| name.name == member.name)
+                        .map(|(_, value)| value)
+                        .ok_or_else(|| {
+                            ExpressionError::undefined_member_access(
+                                circuit.get().name.borrow().to_string(),
+                                member.to_string(),
+                                &member.span,
+                            )
+                            .into()
+                        }),
+                    value => Err(ExpressionError::undefined_circuit(
+                        value.to_string(),
+                        &target.span().cloned().unwrap_or_default(),
+                    )
+                    .into()),
+                }
+            }
+
+            // Functions
+            Expression::Call(CallExpression {
+                function,
+                target,
+                arguments,
+                ..
+            }) => {
+                let function = function.get();
+                let target = match target.get() {
+                    Some(target) => Some((target, self.evaluate_expression(target)?)),
+                    None => None,
+                };
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| self.evaluate_expression(argument.get()))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                if let Some(circuit) = function.circuit.get() {
+                    let core_mapping = circuit.core_mapping.borrow();
+                    if let Some(core_mapping) = core_mapping.as_deref() {
+                        // Core functions are computed by their gadgets on constant values
+                        let core_circuit = self
+                            .core_circuits
+                            .get(core_mapping)
+                            .ok_or_else(|| ExpressionError::undefined_core_circuit(core_mapping, span))?;
+                        let target = target.map(|(_, value)| value.to_constrained(span)).transpose()?;
+                        let arguments = arguments
+                            .iter()
+                            .map(|argument| argument.to_constrained(span))
+                            .collect::<Result<Vec<_>, _>>()?;
+                        let value = core_circuit.call_function(
+                            &mut ErasedConstraintSystem::new(&mut TestConstraintSystem::<F>::new()),
+                            function,
+                            span,
+                            target,
+                            arguments,
+                        )?;
+
+                        return Value::from_constrained(value, span);
+                    }
+                }
+
+                self.call_function(function, target, arguments, span)
+            }
+        }
+    }
+
+    /// Returns the value of an array index or loop bound.
+    pub(crate) fn evaluate_index(&mut self, index: &'a Expression<'a>, span: &Span) -> Result<usize, InterpreterError> {
+        match self.evaluate_expression(index)? {
+            Value::Integer(integer) => integer
+                .to_usize()
+                .ok_or_else(|| ExpressionError::invalid_index(integer.to_string(), span).into()),
+            value => Err(ExpressionError::invalid_index(value.to_string(), span).into()),
+        }
+    }
+}
+
+///
+/// Returns the result of a binary operation on two values.
+///
+/// Integer operations are checked, an overflow or a division by zero is an error at `span`.
+///
+pub fn evaluate_binary<'a, F: PrimeField, G: GroupType<F>>(
+    operation: &BinaryOperation,
+    left: Value<'a, F, G>,
+    right: Value<'a, F, G>,
+    span: &Span,
+) -> Result<Value<'a, F, G>, InterpreterError> {
+    let describe =
+        |left: &Value<'a, F, G>, right: &Value<'a, F, G>| format!("{} {} {}", left, operation.as_ref(), right);
+
+    match operation {
+        BinaryOperation::Eq => return Ok(Value::Boolean(values_equal(&left, &right, span)?)),
+        BinaryOperation::Ne => return Ok(Value::Boolean(!values_equal(&left, &right, span)?)),
+        _ => (),
+    }
+
+    Ok(match (left, right) {
+        (Value::Integer(left), Value::Integer(right)) => {
+            let overflow = || {
+                InterpreterError::overflow(
+                    describe(&Value::Integer(left.clone()), &Value::Integer(right.clone())),
+                    span,
+                )
+            };
+            let is_zero = right.to_u128() == 0;
+            let shift = match &right {
+                ConstInt::U32(shift) => Some(*shift),
+                _ => None,
+            };
+
+            match operation {
+                BinaryOperation::Add => Value::Integer(left.value_add(&right).ok_or_else(overflow)?),
+                BinaryOperation::Sub => Value::Integer(left.value_sub(&right).ok_or_else(overflow)?),
+                BinaryOperation::Mul => Value::Integer(left.value_mul(&right).ok_or_else(overflow)?),
+                BinaryOperation::Pow => Value::Integer(left.value_pow(&right).ok_or_else(overflow)?),
+                BinaryOperation::Div | BinaryOperation::Mod if is_zero => {
+                    return Err(InterpreterError::division_by_zero(
+                        describe(&Value::Integer(left), &Value::Integer(right)),
+                        span,
+                    ));
+                }
+                BinaryOperation::Div => Value::Integer(left.value_div(&right).ok_or_else(overflow)?),
+                BinaryOperation::Mod => Value::Integer(left.value_rem(&right).ok_or_else(overflow)?),
+                BinaryOperation::BitAnd => Value::Integer(left.value_bit_and(&right).ok_or_else(overflow)?),
+                BinaryOperation::BitOr => Value::Integer(left.value_bit_or(&right).ok_or_else(overflow)?),
+                BinaryOperation::BitXor => Value::Integer(left.value_bit_xor(&right).ok_or_else(overflow)?),
+                BinaryOperation::Shl | BinaryOperation::Shr | BinaryOperation::ShrSigned => {
+                    let shift = shift.ok_or_else(|| {
+                        ExpressionError::from(IntegerError::integer_type_mismatch(
+                            &IntegerType::U32,
+                            right.get_int_type(),
+                            span,
+                        ))
+                    })?;
+                    let result = match operation {
+                        BinaryOperation::Shl => left.value_shl(shift),
+                        BinaryOperation::Shr => left.value_shr(shift),
+                        _ => left.value_shr_signed(shift),
+                    };
+                    Value::Integer(result.ok_or_else(overflow)?)
+                }
+                BinaryOperation::Lt => Value::Boolean(left.value_lt(&right).ok_or_else(overflow)?),
+                BinaryOperation::Le => Value::Boolean(left.value_le(&right).ok_or_else(overflow)?),
+                BinaryOperation::Gt => Value::Boolean(left.value_gt(&right).ok_or_else(overflow)?),
+                BinaryOperation::Ge => Value::Boolean(left.value_ge(&right).ok_or_else(overflow)?),
+                _ => {
+                    return Err(incompatible(
+                        describe(&Value::Integer(left), &Value::Integer(right)),
+                        span,
+                    ))
+                }
+            }
+        }
+        (Value::Field(left), Value::Field(right)) => match operation {
+            BinaryOperation::Add => Value::Field(left + right),
+            BinaryOperation::Sub => Value::Field(left - right),
+            BinaryOperation::Mul => Value::Field(left * right),
+            BinaryOperation::Div => match right.inverse() {
+                Some(inverse) => Value::Field(left * inverse),
+                None => {
+                    return Err(InterpreterError::division_by_zero(
+                        describe(&Value::Field(left), &Value::Field(right)),
+                        span,
+                    ));
+                }
+            },
+            // Fields are ordered by their canonical representatives in `[0, p)`
+            BinaryOperation::Lt => Value::Boolean(left.into_repr() < right.into_repr()),
+            BinaryOperation::Le => Value::Boolean(left.into_repr() <= right.into_repr()),
+            BinaryOperation::Gt => Value::Boolean(left.into_repr() > right.into_repr()),
+            BinaryOperation::Ge => Value::Boolean(left.into_repr() >= right.into_repr()),
+            _ => return Err(incompatible(describe(&Value::Field(left), &Value::Field(right)), span)),
+        },
+        (Value::Group(left), Value::Group(right)) => {
+            let cs = &mut TestConstraintSystem::<F>::new();
+            match operation {
+                BinaryOperation::Add => Value::Group(left.add(cs, &right, span).map_err(ExpressionError::from)?),
+                BinaryOperation::Sub => Value::Group(left.sub(cs, &right, span).map_err(ExpressionError::from)?),
+                _ => return Err(incompatible(describe(&Value::Group(left), &Value::Group(right)), span)),
+            }
+        }
+        (Value::Boolean(left), Value::Boolean(right)) => match operation {
+            BinaryOperation::And => Value::Boolean(left && right),
+            BinaryOperation::Or => Value::Boolean(left || right),
+            _ => {
+                return Err(incompatible(
+                    describe(&Value::Boolean(left), &Value::Boolean(right)),
+                    span,
+                ))
+            }
+        },
+        (Value::Char(left), Value::Char(right)) => match operation {
+            BinaryOperation::Lt => Value::Boolean(left.code() < right.code()),
+            BinaryOperation::Le => Value::Boolean(left.code() <= right.code()),
+            BinaryOperation::Gt => Value::Boolean(left.code() > right.code()),
+            BinaryOperation::Ge => Value::Boolean(left.code() >= right.code()),
+            _ => return Err(incompatible(describe(&Value::Char(left), &Value::Char(right)), span)),
+        },
+        // Strings of the same length are ordered lexicographically by the code points of their characters
+        (Value::Array(left), Value::Array(right)) if left.len() == right.len() => {
+            let mut ordering = Some(std::cmp::Ordering::Equal);
+            for pair in left.iter().zip(right.iter()) {
+                ordering = match pair {
+                    (Value::Char(left), Value::Char(right)) => match left.code().cmp(&right.code()) {
+                        std::cmp::Ordering::Equal => continue,
+                        ordering => Some(ordering),
+                    },
+                    _ => None,
+                };
+                break;
+            }
+            match (operation, ordering) {
+                (BinaryOperation::Lt, Some(ordering)) => Value::Boolean(ordering.is_lt()),
+                (BinaryOperation::Le, Some(ordering)) => Value::Boolean(ordering.is_le()),
+                (BinaryOperation::Gt, Some(ordering)) => Value::Boolean(ordering.is_gt()),
+                (BinaryOperation::Ge, Some(ordering)) => Value::Boolean(ordering.is_ge()),
+                _ => return Err(incompatible(describe(&Value::Array(left), &Value::Array(right)), span)),
+            }
+        }
+        (left, right) => return Err(incompatible(describe(&left, &right), span)),
+    })
+}
+
+///
+/// Returns the result of a unary operation on a value.
+///
+pub fn evaluate_unary<'a, F: PrimeField, G: GroupType<F>>(
+    operation: &UnaryOperation,
+    inner: Value<'a, F, G>,
+    span: &Span,
+) -> Result<Value<'a, F, G>, InterpreterError> {
+    Ok(match (operation, inner) {
+        (UnaryOperation::Not, Value::Boolean(value)) => Value::Boolean(!value),
+        (UnaryOperation::Negate, Value::Integer(value)) => {
+            if !value.get_int_type().is_signed() {
+                return Err(ExpressionError::from(IntegerError::negate_operation(span)).into());
+            }
+            match value.value_negate() {
+                Some(negated) => Value::Integer(negated),
+                None => {
+                    return Err(InterpreterError::overflow(
+                        format!("-{}", Value::<F, G>::Integer(value)),
+                        span,
+                    ))
+                }
+            }
+        }
+        (UnaryOperation::Negate, Value::Field(value)) => Value::Field(-value),
+        (UnaryOperation::Negate, Value::Group(value)) => Value::Group(
+            value
+                .negate(&mut TestConstraintSystem::<F>::new(), span)
+                .map_err(ExpressionError::from)?,
+        ),
+        (UnaryOperation::BitNot, Value::Integer(value)) => {
+            Value::Integer(value.value_bit_negate().expect("bit negation never fails"))
+        }
+        (operation, value) => {
+            return Err(ExpressionError::incompatible_types(format!("{:?} {}", operation, value), span).into());
+        }
+    })
+}
+
+///
+/// Returns `true` if the two values are equal.
+///
+pub fn values_equal<'a, F: PrimeField, G: GroupType<F>>(
+    left: &Value<'a, F, G>,
+    right: &Value<'a, F, G>,
+    span: &Span,
+) -> Result<bool, InterpreterError> {
+    Ok(match (left, right) {
+        (Value::Address(left), Value::Address(right)) => left == right,
+        (Value::Boolean(left), Value::Boolean(right)) => left == right,
+        (Value::Char(left), Value::Char(right)) => left.code() == right.code(),
+        (Value::Field(left), Value::Field(right)) => left == right,
+        (Value::Group(left), Value::Group(right)) => left
+            .evaluate_equal(&mut TestConstraintSystem::<F>::new(), right)
+            .ok()
+            .and_then(|equal| equal.get_value())
+            .ok_or_else(|| ExpressionError::cannot_evaluate("==".to_string(), span))?,
+        (Value::Integer(left), Value::Integer(right)) if left.get_int_type() == right.get_int_type() => left == right,
+        (Value::Array(left), Value::Array(right)) | (Value::Tuple(left), Value::Tuple(right)) => {
+            let mut equal = left.len() == right.len();
+            for (left, right) in left.iter().zip(right.iter()) {
+                equal &= values_equal(left, right, span)?;
+            }
+            equal
+        }
+        (left, right) => return Err(incompatible(format!("{} == {}", left, right), span)),
+    })
+}
+
+fn incompatible(operation: String, span: &Span) -> InterpreterError {
+    ExpressionError::incompatible_types(operation, span).into()
+}
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Reads the values of main function inputs in an interpreted Leo program.
+
+use crate::{
+    errors::{FunctionError, InterpreterError},
+    ConstrainedProgram,
+    GroupType,
+    Interpreter,
+    Value,
+    RECORD_VARIABLE_NAME,
+    REGISTERS_VARIABLE_NAME,
+    STATE_LEAF_VARIABLE_NAME,
+    STATE_VARIABLE_NAME,
+};
+use leo_asg::{AsgConvertError, Circuit, CircuitMember, InnerVariable, Type};
+use leo_ast::{Identifier, Input, InputValue, Parameter, Span};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::TestConstraintSystem;
+
+use indexmap::IndexMap;
+
+impl<'a, F: PrimeField, G: GroupType<F>> Interpreter<'a, F, G> {
+    ///
+    /// Returns the value of a main function input.
+    ///
+    /// Inputs are looked up in the `[main]` and `[constants]` sections as in the compiled program,
+    /// but every input has a concrete value.
+    ///
+    pub(crate) fn main_function_input(
+        &mut self,
+        input_variable: &InnerVariable<'a>,
+        input: &Input,
+    ) -> Result<Value<'a, F, G>, InterpreterError> {
+        let name = input_variable.name.name.to_string();
+        let span = &input_variable.name.span;
+
+        let input_option = match (input_variable.const_, input.get(&name), input.get_constant(&name)) {
+            // If variable is in both [main] and [constants] sections - error.
+            (_, Some(_), Some(_)) => return Err(FunctionError::double_input_declaration(name, span).into()),
+            // If input option is found in [main] section and input is not const.
+            (false, Some(input_option), _) => input_option,
+            // If input option is found in [constants] section and function argument is const.
+            (true, _, Some(input_option)) => input_option,
+            // Function argument is const, input is not.
+            (true, Some(_), None) => return Err(FunctionError::expected_const_input(name, span).into()),
+            // Input is const, function argument is not.
+            (false, None, Some(_)) => return Err(FunctionError::expected_non_const_input(name, span).into()),
+            // When not found - Error out.
+            (_, _, _) => return Err(FunctionError::input_not_found(name, span).into()),
+        };
+
+        self.input_value(&input_variable.type_, &name, input_option, span)
+    }
+
+    ///
+    /// Returns the value of an input of the given type.
+    ///
+    /// Input values are read with the rules of constant main function inputs.
+    ///
+    fn input_value(
+        &self,
+        type_: &Type<'a>,
+        name: &str,
+        input_option: Option<InputValue>,
+        span: &Span,
+    ) -> Result<Value<'a, F, G>, InterpreterError> {
+        let value = ConstrainedProgram::<F, G>::new(self.asg.clone()).constant_main_function_input(
+            &mut TestConstraintSystem::<F>::new(),
+            type_,
+            name,
+            input_option,
+            span,
+        )?;
+
+        Value::from_constrained(value, span)
+    }
+
+    ///
+    /// Returns the value of the `input` keyword, a circuit with the registers, record, state and state leaf sections.
+    ///
+    pub(crate) fn input_keyword(
+        &mut self,
+        span: &Span,
+        expected_type: &'a Circuit<'a>,
+        input: &Input,
+    ) -> Result<Value<'a, F, G>, InterpreterError> {
+        let sections = vec![
+            (REGISTERS_VARIABLE_NAME, input.get_registers().values()),
+            (RECORD_VARIABLE_NAME, input.get_record().values()),
+            (STATE_VARIABLE_NAME, input.get_state().values()),
+            (STATE_LEAF_VARIABLE_NAME, input.get_state_leaf().values()),
+        ];
+
+        let mut members = Vec::with_capacity(sections.len());
+
+        for (name, values) in sections {
+            let sub_circuit = match expected_type.members.borrow().get(name) {
+                Some(CircuitMember::Variable(Type::Circuit(circuit))) => *circuit,
+                _ => panic!("illegal input type definition from asg"),
+            };
+
+            let identifier = Identifier {
+                name: name.into(),
+                span: span.clone(),
+            };
+            let value = self.input_section(&identifier, sub_circuit, values)?;
+
+            members.push((identifier, value));
+        }
+
+        Ok(Value::Circuit(expected_type, members))
+    }
+
+    fn input_section(
+        &mut self,
+        identifier: &Identifier,
+        expected_type: &'a Circuit<'a>,
+        section: IndexMap<Parameter, Option<InputValue>>,
+    ) -> Result<Value<'a, F, G>, InterpreterError> {
+        let mut members = Vec::with_capacity(section.len());
+
+        for (parameter, option) in section.into_iter() {
+            let section_members = expected_type.members.borrow();
+            let expected_type = match section_members.get(parameter.variable.name.as_ref()) {
+                Some(CircuitMember::Variable(inner)) => inner,
+                _ => continue, // present, but unused
+            };
+            let declared_type = self.asg.scope.resolve_ast_type(&parameter.type_)?;
+            if !expected_type.is_assignable_from(&declared_type) {
+                return Err(AsgConvertError::unexpected_type(
+                    &expected_type.to_string(),
+                    Some(&declared_type.to_string()),
+                    &identifier.span,
+                )
+                .into());
+            }
+
+            let value = self.input_value(&declared_type, &parameter.variable.name, option, &parameter.span)?;
+
+            members.push((parameter.variable.clone(), value));
+        }
+
+        Ok(Value::Circuit(expected_type, members))
+    }
+}
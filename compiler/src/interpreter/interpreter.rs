@@ -0,0 +1,316 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Evaluates a Leo program on concrete values without generating constraints.
+
+use crate::{
+    constraints::parse_test_input,
+    describe_call_stack,
+    errors::{CompilerError, FunctionError, InterpreterError, StatementError},
+    CompilerOptions,
+    CoreCircuitRegistry,
+    Flow,
+    GroupType,
+    Output,
+    OutputFile,
+    Value,
+    DEFAULT_INLINE_LIMIT,
+};
+use leo_asg::{Expression, Function, FunctionQualifier, Program, Span, Variable};
+use leo_ast::Input;
+use leo_package::inputs::InputPairs;
+
+use snarkvm_fields::PrimeField;
+
+use indexmap::{IndexMap, IndexSet};
+use std::path::Path;
+
+/// A function call that is currently being evaluated.
+struct CallFrame<'a, F: PrimeField, G: GroupType<F>> {
+    function: &'a Function<'a>,
+    /// Variables defined by this call.
+    defined: IndexSet<u32>,
+    /// Values that variables held before this call redefined them.
+    shadowed: IndexMap<u32, Value<'a, F, G>>,
+}
+
+///
+/// Evaluates the functions of a Leo program on concrete values.
+///
+/// Unlike the constraint generator, only the branches that are taken are evaluated,
+/// so a failing operation is reported with its span instead of an unsatisfied constraint system.
+///
+pub struct Interpreter<'a, F: PrimeField, G: GroupType<F>> {
+    pub asg: Program<'a>,
+    values: IndexMap<u32, Value<'a, F, G>>,
+    inline_limit: u32,
+    call_stack: Vec<CallFrame<'a, F, G>>,
+    pub(crate) core_circuits: CoreCircuitRegistry<F, G>,
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> Interpreter<'a, F, G> {
+    pub fn new(asg: Program<'a>) -> Self {
+        Self {
+            asg,
+            values: IndexMap::new(),
+            inline_limit: DEFAULT_INLINE_LIMIT,
+            call_stack: vec![],
+            core_circuits: CoreCircuitRegistry::default(),
+        }
+    }
+
+    /// Sets the maximum depth of nested function calls.
+    pub fn with_inline_limit(mut self, inline_limit: u32) -> Self {
+        self.inline_limit = inline_limit;
+        self
+    }
+
+    /// Sets the core circuits that calls to `core` functions are computed with.
+    pub fn with_core_circuits(mut self, core_circuits: CoreCircuitRegistry<F, G>) -> Self {
+        self.core_circuits = core_circuits;
+        self
+    }
+
+    pub(crate) fn store(&mut self, id: u32, value: Value<'a, F, G>) {
+        let previous = self.values.insert(id, value);
+
+        // A recursive call redefines the variables of the calls below it, remember their values.
+        if let Some(frame) = self.call_stack.last_mut() {
+            if frame.defined.insert(id) {
+                if let Some(previous) = previous {
+                    frame.shadowed.insert(id, previous);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn get(&self, variable: &Variable<'a>, span: &Span) -> Result<&Value<'a, F, G>, InterpreterError> {
+        let variable = variable.borrow();
+        self.values
+            .get(&variable.id)
+            .ok_or_else(|| InterpreterError::undefined_variable(variable.name.name.to_string(), span))
+    }
+
+    pub(crate) fn get_mut(
+        &mut self,
+        variable: &Variable<'a>,
+        span: &Span,
+    ) -> Result<&mut Value<'a, F, G>, InterpreterError> {
+        let variable = variable.borrow();
+        self.values
+            .get_mut(&variable.id)
+            .ok_or_else(|| InterpreterError::undefined_variable(variable.name.name.to_string(), span))
+    }
+
+    /// Evaluates the definitions of the global constants of the program.
+    pub fn evaluate_global_consts(&mut self) -> Result<(), InterpreterError> {
+        for (_, global_const) in self.asg.global_consts.clone().iter() {
+            self.evaluate_definition_statement(global_const)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Calls `function` with the given `self` target and arguments.
+    ///
+    /// A `mut self` target is written back to the expression it was read from when the call returns.
+    ///
+    pub(crate) fn call_function(
+        &mut self,
+        function: &'a Function<'a>,
+        target: Option<(&'a Expression<'a>, Value<'a, F, G>)>,
+        arguments: Vec<Value<'a, F, G>>,
+        span: &Span,
+    ) -> Result<Value<'a, F, G>, InterpreterError> {
+        if function.arguments.len() != arguments.len() {
+            return Err(FunctionError::input_not_found(
+                "arguments length invalid".to_string(),
+                &function.span.clone().unwrap_or_default(),
+            )
+            .into());
+        }
+
+        // `main` is at depth 0
+        if self.call_stack.len() > self.inline_limit as usize {
+            let call_stack = describe_call_stack(
+                self.call_stack
+                    .iter()
+                    .map(|frame| frame.function)
+                    .chain(std::iter::once(function)),
+            );
+            return Err(FunctionError::inline_limit_exceeded(self.inline_limit, call_stack, span).into());
+        }
+
+        self.call_stack.push(CallFrame {
+            function,
+            defined: IndexSet::new(),
+            shadowed: IndexMap::new(),
+        });
+
+        let self_variable = match &target {
+            Some((_, target_value)) => {
+                let self_variable = function
+                    .scope
+                    .resolve_variable("self")
+                    .expect("attempted to call static function from non-static context");
+                self.store(self_variable.borrow().id, target_value.clone());
+                Some(self_variable)
+            }
+            None => None,
+        };
+
+        for ((_, variable), argument) in function.arguments.iter().zip(arguments) {
+            self.store(variable.get().borrow().id, argument);
+        }
+
+        let result = self.evaluate_statement(function.body.get().expect("attempted to call function header"));
+
+        let new_self = match (function.qualifier == FunctionQualifier::MutSelfRef, self_variable) {
+            (true, Some(self_variable)) => Some(self.get(self_variable, span)?.clone()),
+            _ => None,
+        };
+
+        // The caller's variables must be visible again before `self` is written back
+        if let Some(frame) = self.call_stack.pop() {
+            for (id, value) in frame.shadowed {
+                self.values.insert(id, value);
+            }
+        }
+
+        let result = result?;
+
+        if let (Some(new_self), Some((target, _))) = (new_self, target) {
+            self.write_back(target, new_self)?;
+        }
+
+        match result {
+            _ if function.output.is_unit() => Ok(Value::Tuple(vec![])),
+            Flow::Return(value) => Ok(value),
+            _ => Err(StatementError::no_returns(&function.output, &function.span.clone().unwrap_or_default()).into()),
+        }
+    }
+
+    ///
+    /// Evaluates `main` or a test function with the given program input.
+    ///
+    pub fn evaluate_main_function(
+        &mut self,
+        function: &'a Function<'a>,
+        input: &Input,
+    ) -> Result<Output, InterpreterError> {
+        let registers = input.get_registers();
+
+        if let Some(asg_input) = function.scope.resolve_input() {
+            let value = self.input_keyword(&function.name.borrow().span, asg_input.container_circuit, input)?;
+
+            self.store(asg_input.container.borrow().id, value);
+        }
+
+        let mut arguments = Vec::with_capacity(function.arguments.len());
+        for (_, input_variable) in function.arguments.iter() {
+            arguments.push(self.main_function_input(&input_variable.get().borrow(), input)?);
+        }
+
+        let span = function.span.clone().unwrap_or_default();
+        let result = self.call_function(function, None, arguments, &span)?;
+        let output = Output::new(&self.asg, registers, result.to_constrained(&span)?, &span)?;
+
+        Ok(output)
+    }
+}
+
+///
+/// Evaluates the `main` function of the program on the given input.
+///
+pub fn interpret_program<'a, F: PrimeField, G: GroupType<F>>(
+    program: &Program<'a>,
+    input: &Input,
+    options: &CompilerOptions,
+    core_circuits: &CoreCircuitRegistry<F, G>,
+) -> Result<Output, CompilerError> {
+    let mut interpreter = Interpreter::<F, G>::new(program.clone())
+        .with_inline_limit(options.inline_limit)
+        .with_core_circuits(core_circuits.clone());
+
+    interpreter.evaluate_global_consts()?;
+
+    match program.functions.get("main").cloned() {
+        Some(function) => Ok(interpreter.evaluate_main_function(function, input)?),
+        None => Err(CompilerError::NoMainFunction),
+    }
+}
+
+///
+/// Evaluates the test functions of the program, writing the output of every passing test.
+///
+/// Returns the number of passed and failed tests.
+///
+pub fn interpret_tests<'a, F: PrimeField, G: GroupType<F>>(
+    program: &Program<'a>,
+    input: InputPairs,
+    output_directory: &Path,
+    options: &CompilerOptions,
+    core_circuits: &CoreCircuitRegistry<F, G>,
+) -> Result<(u32, u32), CompilerError> {
+    let program_name = program.name.clone();
+
+    let tests = program
+        .functions
+        .iter()
+        .filter(|(_name, func)| func.is_test())
+        .collect::<Vec<_>>();
+    tracing::info!("Running {} tests", tests.len());
+
+    // Count passed and failed tests
+    let mut passed = 0;
+    let mut failed = 0;
+
+    for (test_name, function) in tests.into_iter() {
+        let full_test_name = format!("{}::{}", program_name, test_name);
+        let (output_file_name, test_input) = parse_test_input(&program_name, function, &input)?;
+
+        // every test starts from a fresh interpreter
+        let mut interpreter = Interpreter::<F, G>::new(program.clone())
+            .with_inline_limit(options.inline_limit)
+            .with_core_circuits(core_circuits.clone());
+        let result = interpreter
+            .evaluate_global_consts()
+            .and_then(|_| interpreter.evaluate_main_function(function, &test_input));
+
+        match result {
+            Ok(output) => {
+                tracing::info!("{} ... ok\n", full_test_name);
+
+                // write result to file
+                let output_file = OutputFile::new(&output_file_name);
+
+                output_file
+                    .write(output_directory, output.to_string().as_bytes())
+                    .unwrap();
+
+                passed += 1;
+            }
+            Err(error) => {
+                tracing::error!("{} failed due to error\n\n{}\n", full_test_name, error);
+
+                failed += 1;
+            }
+        }
+    }
+
+    Ok((passed, failed))
+}
@@ -0,0 +1,402 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Evaluates a statement in an interpreted Leo program.
+
+use crate::{
+    errors::{ConsoleError, InterpreterError, StatementError},
+    evaluate_binary,
+    ConstrainedProgram,
+    GroupType,
+    Interpreter,
+    Value,
+};
+use leo_asg::{
+    ArrayAccessExpression,
+    ArrayRangeAccessExpression,
+    AssignAccess,
+    AssignOperation,
+    BinaryOperation,
+    CircuitAccessExpression,
+    ConsoleFunction,
+    ConsoleStatement,
+    ConstInt,
+    DefinitionStatement,
+    Expression,
+    FormatString,
+    MatchPattern,
+    Node,
+    Span,
+    Statement,
+    TupleAccessExpression,
+    Variable,
+};
+use leo_ast::FormatStringPart;
+
+use snarkvm_fields::PrimeField;
+
+/// A resolved access into the value of a variable that is assigned to.
+enum Access {
+    ArrayIndex(usize),
+    ArrayRange(Option<usize>, Option<usize>),
+    Tuple(usize),
+    Member(String),
+}
+
+/// Where evaluation continues after a statement.
+pub(crate) enum Flow<'a, F: PrimeField, G: GroupType<F>> {
+    /// The next statement.
+    Next,
+    /// After the enclosing loop.
+    Break,
+    /// The next iteration of the enclosing loop.
+    Continue,
+    /// The caller of the enclosing function, with the returned value.
+    Return(Value<'a, F, G>),
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> Interpreter<'a, F, G> {
+    ///
+    /// Evaluates a statement.
+    ///
+    /// Returns where evaluation continues, with the value of the `return` statement that was executed, if any.
+    ///
+    pub(crate) fn evaluate_statement(
+        &mut self,
+        statement: &'a Statement<'a>,
+    ) -> Result<Flow<'a, F, G>, InterpreterError> {
+        let span = statement.span().cloned().unwrap_or_default();
+
+        match statement {
+            Statement::Return(statement) => {
+                return Ok(Flow::Return(self.evaluate_expression(statement.expression.get())?));
+            }
+            Statement::Definition(statement) => self.evaluate_definition_statement(statement)?,
+            Statement::Assign(statement) => {
+                let value = self.evaluate_expression(statement.value.get())?;
+                let accesses = statement
+                    .target_accesses
+                    .iter()
+                    .map(|access| self.resolve_access(access, &span))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                self.assign(
+                    statement.target_variable.get(),
+                    &accesses,
+                    &statement.operation,
+                    value,
+                    &span,
+                )?;
+            }
+            Statement::Conditional(statement) => {
+                let next = match self.evaluate_expression(statement.condition.get())? {
+                    Value::Boolean(true) => Some(statement.result.get()),
+                    Value::Boolean(false) => statement.next.get(),
+                    value => return Err(StatementError::conditional_boolean(value.to_string(), &span).into()),
+                };
+                if let Some(next) = next {
+                    return self.evaluate_statement(next);
+                }
+            }
+            Statement::Iteration(statement) => {
+                let from = self.evaluate_index(statement.start.get(), &span)?;
+                let to = self.evaluate_index(statement.stop.get(), &span)?;
+
+                for i in from..to {
+                    self.store(statement.variable.borrow().id, Value::Integer(ConstInt::U32(i as u32)));
+
+                    match self.evaluate_statement(statement.body.get())? {
+                        Flow::Next | Flow::Continue => (),
+                        Flow::Break => break,
+                        flow @ Flow::Return(_) => return Ok(flow),
+                    }
+                }
+            }
+            Statement::Break(_) => return Ok(Flow::Break),
+            Statement::Continue(_) => return Ok(Flow::Continue),
+            Statement::Match(statement) => {
+                let (variant, values) = match self.evaluate_expression(statement.value.get())? {
+                    Value::Enum(_, variant, values) => (variant, values),
+                    value => return Err(StatementError::match_enum(value.to_string(), &span).into()),
+                };
+                // the match is exhaustive, so exactly one arm covers the variant
+                if let Some(arm) = statement.arms.iter().find(|arm| arm.variants.contains(&variant)) {
+                    if let MatchPattern::Variant { bindings, .. } = &arm.pattern {
+                        for (binding, value) in bindings.iter().zip(values) {
+                            if let Some(variable) = binding {
+                                self.store(variable.borrow().id, value);
+                            }
+                        }
+                    }
+                    return self.evaluate_statement(arm.body.get());
+                }
+            }
+            Statement::Console(statement) => self.evaluate_console_statement(statement)?,
+            Statement::Expression(statement) => {
+                // only calls without a return value may be used as statements
+                match self.evaluate_expression(statement.expression.get())? {
+                    Value::Tuple(_) => (),
+                    _ => return Err(StatementError::unassigned(&span).into()),
+                }
+            }
+            Statement::Block(statement) => {
+                for statement in statement.statements.iter() {
+                    match self.evaluate_statement(statement.get())? {
+                        Flow::Next => (),
+                        flow => return Ok(flow),
+                    }
+                }
+            }
+            Statement::Empty(_) => (),
+        }
+
+        Ok(Flow::Next)
+    }
+
+    pub(crate) fn evaluate_definition_statement(
+        &mut self,
+        statement: &'a DefinitionStatement<'a>,
+    ) -> Result<(), InterpreterError> {
+        let value = self.evaluate_expression(statement.value.get())?;
+        let span = statement.span.clone().unwrap_or_default();
+
+        if statement.variables.len() == 1 {
+            self.store(statement.variables[0].borrow().id, value);
+            return Ok(());
+        }
+
+        // Define multiple variables for an expression that returns multiple results
+        let values = match value {
+            Value::Tuple(values) => values,
+            value => return Err(StatementError::multiple_definition(value.to_string(), &span).into()),
+        };
+        if values.len() != statement.variables.len() {
+            return Err(
+                StatementError::invalid_number_of_definitions(values.len(), statement.variables.len(), &span).into(),
+            );
+        }
+
+        for (variable, value) in statement.variables.iter().zip(values) {
+            self.store(variable.borrow().id, value);
+        }
+
+        Ok(())
+    }
+
+    fn evaluate_console_statement(&mut self, statement: &'a ConsoleStatement<'a>) -> Result<(), InterpreterError> {
+        let span = statement.span.clone().unwrap_or_default();
+
+        match &statement.function {
+            ConsoleFunction::Assert(expression) => match self.evaluate_expression(expression.get())? {
+                Value::Boolean(true) => (),
+                Value::Boolean(false) => return Err(ConsoleError::assertion_failed(&span).into()),
+                _ => return Err(ConsoleError::assertion_must_be_boolean(&span).into()),
+            },
+            ConsoleFunction::Debug(string) => tracing::debug!("{}", self.format(string)?),
+            ConsoleFunction::Error(string) => tracing::error!("{}", self.format(string)?),
+            ConsoleFunction::Log(string) => tracing::info!("{}", self.format(string)?),
+        }
+
+        Ok(())
+    }
+
+    fn format(&mut self, formatted: &'a FormatString<'a>) -> Result<String, InterpreterError> {
+        // Check that containers and parameters match
+        let container_count = formatted
+            .parts
+            .iter()
+            .filter(|x| matches!(x, FormatStringPart::Container))
+            .count();
+        if container_count != formatted.parameters.len() {
+            return Err(ConsoleError::length(container_count, formatted.parameters.len(), &formatted.span).into());
+        }
+
+        let mut parameters = Vec::with_capacity(formatted.parameters.len());
+        for parameter in formatted.parameters.iter() {
+            let value = self.evaluate_expression(parameter.get())?;
+            parameters.push(value.to_constrained(&formatted.span)?.to_string());
+        }
+
+        let mut parameters = parameters.into_iter();
+        let mut out = String::new();
+        for part in formatted.parts.iter() {
+            match part {
+                FormatStringPart::Const(c) => out.push_str(c),
+                FormatStringPart::Container => out.push_str(&parameters.next().unwrap()),
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Evaluates the indices of an access, before the assigned variable is read.
+    fn resolve_access(&mut self, access: &'a AssignAccess<'a>, span: &Span) -> Result<Access, InterpreterError> {
+        Ok(match access {
+            AssignAccess::ArrayIndex(index) => Access::ArrayIndex(self.evaluate_index(index.get(), span)?),
+            AssignAccess::ArrayRange(start, stop) => {
+                let start = start.get().map(|start| self.evaluate_index(start, span)).transpose()?;
+                let stop = stop.get().map(|stop| self.evaluate_index(stop, span)).transpose()?;
+                Access::ArrayRange(start, stop)
+            }
+            AssignAccess::Tuple(index) => Access::Tuple(*index),
+            AssignAccess::Member(member) => Access::Member(member.name.to_string()),
+        })
+    }
+
+    fn assign(
+        &mut self,
+        variable: &'a Variable<'a>,
+        accesses: &[Access],
+        operation: &AssignOperation,
+        value: Value<'a, F, G>,
+        span: &Span,
+    ) -> Result<(), InterpreterError> {
+        let target = self.get_mut(variable, span)?;
+
+        assign_access(target, accesses, operation, value, span)
+    }
+
+    ///
+    /// Writes the new value of a `mut self` target back to the variable it was read from.
+    ///
+    /// Targets that are not a variable or an access into one are temporary copies and are left alone.
+    ///
+    pub(crate) fn write_back(
+        &mut self,
+        target: &'a Expression<'a>,
+        value: Value<'a, F, G>,
+    ) -> Result<(), InterpreterError> {
+        let span = target.span().cloned().unwrap_or_default();
+
+        let mut accesses = vec![];
+        let mut expression = target;
+        let variable = loop {
+            match expression {
+                Expression::ArrayRangeAccess(ArrayRangeAccessExpression { array, left, right, .. }) => {
+                    let left = left.get().map(|left| self.evaluate_index(left, &span)).transpose()?;
+                    let right = right.get().map(|right| self.evaluate_index(right, &span)).transpose()?;
+                    accesses.push(Access::ArrayRange(left, right));
+                    expression = array.get();
+                }
+                Expression::ArrayAccess(ArrayAccessExpression { array, index, .. }) => {
+                    accesses.push(Access::ArrayIndex(self.evaluate_index(index.get(), &span)?));
+                    expression = array.get();
+                }
+                Expression::TupleAccess(TupleAccessExpression { tuple_ref, index, .. }) => {
+                    accesses.push(Access::Tuple(*index));
+                    expression = tuple_ref.get();
+                }
+                Expression::CircuitAccess(CircuitAccessExpression { target, member, .. }) => match target.get() {
+                    Some(target) => {
+                        accesses.push(Access::Member(member.name.to_string()));
+                        expression = target;
+                    }
+                    None => return Ok(()),
+                },
+                Expression::VariableRef(variable_ref) => break variable_ref.variable,
+                _ => return Ok(()),
+            }
+        };
+        accesses.reverse();
+
+        self.assign(variable, &accesses, &AssignOperation::Assign, value, &span)
+    }
+}
+
+fn assign_access<'a, F: PrimeField, G: GroupType<F>>(
+    target: &mut Value<'a, F, G>,
+    accesses: &[Access],
+    operation: &AssignOperation,
+    value: Value<'a, F, G>,
+    span: &Span,
+) -> Result<(), InterpreterError> {
+    let (access, remaining) = match accesses.split_first() {
+        Some(split) => split,
+        None => {
+            *target = match assign_operation(operation) {
+                Some(operation) => evaluate_binary(&operation, target.clone(), value, span)?,
+                None => value,
+            };
+            return Ok(());
+        }
+    };
+
+    match (access, target) {
+        (Access::ArrayIndex(index), Value::Array(items)) => {
+            let length = items.len();
+            let item = items
+                .get_mut(*index)
+                .ok_or_else(|| StatementError::array_assign_index_bounds(*index, length, span))?;
+
+            assign_access(item, remaining, operation, value, span)
+        }
+        (Access::ArrayRange(start, stop), Value::Array(items)) => {
+            let start = start.unwrap_or(0);
+            let stop = stop.unwrap_or_else(|| items.len());
+            ConstrainedProgram::<F, G>::check_range_index(start, stop, items.len(), span)?;
+
+            let mut slice = Value::Array(items[start..stop].to_vec());
+            assign_access(&mut slice, remaining, operation, value, span)?;
+            match slice {
+                Value::Array(slice) if slice.len() == stop - start => {
+                    items.splice(start..stop, slice);
+                    Ok(())
+                }
+                _ => Err(StatementError::array_assign_range(span).into()),
+            }
+        }
+        (Access::ArrayIndex(_), _) | (Access::ArrayRange(_, _), _) => {
+            Err(StatementError::array_assign_index(span).into())
+        }
+        (Access::Tuple(index), Value::Tuple(items)) => {
+            let length = items.len();
+            let item = items
+                .get_mut(*index)
+                .ok_or_else(|| StatementError::tuple_assign_index_bounds(*index, length, span))?;
+
+            assign_access(item, remaining, operation, value, span)
+        }
+        (Access::Tuple(_), _) => Err(StatementError::tuple_assign_index(span).into()),
+        (Access::Member(name), Value::Circuit(_, members)) => {
+            match members.iter_mut().find(|(member, _)| member.name.as_ref() == name) {
+                Some((_, member)) => assign_access(member, remaining, operation, value, span),
+                None => Err(StatementError::undefined_circuit_variable(name.clone(), span).into()),
+            }
+        }
+        (Access::Member(_), value) => Err(StatementError::undefined_circuit(value.to_string(), span).into()),
+    }
+}
+
+/// Returns the binary operation applied by a compound assignment.
+fn assign_operation(operation: &AssignOperation) -> Option<BinaryOperation> {
+    Some(match operation {
+        AssignOperation::Assign => return None,
+        AssignOperation::Add => BinaryOperation::Add,
+        AssignOperation::Sub => BinaryOperation::Sub,
+        AssignOperation::Mul => BinaryOperation::Mul,
+        AssignOperation::Div => BinaryOperation::Div,
+        AssignOperation::Pow => BinaryOperation::Pow,
+        AssignOperation::Or => BinaryOperation::Or,
+        AssignOperation::And => BinaryOperation::And,
+        AssignOperation::BitOr => BinaryOperation::BitOr,
+        AssignOperation::BitAnd => BinaryOperation::BitAnd,
+        AssignOperation::BitXor => BinaryOperation::BitXor,
+        AssignOperation::Shr => BinaryOperation::Shr,
+        AssignOperation::ShrSigned => BinaryOperation::ShrSigned,
+        AssignOperation::Shl => BinaryOperation::Shl,
+        AssignOperation::Mod => BinaryOperation::Mod,
+    })
+}
@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The concrete value of an expression in an interpreted Leo program.
+
+use crate::{
+    errors::{ExpressionError, InterpreterError},
+    Address,
+    Char,
+    CharType,
+    ConstrainedCircuitMember,
+    ConstrainedValue,
+    FieldType,
+    GroupType,
+    Integer,
+};
+use leo_asg::{CharValue, Circuit, ConstInt, Enum, Identifier, Span};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::boolean::Boolean;
+use std::fmt;
+
+#[derive(Clone)]
+pub enum Value<'a, F: PrimeField, G: GroupType<F>> {
+    // Data types
+    Address(String),
+    Boolean(bool),
+    Char(CharValue),
+    Field(F),
+    Group(G),
+    Integer(ConstInt),
+
+    // Arrays
+    Array(Vec<Value<'a, F, G>>),
+
+    // Tuples
+    Tuple(Vec<Value<'a, F, G>>),
+
+    // Circuits
+    Circuit(&'a Circuit<'a>, Vec<(Identifier, Value<'a, F, G>)>),
+
+    // Enums
+    Enum(&'a Enum<'a>, usize, Vec<Value<'a, F, G>>),
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> Value<'a, F, G> {
+    ///
+    /// Returns the constant circuit value of `self`.
+    ///
+    /// Output registers and formatted strings are built from the constant value,
+    /// so they match the output of the compiled program exactly.
+    ///
+    pub fn to_constrained(&self, span: &Span) -> Result<ConstrainedValue<'a, F, G>, InterpreterError> {
+        Ok(match self {
+            Value::Address(address) => {
+                ConstrainedValue::Address(Address::constant(address.clone(), span).map_err(ExpressionError::from)?)
+            }
+            Value::Boolean(boolean) => ConstrainedValue::Boolean(Boolean::constant(*boolean)),
+            Value::Char(character) => ConstrainedValue::Char(Char {
+                character: match character {
+                    CharValue::Scalar(scalar) => CharType::Scalar(*scalar),
+                    CharValue::NonScalar(non_scalar) => CharType::NonScalar(*non_scalar),
+                },
+                field: FieldType::from_value(F::from(character.code() as u128)),
+            }),
+            Value::Field(field) => ConstrainedValue::Field(FieldType::from_value(*field)),
+            Value::Group(group) => ConstrainedValue::Group(group.clone()),
+            Value::Integer(integer) => ConstrainedValue::Integer(Integer::new(integer)),
+            Value::Array(values) => ConstrainedValue::Array(
+                values
+                    .iter()
+                    .map(|value| value.to_constrained(span))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            Value::Tuple(values) => ConstrainedValue::Tuple(
+                values
+                    .iter()
+                    .map(|value| value.to_constrained(span))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            Value::Circuit(circuit, members) => ConstrainedValue::CircuitExpression(
+                circuit,
+                members
+                    .iter()
+                    .map(|(name, value)| Ok(ConstrainedCircuitMember(name.clone(), value.to_constrained(span)?)))
+                    .collect::<Result<Vec<_>, InterpreterError>>()?,
+            ),
+            Value::Enum(enum_, variant, values) => {
+                let mut payloads = vec![None; enum_.variants.borrow().len()];
+                payloads[*variant] = Some(
+                    values
+                        .iter()
+                        .map(|value| value.to_constrained(span))
+                        .collect::<Result<Vec<_>, _>>()?,
+                );
+                ConstrainedValue::Enum(enum_, Integer::new(&ConstInt::U8(*variant as u8)), payloads)
+            }
+        })
+    }
+
+    ///
+    /// Returns the concrete value of a circuit value.
+    ///
+    /// Used for the results of gadgets applied to constant values, every part must have a known value.
+    ///
+    pub fn from_constrained(value: ConstrainedValue<'a, F, G>, span: &Span) -> Result<Self, InterpreterError> {
+        let unknown = |value: &dyn fmt::Display| InterpreterError::unknown_value(value.to_string(), span);
+
+        Ok(match value {
+            ConstrainedValue::Address(address) => {
+                Value::Address(address.address.as_ref().ok_or_else(|| unknown(&"address"))?.to_string())
+            }
+            ConstrainedValue::Boolean(boolean) => Value::Boolean(boolean.get_value().ok_or_else(|| unknown(&"bool"))?),
+            ConstrainedValue::Char(character) => Value::Char(match character.character {
+                CharType::Scalar(scalar) => CharValue::Scalar(scalar),
+                CharType::NonScalar(non_scalar) => CharValue::NonScalar(non_scalar),
+            }),
+            ConstrainedValue::Field(field) => Value::Field(field.get_value().ok_or_else(|| unknown(&field))?),
+            ConstrainedValue::Group(group) => Value::Group(group),
+            ConstrainedValue::Integer(integer) => {
+                let value = integer.get_value().ok_or_else(|| unknown(&integer))?;
+                Value::Integer(ConstInt::parse(&integer.get_type(), &value, span)?)
+            }
+            ConstrainedValue::Array(values) => Value::Array(
+                values
+                    .into_iter()
+                    .map(|value| Self::from_constrained(value, span))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            ConstrainedValue::Tuple(values) => Value::Tuple(
+                values
+                    .into_iter()
+                    .map(|value| Self::from_constrained(value, span))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            ConstrainedValue::CircuitExpression(circuit, members) => Value::Circuit(
+                circuit,
+                members
+                    .into_iter()
+                    .map(|member| Ok((member.0, Self::from_constrained(member.1, span)?)))
+                    .collect::<Result<Vec<_>, InterpreterError>>()?,
+            ),
+            ConstrainedValue::Enum(enum_, tag, mut payloads) => {
+                let variant = tag
+                    .get_value()
+                    .and_then(|tag| tag.parse::<usize>().ok())
+                    .ok_or_else(|| unknown(&tag))?;
+                let values = payloads
+                    .get_mut(variant)
+                    .and_then(Option::take)
+                    .ok_or_else(|| unknown(&tag))?;
+                Value::Enum(
+                    enum_,
+                    variant,
+                    values
+                        .into_iter()
+                        .map(|value| Self::from_constrained(value, span))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )
+            }
+        })
+    }
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> fmt::Display for Value<'a, F, G> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.to_constrained(&Span::default()) {
+            Ok(value) => write!(f, "{}", value),
+            Err(_) => write!(f, "[invalid]"),
+        }
+    }
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> fmt::Debug for Value<'a, F, G> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
@@ -42,6 +42,9 @@ pub use expression::*;
 pub mod function;
 pub use function::*;
 
+pub mod interpreter;
+pub use interpreter::*;
+
 pub mod output;
 pub use output::*;
 
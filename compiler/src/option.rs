@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+/// The default maximum depth of the function inlining stack, `main` is at depth 0.
+pub const DEFAULT_INLINE_LIMIT: u32 = 32;
+
 ///
 /// Toggles compiler optimizations on the program.
 ///
@@ -22,6 +25,8 @@ pub struct CompilerOptions {
     pub canonicalization_enabled: bool,
     pub constant_folding_enabled: bool,
     pub dead_code_elimination_enabled: bool,
+    /// The maximum depth of the function inlining stack, bounds recursive calls.
+    pub inline_limit: u32,
 }
 
 impl Default for CompilerOptions {
@@ -33,6 +38,7 @@ impl Default for CompilerOptions {
             canonicalization_enabled: true,
             constant_folding_enabled: true,
             dead_code_elimination_enabled: true,
+            inline_limit: DEFAULT_INLINE_LIMIT,
         }
     }
 }
@@ -22,6 +22,9 @@ pub use self::output_file::*;
 pub mod output_bytes;
 pub use self::output_bytes::*;
 
+pub mod public_inputs_file;
+pub use self::public_inputs_file::*;
+
 use crate::{errors::OutputBytesError, Char, CharType, ConstrainedValue, GroupType, REGISTERS_VARIABLE_NAME};
 use leo_asg::Program;
 use leo_ast::{Parameter, Registers, Span};
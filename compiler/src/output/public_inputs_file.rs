@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The `program.public` file.
+
+use crate::{errors::OutputFileError, OUTPUTS_DIRECTORY_NAME};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use std::{
+    borrow::Cow,
+    fs::{
+        File,
+        {self},
+    },
+    io::Write,
+    path::Path,
+};
+
+pub static PUBLIC_INPUTS_FILE_EXTENSION: &str = ".public";
+
+/// Stores the public inputs of a program execution, one hex-encoded field element per line.
+pub struct PublicInputsFile {
+    pub package_name: String,
+}
+
+impl PublicInputsFile {
+    pub fn new(package_name: &str) -> Self {
+        Self {
+            package_name: package_name.to_string(),
+        }
+    }
+
+    pub fn exists_at(&self, path: &Path) -> bool {
+        let path = self.setup_file_path(path);
+        path.exists()
+    }
+
+    /// Reads the public inputs from the given file path if it exists.
+    pub fn read_from(&self, path: &Path) -> Result<String, OutputFileError> {
+        let path = self.setup_file_path(path);
+
+        fs::read_to_string(&path).map_err(|_| OutputFileError::FileReadError(path.into_owned()))
+    }
+
+    /// Reads the public input values from the given file path, in the order they were written.
+    pub fn read_values<F: FromBytes>(&self, path: &Path) -> Result<Vec<F>, OutputFileError> {
+        self.read_from(path)?
+            .lines()
+            .map(|line| {
+                let bytes = hex::decode(line.trim()).map_err(|_| OutputFileError::InvalidValue(line.to_string()))?;
+                F::read(&bytes[..]).map_err(|_| OutputFileError::InvalidValue(line.to_string()))
+            })
+            .collect()
+    }
+
+    /// Writes the given public input values to a file, as the hex encoding of their canonical bytes.
+    pub fn write<F: ToBytes>(&self, path: &Path, values: &[F]) -> Result<(), OutputFileError> {
+        let path = self.setup_file_path(path);
+        let mut file = File::create(&path)?;
+
+        for value in values {
+            let mut bytes = vec![];
+            value.write(&mut bytes)?;
+            writeln!(file, "{}", hex::encode(bytes))?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes the public inputs file at the given path if it exists. Returns `true` on success,
+    /// `false` if the file doesn't exist, and `Error` if the file system fails during operation.
+    pub fn remove(&self, path: &Path) -> Result<bool, OutputFileError> {
+        let path = self.setup_file_path(path);
+        if !path.exists() {
+            return Ok(false);
+        }
+
+        fs::remove_file(&path).map_err(|_| OutputFileError::FileRemovalError(path.into_owned()))?;
+        Ok(true)
+    }
+
+    fn setup_file_path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        let mut path = Cow::from(path);
+        if path.is_dir() {
+            if !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+                path.to_mut().push(OUTPUTS_DIRECTORY_NAME);
+            }
+            path.to_mut()
+                .push(format!("{}{}", self.package_name, PUBLIC_INPUTS_FILE_EXTENSION));
+        }
+        path
+    }
+}
+
+#[cfg(test)]
+mod test_public_inputs_file {
+    use crate::{PublicInputsFile, OUTPUTS_DIRECTORY_NAME};
+    use snarkvm_curves::bls12_377::Fr;
+    use std::{error::Error, fs, str::FromStr};
+
+    #[test]
+    fn test_all() -> Result<(), Box<dyn Error>> {
+        let dir = tempfile::tempdir()?;
+        let file = PublicInputsFile::new("test");
+        let path = dir.path();
+
+        assert!(file.write(path, &[1u8, 2u8]).is_err());
+        assert!(!file.exists_at(path));
+        assert!(!(file.remove(path)?));
+
+        fs::create_dir(dir.path().join(OUTPUTS_DIRECTORY_NAME))?;
+
+        assert!(file.write(path, &[1u8, 2u8]).is_ok());
+        assert_eq!(file.read_from(path)?, "01\n02\n");
+        assert!(file.remove(path)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_multi_limb_values() -> Result<(), Box<dyn Error>> {
+        let dir = tempfile::tempdir()?;
+        let file = PublicInputsFile::new("test");
+        let path = dir.path();
+        fs::create_dir(dir.path().join(OUTPUTS_DIRECTORY_NAME))?;
+
+        // Values spanning more than one 64-bit limb must read back as the same field elements.
+        let values = vec![
+            Fr::from_str("1").unwrap(),
+            Fr::from_str("18446744073709551616").unwrap(),
+            Fr::from_str("8444461749428370424248824938781546531375899335154063827935233455917409239040").unwrap(),
+        ];
+        file.write(path, &values)?;
+        assert_eq!(file.read_values::<Fr>(path)?, values);
+
+        fs::write(path.join(OUTPUTS_DIRECTORY_NAME).join("test.public"), "zz\n")?;
+        assert!(file.read_values::<Fr>(path).is_err());
+
+        Ok(())
+    }
+}
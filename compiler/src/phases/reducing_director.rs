@@ -79,6 +79,7 @@ use leo_ast::{
     ConsoleFunction as AstConsoleFunction,
     ConsoleStatement as AstConsoleStatement,
     DefinitionStatement as AstDefinitionStatement,
+    Dimension,
     Expression as AstExpression,
     ExpressionStatement as AstExpressionStatement,
     FormatString,
@@ -124,9 +125,9 @@ impl<R: ReconstructingReducer, O: CombinerOptions> CombineAstAsgDirector<R, O> {
                 if self.options.type_inference_enabled() {
                     AstType::Array(
                         Box::new(self.reduce_type(ast_type, asg_type, span)?),
-                        ArrayDimensions(vec![PositiveNumber {
+                        ArrayDimensions(vec![Dimension::Number(PositiveNumber {
                             value: StrTendril::from(format!("{}", asg_dimensions)),
-                        }]),
+                        })]),
                     )
                 } else {
                     AstType::Array(
@@ -729,12 +730,18 @@ impl<R: ReconstructingReducer, O: CombinerOptions> CombineAstAsgDirector<R, O> {
             ast.expected_input.clone(),
             ast.imports.clone(),
             circuits,
+            ast.enums.clone(),
             functions,
             global_consts,
         )
     }
 
     pub fn reduce_function(&mut self, ast: &AstFunction, asg: &AsgFunction) -> Result<AstFunction, ReducerError> {
+        // Generic functions are only type checked per instance, so there is nothing to reduce against.
+        if asg.is_generic() {
+            return Ok(ast.clone());
+        }
+
         let output = ast
             .output
             .as_ref()
@@ -785,6 +792,10 @@ impl<R: ReconstructingReducer, O: CombinerOptions> CombineAstAsgDirector<R, O> {
     }
 
     pub fn reduce_circuit(&mut self, ast: &AstCircuit, asg: &AsgCircuit) -> Result<AstCircuit, ReducerError> {
+        if asg.is_generic() {
+            return Ok(ast.clone());
+        }
+
         let mut members = vec![];
         for (ast_member, asg_member) in ast.members.iter().zip(asg.members.borrow().iter()) {
             members.push(self.reduce_circuit_member(ast_member, asg_member.1)?);
@@ -15,8 +15,8 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::CoreCircuit;
-use crate::{errors::ExpressionError, ConstrainedValue, GroupType, Integer};
-use leo_asg::{Function, Span};
+use crate::{errors::ExpressionError, ConstrainedValue, ErasedConstraintSystem, GroupType, Integer};
+use leo_asg::{CoreCircuitDeclaration, Function, Span};
 
 use snarkvm_fields::PrimeField;
 use snarkvm_gadgets::{
@@ -46,10 +46,14 @@ fn unwrap_argument<F: PrimeField, G: GroupType<F>>(arg: ConstrainedValue<F, G>)
     }
 }
 
-impl<'a, F: PrimeField, G: GroupType<F>> CoreCircuit<'a, F, G> for Blake2s {
-    fn call_function<CS: ConstraintSystem<F>>(
+impl<F: PrimeField, G: GroupType<F>> CoreCircuit<F, G> for Blake2s {
+    fn declaration(&self) -> CoreCircuitDeclaration {
+        CoreCircuitDeclaration::blake2s()
+    }
+
+    fn call_function<'a>(
         &self,
-        cs: &mut CS,
+        cs: &mut ErasedConstraintSystem<F>,
         function: &'a Function<'a>,
         span: &Span,
         target: Option<ConstrainedValue<'a, F, G>>,
@@ -17,15 +17,34 @@
 pub mod blake2s;
 pub use blake2s::*;
 
-use crate::{errors::ExpressionError, ConstrainedValue, GroupType};
-use leo_asg::{Function, Span};
+pub mod pedersen;
+pub use pedersen::*;
+
+pub mod poseidon;
+pub use poseidon::*;
+
+pub mod sha256;
+pub use sha256::*;
+
+use crate::{errors::ExpressionError, ConstrainedValue, ErasedConstraintSystem, GroupType};
+use leo_asg::{CoreCircuitDeclaration, Function, Span};
 use snarkvm_fields::PrimeField;
-use snarkvm_r1cs::ConstraintSystem;
 
-pub trait CoreCircuit<'a, F: PrimeField, G: GroupType<F>>: Send + Sync {
-    fn call_function<CS: ConstraintSystem<F>>(
+use indexmap::IndexMap;
+use std::sync::Arc;
+
+///
+/// A circuit of the `core` library, implemented by gadgets.
+///
+/// The ASG type checks calls against the declaration, so the arguments always match its signatures.
+///
+pub trait CoreCircuit<F: PrimeField, G: GroupType<F>>: Send + Sync {
+    /// Returns the Leo signatures of the functions of the circuit.
+    fn declaration(&self) -> CoreCircuitDeclaration;
+
+    fn call_function<'a>(
         &self,
-        cs: &mut CS,
+        cs: &mut ErasedConstraintSystem<F>,
         function: &'a Function<'a>,
         span: &Span,
         target: Option<ConstrainedValue<'a, F, G>>,
@@ -33,9 +52,41 @@ pub trait CoreCircuit<'a, F: PrimeField, G: GroupType<F>>: Send + Sync {
     ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError>;
 }
 
-pub fn resolve_core_circuit<'a, F: PrimeField, G: GroupType<F>>(name: &str) -> impl CoreCircuit<'a, F, G> {
-    match name {
-        "blake2s" => Blake2s,
-        _ => unimplemented!("invalid core circuit: {}", name),
+///
+/// The core circuits a program can call, by the mapping of their declaration.
+///
+/// The default registry holds the circuits of the `core` library.
+///
+#[derive(Clone)]
+pub struct CoreCircuitRegistry<F: PrimeField, G: GroupType<F>> {
+    circuits: IndexMap<String, Arc<dyn CoreCircuit<F, G>>>,
+}
+
+impl<F: PrimeField, G: GroupType<F>> Default for CoreCircuitRegistry<F, G> {
+    fn default() -> Self {
+        let mut registry = Self {
+            circuits: IndexMap::new(),
+        };
+        registry.register(Blake2s);
+        registry.register(Pedersen);
+        registry.register(Poseidon::default());
+        registry.register(Sha256);
+        registry
+    }
+}
+
+impl<F: PrimeField, G: GroupType<F>> CoreCircuitRegistry<F, G> {
+    /// Adds a circuit, replacing any circuit previously registered with the same mapping.
+    pub fn register<C: CoreCircuit<F, G> + 'static>(&mut self, circuit: C) {
+        self.circuits.insert(circuit.declaration().mapping, Arc::new(circuit));
+    }
+
+    pub fn get(&self, mapping: &str) -> Option<Arc<dyn CoreCircuit<F, G>>> {
+        self.circuits.get(mapping).cloned()
+    }
+
+    /// Returns the declarations of the registered circuits.
+    pub fn declarations(&self) -> impl Iterator<Item = CoreCircuitDeclaration> + '_ {
+        self.circuits.values().map(|circuit| circuit.declaration())
     }
 }
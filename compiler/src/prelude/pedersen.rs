@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::CoreCircuit;
+use crate::{errors::ExpressionError, ConstrainedValue, ErasedConstraintSystem, GroupType};
+use leo_asg::{CoreCircuitDeclaration, Function, GroupCoordinate, GroupValue, Span};
+
+use lazy_static::lazy_static;
+use sha2::Digest;
+use snarkvm_curves::{
+    edwards_bls12::{EdwardsAffine, Fq},
+    AffineCurve,
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_gadgets::boolean::Boolean;
+use snarkvm_r1cs::ConstraintSystem;
+use snarkvm_utilities::biginteger::BigInteger;
+
+pub struct Pedersen;
+
+/// The number of bits in a message or in the commitment randomness.
+const WINDOW: usize = 256;
+
+lazy_static! {
+    /// The generators for the message bits, followed by the generators for the randomness bits.
+    static ref GENERATORS: Vec<EdwardsAffine> = (0..2 * WINDOW).map(generator).collect();
+}
+
+impl Pedersen {
+    /// Returns the generators for the message bits, followed by the generators for the randomness bits.
+    pub fn generators() -> &'static [EdwardsAffine] {
+        &GENERATORS
+    }
+}
+
+///
+/// Returns the generator at the given index, hashing `"Leo Pedersen generator" || index || counter`
+/// to an x-coordinate for increasing counters until it lands on the curve.
+///
+/// The point is multiplied by the cofactor to move it into the prime order subgroup, so that no
+/// generator has a known discrete logarithm relative to another.
+///
+fn generator(index: usize) -> EdwardsAffine {
+    for counter in 0u32.. {
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(b"Leo Pedersen generator");
+        hasher.update((index as u32).to_le_bytes());
+        hasher.update(counter.to_le_bytes());
+        let x = hasher
+            .finalize()
+            .iter()
+            .rev()
+            .fold(Fq::zero(), |acc, byte| acc * Fq::from(256u64) + Fq::from(*byte));
+
+        if let Some(point) = EdwardsAffine::from_x_coordinate(x, true) {
+            let point = point.mul_by_cofactor();
+            if !point.is_zero() {
+                return point;
+            }
+        }
+    }
+    unreachable!("ran out of counters for pedersen generator {}", index)
+}
+
+/// Returns the decimal digits of a base field element, as expected by group coordinates.
+fn to_decimal(value: &Fq) -> String {
+    let mut digits = vec![0u8];
+    for bit in value.into_repr().to_bits_be() {
+        let mut carry = bit as u8;
+        for digit in digits.iter_mut() {
+            let doubled = *digit * 2 + carry;
+            *digit = doubled % 10;
+            carry = doubled / 10;
+        }
+        if carry > 0 {
+            digits.push(carry);
+        }
+    }
+
+    digits.iter().rev().map(|digit| char::from(b'0' + digit)).collect()
+}
+
+fn unwrap_argument<F: PrimeField, G: GroupType<F>>(arg: ConstrainedValue<F, G>) -> Vec<Boolean> {
+    if let ConstrainedValue::Array(args) = arg {
+        assert_eq!(args.len(), WINDOW); // asg enforced
+        args.into_iter()
+            .map(|item| {
+                if let ConstrainedValue::Boolean(item) = item {
+                    item
+                } else {
+                    panic!("illegal non-bool type in pedersen call");
+                }
+            })
+            .collect()
+    } else {
+        panic!("illegal non-array type in pedersen call");
+    }
+}
+
+///
+/// Returns the sum of the generators starting at `offset` whose bits are set.
+///
+fn pedersen_sum<F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    bits: &[Boolean],
+    offset: usize,
+    span: &Span,
+) -> Result<G, ExpressionError> {
+    let zero = G::constant(&GroupValue::Single("0".into()), span)?;
+    let mut sum = zero.clone();
+    for (i, bit) in bits.iter().enumerate() {
+        let point = GENERATORS[offset + i];
+        let generator = G::constant(
+            &GroupValue::Tuple(
+                GroupCoordinate::Number(to_decimal(&point.x).into()),
+                GroupCoordinate::Number(to_decimal(&point.y).into()),
+            ),
+            span,
+        )?;
+        let selected = G::conditionally_select(cs.ns(|| format!("select generator {}", i)), bit, &generator, &zero)
+            .map_err(|e| ExpressionError::cannot_enforce("pedersen generator selection".to_owned(), e, span))?;
+        sum = sum.add(cs.ns(|| format!("add generator {}", i)), &selected, span)?;
+    }
+
+    Ok(sum)
+}
+
+impl<F: PrimeField, G: GroupType<F>> CoreCircuit<F, G> for Pedersen {
+    fn declaration(&self) -> CoreCircuitDeclaration {
+        CoreCircuitDeclaration::pedersen()
+    }
+
+    fn call_function<'a>(
+        &self,
+        cs: &mut ErasedConstraintSystem<F>,
+        function: &'a Function<'a>,
+        span: &Span,
+        target: Option<ConstrainedValue<'a, F, G>>,
+        mut arguments: Vec<ConstrainedValue<'a, F, G>>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        assert!(target.is_none()); // asg enforced
+        let message = unwrap_argument(arguments.remove(0));
+        let hash = pedersen_sum::<F, G, _>(cs.ns(|| "pedersen message"), &message, 0, span)?;
+
+        match function.name.borrow().name.as_ref() {
+            "hash" => {
+                assert!(arguments.is_empty()); // asg enforced
+                Ok(ConstrainedValue::Group(hash))
+            }
+            "commit" => {
+                assert_eq!(arguments.len(), 1); // asg enforced
+                let randomness = unwrap_argument(arguments.remove(0));
+                let blinding = pedersen_sum::<F, G, _>(cs.ns(|| "pedersen randomness"), &randomness, WINDOW, span)?;
+                let commitment = hash.add(cs.ns(|| "pedersen commitment"), &blinding, span)?;
+
+                Ok(ConstrainedValue::Group(commitment))
+            }
+            name => unimplemented!("invalid pedersen function: {}", name),
+        }
+    }
+}
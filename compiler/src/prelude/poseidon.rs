@@ -0,0 +1,274 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::CoreCircuit;
+use crate::{errors::ExpressionError, ConstrainedValue, ErasedConstraintSystem, FieldType, GroupType};
+use leo_asg::{CoreCircuitDeclaration, Function, Span};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::ConstraintSystem;
+use snarkvm_utilities::biginteger::BigInteger;
+
+/// The Poseidon core circuit, with the parameters generated once for its field.
+pub struct Poseidon<F: PrimeField> {
+    parameters: PoseidonParameters<F>,
+}
+
+impl<F: PrimeField> Default for Poseidon<F> {
+    fn default() -> Self {
+        Self {
+            parameters: PoseidonParameters::new(),
+        }
+    }
+}
+
+/// The width of the permutation, one capacity element and two rate elements.
+const WIDTH: usize = 3;
+
+/// The exponent of the S-box, coprime to `p - 1` on the BLS12-377 scalar field so that the S-box is a permutation.
+const ALPHA: u64 = 17;
+
+const FULL_ROUNDS: usize = 8;
+
+const PARTIAL_ROUNDS: usize = 31;
+
+///
+/// The Grain LFSR of the Poseidon paper, seeded with the description of the instance.
+///
+/// Generates the round constants and MDS matrix as the reference `generate_parameters_grain.sage` does.
+/// Like snarkVM and arkworks, the first sampled Cauchy matrix is used without the further checks of the script.
+///
+struct GrainLfsr {
+    state: [bool; 80],
+    head: usize,
+}
+
+impl GrainLfsr {
+    /// Seeds the register for a prime field of `field_bits` bits and an `x^alpha` S-box, then discards 160 bits.
+    fn new(field_bits: usize, width: usize, full_rounds: usize, partial_rounds: usize) -> Self {
+        let mut state = [false; 80];
+        let mut seed = |start: usize, len: usize, value: usize| {
+            for i in 0..len {
+                state[start + i] = value >> (len - 1 - i) & 1 == 1;
+            }
+        };
+        seed(0, 2, 1);
+        seed(2, 4, 0);
+        seed(6, 12, field_bits);
+        seed(18, 12, width);
+        seed(30, 10, full_rounds);
+        seed(40, 10, partial_rounds);
+        state[50..].iter_mut().for_each(|bit| *bit = true);
+
+        let mut lfsr = Self { state, head: 0 };
+        for _ in 0..160 {
+            lfsr.update();
+        }
+        lfsr
+    }
+
+    fn update(&mut self) -> bool {
+        let bit = [62, 51, 38, 23, 13, 0]
+            .iter()
+            .fold(false, |acc, tap| acc ^ self.state[(self.head + tap) % 80]);
+        self.state[self.head] = bit;
+        self.head = (self.head + 1) % 80;
+        bit
+    }
+
+    /// Returns the next bits, most significant first, keeping each second bit whose first bit is set.
+    fn next_bits(&mut self, count: usize) -> Vec<bool> {
+        (0..count)
+            .map(|_| {
+                while !self.update() {
+                    self.update();
+                }
+                self.update()
+            })
+            .collect()
+    }
+
+    /// Returns the next field element, sampling again while the bits exceed the modulus.
+    fn next_element<F: PrimeField>(&mut self) -> F {
+        loop {
+            let bits = self.next_bits(F::size_in_bits());
+            if let Some(element) = F::from_repr(F::BigInteger::from_bits_be(bits)) {
+                return element;
+            }
+        }
+    }
+
+    /// Returns the next field element, reducing the bits modulo the modulus.
+    fn next_element_mod_p<F: PrimeField>(&mut self) -> F {
+        self.next_bits(F::size_in_bits())
+            .into_iter()
+            .fold(F::zero(), |acc, bit| acc.double() + F::from(bit as u64))
+    }
+}
+
+struct PoseidonParameters<F: PrimeField> {
+    /// The round constants, `WIDTH` per round.
+    round_constants: Vec<Vec<F>>,
+    /// The Cauchy MDS matrix `1 / (x_i + y_j)`.
+    mds: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> PoseidonParameters<F> {
+    fn new() -> Self {
+        let mut lfsr = GrainLfsr::new(F::size_in_bits(), WIDTH, FULL_ROUNDS, PARTIAL_ROUNDS);
+        let round_constants = (0..FULL_ROUNDS + PARTIAL_ROUNDS)
+            .map(|_| (0..WIDTH).map(|_| lfsr.next_element()).collect())
+            .collect();
+
+        let xs = (0..WIDTH).map(|_| lfsr.next_element_mod_p()).collect::<Vec<F>>();
+        let ys = (0..WIDTH).map(|_| lfsr.next_element_mod_p()).collect::<Vec<F>>();
+        let mds = xs
+            .iter()
+            .map(|x| {
+                ys.iter()
+                    .map(|y| (*x + y).inverse().expect("mds entries are non-zero"))
+                    .collect()
+            })
+            .collect();
+
+        Self { round_constants, mds }
+    }
+}
+
+fn unwrap_argument<F: PrimeField, G: GroupType<F>>(arg: ConstrainedValue<F, G>) -> Vec<FieldType<F>> {
+    if let ConstrainedValue::Array(args) = arg {
+        assert_eq!(args.len(), WIDTH - 1); // asg enforced
+        args.into_iter()
+            .map(|item| {
+                if let ConstrainedValue::Field(item) = item {
+                    item
+                } else {
+                    panic!("illegal non-field type in poseidon call");
+                }
+            })
+            .collect()
+    } else {
+        panic!("illegal non-array type in poseidon call");
+    }
+}
+
+impl<F: PrimeField> Poseidon<F> {
+    ///
+    /// Computes the Poseidon hash of two field elements.
+    ///
+    /// As in the sponges of snarkVM and arkworks, the elements are absorbed into the rate of a zeroed
+    /// state, and the first rate element is squeezed after one permutation.
+    ///
+    pub fn hash_gadget<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        message: &[FieldType<F>],
+        span: &Span,
+    ) -> Result<FieldType<F>, ExpressionError> {
+        let parameters = &self.parameters;
+        let mut state = vec![FieldType::from_value(F::zero())];
+        state.extend(message.iter().cloned());
+
+        for round in 0..FULL_ROUNDS + PARTIAL_ROUNDS {
+            let mut cs = cs.ns(|| format!("round {}", round));
+            let is_full = !(FULL_ROUNDS / 2..FULL_ROUNDS / 2 + PARTIAL_ROUNDS).contains(&round);
+
+            for (i, element) in state.iter_mut().enumerate() {
+                let constant = FieldType::from_value(parameters.round_constants[round][i]);
+                *element = element.add(cs.ns(|| format!("add round constant {}", i)), &constant, span)?;
+
+                if is_full || i == 0 {
+                    *element = sbox(cs.ns(|| format!("sbox {}", i)), element, span)?;
+                }
+            }
+
+            let mut mixed = vec![];
+            for row in 0..WIDTH {
+                let mut sum = FieldType::from_value(F::zero());
+                for (column, element) in state.iter().enumerate() {
+                    let entry = FieldType::from_value(parameters.mds[row][column]);
+                    let product = element.mul(cs.ns(|| format!("mds product {} {}", row, column)), &entry, span)?;
+                    sum = sum.add(cs.ns(|| format!("mds sum {} {}", row, column)), &product, span)?;
+                }
+                mixed.push(sum);
+            }
+            state = mixed;
+        }
+
+        Ok(state.remove(1))
+    }
+}
+
+/// Raises an element to the power `ALPHA` by square and multiply.
+fn sbox<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    element: &FieldType<F>,
+    span: &Span,
+) -> Result<FieldType<F>, ExpressionError> {
+    let mut result = element.clone();
+    for bit in (0..63 - ALPHA.leading_zeros()).rev() {
+        result = result.mul(cs.ns(|| format!("square {}", bit)), &result, span)?;
+        if ALPHA >> bit & 1 == 1 {
+            result = result.mul(cs.ns(|| format!("multiply {}", bit)), element, span)?;
+        }
+    }
+
+    Ok(result)
+}
+
+impl<F: PrimeField, G: GroupType<F>> CoreCircuit<F, G> for Poseidon<F> {
+    fn declaration(&self) -> CoreCircuitDeclaration {
+        CoreCircuitDeclaration::poseidon()
+    }
+
+    fn call_function<'a>(
+        &self,
+        cs: &mut ErasedConstraintSystem<F>,
+        function: &'a Function<'a>,
+        span: &Span,
+        target: Option<ConstrainedValue<'a, F, G>>,
+        mut arguments: Vec<ConstrainedValue<'a, F, G>>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        assert_eq!(arguments.len(), 1); // asg enforced
+        assert!(function.name.borrow().name.as_ref() == "hash"); // asg enforced
+        assert!(target.is_none()); // asg enforced
+        let message = unwrap_argument(arguments.remove(0));
+
+        let digest = self.hash_gadget(cs.ns(|| "poseidon hash"), &message, span)?;
+
+        Ok(ConstrainedValue::Field(digest))
+    }
+}
+
+#[cfg(test)]
+mod test_poseidon {
+    use super::*;
+
+    #[test]
+    fn test_grain_lfsr_reference_constant() {
+        // The first round constant of the reference x^5 instance on the BN254 scalar field, with width 3.
+        let expected = "0ee9a592ba9a9518d05986d656f40c2114c4993c11bb29938d21d47304cd8e6e";
+        let expected_bits = hex::decode(expected)
+            .unwrap()
+            .iter()
+            .flat_map(|byte| (0..8).rev().map(move |i| byte >> i & 1 == 1))
+            .skip(2)
+            .collect::<Vec<_>>();
+
+        let mut lfsr = GrainLfsr::new(254, 3, 8, 57);
+        assert_eq!(lfsr.next_bits(254), expected_bits);
+    }
+}
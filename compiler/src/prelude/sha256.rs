@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::CoreCircuit;
+use crate::{errors::ExpressionError, ConstrainedValue, ErasedConstraintSystem, GroupType, Integer};
+use leo_asg::{CoreCircuitDeclaration, Function, Span};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::{
+    boolean::Boolean,
+    integers::uint::{UInt, UInt32, UInt8},
+    traits::{bits::Xor, integers::Integer as IntegerTrait},
+};
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+
+pub struct Sha256;
+
+/// The initial hash value, the first 32 bits of the fractional parts of the square roots of the first 8 primes.
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// The round constants, the first 32 bits of the fractional parts of the cube roots of the first 64 primes.
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98,
+    0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786,
+    0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8,
+    0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13,
+    0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819,
+    0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a,
+    0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+    0xc67178f2,
+];
+
+fn unwrap_argument<F: PrimeField, G: GroupType<F>>(arg: ConstrainedValue<F, G>) -> Vec<UInt8> {
+    if let ConstrainedValue::Array(args) = arg {
+        assert_eq!(args.len(), 32); // asg enforced
+        args.into_iter()
+            .map(|item| {
+                if let ConstrainedValue::Integer(Integer::U8(item)) = item {
+                    item
+                } else {
+                    panic!("illegal non-u8 type in sha256 call");
+                }
+            })
+            .collect()
+    } else {
+        panic!("illegal non-array type in sha256 call");
+    }
+}
+
+/// Shifts the bits of a word right, filling in zeros.
+fn shr(word: &UInt32, by: usize) -> UInt32 {
+    let mut shifted = word.to_bits_le()[by..].to_vec();
+    shifted.resize(32, Boolean::constant(false));
+
+    UInt32::from_bits_le(&shifted)
+}
+
+/// XORs three words.
+fn xor3<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    a: &UInt32,
+    b: &UInt32,
+    c: &UInt32,
+) -> Result<UInt32, SynthesisError> {
+    let ab = a.xor(cs.ns(|| "a xor b"), b)?;
+    ab.xor(cs.ns(|| "ab xor c"), c)
+}
+
+/// Combines the bits of three words with a function of the bits at the same position.
+fn bitwise<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    a: &UInt32,
+    b: &UInt32,
+    c: &UInt32,
+    f: impl Fn(&mut CS, usize, &Boolean, &Boolean, &Boolean) -> Result<Boolean, SynthesisError>,
+) -> Result<UInt32, SynthesisError> {
+    let bits = a
+        .to_bits_le()
+        .iter()
+        .zip(b.to_bits_le().iter())
+        .zip(c.to_bits_le().iter())
+        .enumerate()
+        .map(|(i, ((a, b), c))| f(&mut cs, i, a, b, c))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(UInt32::from_bits_le(&bits))
+}
+
+/// Returns the bits of `b` where `a` is set and the bits of `c` elsewhere, as `c ^ (a & (b ^ c))`.
+fn choose<F: PrimeField, CS: ConstraintSystem<F>>(
+    cs: CS,
+    a: &UInt32,
+    b: &UInt32,
+    c: &UInt32,
+) -> Result<UInt32, SynthesisError> {
+    bitwise(cs, a, b, c, |cs, i, a, b, c| {
+        let b_xor_c = b.xor(cs.ns(|| format!("b xor c {}", i)), c)?;
+        let masked = Boolean::and(cs.ns(|| format!("a and (b xor c) {}", i)), a, &b_xor_c)?;
+        c.xor(cs.ns(|| format!("c xor masked {}", i)), &masked)
+    })
+}
+
+/// Returns the majority of the bits of three words, as `(a & b) ^ (c & (a ^ b))`.
+fn majority<F: PrimeField, CS: ConstraintSystem<F>>(
+    cs: CS,
+    a: &UInt32,
+    b: &UInt32,
+    c: &UInt32,
+) -> Result<UInt32, SynthesisError> {
+    bitwise(cs, a, b, c, |cs, i, a, b, c| {
+        let a_and_b = Boolean::and(cs.ns(|| format!("a and b {}", i)), a, b)?;
+        let a_xor_b = a.xor(cs.ns(|| format!("a xor b {}", i)), b)?;
+        let c_and_a_xor_b = Boolean::and(cs.ns(|| format!("c and (a xor b) {}", i)), c, &a_xor_b)?;
+        a_and_b.xor(cs.ns(|| format!("majority {}", i)), &c_and_a_xor_b)
+    })
+}
+
+///
+/// Computes the SHA-256 digest of a 32 byte message, which fits in a single padded block.
+///
+pub fn sha256_gadget<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    message: &[UInt8],
+) -> Result<Vec<UInt8>, SynthesisError> {
+    assert_eq!(message.len(), 32);
+
+    // Pad the message with a one bit, zeros and the message length in bits, as a big endian u64.
+    let mut block = message.to_vec();
+    block.push(UInt8::constant(0x80));
+    block.extend(UInt8::constant_vec(&[0; 23]));
+    block.extend(UInt8::constant_vec(&(256u64).to_be_bytes()));
+
+    // The message schedule, with words read big endian.
+    let mut w = block
+        .chunks(4)
+        .map(|bytes| {
+            let bits = bytes
+                .iter()
+                .rev()
+                .flat_map(|byte| byte.to_bits_le())
+                .collect::<Vec<_>>();
+            UInt32::from_bits_le(&bits)
+        })
+        .collect::<Vec<_>>();
+    for t in 16..64 {
+        let mut cs = cs.ns(|| format!("schedule {}", t));
+        let s0 = xor3(
+            cs.ns(|| "s0"),
+            &w[t - 15].rotr(7),
+            &w[t - 15].rotr(18),
+            &shr(&w[t - 15], 3),
+        )?;
+        let s1 = xor3(
+            cs.ns(|| "s1"),
+            &w[t - 2].rotr(17),
+            &w[t - 2].rotr(19),
+            &shr(&w[t - 2], 10),
+        )?;
+        let word = UInt32::addmany(cs.ns(|| "word"), &[w[t - 16].clone(), s0, w[t - 7].clone(), s1])?;
+        w.push(word);
+    }
+
+    let iv = IV.iter().map(|word| UInt32::constant(*word)).collect::<Vec<_>>();
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = [
+        iv[0].clone(),
+        iv[1].clone(),
+        iv[2].clone(),
+        iv[3].clone(),
+        iv[4].clone(),
+        iv[5].clone(),
+        iv[6].clone(),
+        iv[7].clone(),
+    ];
+
+    for t in 0..64 {
+        let mut cs = cs.ns(|| format!("round {}", t));
+        let s1 = xor3(cs.ns(|| "s1"), &e.rotr(6), &e.rotr(11), &e.rotr(25))?;
+        let ch = choose(cs.ns(|| "ch"), &e, &f, &g)?;
+        let temp1 = UInt32::addmany(cs.ns(|| "temp1"), &[h, s1, ch, UInt32::constant(K[t]), w[t].clone()])?;
+        let s0 = xor3(cs.ns(|| "s0"), &a.rotr(2), &a.rotr(13), &a.rotr(22))?;
+        let maj = majority(cs.ns(|| "maj"), &a, &b, &c)?;
+
+        h = g;
+        g = f;
+        f = e;
+        e = UInt32::addmany(cs.ns(|| "e"), &[d, temp1.clone()])?;
+        d = c;
+        c = b;
+        b = a;
+        a = UInt32::addmany(cs.ns(|| "a"), &[temp1, s0, maj])?;
+    }
+
+    // Add the compressed chunk to the initial hash value and write the digest big endian.
+    let mut digest = vec![];
+    for (i, (word, initial)) in [a, b, c, d, e, f, g, h].iter().zip(iv.iter()).enumerate() {
+        let word = UInt32::addmany(cs.ns(|| format!("digest {}", i)), &[initial.clone(), word.clone()])?;
+        let bits = word.to_bits_le();
+        digest.extend(bits.chunks(8).rev().map(UInt8::from_bits_le));
+    }
+
+    Ok(digest)
+}
+
+impl<F: PrimeField, G: GroupType<F>> CoreCircuit<F, G> for Sha256 {
+    fn declaration(&self) -> CoreCircuitDeclaration {
+        CoreCircuitDeclaration::sha256()
+    }
+
+    fn call_function<'a>(
+        &self,
+        cs: &mut ErasedConstraintSystem<F>,
+        function: &'a Function<'a>,
+        span: &Span,
+        target: Option<ConstrainedValue<'a, F, G>>,
+        mut arguments: Vec<ConstrainedValue<'a, F, G>>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        assert_eq!(arguments.len(), 1); // asg enforced
+        assert!(function.name.borrow().name.as_ref() == "hash"); // asg enforced
+        assert!(target.is_none()); // asg enforced
+        let message = unwrap_argument(arguments.remove(0));
+
+        let digest = sha256_gadget(cs.ns(|| "sha256 hash"), &message)
+            .map_err(|e| ExpressionError::cannot_enforce("SHA-256 gadget".to_owned(), e, span))?;
+
+        Ok(ConstrainedValue::Array(
+            digest
+                .into_iter()
+                .map(Integer::U8)
+                .map(ConstrainedValue::Integer)
+                .collect(),
+        ))
+    }
+}
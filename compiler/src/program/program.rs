@@ -16,16 +16,69 @@
 
 //! An in memory store to keep track of defined names when constraining a Leo program.
 
-use crate::{value::ConstrainedValue, GroupType};
+use crate::{
+    errors::StatementError,
+    expression::IndexSelectorKey,
+    value::ConstrainedValue,
+    CoreCircuitRegistry,
+    GroupType,
+    Profile,
+    ProfileFrame,
+    Profiler,
+    SpanTracer,
+    DEFAULT_INLINE_LIMIT,
+};
 
-use leo_asg::Program;
+use leo_asg::{Function, Program, Span};
 use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::boolean::Boolean;
+use snarkvm_r1cs::ConstraintSystem;
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
+
+/// A statement that leaves the rest of its loop iteration, loop, or function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Exit {
+    Return,
+    Break,
+    Continue,
+}
+
+/// A function call that is currently being inlined.
+pub(crate) struct CallFrame<'a, F: PrimeField, G: GroupType<F>> {
+    pub(crate) function: &'a Function<'a>,
+    /// The values of the constant arguments of the call, `None` for allocated arguments.
+    pub(crate) constant_arguments: Vec<Option<String>>,
+    /// Variables defined by this call.
+    defined: IndexSet<u32>,
+    /// Values that variables held before this call redefined them.
+    shadowed: IndexMap<u32, ConstrainedValue<'a, F, G>>,
+    /// The exits enforced so far that still deactivate the statements after them, with their indicators.
+    exits: Vec<(Exit, Boolean)>,
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> CallFrame<'a, F, G> {
+    pub(crate) fn new(function: &'a Function<'a>, constant_arguments: Vec<Option<String>>) -> Self {
+        Self {
+            function,
+            constant_arguments,
+            defined: IndexSet::new(),
+            shadowed: IndexMap::new(),
+            exits: vec![],
+        }
+    }
+}
 
 pub struct ConstrainedProgram<'a, F: PrimeField, G: GroupType<F>> {
     pub asg: Program<'a>,
     identifiers: IndexMap<u32, ConstrainedValue<'a, F, G>>,
+    pub(crate) inline_limit: u32,
+    pub(crate) call_stack: Vec<CallFrame<'a, F, G>>,
+    pub(crate) core_circuits: CoreCircuitRegistry<F, G>,
+    /// The one-hot selectors of the variable indices enforced in the current constraint system.
+    pub(crate) index_selectors: IndexMap<IndexSelectorKey, Vec<Boolean>>,
+    profiler: Option<Profiler>,
+    span_tracer: Option<SpanTracer>,
 }
 
 impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
@@ -33,11 +86,96 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         Self {
             asg,
             identifiers: IndexMap::new(),
+            inline_limit: DEFAULT_INLINE_LIMIT,
+            call_stack: vec![],
+            core_circuits: CoreCircuitRegistry::default(),
+            index_selectors: IndexMap::new(),
+            profiler: None,
+            span_tracer: None,
+        }
+    }
+
+    /// Sets the maximum depth of the function inlining stack.
+    pub fn with_inline_limit(mut self, inline_limit: u32) -> Self {
+        self.inline_limit = inline_limit;
+        self
+    }
+
+    /// Sets the core circuits that calls to `core` functions are enforced with.
+    pub fn with_core_circuits(mut self, core_circuits: CoreCircuitRegistry<F, G>) -> Self {
+        self.core_circuits = core_circuits;
+        self
+    }
+
+    /// Records which frames of the program produce the constraints and variables of the circuit.
+    pub fn with_profiler(mut self) -> Self {
+        self.profiler = Some(Profiler::default());
+        self
+    }
+
+    /// Returns the recorded profile, or `None` if the program was not profiled.
+    pub fn into_profile(self) -> Option<Profile> {
+        self.profiler.map(Profiler::into_profile)
+    }
+
+    ///
+    /// Attributes what `cs` produces until the matching [`Self::exit_profile_frame`] to a new frame.
+    ///
+    /// The frame is only built if the program is profiled.
+    ///
+    pub(crate) fn enter_profile_frame<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &CS,
+        frame: impl FnOnce() -> ProfileFrame,
+    ) {
+        if let Some(profiler) = &mut self.profiler {
+            profiler.enter(cs, frame());
+        }
+    }
+
+    pub(crate) fn exit_profile_frame<CS: ConstraintSystem<F>>(&mut self, cs: &CS) {
+        if let Some(profiler) = &mut self.profiler {
+            profiler.exit(cs);
+        }
+    }
+
+    ///
+    /// Records which statements and expressions enforce the constraints synthesized from now on.
+    ///
+    /// Any previous trace is discarded, so a new trace starts with a new constraint system.
+    ///
+    pub(crate) fn trace_spans(&mut self) {
+        self.span_tracer = Some(SpanTracer::default());
+    }
+
+    /// Returns the span of the innermost statement or expression that enforced the constraint at `index`.
+    pub(crate) fn traced_span(&self, index: usize) -> Option<&Span> {
+        self.span_tracer.as_ref()?.span_of(index)
+    }
+
+    pub(crate) fn enter_traced_span<CS: ConstraintSystem<F>>(&mut self, cs: &CS, span: Option<&Span>) {
+        if let Some(span_tracer) = &mut self.span_tracer {
+            span_tracer.enter(span, cs.num_constraints());
+        }
+    }
+
+    pub(crate) fn exit_traced_span<CS: ConstraintSystem<F>>(&mut self, cs: &CS) {
+        if let Some(span_tracer) = &mut self.span_tracer {
+            span_tracer.exit(cs.num_constraints());
         }
     }
 
     pub(crate) fn store(&mut self, id: u32, value: ConstrainedValue<'a, F, G>) {
-        self.identifiers.insert(id, value);
+        let previous = self.identifiers.insert(id, value);
+
+        // A recursive call redefines the variables of the calls below it, remember their values.
+        if let Some(frame) = self.call_stack.last_mut() {
+            if frame.defined.insert(id) {
+                if let Some(previous) = previous {
+                    frame.shadowed.insert(id, previous);
+                }
+            }
+        }
     }
 
     pub(crate) fn get(&self, id: u32) -> Option<&ConstrainedValue<'a, F, G>> {
@@ -47,4 +185,64 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
     pub(crate) fn get_mut(&mut self, id: u32) -> Option<&mut ConstrainedValue<'a, F, G>> {
         self.identifiers.get_mut(&id)
     }
+
+    /// Records that the statements after an exit are inactive whenever `indicator` is true.
+    pub(crate) fn record_exit(&mut self, exit: Exit, indicator: &Boolean) {
+        if let Some(frame) = self.call_stack.last_mut() {
+            frame.exits.push((exit, *indicator));
+        }
+    }
+
+    /// Returns the number of exits recorded in the innermost function call.
+    pub(crate) fn exit_count(&self) -> usize {
+        self.call_stack.last().map(|frame| frame.exits.len()).unwrap_or(0)
+    }
+
+    /// Forgets the exits of the given kind recorded since `start`, once the statements they skip are enforced.
+    pub(crate) fn forget_exits(&mut self, start: usize, exit: Exit) {
+        if let Some(frame) = self.call_stack.last_mut() {
+            let recorded = frame.exits.split_off(start);
+            frame
+                .exits
+                .extend(recorded.into_iter().filter(|(kind, _)| *kind != exit));
+        }
+    }
+
+    ///
+    /// Returns the indicator of the statements after the exits recorded since `start`,
+    /// which are active if `indicator` is true and none of these exits were taken.
+    ///
+    pub(crate) fn deactivate_exits<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        indicator: &Boolean,
+        start: usize,
+        span: &Span,
+    ) -> Result<Boolean, StatementError> {
+        let exits = match self.call_stack.last() {
+            Some(frame) => frame.exits[start..].to_vec(),
+            None => return Ok(*indicator),
+        };
+
+        let mut active = *indicator;
+        for (i, (_, exit_indicator)) in exits.iter().enumerate() {
+            active = Boolean::and(
+                &mut cs.ns(|| format!("active after exit {} {}:{}", start + i, span.line_start, span.col_start)),
+                &active,
+                &exit_indicator.not(),
+            )
+            .map_err(|_| StatementError::indicator_calculation(format!("active after exit {}", start + i), span))?;
+        }
+
+        Ok(active)
+    }
+
+    /// Leaves the innermost function call and restores the variables it redefined.
+    pub(crate) fn pop_call_frame(&mut self) {
+        if let Some(frame) = self.call_stack.pop() {
+            for (id, value) in frame.shadowed {
+                self.identifiers.insert(id, value);
+            }
+        }
+    }
 }
@@ -16,7 +16,14 @@
 
 //! Enforces an assign statement in a compiled Leo program.
 
-use crate::{arithmetic::*, errors::StatementError, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
+use crate::{
+    arithmetic::*,
+    bitwise::*,
+    errors::StatementError,
+    program::ConstrainedProgram,
+    value::ConstrainedValue,
+    GroupType,
+};
 use leo_asg::{AssignOperation, AssignStatement, Span};
 
 use snarkvm_fields::PrimeField;
@@ -55,6 +62,13 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             AssignOperation::Mul => enforce_mul(cs, target.clone(), new_value, span)?,
             AssignOperation::Div => enforce_div(cs, target.clone(), new_value, span)?,
             AssignOperation::Pow => enforce_pow(cs, target.clone(), new_value, span)?,
+            AssignOperation::Mod => enforce_rem(cs, target.clone(), new_value, span)?,
+            AssignOperation::BitOr => enforce_bit_or(cs, target.clone(), new_value, span)?,
+            AssignOperation::BitAnd => enforce_bit_and(cs, target.clone(), new_value, span)?,
+            AssignOperation::BitXor => enforce_bit_xor(cs, target.clone(), new_value, span)?,
+            AssignOperation::Shr => enforce_shr(cs, target.clone(), new_value, span)?,
+            AssignOperation::ShrSigned => enforce_shr_signed(cs, target.clone(), new_value, span)?,
+            AssignOperation::Shl => enforce_shl(cs, target.clone(), new_value, span)?,
             _ => unimplemented!("unimplemented assign operator"),
         };
         let selected_value = ConstrainedValue::conditionally_select(cs.ns(|| scope), condition, &new_value, target)
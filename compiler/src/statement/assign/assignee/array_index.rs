@@ -16,19 +16,16 @@
 
 //! Resolves assignees in a compiled Leo program.
 
-use std::convert::TryInto;
-
 use crate::{
     errors::{ExpressionError, StatementError},
     program::ConstrainedProgram,
     value::ConstrainedValue,
     GroupType,
-    Integer,
 };
-use leo_asg::{ConstInt, Expression, Node};
+use leo_asg::{Expression, Node};
 
 use snarkvm_fields::PrimeField;
-use snarkvm_gadgets::{eq::EvaluateEqGadget, select::CondSelectGadget};
+use snarkvm_gadgets::select::CondSelectGadget;
 use snarkvm_r1cs::ConstraintSystem;
 
 use super::ResolverContext;
@@ -64,29 +61,9 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                         }
                     } else {
                         let span = index.span().cloned().unwrap_or_default();
-                        {
-                            let array_len: u32 = input
-                                .len()
-                                .try_into()
-                                .map_err(|_| ExpressionError::array_length_out_of_bounds(&span))?;
-                            self.array_bounds_check(cs, &&index_resolved, array_len, &span)?;
-                        }
-
-                        for (i, item) in input.iter_mut().enumerate() {
-                            let namespace_string = format!(
-                                "evaluate dyn array assignment eq {} {}:{}",
-                                i, span.line_start, span.col_start
-                            );
-                            let eq_namespace = cs.ns(|| namespace_string);
-
-                            let index_bounded = i
-                                .try_into()
-                                .map_err(|_| ExpressionError::array_index_out_of_legal_bounds(&span))?;
-                            let const_index = ConstInt::U32(index_bounded).cast_to(&index_resolved.get_type());
-                            let index_comparison = index_resolved
-                                .evaluate_equal(eq_namespace, &Integer::new(&const_index))
-                                .map_err(|_| ExpressionError::cannot_evaluate("==".to_string(), &span))?;
+                        let selector = self.enforce_index_selector(cs, &index_resolved, input.len(), &span)?;
 
+                        for (i, (item, is_selected)) in input.iter_mut().zip(selector.iter()).enumerate() {
                             let mut unique_namespace = cs.ns(|| {
                                 format!(
                                     "select array dyn assignment {} {}:{}",
@@ -114,7 +91,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                             };
                             let value = ConstrainedValue::conditionally_select(
                                 unique_namespace,
-                                &index_comparison,
+                                is_selected,
                                 &temp_item,
                                 &item,
                             )
@@ -147,30 +124,9 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             } else {
                 // index is input variable
                 let span = index.span().cloned().unwrap_or_default();
-                {
-                    let array_len: u32 = context
-                        .input
-                        .len()
-                        .try_into()
-                        .map_err(|_| ExpressionError::array_length_out_of_bounds(&span))?;
-                    self.array_bounds_check(cs, &&index_resolved, array_len, &span)?;
-                }
-
-                for (i, item) in context.input.iter_mut().enumerate() {
-                    let namespace_string = format!(
-                        "evaluate dyn array assignment eq {} {}:{}",
-                        i, span.line_start, span.col_start
-                    );
-                    let eq_namespace = cs.ns(|| namespace_string);
-
-                    let index_bounded = i
-                        .try_into()
-                        .map_err(|_| ExpressionError::array_index_out_of_legal_bounds(&span))?;
-                    let const_index = ConstInt::U32(index_bounded).cast_to(&index_resolved.get_type());
-                    let index_comparison = index_resolved
-                        .evaluate_equal(eq_namespace, &Integer::new(&const_index))
-                        .map_err(|_| ExpressionError::cannot_evaluate("==".to_string(), &span))?;
+                let selector = self.enforce_index_selector(cs, &index_resolved, context.input.len(), &span)?;
 
+                for (i, (item, is_selected)) in context.input.iter_mut().zip(selector.iter()).enumerate() {
                     let mut unique_namespace = cs.ns(|| {
                         format!(
                             "select array dyn assignment {} {}:{}",
@@ -197,7 +153,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                         item
                     };
                     let value =
-                        ConstrainedValue::conditionally_select(unique_namespace, &index_comparison, &temp_item, &item)
+                        ConstrainedValue::conditionally_select(unique_namespace, is_selected, &temp_item, &item)
                             .map_err(|e| ExpressionError::cannot_enforce("conditional select".to_string(), e, &span))?;
                     **item = value;
                 }
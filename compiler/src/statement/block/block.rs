@@ -33,10 +33,22 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         indicator: &Boolean,
         block: &BlockStatement<'a>,
     ) -> StatementResult<Vec<IndicatorAndConstrainedValue<'a, F, G>>> {
+        let span = block.span.clone().unwrap_or_default();
         let mut results = Vec::with_capacity(block.statements.len());
+        let mut indicator = *indicator;
+        let mut exits = self.exit_count();
         // Evaluate statements. Only allow a single return argument to be returned.
         for statement in block.statements.iter() {
-            let value = self.enforce_statement(cs, indicator, statement.get())?;
+            // Statements after a `return`, `break` or `continue` only run if it was not taken.
+            if self.exit_count() > exits {
+                indicator = self.deactivate_exits(cs, &indicator, exits, &span)?;
+                exits = self.exit_count();
+            }
+            if let Boolean::Constant(false) = indicator {
+                break;
+            }
+
+            let value = self.enforce_statement(cs, &indicator, statement.get())?;
 
             results.extend(value);
         }
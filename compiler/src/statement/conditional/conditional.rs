@@ -42,6 +42,8 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
     /// Due to R1CS constraints, we must evaluate every branch to properly construct the circuit.
     /// At program execution, we will pass an `indicator` bit down to all child statements within each branch.
     /// The `indicator` bit will select that branch while keeping the constraint system satisfied.
+    /// Branches whose indicator is the constant `false` can never be taken and are skipped,
+    /// this is what lets the inlining of recursive functions on constant arguments terminate.
     #[allow(clippy::too_many_arguments)]
     pub fn enforce_conditional_statement<CS: ConstraintSystem<F>>(
         &mut self,
@@ -78,7 +80,10 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         let mut results = vec![];
 
         // Evaluate branch 1
-        let mut branch_1_result = self.enforce_statement(cs, &branch_1_indicator, statement.result.get())?;
+        let mut branch_1_result = match branch_1_indicator {
+            Boolean::Constant(false) => vec![],
+            _ => self.enforce_statement(cs, &branch_1_indicator, statement.result.get())?,
+        };
 
         results.append(&mut branch_1_result);
 
@@ -98,6 +103,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
 
         // Evaluate branch 2
         let mut branch_2_result = match statement.next.get() {
+            Some(_) if matches!(branch_2_indicator, Boolean::Constant(false)) => vec![],
             Some(next) => self.enforce_statement(cs, &branch_2_indicator, next)?,
             None => vec![],
         };
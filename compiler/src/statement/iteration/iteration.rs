@@ -18,12 +18,13 @@
 
 use crate::{
     errors::StatementError,
-    program::ConstrainedProgram,
+    program::{ConstrainedProgram, Exit},
     value::ConstrainedValue,
     GroupType,
     IndicatorAndConstrainedValue,
     Integer,
     IntegerTrait,
+    ProfileFrame,
     StatementResult,
 };
 use leo_asg::IterationStatement;
@@ -53,7 +54,19 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             .to_usize()
             .ok_or_else(|| StatementError::loop_index_const(&span))?;
 
+        let mut indicator = *indicator;
+        let exits = self.exit_count();
+        let mut iteration_exits = exits;
         for i in from..to {
+            // Later iterations only run if no `break` or `return` was taken.
+            if self.exit_count() > iteration_exits {
+                indicator = self.deactivate_exits(cs, &indicator, iteration_exits, &span)?;
+                iteration_exits = self.exit_count();
+            }
+            if let Boolean::Constant(false) = indicator {
+                break;
+            }
+
             // Store index in current function scope.
             // For loop scope is not implemented.
             let variable = statement.variable.borrow();
@@ -65,14 +78,19 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             );
 
             // Evaluate statements and possibly return early
-            let result = self.enforce_statement(
-                &mut cs.ns(|| format!("for loop iteration {} {}:{}", i, &span.line_start, &span.col_start)),
-                indicator,
-                statement.body.get(),
-            )?;
+            let cs = &mut cs.ns(|| format!("for loop iteration {} {}:{}", i, &span.line_start, &span.col_start));
+            self.enter_profile_frame(cs, || {
+                ProfileFrame::new(format!("iteration {} = {}", variable.name.name, i), &span)
+            });
+            let result = self.enforce_statement(cs, &indicator, statement.body.get());
+            self.exit_profile_frame(cs);
+
+            results.extend(result?);
 
-            results.extend(result);
+            // A `continue` only skips the rest of its own iteration.
+            self.forget_exits(iteration_exits, Exit::Continue);
         }
+        self.forget_exits(exits, Exit::Break);
 
         Ok(results)
     }
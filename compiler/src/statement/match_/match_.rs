@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces a match statement in a compiled Leo program.
+
+use crate::{
+    errors::StatementError,
+    program::ConstrainedProgram,
+    value::ConstrainedValue,
+    GroupType,
+    IndicatorAndConstrainedValue,
+    Integer,
+    StatementResult,
+};
+use leo_asg::{ConstInt, MatchPattern, MatchStatement};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::{boolean::Boolean, traits::eq::EvaluateEqGadget};
+use snarkvm_r1cs::ConstraintSystem;
+
+impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
+    /// Enforces a match statement over an enum value.
+    /// Like a conditional statement every arm is evaluated, each with an `indicator` bit that is
+    /// true when the outer indicator is true and the enum tag is one of the arm's variants.
+    /// Variants that carry no values can never be the active one and are skipped.
+    #[allow(clippy::too_many_arguments)]
+    pub fn enforce_match_statement<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        indicator: &Boolean,
+        statement: &MatchStatement<'a>,
+    ) -> StatementResult<Vec<IndicatorAndConstrainedValue<'a, F, G>>> {
+        let span = statement.span.clone().unwrap_or_default();
+
+        let (tag, payloads) = match self.enforce_expression(cs, statement.value.get())? {
+            ConstrainedValue::Enum(_, tag, payloads) => (tag, payloads),
+            value => return Err(StatementError::match_enum(value.to_string(), &span)),
+        };
+
+        let mut results = vec![];
+        for (i, arm) in statement.arms.iter().enumerate() {
+            let mut arm_indicator = Boolean::Constant(false);
+            for variant in arm.variants.iter().filter(|variant| payloads[**variant].is_some()) {
+                let is_variant = tag
+                    .evaluate_equal(
+                        cs.ns(|| format!("arm {} tag is {} {}:{}", i, variant, span.line_start, span.col_start)),
+                        &Integer::new(&ConstInt::U8(*variant as u8)),
+                    )
+                    .map_err(|_| StatementError::indicator_calculation(format!("arm {}", i), &span))?;
+                arm_indicator = Boolean::or(
+                    cs.ns(|| format!("arm {} variant {} {}:{}", i, variant, span.line_start, span.col_start)),
+                    &arm_indicator,
+                    &is_variant,
+                )
+                .map_err(|_| StatementError::indicator_calculation(format!("arm {}", i), &span))?;
+            }
+            let arm_indicator = Boolean::and(
+                cs.ns(|| format!("arm {} {}:{}", i, span.line_start, span.col_start)),
+                indicator,
+                &arm_indicator,
+            )
+            .map_err(|_| StatementError::indicator_calculation(format!("arm {}", i), &span))?;
+
+            if let Boolean::Constant(false) = arm_indicator {
+                continue;
+            }
+
+            if let MatchPattern::Variant { bindings, .. } = &arm.pattern {
+                let values = payloads[arm.variants[0]].as_ref().unwrap();
+                for (binding, value) in bindings.iter().zip(values.iter()) {
+                    if let Some(variable) = binding {
+                        self.store_definition(variable, value.clone());
+                    }
+                }
+            }
+
+            results.extend(self.enforce_statement(cs, &arm_indicator, arm.body.get())?);
+        }
+
+        Ok(results)
+    }
+}
@@ -31,6 +31,9 @@ pub use self::definition::*;
 pub mod iteration;
 pub use self::iteration::*;
 
+pub mod match_;
+pub use self::match_::*;
+
 pub mod return_;
 pub use self::return_::*;
 
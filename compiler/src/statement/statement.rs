@@ -16,7 +16,13 @@
 
 //! Enforces a statement in a compiled Leo program.
 
-use crate::{errors::StatementError, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
+use crate::{
+    errors::StatementError,
+    program::{ConstrainedProgram, Exit},
+    value::ConstrainedValue,
+    GroupType,
+    ProfileFrame,
+};
 use leo_asg::{Node, Statement};
 
 use snarkvm_fields::PrimeField;
@@ -33,6 +39,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
     /// Each evaluated statement may execute of one or more statements that may return early.
     /// To indicate which of these return values to take we conditionally select the value according
     /// to the `indicator` bit that evaluates to true.
+    /// Statements that exit early are recorded so the statements after them can be deactivated.
     ///
     #[allow(clippy::too_many_arguments)]
     pub fn enforce_statement<CS: ConstraintSystem<F>>(
@@ -41,16 +48,33 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         indicator: &Boolean,
         statement: &'a Statement<'a>,
     ) -> StatementResult<Vec<IndicatorAndConstrainedValue<'a, F, G>>> {
-        let mut results = vec![];
         let span = statement.span().cloned().unwrap_or_default();
         let mut cs = cs.ns(|| format!("statement {}:{}", span.line_start, span.col_start));
         let cs = &mut cs;
 
+        self.enter_profile_frame(cs, || ProfileFrame::new(statement_label(statement), &span));
+        self.enter_traced_span(cs, statement.span());
+        let results = self.enforce_statement_kind(cs, indicator, statement);
+        self.exit_traced_span(cs);
+        self.exit_profile_frame(cs);
+
+        results
+    }
+
+    fn enforce_statement_kind<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        indicator: &Boolean,
+        statement: &'a Statement<'a>,
+    ) -> StatementResult<Vec<IndicatorAndConstrainedValue<'a, F, G>>> {
+        let mut results = vec![];
+
         match statement {
             Statement::Return(statement) => {
                 let return_value = (*indicator, self.enforce_return_statement(cs, statement)?);
 
                 results.push(return_value);
+                self.record_exit(Exit::Return, indicator);
             }
             Statement::Definition(statement) => {
                 self.enforce_definition_statement(cs, statement)?;
@@ -68,6 +92,13 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
 
                 results.extend(result);
             }
+            Statement::Break(_) => self.record_exit(Exit::Break, indicator),
+            Statement::Continue(_) => self.record_exit(Exit::Continue, indicator),
+            Statement::Match(statement) => {
+                let result = self.enforce_match_statement(cs, indicator, statement)?;
+
+                results.extend(result);
+            }
             Statement::Console(statement) => {
                 self.evaluate_console_function_call(cs, indicator, statement)?;
             }
@@ -102,6 +133,25 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
     }
 }
 
+/// Names the kind of a statement in a constraint profile.
+fn statement_label(statement: &Statement) -> String {
+    let kind = match statement {
+        Statement::Return(_) => "return",
+        Statement::Definition(_) => "definition",
+        Statement::Assign(_) => "assignment",
+        Statement::Conditional(_) => "conditional",
+        Statement::Iteration(_) => "loop",
+        Statement::Break(_) => "break",
+        Statement::Continue(_) => "continue",
+        Statement::Match(_) => "match",
+        Statement::Console(_) => "console",
+        Statement::Expression(_) => "expression",
+        Statement::Block(_) => "block",
+        Statement::Empty(_) => "empty",
+    };
+    format!("{} statement", kind)
+}
+
 /// Unwraps the indicator boolean gadget value or `false` if `None`.
 /// This method is used by logging methods only.
 /// We can directly get the boolean value of the indicator since we are not enforcing any
@@ -31,6 +31,9 @@ use snarkvm_gadgets::{
 };
 use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
 
+/// The largest Unicode code point.
+const MAX_CHAR_CODE: u32 = 0x10FFFF;
+
 #[derive(Clone, Debug)]
 pub enum CharType {
     Scalar(char),
@@ -56,6 +59,54 @@ impl<F: PrimeField> Char<F> {
             field: FieldType::constant(cs, field, span)?,
         })
     }
+
+    ///
+    /// Returns the character with the code point given by the little-endian bits of a `u32`.
+    ///
+    /// Enforces that the code point is at most `0x10FFFF`, the largest Unicode code point.
+    ///
+    pub fn from_code_bits<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        bits: &[Boolean],
+        span: &Span,
+    ) -> Result<Self, CharError> {
+        let code = bits
+            .iter()
+            .enumerate()
+            .map(|(i, bit)| bit.get_value().map(|bit| (bit as u32) << i))
+            .sum::<Option<u32>>();
+        if let Some(code) = code {
+            if code > MAX_CHAR_CODE {
+                return Err(CharError::invalid_code(code, span));
+            }
+        }
+
+        // Above 0x10FFFF either a bit from 21 up is set, or bit 20 is set together with one of bits 16 to 19.
+        let mut high = Boolean::constant(false);
+        for (i, bit) in bits.iter().enumerate().skip(16).take(4) {
+            high = Boolean::or(cs.ns(|| format!("high bit {}", i)), &high, bit)
+                .map_err(|e| CharError::cannot_enforce(e, span))?;
+        }
+        let above = Boolean::and(cs.ns(|| "above plane 16"), &high, &bits[20])
+            .map_err(|e| CharError::cannot_enforce(e, span))?;
+        for (i, bit) in bits.iter().enumerate().skip(21).chain(std::iter::once((20, &above))) {
+            bit.enforce_equal(cs.ns(|| format!("range bit {}", i)), &Boolean::constant(false))
+                .map_err(|e| CharError::cannot_enforce(e, span))?;
+        }
+
+        let character = match code {
+            Some(code) => match std::char::from_u32(code) {
+                Some(scalar) => CharType::Scalar(scalar),
+                None => CharType::NonScalar(code),
+            },
+            None => CharType::Scalar(0 as char),
+        };
+
+        Ok(Self {
+            character,
+            field: FieldType::from_integer_bits(cs.ns(|| "code"), bits, false, span)?,
+        })
+    }
 }
 
 impl<F: PrimeField> PartialEq for Char<F> {
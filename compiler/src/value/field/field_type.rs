@@ -16,24 +16,28 @@
 
 //! A data type that represents a field value
 
-use crate::{errors::FieldError, number_string_typing};
-use leo_ast::Span;
+use crate::{errors::FieldError, number_string_typing, Integer};
+use leo_ast::{IntegerType, Span};
 
 use snarkvm_fields::PrimeField;
 use snarkvm_gadgets::{
-    bits::{ToBitsBEGadget, ToBytesGadget},
-    boolean::Boolean,
+    bits::{ToBitsBEGadget, ToBitsLEGadget, ToBytesGadget},
+    boolean::{AllocatedBit, Boolean},
     fields::FpGadget,
     integers::uint::UInt8,
     traits::{
         alloc::AllocGadget,
-        bits::comparator::{ComparatorGadget, EvaluateLtGadget},
+        bits::{
+            comparator::{ComparatorGadget, EvaluateLtGadget},
+            Xor,
+        },
         eq::{ConditionalEqGadget, EqGadget, EvaluateEqGadget, NEqGadget},
         fields::FieldGadget,
         select::CondSelectGadget,
     },
 };
 use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+use snarkvm_utilities::biginteger::BigInteger;
 use std::{borrow::Borrow, cmp::Ordering};
 
 #[derive(Clone, Debug)]
@@ -45,6 +49,16 @@ impl<F: PrimeField> FieldType<F> {
         self.0.get_value()
     }
 
+    /// Returns `true` if the field is a constant in the circuit.
+    pub fn is_constant(&self) -> bool {
+        matches!(self.0, FpGadget::Constant(_))
+    }
+
+    /// Returns a new constant `FieldType` with the given value.
+    pub fn from_value(value: F) -> Self {
+        FieldType(FpGadget::Constant(value))
+    }
+
     /// Returns a new `FieldType` from the given `String` or returns a `FieldError`.
     pub fn constant<CS: ConstraintSystem<F>>(cs: CS, string: String, span: &Span) -> Result<Self, FieldError> {
         let number_info = number_string_typing(&string);
@@ -115,6 +129,166 @@ impl<F: PrimeField> FieldType<F> {
         self.mul(cs, &inverse, span)
     }
 
+    ///
+    /// Returns the field element with the value of the integer with the given little-endian bits.
+    /// The top bit of a signed integer weighs negative, so negative integers map to their negation in the field.
+    ///
+    /// Only builds a linear combination of the bits, no constraints are added.
+    ///
+    pub fn from_integer_bits<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        bits: &[Boolean],
+        signed: bool,
+        span: &Span,
+    ) -> Result<Self, FieldError> {
+        let mut coefficients = Vec::with_capacity(bits.len());
+        let mut coefficient = F::one();
+        for _ in bits {
+            coefficients.push(coefficient);
+            coefficient = coefficient.double();
+        }
+        if signed {
+            if let Some(top) = coefficients.last_mut() {
+                *top = -*top;
+            }
+        }
+
+        if bits.iter().all(|bit| matches!(bit, Boolean::Constant(_))) {
+            let value = bits
+                .iter()
+                .zip(coefficients)
+                .filter(|(bit, _)| matches!(bit, Boolean::Constant(true)))
+                .fold(F::zero(), |value, (_, coefficient)| value + coefficient);
+
+            return Ok(FieldType(FpGadget::Constant(value)));
+        }
+
+        let mut result = FpGadget::zero(cs.ns(|| "zero")).map_err(|e| FieldError::cast(e, span))?;
+        for (i, (bit, coefficient)) in bits.iter().zip(coefficients).enumerate() {
+            result = result
+                .conditionally_add_constant(cs.ns(|| format!("bit {}", i)), bit, coefficient)
+                .map_err(|e| FieldError::cast(e, span))?;
+        }
+
+        Ok(FieldType(result))
+    }
+
+    ///
+    /// Returns the little-endian bits of the integer of `size` bits with the value of this field element.
+    /// Elements close to the modulus are read as negative if `signed`, mirroring [`FieldType::from_integer_bits`].
+    ///
+    /// Enforces that the bits recompose to this element, so values out of the integer range are rejected.
+    ///
+    pub fn to_integer_bits<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        target: &IntegerType,
+        span: &Span,
+    ) -> Result<Vec<Boolean>, FieldError> {
+        let size = Integer::size(target);
+        let signed = target.is_signed();
+
+        let value_bits = match self.get_value() {
+            Some(value) => Some(
+                integer_bits(value, size, signed)
+                    .ok_or_else(|| FieldError::cast_out_of_range(value.to_string(), target, span))?,
+            ),
+            None => None,
+        };
+
+        self.enforce_integer_bits(cs, value_bits, size, signed, span)
+    }
+
+    ///
+    /// Returns the little-endian bits of the integer of the `target` type with the low bits of this field element.
+    /// Elements above half the modulus are read as negative, so the result agrees with [`FieldType::to_integer_bits`]
+    /// whenever the element is in range, and the two's complement of the element is truncated otherwise.
+    ///
+    /// Enforces the canonical bit decomposition of the element, so the low bits are unique.
+    ///
+    pub fn to_wrapped_integer_bits<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        target: &IntegerType,
+        span: &Span,
+    ) -> Result<Vec<Boolean>, FieldError> {
+        let size = Integer::size(target);
+
+        // Adding half the modulus moves the signed range [-half, half] to the canonical range [0, 2 * half].
+        let half = -F::one() * F::one().double().inverse().unwrap();
+        let shifted = self
+            .0
+            .add_constant(cs.ns(|| "shift"), &half)
+            .map_err(|e| FieldError::cast(e, span))?;
+        let bits = shifted
+            .to_bits_le_strict(cs.ns(|| "bits"))
+            .map_err(|e| FieldError::cast(e, span))?;
+
+        // Subtracting half again, modulo 2^size, leaves the low bits of the signed value.
+        let half_repr = half.into_repr();
+        let half_low = (0..size)
+            .filter(|i| half_repr.get_bit(*i))
+            .fold(0u128, |acc, i| acc | 1 << i);
+        let offset = if size == 128 {
+            half_low.wrapping_neg()
+        } else {
+            half_low.wrapping_neg() & ((1 << size) - 1)
+        };
+
+        let low = Self::from_integer_bits(cs.ns(|| "low"), &bits[..size], false, span)?;
+        let sum = FieldType(
+            low.0
+                .add_constant(cs.ns(|| "offset"), &F::from(offset))
+                .map_err(|e| FieldError::cast(e, span))?,
+        );
+        // The sum is below 2^(size + 1), its top bit is the carry out of the low bits.
+        let value_bits = sum.get_value().map(|value| {
+            let repr = value.into_repr();
+            (0..=size).map(|i| repr.get_bit(i)).collect::<Vec<_>>()
+        });
+        let bits = sum.enforce_integer_bits(cs.ns(|| "wrap"), value_bits, size + 1, false, span)?;
+
+        Ok(bits[..size].to_vec())
+    }
+
+    ///
+    /// Returns `value_bits`, the little-endian bits of an integer of `size` bits with the value of this element,
+    /// as constants if the element is constant, or allocated and enforced to recompose to the element otherwise.
+    ///
+    fn enforce_integer_bits<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        value_bits: Option<Vec<bool>>,
+        size: usize,
+        signed: bool,
+        span: &Span,
+    ) -> Result<Vec<Boolean>, FieldError> {
+        if let (FpGadget::Constant(_), Some(value_bits)) = (&self.0, &value_bits) {
+            return Ok(value_bits.iter().copied().map(Boolean::constant).collect());
+        }
+
+        let bits = (0..size)
+            .map(|i| {
+                AllocatedBit::alloc(cs.ns(|| format!("bit {}", i)), || {
+                    value_bits
+                        .as_ref()
+                        .map(|bits| bits[i])
+                        .ok_or(SynthesisError::AssignmentMissing)
+                })
+                .map(Boolean::from)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| FieldError::cast(e, span))?;
+
+        let recomposed = Self::from_integer_bits(cs.ns(|| "recompose"), &bits, signed, span)?;
+        recomposed
+            .0
+            .enforce_equal(cs.ns(|| "range"), &self.0)
+            .map_err(|e| FieldError::cast(e, span))?;
+
+        Ok(bits)
+    }
+
     pub fn alloc_helper<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<String>>(
         value_gen: Fn,
     ) -> Result<F, SynthesisError> {
@@ -130,6 +304,29 @@ impl<F: PrimeField> FieldType<F> {
     }
 }
 
+/// Returns the field element as an integer below 2^128, if it is one.
+fn field_to_u128<F: PrimeField>(value: F) -> Option<u128> {
+    let repr = value.into_repr();
+    if repr.num_bits() > 128 {
+        return None;
+    }
+
+    Some((0..128).filter(|i| repr.get_bit(*i)).fold(0, |acc, i| acc | 1 << i))
+}
+
+/// Returns the two's complement little-endian bits of the field element as an integer of `size` bits,
+/// or `None` if the element is out of range.
+fn integer_bits<F: PrimeField>(value: F, size: usize, signed: bool) -> Option<Vec<bool>> {
+    let word = match (field_to_u128(value), field_to_u128(-value)) {
+        (Some(magnitude), _) if signed && magnitude < 1 << (size - 1) => magnitude,
+        (Some(magnitude), _) if !signed && (size == 128 || magnitude < 1 << size) => magnitude,
+        (_, Some(magnitude)) if signed && magnitude <= 1 << (size - 1) => magnitude.wrapping_neg(),
+        _ => return None,
+    };
+
+    Some((0..size).map(|i| word >> i & 1 == 1).collect())
+}
+
 impl<F: PrimeField> AllocGadget<String, F> for FieldType<F> {
     fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<String>, CS: ConstraintSystem<F>>(
         cs: CS,
@@ -168,8 +365,24 @@ impl<F: PrimeField> PartialOrd for FieldType<F> {
 }
 
 impl<F: PrimeField> EvaluateLtGadget<F> for FieldType<F> {
-    fn less_than<CS: ConstraintSystem<F>>(&self, _cs: CS, _other: &Self) -> Result<Boolean, SynthesisError> {
-        unimplemented!()
+    /// Compares the canonical representatives of both field elements, i.e. the integers in `[0, p)`.
+    ///
+    /// Both values are decomposed into bits that are checked to be smaller than the modulus,
+    /// so a prover cannot pick an alternative decomposition to flip the result.
+    fn less_than<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+        let self_bits = self.0.to_bits_le_strict(cs.ns(|| "self to bits"))?;
+        let other_bits = other.0.to_bits_le_strict(cs.ns(|| "other to bits"))?;
+
+        // lsb -> msb, the most significant differing bit decides the result
+        let mut result = Boolean::constant(false);
+        for (i, (a, b)) in self_bits.iter().zip(other_bits.iter()).enumerate() {
+            let differ = a.xor(cs.ns(|| format!("a XOR b [{}]", i)), b)?;
+
+            // when the bits differ, `self < other` iff the bit of `other` is set
+            result = Boolean::conditionally_select(cs.ns(|| format!("select bit [{}]", i)), &differ, b, &result)?;
+        }
+
+        Ok(result)
     }
 }
 
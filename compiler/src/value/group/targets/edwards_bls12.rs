@@ -385,49 +385,44 @@ impl PartialEq for EdwardsGroupType {
 
 impl Eq for EdwardsGroupType {}
 
-// fn compare_allocated_edwards_bls_gadgets<CS: ConstraintSystem<Fq>>(
-//     mut cs: CS,
-//     first: &EdwardsBlsGadget,
-//     second: &EdwardsBlsGadget,
-// ) -> Result<Boolean, SynthesisError> {
-//     // compare x coordinates
-//     let x_first = &first.x;
-//     let x_second = &second.x;
-//
-//     let compare_x = x_first.evaluate_equal(&mut cs.ns(|| format!("compare x")), x_second)?;
-//
-//     // compare y coordinates
-//     let y_first = &first.y;
-//     let y_second = &second.y;
-//
-//     let compare_y = y_first.evaluate_equal(&mut cs.ns(|| format!("compare y")), y_second)?;
-//
-//     Boolean::and(
-//         &mut cs.ns(|| format!("compare x and y results")),
-//         &compare_x,
-//         &compare_y,
-//     )
-// }
+fn compare_allocated_edwards_bls_gadgets<CS: ConstraintSystem<Fq>>(
+    mut cs: CS,
+    first: &EdwardsBlsGadget,
+    second: &EdwardsBlsGadget,
+) -> Result<Boolean, SynthesisError> {
+    // compare x coordinates
+    let x_first = &first.x;
+    let x_second = &second.x;
+
+    let compare_x = x_first.is_eq(&mut cs.ns(|| "compare x"), x_second)?;
+
+    // compare y coordinates
+    let y_first = &first.y;
+    let y_second = &second.y;
+
+    let compare_y = y_first.is_eq(&mut cs.ns(|| "compare y"), y_second)?;
+
+    Boolean::and(&mut cs.ns(|| "compare x and y results"), &compare_x, &compare_y)
+}
 
 impl EvaluateEqGadget<Fq> for EdwardsGroupType {
-    fn evaluate_equal<CS: ConstraintSystem<Fq>>(&self, mut _cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+    fn evaluate_equal<CS: ConstraintSystem<Fq>>(&self, mut cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
         match (self, other) {
             (EdwardsGroupType::Constant(self_value), EdwardsGroupType::Constant(other_value)) => {
                 Ok(Boolean::constant(self_value.eq(other_value)))
             }
-            _ => unimplemented!(),
-            // (EdwardsGroupType::Allocated(first), EdwardsGroupType::Allocated(second)) => {
-            //     compare_allocated_edwards_bls_gadgets(cs, first, second)
-            // }
-            // (EdwardsGroupType::Constant(constant_value), EdwardsGroupType::Allocated(allocated_value))
-            // | (EdwardsGroupType::Allocated(allocated_value), EdwardsGroupType::Constant(constant_value)) => {
-            //     let allocated_constant_value =
-            //         <EdwardsBlsGadget as AllocGadget<GroupAffine<EdwardsParameters>, Fq>>::alloc(
-            //             &mut cs.ns(|| format!("alloc constant for eq")),
-            //             || Ok(constant_value),
-            //         )?;
-            //     compare_allocated_edwards_bls_gadgets(cs, allocated_value, &allocated_constant_value)
-            // }
+            (EdwardsGroupType::Allocated(first), EdwardsGroupType::Allocated(second)) => {
+                compare_allocated_edwards_bls_gadgets(cs, first, second)
+            }
+            (EdwardsGroupType::Constant(constant_value), EdwardsGroupType::Allocated(allocated_value))
+            | (EdwardsGroupType::Allocated(allocated_value), EdwardsGroupType::Constant(constant_value)) => {
+                let allocated_constant_value =
+                    <EdwardsBlsGadget as AllocGadget<GroupAffine<EdwardsParameters>, Fq>>::alloc_constant(
+                        &mut cs.ns(|| "alloc constant for eq"),
+                        || Ok(*constant_value),
+                    )?;
+                compare_allocated_edwards_bls_gadgets(cs, allocated_value, &allocated_constant_value)
+            }
         }
     }
 }
@@ -479,7 +474,11 @@ impl CondSelectGadget<Fq> for EdwardsGroupType {
         second: &Self,
     ) -> Result<Self, SynthesisError> {
         if let Boolean::Constant(cond) = *cond {
-            if cond { Ok(first.clone()) } else { Ok(second.clone()) }
+            if cond {
+                Ok(first.clone())
+            } else {
+                Ok(second.clone())
+            }
         } else {
             let first_gadget = first.allocated(cs.ns(|| "first"))?;
             let second_gadget = second.allocated(cs.ns(|| "second"))?;
@@ -28,7 +28,10 @@ use snarkvm_gadgets::{
     },
     traits::{
         alloc::AllocGadget,
-        bits::comparator::{ComparatorGadget, EvaluateLtGadget},
+        bits::{
+            comparator::{ComparatorGadget, EvaluateLtGadget},
+            Xor,
+        },
         eq::{ConditionalEqGadget, EqGadget, EvaluateEqGadget},
         integers::{Add, Div, Mul, Neg, Pow, Sub},
         select::CondSelectGadget,
@@ -266,6 +269,334 @@ impl Integer {
 
         result.ok_or_else(|| IntegerError::binary_operation("**".to_string(), span))
     }
+
+    ///
+    /// Returns the remainder of dividing `self` by `other`.
+    ///
+    /// The result takes the sign of the dividend, so `a == (a / b) * b + a % b` always holds.
+    ///
+    pub fn rem<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} % {} {}:{}", self, other, span.line_start, span.col_start);
+        let mut cs = cs.ns(|| unique_namespace);
+
+        let quotient = self.clone().div(&mut cs.ns(|| "quotient"), other.clone(), span)?;
+        let product = quotient.mul(&mut cs.ns(|| "product"), other, span)?;
+
+        let remainder = self.sub(&mut cs.ns(|| "remainder"), product, span)?;
+
+        Ok(remainder)
+    }
+
+    ///
+    /// Returns the bitwise negation of `self`.
+    ///
+    pub fn bit_not(self) -> Self {
+        let bits = self.get_bits().iter().map(Boolean::not).collect::<Vec<_>>();
+
+        self.with_bits_le(&bits)
+    }
+
+    pub fn bit_and<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} & {} {}:{}", self, other, span.line_start, span.col_start);
+        let mut cs = cs.ns(|| unique_namespace);
+
+        let bits = self
+            .zip_bits(&other, "&", span)?
+            .iter()
+            .enumerate()
+            .map(|(i, (a, b))| Boolean::and(cs.ns(|| format!("bit {}", i)), a, b))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        Ok(self.with_bits_le(&bits))
+    }
+
+    pub fn bit_or<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} | {} {}:{}", self, other, span.line_start, span.col_start);
+        let mut cs = cs.ns(|| unique_namespace);
+
+        let bits = self
+            .zip_bits(&other, "|", span)?
+            .iter()
+            .enumerate()
+            .map(|(i, (a, b))| Boolean::or(cs.ns(|| format!("bit {}", i)), a, b))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        Ok(self.with_bits_le(&bits))
+    }
+
+    pub fn bit_xor<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} ^ {} {}:{}", self, other, span.line_start, span.col_start);
+        let mut cs = cs.ns(|| unique_namespace);
+
+        let bits = self
+            .zip_bits(&other, "^", span)?
+            .iter()
+            .enumerate()
+            .map(|(i, (a, b))| a.xor(cs.ns(|| format!("bit {}", i)), b))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        Ok(self.with_bits_le(&bits))
+    }
+
+    ///
+    /// Shifts `self` left by the `u32` amount `shift`. Bits shifted out are discarded.
+    ///
+    pub fn shl<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        shift: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} << {} {}:{}", self, shift, span.line_start, span.col_start);
+
+        self.shift(&mut cs.ns(|| unique_namespace), shift, Shift::Left, span)
+    }
+
+    ///
+    /// Shifts `self` right by the `u32` amount `shift`, filling the vacated bits with zeros.
+    ///
+    pub fn shr<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        shift: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} >> {} {}:{}", self, shift, span.line_start, span.col_start);
+
+        self.shift(&mut cs.ns(|| unique_namespace), shift, Shift::Right, span)
+    }
+
+    ///
+    /// Shifts `self` right by the `u32` amount `shift`, filling the vacated bits with the sign bit.
+    ///
+    pub fn shr_signed<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        shift: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} >>> {} {}:{}", self, shift, span.line_start, span.col_start);
+
+        self.shift(&mut cs.ns(|| unique_namespace), shift, Shift::RightSigned, span)
+    }
+
+    ///
+    /// Enforces a barrel shifter over the bits of `self`.
+    ///
+    /// Each bit of the shift amount conditionally shifts the value by the matching power of two.
+    /// Amounts of at least the bit width shift every bit out of the value.
+    ///
+    fn shift<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        shift: Self,
+        direction: Shift,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let shift_bits = match shift {
+            Integer::U32(shift) => shift.to_bits_le(),
+            shift => {
+                return Err(IntegerError::integer_type_mismatch(
+                    &IntegerType::U32,
+                    shift.get_type(),
+                    span,
+                ))
+            }
+        };
+
+        let mut bits = self.get_bits();
+        let size = bits.len();
+        let fill = match direction {
+            Shift::RightSigned => bits[size - 1],
+            Shift::Left | Shift::Right => Boolean::constant(false),
+        };
+
+        // Only the lowest log2(size) bits of the amount select a shift within the value.
+        let stages = size.trailing_zeros() as usize;
+
+        for (stage, condition) in shift_bits.iter().take(stages).enumerate() {
+            let distance = 1 << stage;
+            let shifted = (0..size)
+                .map(|i| match direction {
+                    Shift::Left if i >= distance => bits[i - distance],
+                    Shift::Right | Shift::RightSigned if i + distance < size => bits[i + distance],
+                    _ => fill,
+                })
+                .collect::<Vec<_>>();
+
+            bits = bits
+                .iter()
+                .zip(shifted.iter())
+                .enumerate()
+                .map(|(i, (bit, shifted_bit))| {
+                    Boolean::conditionally_select(
+                        cs.ns(|| format!("stage {} bit {}", stage, i)),
+                        condition,
+                        shifted_bit,
+                        bit,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| IntegerError::synthesis(e, span))?;
+        }
+
+        // Any higher bit of the amount shifts every bit out.
+        let mut overflow = Boolean::constant(false);
+        for (i, bit) in shift_bits.iter().enumerate().skip(stages) {
+            overflow = Boolean::or(cs.ns(|| format!("overflow bit {}", i)), &overflow, bit)
+                .map_err(|e| IntegerError::synthesis(e, span))?;
+        }
+
+        let bits = bits
+            .iter()
+            .enumerate()
+            .map(|(i, bit)| Boolean::conditionally_select(cs.ns(|| format!("overflow {}", i)), &overflow, &fill, bit))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        Ok(self.with_bits_le(&bits))
+    }
+
+    ///
+    /// Returns `self` cast to the `target` integer type.
+    ///
+    /// The cast preserves the value: the bits are sign or zero extended as the source type requires,
+    /// and the bits dropped from the extended value must match the extension of the result.
+    ///
+    pub fn cast<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        target: &IntegerType,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let source_size = Self::size(&self.get_type());
+        let target_size = Self::size(target);
+
+        let extended = self.extended_bits(source_size.max(target_size) + 1);
+        let bits = &extended[..target_size];
+        let target_fill = if target.is_signed() {
+            bits[target_size - 1]
+        } else {
+            Boolean::constant(false)
+        };
+
+        let dropped = &extended[target_size..];
+        let in_range = dropped
+            .iter()
+            .all(|bit| match (bit.get_value(), target_fill.get_value()) {
+                (Some(bit), Some(fill)) => bit == fill,
+                _ => true,
+            });
+        if !in_range {
+            return Err(IntegerError::cast_out_of_range(self.to_string(), target, span));
+        }
+
+        let unique_namespace = format!("enforce {} as {} {}:{}", self, target, span.line_start, span.col_start);
+        let mut cs = cs.ns(|| unique_namespace);
+        for (i, bit) in dropped.iter().enumerate() {
+            bit.enforce_equal(cs.ns(|| format!("dropped bit {}", i)), &target_fill)
+                .map_err(|e| IntegerError::synthesis(e, span))?;
+        }
+
+        Ok(Self::from_bits_le(target, bits))
+    }
+
+    ///
+    /// Returns `self` cast to the `target` integer type, keeping the low bits of its value.
+    ///
+    /// This is the value-changing cast: the bits are sign or zero extended as the source type requires,
+    /// then truncated to the target size and reinterpreted, so no constraints are needed.
+    ///
+    pub fn wrapping_cast(&self, target: &IntegerType) -> Self {
+        let target_size = Self::size(target);
+
+        Self::from_bits_le(target, &self.extended_bits(target_size)[..target_size])
+    }
+
+    /// Returns at least `size` little-endian bits of `self`, sign extended if it is signed and zero extended otherwise.
+    fn extended_bits(&self, size: usize) -> Vec<Boolean> {
+        let bits = self.get_bits();
+        let fill = if self.get_type().is_signed() {
+            bits[bits.len() - 1]
+        } else {
+            Boolean::constant(false)
+        };
+
+        let len = bits.len().max(size);
+        bits.into_iter().chain(std::iter::repeat(fill)).take(len).collect()
+    }
+
+    /// Returns the number of bits of the given integer type.
+    pub fn size(integer_type: &IntegerType) -> usize {
+        match integer_type {
+            IntegerType::U8 | IntegerType::I8 => 8,
+            IntegerType::U16 | IntegerType::I16 => 16,
+            IntegerType::U32 | IntegerType::I32 => 32,
+            IntegerType::U64 | IntegerType::I64 => 64,
+            IntegerType::U128 | IntegerType::I128 => 128,
+        }
+    }
+
+    /// Returns an integer of the given type built from the given little-endian bits.
+    pub fn from_bits_le(integer_type: &IntegerType, bits: &[Boolean]) -> Self {
+        match integer_type {
+            IntegerType::U8 => Integer::U8(UInt8::from_bits_le(bits)),
+            IntegerType::U16 => Integer::U16(UInt16::from_bits_le(bits)),
+            IntegerType::U32 => Integer::U32(UInt32::from_bits_le(bits)),
+            IntegerType::U64 => Integer::U64(UInt64::from_bits_le(bits)),
+            IntegerType::U128 => Integer::U128(UInt128::from_bits_le(bits)),
+
+            IntegerType::I8 => Integer::I8(Int8::from_bits_le(bits)),
+            IntegerType::I16 => Integer::I16(Int16::from_bits_le(bits)),
+            IntegerType::I32 => Integer::I32(Int32::from_bits_le(bits)),
+            IntegerType::I64 => Integer::I64(Int64::from_bits_le(bits)),
+            IntegerType::I128 => Integer::I128(Int128::from_bits_le(bits)),
+        }
+    }
+
+    fn zip_bits(&self, other: &Self, operation: &str, span: &Span) -> Result<Vec<(Boolean, Boolean)>, IntegerError> {
+        if self.get_type() != other.get_type() {
+            return Err(IntegerError::binary_operation(operation.to_string(), span));
+        }
+
+        Ok(self.get_bits().into_iter().zip(other.get_bits()).collect())
+    }
+
+    /// Returns an integer of the same type as `self` built from the given little-endian bits.
+    fn with_bits_le(&self, bits: &[Boolean]) -> Self {
+        Self::from_bits_le(&self.get_type(), bits)
+    }
+}
+
+/// The direction of a bit shift.
+#[derive(Clone, Copy)]
+enum Shift {
+    Left,
+    Right,
+    RightSigned,
 }
 
 impl<F: PrimeField> EvaluateEqGadget<F> for Integer {
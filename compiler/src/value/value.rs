@@ -17,12 +17,15 @@
 //! The in memory stored value for a defined name in a compiled Leo program.
 
 use crate::{errors::ValueError, Address, Char, FieldType, GroupType, Integer};
-use leo_asg::{Circuit, Identifier, Span, Type};
+use leo_asg::{Circuit, ConstInt, Enum, Identifier, Span, Type};
 
 use snarkvm_fields::PrimeField;
 use snarkvm_gadgets::{
     bits::Boolean,
-    traits::{eq::ConditionalEqGadget, select::CondSelectGadget},
+    traits::{
+        eq::{ConditionalEqGadget, EvaluateEqGadget},
+        select::CondSelectGadget,
+    },
 };
 use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
 use std::fmt;
@@ -48,6 +51,11 @@ pub enum ConstrainedValue<'a, F: PrimeField, G: GroupType<F>> {
 
     // Circuits
     CircuitExpression(&'a Circuit<'a>, Vec<ConstrainedCircuitMember<'a, F, G>>),
+
+    // Enums
+    /// The `u8` tag of the active variant and the values carried by each variant.
+    /// Variants that cannot be active have no values.
+    Enum(&'a Enum<'a>, Integer, Vec<Option<Vec<ConstrainedValue<'a, F, G>>>>),
 }
 
 impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedValue<'a, F, G> {
@@ -78,8 +86,32 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedValue<'a, F, G> {
                 Type::Tuple(types)
             }
             ConstrainedValue::CircuitExpression(id, _members) => Type::Circuit(*id),
+            ConstrainedValue::Enum(enum_, _tag, _payloads) => Type::Enum(*enum_),
         })
     }
+
+    /// Returns `true` if the value is known at compile time and has no variables in the circuit.
+    /// Groups and characters are conservatively treated as allocated.
+    pub(crate) fn is_constant(&self) -> bool {
+        match self {
+            ConstrainedValue::Address(address) => address.is_constant(),
+            ConstrainedValue::Boolean(boolean) => matches!(boolean, Boolean::Constant(_)),
+            ConstrainedValue::Field(field) => field.is_constant(),
+            ConstrainedValue::Integer(integer) => !integer.is_allocated(),
+            ConstrainedValue::Char(_) | ConstrainedValue::Group(_) => false,
+            ConstrainedValue::Array(values) | ConstrainedValue::Tuple(values) => {
+                values.iter().all(|value| value.is_constant())
+            }
+            ConstrainedValue::CircuitExpression(_, members) => members.iter().all(|member| member.1.is_constant()),
+            ConstrainedValue::Enum(_, tag, payloads) => {
+                !tag.is_allocated()
+                    && payloads
+                        .iter()
+                        .flatten()
+                        .all(|payload| payload.iter().all(|value| value.is_constant()))
+            }
+        }
+    }
 }
 
 impl<'a, F: PrimeField, G: GroupType<F>> fmt::Display for ConstrainedValue<'a, F, G> {
@@ -134,6 +166,23 @@ impl<'a, F: PrimeField, G: GroupType<F>> fmt::Display for ConstrainedValue<'a, F
                 }
                 write!(f, "}}")
             }
+            ConstrainedValue::Enum(ref enum_, ref tag, ref payloads) => {
+                write!(f, "{}::", enum_.name.borrow())?;
+                let variant = tag.get_value().and_then(|tag| tag.parse::<usize>().ok());
+                match variant {
+                    Some(variant) => {
+                        write!(f, "{}", enum_.variants.borrow().get_index(variant).unwrap().0)?;
+                        match payloads.get(variant) {
+                            Some(Some(payload)) if !payload.is_empty() => {
+                                let values = payload.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+                                write!(f, "({})", values)
+                            }
+                            _ => Ok(()),
+                        }
+                    }
+                    None => write!(f, "[allocated]"),
+                }
+            }
         }
     }
 }
@@ -182,6 +231,28 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConditionalEqGadget<F> for ConstrainedV
                 }
                 Ok(())
             }
+            (ConstrainedValue::Enum(_, tag_1, payloads_1), ConstrainedValue::Enum(_, tag_2, payloads_2)) => {
+                tag_1.conditional_enforce_equal(cs.ns(|| "enum tag"), tag_2, condition)?;
+                // The values of a variant only have to agree while it is the active one.
+                for (i, (payload_1, payload_2)) in payloads_1.iter().zip(payloads_2.iter()).enumerate() {
+                    if let (Some(payload_1), Some(payload_2)) = (payload_1, payload_2) {
+                        let is_variant = tag_1.evaluate_equal(
+                            cs.ns(|| format!("enum tag is {}", i)),
+                            &Integer::new(&ConstInt::U8(i as u8)),
+                        )?;
+                        let active =
+                            Boolean::and(cs.ns(|| format!("enum variant {} active", i)), condition, &is_variant)?;
+                        for (j, (left, right)) in payload_1.iter().zip(payload_2.iter()).enumerate() {
+                            left.conditional_enforce_equal(
+                                cs.ns(|| format!("enum variant {} value {}", i, j)),
+                                right,
+                                &active,
+                            )?;
+                        }
+                    }
+                }
+                Ok(())
+            }
             (_, _) => Err(SynthesisError::Unsatisfiable),
         }
     }
@@ -262,6 +333,32 @@ impl<'a, F: PrimeField, G: GroupType<F>> CondSelectGadget<F> for ConstrainedValu
 
                 ConstrainedValue::CircuitExpression(*identifier, members)
             }
+            (ConstrainedValue::Enum(enum_, tag_1, payloads_1), ConstrainedValue::Enum(_, tag_2, payloads_2)) => {
+                let tag = Integer::conditionally_select(cs.ns(|| "enum tag"), cond, tag_1, tag_2)?;
+
+                // A variant that only one side can have is selected whenever the result has its tag.
+                let mut payloads = Vec::with_capacity(payloads_1.len());
+                for (i, (payload_1, payload_2)) in payloads_1.iter().zip(payloads_2.iter()).enumerate() {
+                    payloads.push(match (payload_1, payload_2) {
+                        (Some(payload_1), Some(payload_2)) => {
+                            let mut values = Vec::with_capacity(payload_1.len());
+                            for (j, (first, second)) in payload_1.iter().zip(payload_2.iter()).enumerate() {
+                                values.push(Self::conditionally_select(
+                                    cs.ns(|| format!("enum variant {} value {}", i, j)),
+                                    cond,
+                                    first,
+                                    second,
+                                )?);
+                            }
+                            Some(values)
+                        }
+                        (Some(payload), None) | (None, Some(payload)) => Some(payload.clone()),
+                        (None, None) => None,
+                    });
+                }
+
+                ConstrainedValue::Enum(*enum_, tag, payloads)
+            }
             (_, _) => return Err(SynthesisError::Unsatisfiable),
         })
     }
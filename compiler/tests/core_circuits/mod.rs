@@ -0,0 +1,162 @@
+use crate::{new_compiler, parse_program_with_input, EdwardsTestCompiler};
+use leo_asg::{CoreCircuitDeclaration, CoreFunction, Function, Span};
+use leo_ast::Type;
+use leo_compiler::{
+    errors::{CompilerError, ExpressionError},
+    ConstrainedValue,
+    CoreCircuit,
+    ErasedConstraintSystem,
+    GroupType,
+    Pedersen,
+};
+
+use num_bigint::BigUint;
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use snarkvm_algorithms::{
+    crh::{PedersenCRH, PedersenCRHParameters, PedersenSize},
+    traits::CRH,
+};
+use snarkvm_curves::edwards_bls12::EdwardsAffine;
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+use snarkvm_utilities::{bytes::ToBytes, to_bytes};
+
+use std::path::PathBuf;
+
+/// A core circuit registered by the host, multiplies a field by a constant factor.
+struct Scale;
+
+impl<F: PrimeField, G: GroupType<F>> CoreCircuit<F, G> for Scale {
+    fn declaration(&self) -> CoreCircuitDeclaration {
+        CoreCircuitDeclaration::new("host.scale", "Scale", "scale").function(
+            CoreFunction::new("mul", Type::Field)
+                .const_argument("factor", Type::Field)
+                .argument("value", Type::Field),
+        )
+    }
+
+    fn call_function<'a>(
+        &self,
+        cs: &mut ErasedConstraintSystem<F>,
+        _function: &'a Function<'a>,
+        span: &Span,
+        _target: Option<ConstrainedValue<'a, F, G>>,
+        arguments: Vec<ConstrainedValue<'a, F, G>>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        match &arguments[..] {
+            [ConstrainedValue::Field(factor), ConstrainedValue::Field(value)] => {
+                Ok(ConstrainedValue::Field(factor.mul(cs.ns(|| "scale"), value, span)?))
+            }
+            _ => panic!("asg enforced"),
+        }
+    }
+}
+
+fn parse_program(program_string: &str, register: bool) -> Result<EdwardsTestCompiler, CompilerError> {
+    let mut compiler = new_compiler();
+    if register {
+        compiler.register_core_circuit(Scale);
+    }
+
+    let path = PathBuf::new();
+    compiler.parse_input(include_str!("main.in"), &path, "", &path)?;
+    compiler.parse_program_from_string(program_string)?;
+
+    Ok(compiler)
+}
+
+#[test]
+fn test_registered_core_circuit() {
+    let program = parse_program(include_str!("main.leo"), true).unwrap();
+
+    let mut cs = TestConstraintSystem::new();
+    let output = program.compile_constraints(&mut cs).unwrap();
+    assert!(cs.is_satisfied());
+    assert!(output.to_string().contains("true"), "unexpected output: {}", output);
+
+    let interpreted = program.interpret().unwrap();
+    assert_eq!(interpreted.to_string(), output.to_string());
+}
+
+#[test]
+fn test_unregistered_core_circuit_fail() {
+    let error = parse_program(include_str!("main.leo"), false)
+        .err()
+        .unwrap()
+        .to_string();
+
+    assert!(error.contains("core.host.scale"), "unexpected error: {}", error);
+}
+
+#[test]
+fn test_core_const_argument_fail() {
+    let program_string = include_str!("main.leo").replace("Scale::mul(3field, value)", "Scale::mul(value, value)");
+    let error = parse_program(&program_string, true).err().unwrap().to_string();
+
+    assert!(
+        error.contains("expected const, found non-const value"),
+        "unexpected error: {}",
+        error
+    );
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct PedersenWindow;
+
+impl PedersenSize for PedersenWindow {
+    const NUM_WINDOWS: usize = 1;
+    const WINDOW_SIZE: usize = 256;
+}
+
+/// Returns the point snarkVM's Pedersen CRH computes for the bits over the given generators.
+fn reference_pedersen(bits: &[bool], generators: &[EdwardsAffine]) -> EdwardsAffine {
+    let crh =
+        PedersenCRH::<EdwardsAffine, PedersenWindow>::from(PedersenCRHParameters::from(vec![generators.to_vec()]));
+
+    let mut bytes = vec![0u8; bits.len() / 8];
+    for (i, bit) in bits.iter().enumerate() {
+        bytes[i / 8] |= (*bit as u8) << (i % 8);
+    }
+
+    crh.hash(&bytes).unwrap()
+}
+
+fn group_literal(point: &EdwardsAffine) -> String {
+    let decimal = |coordinate| BigUint::from_bytes_le(&to_bytes![coordinate].unwrap()).to_string();
+    format!("({}, {})group", decimal(point.x), decimal(point.y))
+}
+
+fn bool_array_literal(bits: &[bool]) -> String {
+    let bits = bits.iter().map(|bit| bit.to_string()).collect::<Vec<_>>();
+    format!("[{}]", bits.join(", "))
+}
+
+#[test]
+fn test_pedersen_matches_snarkvm() {
+    let program_string = include_str!("../../../tests/compiler/core/pedersen.leo");
+    let generators = Pedersen::generators();
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..4 {
+        let message = (0..256).map(|_| rng.gen()).collect::<Vec<bool>>();
+        let randomness = (0..256).map(|_| rng.gen()).collect::<Vec<bool>>();
+
+        let hash = reference_pedersen(&message, &generators[..256]);
+        let commitment = hash + reference_pedersen(&randomness, &generators[256..]);
+
+        let input_string = format!(
+            "[main]\nmessage: [bool; 256] = {};\nrandomness: [bool; 256] = {};\nhash: group = {};\ncommitment: group = {};\n\n[registers]\nr0: bool = false;\n",
+            bool_array_literal(&message),
+            bool_array_literal(&randomness),
+            group_literal(&hash),
+            group_literal(&commitment),
+        );
+        let program = parse_program_with_input(program_string, &input_string).unwrap();
+
+        let mut cs = TestConstraintSystem::new();
+        let output = program.compile_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+        assert!(output.to_string().contains("true"), "unexpected output: {}", output);
+    }
+}
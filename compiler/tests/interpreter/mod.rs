@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{get_output, make_test_context, parse_program_with_input, EdwardsTestCompiler};
+use leo_compiler::OutputBytes;
+use leo_imports::ImportsMap;
+use leo_package::inputs::{InputPair, InputPairs};
+
+use std::path::PathBuf;
+
+/// Parses the program `file_name` in this directory with the input `input_name`.
+fn parse_file(file_name: &str, input_name: &str) -> EdwardsTestCompiler {
+    let program_string = std::fs::read_to_string(format!("tests/interpreter/{}", file_name)).unwrap();
+    let input_string = std::fs::read_to_string(format!("tests/interpreter/{}", input_name)).unwrap();
+
+    parse_program_with_input(&program_string, &input_string).unwrap()
+}
+
+#[test]
+fn test_output_matches_constraints() {
+    let program = parse_file("main.leo", "main.in");
+
+    let interpreted: OutputBytes = program.interpret().unwrap().into();
+    let compiled = get_output(program);
+
+    assert_eq!(compiled.bytes(), interpreted.bytes());
+}
+
+#[test]
+fn test_loop_control_matches_constraints() {
+    let program = parse_file("loop_control.leo", "loop_control.in");
+
+    let interpreted: OutputBytes = program.interpret().unwrap().into();
+    let compiled = get_output(program);
+
+    assert_eq!(compiled.bytes(), interpreted.bytes());
+}
+
+#[test]
+fn test_overflow_fail() {
+    let program = parse_file("overflow_fail.leo", "overflow.in");
+
+    let error = program.interpret().err().unwrap().to_string();
+
+    assert!(error.contains("`80 + 200` does not fit"), "unexpected error: {}", error);
+    assert!(error.contains("3:12"), "unexpected error: {}", error);
+}
+
+#[test]
+fn test_interpret_tests() {
+    let output_directory = tempfile::tempdir().unwrap();
+    std::fs::create_dir(output_directory.path().join("outputs")).unwrap();
+    let program = EdwardsTestCompiler::parse_program_without_input(
+        "test".to_string(),
+        PathBuf::from("tests/interpreter/tests.leo"),
+        output_directory.path().to_path_buf(),
+        ImportsMap::default(),
+        make_test_context(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let mut input_pairs = InputPairs::new();
+    input_pairs.pairs.insert("test".to_string(), InputPair {
+        input_file: String::new(),
+        state_file: String::new(),
+    });
+
+    let (passed, failed) = program.interpret_tests(input_pairs).unwrap();
+
+    assert_eq!((passed, failed), (1, 1));
+    assert!(output_directory.path().join("outputs/test.out").exists());
+}
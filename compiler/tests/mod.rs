@@ -19,7 +19,12 @@
 #![allow(deprecated)]
 
 pub mod canonicalization;
+pub mod core_circuits;
+pub mod interpreter;
+pub mod modules;
+pub mod profile;
 pub mod type_inference;
+pub mod unsatisfied;
 
 use leo_asg::{new_alloc_context, new_context, AsgContext};
 use leo_compiler::{
@@ -29,6 +34,7 @@ use leo_compiler::{
     ConstrainedValue,
     OutputBytes,
 };
+use leo_imports::ImportsMap;
 
 use snarkvm_curves::edwards_bls12::Fq;
 use snarkvm_r1cs::TestConstraintSystem;
@@ -52,7 +58,15 @@ fn new_compiler() -> EdwardsTestCompiler {
     let path = PathBuf::from("/test/src/main.leo");
     let output_dir = PathBuf::from(TEST_OUTPUT_DIRECTORY);
 
-    EdwardsTestCompiler::new(program_name, path, output_dir, make_test_context(), None, None)
+    EdwardsTestCompiler::new(
+        program_name,
+        path,
+        output_dir,
+        ImportsMap::default(),
+        make_test_context(),
+        None,
+        None,
+    )
 }
 
 pub(crate) fn parse_program(program_string: &str) -> Result<EdwardsTestCompiler, CompilerError> {
@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{assert_satisfied, make_test_context, EdwardsTestCompiler, TEST_OUTPUT_DIRECTORY};
+use leo_compiler::errors::CompilerError;
+use leo_imports::ImportsMap;
+
+use std::path::PathBuf;
+
+/// Compiles the main file `file_name` of the package in this directory.
+fn parse_package_file(file_name: &str) -> Result<EdwardsTestCompiler, CompilerError> {
+    let main_file_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/modules")
+        .join(file_name);
+
+    EdwardsTestCompiler::parse_program_without_input(
+        "test".to_string(),
+        main_file_path,
+        PathBuf::from(TEST_OUTPUT_DIRECTORY),
+        ImportsMap::default(),
+        make_test_context(),
+        None,
+        None,
+    )
+}
+
+fn expect_error(file_name: &str, message: &str) {
+    let error = parse_package_file(file_name).err().unwrap().to_string();
+
+    assert!(error.contains(message), "unexpected error: {}", error);
+}
+
+#[test]
+fn test_basic() {
+    let program = parse_package_file("basic.leo").unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_star() {
+    let program = parse_package_file("star.leo").unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_private_import_fail() {
+    expect_error("private_import_fail.leo", "'sub' is private to module 'math'");
+}
+
+#[test]
+fn test_private_star_fail() {
+    expect_error("private_star_fail.leo", "failed to resolve function: 'double'");
+}
+
+#[test]
+fn test_private_module_fail() {
+    expect_error("private_module_fail.leo", "module `math.internal` is private");
+}
+
+#[test]
+fn test_missing_module_fail() {
+    expect_error("missing_module_fail.leo", "of module `missing`");
+}
+
+#[test]
+fn test_recursive_fail() {
+    expect_error("recursive_fail.leo", "recursive imports for `ping`");
+}
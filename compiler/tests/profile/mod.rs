@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::parse_program_with_input;
+use leo_compiler::ProfileFrame;
+
+use snarkvm_r1cs::TestConstraintSystem;
+
+fn frame(label: &str, location: &str) -> ProfileFrame {
+    ProfileFrame {
+        label: label.to_string(),
+        location: location.to_string(),
+    }
+}
+
+#[test]
+fn test_profile() {
+    let program_string = include_str!("main.leo");
+    let input_string = include_str!("main.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    let mut cs = TestConstraintSystem::new();
+    let (_, profile) = program.compile_constraints_with_profile(&mut cs).unwrap();
+    assert!(cs.is_satisfied());
+
+    // Every constraint of the circuit is attributed to a frame
+    assert_eq!(profile.total().constraints, cs.num_constraints());
+
+    let frames = profile.frames();
+
+    // The hash dominates the circuit
+    let (largest, _) = frames[0];
+    assert_eq!(largest, &frame("core Blake2s::hash", "main.leo:12:12"));
+
+    // Each loop iteration calls `double`, which adds its own constraints
+    let stacks = profile
+        .stacks
+        .keys()
+        .map(|stack| stack.iter().map(|frame| frame.label.as_str()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    for i in 0..2 {
+        let iteration = format!("iteration i = {}", i);
+        assert!(stacks
+            .iter()
+            .any(|stack| { stack.contains(&iteration.as_str()) && stack.last() == Some(&"return statement") }));
+    }
+
+    let folded = profile.to_folded();
+    assert!(folded
+        .lines()
+        .all(|line| line.starts_with("function main (main.leo:7:1)")));
+    assert!(folded.contains("core Blake2s::hash (main.leo:12:12) "));
+}
@@ -14,9 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::path::{Path, PathBuf};
+use std::{
+    convert::TryFrom,
+    path::{Path, PathBuf},
+};
 
 use leo_asg::*;
+use leo_imports::ImportsMap;
 use leo_synthesizer::{CircuitSynthesizer, SerializedCircuit, SummarizedCircuit};
 use leo_test_framework::{
     runner::{Namespace, ParseType, Runner},
@@ -40,7 +44,15 @@ fn new_compiler(path: PathBuf) -> EdwardsTestCompiler {
     let program_name = "test".to_string();
     let output_dir = PathBuf::from("/output/");
 
-    EdwardsTestCompiler::new(program_name, path, output_dir, make_test_context(), None, None)
+    EdwardsTestCompiler::new(
+        program_name,
+        path,
+        output_dir,
+        ImportsMap::default(),
+        make_test_context(),
+        None,
+        None,
+    )
 }
 
 pub(crate) fn parse_program(program_string: &str) -> Result<EdwardsTestCompiler, CompilerError> {
@@ -149,7 +161,17 @@ impl Namespace for CompileNamespace {
                 .map_err(|x| x.to_string())?;
             let mut cs: CircuitSynthesizer<Bls12_377> = Default::default();
             let output = parsed.compile_constraints(&mut cs).map_err(|x| x.to_string())?;
-            let circuit: SummarizedCircuit = SerializedCircuit::from(cs).into();
+            let serialized = SerializedCircuit::from(cs);
+
+            // The circuit must survive the binary encoding and still be satisfied by its assignment
+            let bytes = serialized.to_bytes().map_err(|x| x.to_string())?;
+            let reloaded = SerializedCircuit::from_bytes(&bytes).map_err(|x| x.to_string())?;
+            let synthesizer = CircuitSynthesizer::<Bls12_377>::try_from(reloaded).map_err(|x| x.to_string())?;
+            if let Some(index) = synthesizer.which_is_unsatisfied() {
+                return Err(format!("- Reloaded circuit does not satisfy constraint {}", index));
+            }
+
+            let circuit: SummarizedCircuit = serialized.into();
 
             if circuit.num_constraints == 0 {
                 return Err(
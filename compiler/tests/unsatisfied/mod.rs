@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::parse_program_with_input;
+
+use snarkvm_r1cs::TestConstraintSystem;
+
+#[test]
+fn test_unsatisfied_constraint_in_loop() {
+    let program_string = include_str!("main.leo");
+    let input_string = include_str!("main.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    let mut cs = TestConstraintSystem::new();
+    let error = program
+        .compile_constraints_with_public_inputs(&mut cs)
+        .err()
+        .unwrap()
+        .to_string();
+
+    // The third iteration reads past the end of the array
+    assert!(error.contains("main.leo:4:16"), "unexpected error: {}", error);
+    assert!(
+        error.contains("sum += values[index + i];"),
+        "unexpected error: {}",
+        error
+    );
+    assert!(
+        error.contains("for loop iteration 2 3:5"),
+        "unexpected error: {}",
+        error
+    );
+    assert!(!cs.is_satisfied());
+}
+
+#[test]
+fn test_satisfied_constraints() {
+    let program_string = include_str!("main.leo");
+    let input_string = include_str!("main.in").replace("index: u32 = 2;", "index: u32 = 1;");
+    let program = parse_program_with_input(program_string, &input_string).unwrap();
+
+    let mut cs = TestConstraintSystem::new();
+    program.compile_constraints_with_public_inputs(&mut cs).unwrap();
+
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_unsatisfied_index_selector() {
+    let program_string = include_str!("assign.leo");
+    let input_string = include_str!("assign.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    let mut cs = TestConstraintSystem::new();
+    let error = program
+        .compile_constraints_with_public_inputs(&mut cs)
+        .err()
+        .unwrap()
+        .to_string();
+
+    // The read and the write share one selector, which selects no element of the array
+    assert!(
+        error.contains("enforce array index bounds"),
+        "unexpected error: {}",
+        error
+    );
+    assert!(
+        error.contains("values[index] = values[index] + 1;"),
+        "unexpected error: {}",
+        error
+    );
+    assert!(!cs.is_satisfied());
+}
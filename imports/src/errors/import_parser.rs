@@ -62,6 +62,45 @@ impl ImportParserError {
         Self::new_from_span(message, span)
     }
 
+    ///
+    /// A module is declared twice.
+    ///
+    pub fn duplicate_module(module: &str, span: &Span) -> Self {
+        let message = format!("module `{}` is declared more than once.", module);
+
+        Self::new_from_span(message, span)
+    }
+
+    ///
+    /// Failed to find the file of a declared module.
+    ///
+    pub fn module_not_found(module: &str, path: &str, span: &Span) -> Self {
+        let message = format!("Cannot find file `{}` of module `{}`.", path, module);
+
+        Self::new_from_span(message, span)
+    }
+
+    ///
+    /// An imported module path starts with a declared module but is not declared itself.
+    ///
+    pub fn unknown_module(module: &str, span: &Span) -> Self {
+        let message = format!("Cannot find module `{}`, declare it with `mod`.", module);
+
+        Self::new_from_span(message, span)
+    }
+
+    ///
+    /// A nested module is imported from outside its parent module without being declared `pub`.
+    ///
+    pub fn private_module(module: &str, span: &Span) -> Self {
+        let message = format!(
+            "module `{}` is private, declare it with `pub mod` to import it.",
+            module
+        );
+
+        Self::new_from_span(message, span)
+    }
+
     ///
     /// Failed to convert a file path into an os string.
     ///
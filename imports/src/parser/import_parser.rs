@@ -14,31 +14,56 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::errors::ImportParserError;
+use crate::{errors::ImportParserError, ImportsMap, Module};
 use leo_asg::{AsgContext, AsgConvertError, ImportResolver, Program, Span};
 
 use indexmap::{IndexMap, IndexSet};
-use std::path::PathBuf;
+use std::{path::PathBuf, rc::Rc};
 
 /// Stores imported packages.
 ///
-/// A program can import one or more packages. A package can be found locally in the source
-/// directory, foreign in the imports directory, or part of the core package list.
+/// A program can import one or more packages. A package can be a module declared with `mod`,
+/// found locally in the source directory, foreign in the imports directory, or part of the core package list.
 #[derive(Clone, Default)]
 pub struct ImportParser<'a> {
     program_path: PathBuf,
+    imports_map: ImportsMap,
     partial_imports: IndexSet<String>,
     imports: IndexMap<String, Program<'a>>,
+    pub(crate) modules: IndexMap<String, Module>,
+    pub(crate) module_stack: Vec<String>,
+    pub(crate) files: IndexSet<PathBuf>,
+    pub(crate) sources: Rc<IndexMap<PathBuf, String>>,
 }
 
 impl<'a> ImportParser<'a> {
-    pub fn new(program_path: PathBuf) -> Self {
+    pub fn new(program_path: PathBuf, imports_map: ImportsMap) -> Self {
         ImportParser {
             program_path,
+            imports_map,
             partial_imports: Default::default(),
             imports: Default::default(),
+            modules: Default::default(),
+            module_stack: Default::default(),
+            files: Default::default(),
+            sources: Default::default(),
         }
     }
+
+    ///
+    /// Reads the files in `sources` from memory instead of the file system, e.g. the unsaved buffers of an editor.
+    ///
+    pub fn with_sources(mut self, sources: IndexMap<PathBuf, String>) -> Self {
+        self.sources = Rc::new(sources);
+        self
+    }
+
+    ///
+    /// Returns the paths of the files parsed to resolve imports and modules, in the order they were parsed.
+    ///
+    pub fn resolved_files(&self) -> impl Iterator<Item = &PathBuf> {
+        self.files.iter()
+    }
 }
 
 impl<'a> ImportResolver<'a> for ImportParser<'a> {
@@ -55,13 +80,25 @@ impl<'a> ImportResolver<'a> for ImportParser<'a> {
         if let Some(program) = self.imports.get(&full_path) {
             return Ok(Some(program.clone()));
         }
-        let mut imports = Self::default();
-        let path = self.program_path.clone();
-
         self.partial_imports.insert(full_path.clone());
-        let program = imports
-            .parse_package(context, path, package_segments, span)
-            .map_err(|x| -> AsgConvertError { x.into() })?;
+        let program = if self.modules.contains_key(package_segments[0]) {
+            // Modules of the package are converted with this parser, so that they share its imports.
+            self.parse_module(context, package_segments, span)
+        } else {
+            let directory = self.imports_map.get(&self.program_path, package_segments[0]).cloned();
+            let mut imports = Self::new(directory.clone().unwrap_or_default(), self.imports_map.clone());
+            imports.sources = self.sources.clone();
+            let program = match directory {
+                // Packages declared in the manifest are resolved from the directory they were installed to.
+                Some(directory) => {
+                    imports.parse_dependency(context, package_segments[0], directory, &package_segments[1..], span)
+                }
+                None => imports.parse_package(context, self.program_path.clone(), package_segments, span),
+            };
+            self.files.extend(imports.files);
+            program
+        }
+        .map_err(|x| -> AsgConvertError { x.into() })?;
         self.partial_imports.remove(&full_path);
         self.imports.insert(full_path, program.clone());
         Ok(Some(program))
@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use indexmap::IndexMap;
+use std::path::{Path, PathBuf};
+
+///
+/// The dependencies declared in the manifests of a package and of every package it depends on.
+///
+/// Maps the directory of each package to the import names of its dependencies,
+/// and each import name to the directory of the package it resolves to.
+///
+#[derive(Clone, Debug, Default)]
+pub struct ImportsMap {
+    packages: IndexMap<PathBuf, IndexMap<String, PathBuf>>,
+}
+
+impl ImportsMap {
+    ///
+    /// Declares the dependencies of the package at `package`, keyed by import name.
+    ///
+    /// Packages without dependencies must be declared as well, so that their imports
+    /// are not resolved with the dependencies of an enclosing package.
+    ///
+    pub fn insert(&mut self, package: PathBuf, dependencies: IndexMap<String, PathBuf>) {
+        self.packages.insert(package, dependencies);
+    }
+
+    ///
+    /// Returns the directory of the dependency imported as `name` by the package containing `program_path`.
+    ///
+    /// The package containing a path is the innermost package directory it is located in.
+    ///
+    pub fn get(&self, program_path: &Path, name: &str) -> Option<&PathBuf> {
+        let program_path = program_path
+            .canonicalize()
+            .unwrap_or_else(|_| program_path.to_path_buf());
+
+        self.packages
+            .iter()
+            .filter(|(package, _)| program_path.starts_with(package))
+            .max_by_key(|(package, _)| package.components().count())
+            .and_then(|(_, dependencies)| dependencies.get(name))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+}
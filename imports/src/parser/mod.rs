@@ -23,3 +23,9 @@ pub use self::import_parser::*;
 
 pub mod parse_package;
 pub use self::parse_package::*;
+
+pub mod imports_map;
+pub use self::imports_map::*;
+
+pub mod parse_module;
+pub use self::parse_module::*;
@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::ImportParserError, ImportParser};
+use leo_asg::{AsgContext, Program, Span};
+use leo_ast::ModuleDeclaration;
+
+use std::path::Path;
+
+static SOURCE_FILE_EXTENSION: &str = ".leo";
+
+///
+/// A file of the package being compiled, declared with `mod name;`.
+///
+/// The module declared as `mod name;` in `dir/file.leo` is stored in `dir/name.leo` if `file.leo`
+/// is the main file, and in `dir/file/name.leo` otherwise.
+///
+#[derive(Clone, Debug)]
+pub struct Module {
+    /// The syntax tree of the module file.
+    pub ast: leo_ast::Program,
+
+    /// Whether the module can be imported from outside its parent module.
+    pub public: bool,
+}
+
+impl<'a> ImportParser<'a> {
+    ///
+    /// Parses the modules declared by the main file `program` stored in `directory`, and the modules they declare.
+    ///
+    /// Modules are addressed from the package root, e.g. `import math.geometry.Point;`,
+    /// and take precedence over packages of the same name.
+    ///
+    pub fn declare_modules(&mut self, directory: &Path, program: &leo_ast::Program) -> Result<(), ImportParserError> {
+        for declaration in program.modules.iter() {
+            self.declare_module(directory, None, declaration)?;
+        }
+
+        Ok(())
+    }
+
+    fn declare_module(
+        &mut self,
+        directory: &Path,
+        parent: Option<&str>,
+        declaration: &ModuleDeclaration,
+    ) -> Result<(), ImportParserError> {
+        let name = declaration.name.name.to_string();
+        let path = match parent {
+            Some(parent) => format!("{}.{}", parent, name),
+            None => name.clone(),
+        };
+        if self.modules.contains_key(&path) {
+            return Err(ImportParserError::duplicate_module(&path, &declaration.span));
+        }
+
+        let file_path = directory.join(format!("{}{}", name, SOURCE_FILE_EXTENSION));
+        if !self.source_exists(&file_path) {
+            return Err(ImportParserError::module_not_found(
+                &path,
+                &format!("{:?}", file_path.as_path()),
+                &declaration.span,
+            ));
+        }
+        let ast = self.parse_file(&file_path, path.clone(), &declaration.span)?;
+
+        let children = ast.modules.clone();
+        self.modules.insert(path.clone(), Module {
+            ast,
+            public: declaration.public,
+        });
+
+        let directory = directory.join(&name);
+        for declaration in children.iter() {
+            self.declare_module(&directory, Some(&path), declaration)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the ASG of the module at `segments`, converted with the imports of the whole package.
+    ///
+    /// Nested modules that are not `pub` can only be imported from within their parent module.
+    ///
+    pub(crate) fn parse_module(
+        &mut self,
+        context: AsgContext<'a>,
+        segments: &[&str],
+        span: &Span,
+    ) -> Result<Program<'a>, ImportParserError> {
+        let path = segments.join(".");
+        let module = match self.modules.get(&path) {
+            Some(module) => module.clone(),
+            None => return Err(ImportParserError::unknown_module(&path, span)),
+        };
+
+        let importer = self.module_stack.last().cloned().unwrap_or_default();
+        for end in 2..=segments.len() {
+            let parent = segments[..end - 1].join(".");
+            let nested = segments[..end].join(".");
+            let within_parent = importer == parent || importer.starts_with(&format!("{}.", parent));
+            if !self.modules[&nested].public && !within_parent {
+                return Err(ImportParserError::private_module(&nested, span));
+            }
+        }
+
+        self.module_stack.push(path);
+        let asg = leo_asg::Program::new(context, &module.ast, self);
+        self.module_stack.pop();
+
+        let mut asg = asg?;
+        asg.exports = Some(module.ast.public);
+
+        Ok(asg)
+    }
+}
@@ -34,7 +34,27 @@ impl<'a> ImportParser<'a> {
         if !remaining_segments.is_empty() {
             return self.parse_package(context, package.path(), remaining_segments, span);
         }
-        let program = Self::parse_import_file(package, span)?;
+        let program = self.parse_import_file(package, span)?;
+        let asg = leo_asg::Program::new(context, &program, self)?;
+
+        Ok(asg)
+    }
+
+    ///
+    /// Create the Leo syntax tree for a package declared in the manifest as `name`, installed at `directory`.
+    ///
+    pub(crate) fn parse_dependency(
+        &mut self,
+        context: AsgContext<'a>,
+        name: &str,
+        directory: PathBuf,
+        remaining_segments: &[&str],
+        span: &Span,
+    ) -> Result<Program<'a>, ImportParserError> {
+        if !remaining_segments.is_empty() {
+            return self.parse_package(context, directory, remaining_segments, span);
+        }
+        let program = self.parse_dependency_file(&directory, name, span)?;
         let asg = leo_asg::Program::new(context, &program, self)?;
 
         Ok(asg)
@@ -17,7 +17,7 @@
 use crate::{errors::ImportParserError, ImportParser};
 use leo_ast::{Program, Span};
 
-use std::fs::DirEntry;
+use std::{fs::DirEntry, path::Path};
 
 static MAIN_FILE: &str = "src/main.leo";
 
@@ -27,7 +27,7 @@ impl<'a> ImportParser<'a> {
     ///
     /// Builds an abstract syntax tree from the given file and then builds the Leo syntax tree.
     ///
-    pub(crate) fn parse_import_file(package: &DirEntry, span: &Span) -> Result<Program, ImportParserError> {
+    pub(crate) fn parse_import_file(&mut self, package: &DirEntry, span: &Span) -> Result<Program, ImportParserError> {
         // Get the package file type.
         let file_type = package
             .file_type()
@@ -41,7 +41,7 @@ impl<'a> ImportParser<'a> {
         if file_type.is_dir() {
             file_path.push(MAIN_FILE);
 
-            if !file_path.exists() {
+            if !self.source_exists(&file_path) {
                 return Err(ImportParserError::expected_main_file(
                     format!("{:?}", file_path.as_path()),
                     span,
@@ -49,13 +49,57 @@ impl<'a> ImportParser<'a> {
             }
         }
 
+        self.parse_file(&file_path, file_name, span)
+    }
+
+    ///
+    /// Returns a Leo syntax tree from the main file of the package declared in the manifest as `name`.
+    ///
+    pub(crate) fn parse_dependency_file(
+        &mut self,
+        directory: &Path,
+        name: &str,
+        span: &Span,
+    ) -> Result<Program, ImportParserError> {
+        let file_path = directory.join(MAIN_FILE);
+        if !self.source_exists(&file_path) {
+            return Err(ImportParserError::expected_main_file(
+                format!("{:?}", file_path.as_path()),
+                span,
+            ));
+        }
+
+        self.parse_file(&file_path, name.to_string(), span)
+    }
+
+    ///
+    /// Returns a Leo syntax tree from the file at `file_path` and records the file as resolved.
+    ///
+    pub(crate) fn parse_file(
+        &mut self,
+        file_path: &Path,
+        name: String,
+        span: &Span,
+    ) -> Result<Program, ImportParserError> {
         let file_path_str = file_path.to_str().unwrap_or_default();
+        self.files.insert(file_path.to_path_buf());
 
         // Build the package abstract syntax tree.
-        let program_string =
-            &std::fs::read_to_string(&file_path).map_err(|x| ImportParserError::io_error(span, file_path_str, x))?;
+        let program_string = match self.sources.get(file_path) {
+            Some(source) => source.clone(),
+            None => {
+                std::fs::read_to_string(file_path).map_err(|x| ImportParserError::io_error(span, file_path_str, x))?
+            }
+        };
         let mut ast = leo_parser::parse(&file_path_str, &program_string)?;
-        ast.name = file_name;
+        ast.name = name;
         Ok(ast)
     }
+
+    ///
+    /// Returns `true` if the file at `file_path` is held in memory or exists on the file system.
+    ///
+    pub(crate) fn source_exists(&self, file_path: &Path) -> bool {
+        self.sources.contains_key(file_path) || file_path.exists()
+    }
 }
@@ -20,7 +20,7 @@ use pest::Span;
 use pest_ast::FromPest;
 use std::fmt;
 
-#[derive(Clone, Debug, FromPest, PartialEq)]
+#[derive(Clone, Debug, FromPest, PartialEq, Eq)]
 #[pest_ast(rule(Rule::identifier))]
 pub struct Identifier<'ast> {
     #[pest_ast(outer(with(span_into_string)))]
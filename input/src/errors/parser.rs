@@ -135,6 +135,25 @@ impl InputParserError {
         Self::new_from_span(message, &span)
     }
 
+    pub fn enum_name_mismatch(expected: String, actual: String, span: &Span) -> Self {
+        let message = format!(
+            "expected a value of enum `{}`, found a value of enum `{}`",
+            expected, actual
+        );
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn enum_value_type(expression: Expression) -> Self {
+        let message = format!(
+            "the type of enum variant value `{}` cannot be inferred, give it an explicit type",
+            expression
+        );
+        let span = expression.span().to_owned();
+
+        Self::new_from_span(message, &span)
+    }
+
     pub fn array_inline_length(number: usize, array: ArrayInlineExpression) -> Self {
         let message = format!(
             "expected an array with a fixed size of {} elements, found one with {} elements",
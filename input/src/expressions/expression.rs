@@ -25,6 +25,7 @@ use std::fmt;
 pub enum Expression<'ast> {
     ArrayInitializer(ArrayInitializerExpression<'ast>),
     ArrayInline(ArrayInlineExpression<'ast>),
+    Enum(EnumExpression<'ast>),
     StringExpression(StringExpression<'ast>),
     Tuple(TupleExpression<'ast>),
     Value(Value<'ast>),
@@ -35,6 +36,7 @@ impl<'ast> Expression<'ast> {
         match self {
             Expression::ArrayInitializer(expression) => &expression.span,
             Expression::ArrayInline(expression) => &expression.span,
+            Expression::Enum(expression) => &expression.span,
             Expression::StringExpression(string) => &string.span,
             Expression::Tuple(tuple) => &tuple.span,
             Expression::Value(value) => value.span(),
@@ -58,6 +60,20 @@ impl<'ast> fmt::Display for Expression<'ast> {
 
                 write!(f, "array [{}]", values)
             }
+            Expression::Enum(ref enum_) => {
+                write!(f, "{}::{}", enum_.enum_name, enum_.variant)?;
+                if !enum_.expressions.is_empty() {
+                    let values = enum_
+                        .expressions
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+
+                    write!(f, "({})", values)?;
+                }
+                Ok(())
+            }
             Expression::StringExpression(ref string) => write!(f, "{}", string),
             Expression::Tuple(ref tuple) => {
                 let values = tuple
@@ -32,6 +32,9 @@ pub use char_type::*;
 pub mod data_type;
 pub use data_type::*;
 
+pub mod enum_type;
+pub use enum_type::*;
+
 pub mod field_type;
 pub use field_type::*;
 
@@ -25,6 +25,7 @@ pub enum Type<'ast> {
     Basic(DataType),
     Array(ArrayType<'ast>),
     Tuple(TupleType<'ast>),
+    Enum(EnumType<'ast>),
 }
 
 impl<'ast> fmt::Display for Type<'ast> {
@@ -33,6 +34,7 @@ impl<'ast> fmt::Display for Type<'ast> {
             Type::Basic(ref basic) => write!(f, "{}", basic),
             Type::Array(ref array) => write!(f, "{}", array),
             Type::Tuple(ref tuple) => write!(f, "{}", tuple),
+            Type::Enum(ref enum_) => write!(f, "{}", enum_),
         }
     }
 }
@@ -16,14 +16,25 @@
 
 use crate::{commands::Command, context::Context};
 use leo_compiler::{
-    compiler::{thread_leaked_context, Compiler},
+    compiler::{thread_leaked_context, CircuitShape, Compiler},
     group::targets::edwards_bls12::EdwardsGroupType,
     CompilerOptions,
     TheoremOptions,
+    DEFAULT_INLINE_LIMIT,
 };
 use leo_package::{
     inputs::*,
-    outputs::{ChecksumFile, CircuitFile, OutputsDirectory, OUTPUTS_DIRECTORY_NAME},
+    outputs::{
+        BinaryCircuitFile,
+        ChecksumFile,
+        CircuitFile,
+        OutputsDirectory,
+        ProfileFile,
+        ProvingKeyFile,
+        VerificationKeyFile,
+        OUTPUTS_DIRECTORY_NAME,
+    },
+    root::Manifest,
     source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
 use leo_synthesizer::{CircuitSynthesizer, SerializedCircuit};
@@ -44,6 +55,8 @@ pub struct BuildOptions {
     pub disable_code_elimination: bool,
     #[structopt(long, help = "Disable all compiler optimizations")]
     pub disable_all_optimizations: bool,
+    #[structopt(long, help = "Maximum depth of inlined function calls, bounds recursion")]
+    pub inline_limit: Option<u32>,
     #[structopt(long, help = "Writes all theorem input AST files.")]
     pub enable_all_theorems: bool,
     #[structopt(long, help = "Writes AST files needed for the initial theorem before any changes.")]
@@ -60,6 +73,7 @@ impl Default for BuildOptions {
             disable_constant_folding: true,
             disable_code_elimination: true,
             disable_all_optimizations: true,
+            inline_limit: None,
             enable_all_theorems: false,
             enable_initial_theorem: false,
             enable_canonicalized_theorem: false,
@@ -70,22 +84,34 @@ impl Default for BuildOptions {
 
 impl From<BuildOptions> for CompilerOptions {
     fn from(options: BuildOptions) -> Self {
-        if !options.disable_all_optimizations {
+        if options.disable_all_optimizations {
             CompilerOptions {
                 canonicalization_enabled: true,
-                constant_folding_enabled: true,
-                dead_code_elimination_enabled: true,
+                constant_folding_enabled: false,
+                dead_code_elimination_enabled: false,
+                inline_limit: options.inline_limit.unwrap_or(DEFAULT_INLINE_LIMIT),
             }
         } else {
             CompilerOptions {
                 canonicalization_enabled: true,
                 constant_folding_enabled: !options.disable_constant_folding,
                 dead_code_elimination_enabled: !options.disable_code_elimination,
+                inline_limit: options.inline_limit.unwrap_or(DEFAULT_INLINE_LIMIT),
             }
         }
     }
 }
 
+impl BuildOptions {
+    /// Fills in the options that were not given on the command line from the manifest.
+    pub(crate) fn with_manifest(mut self, manifest: &Manifest) -> Self {
+        if self.inline_limit.is_none() {
+            self.inline_limit = manifest.get_inline_limit();
+        }
+        self
+    }
+}
+
 impl From<BuildOptions> for TheoremOptions {
     fn from(options: BuildOptions) -> Self {
         if options.enable_all_theorems {
@@ -110,6 +136,9 @@ impl From<BuildOptions> for TheoremOptions {
 pub struct Build {
     #[structopt(flatten)]
     pub(crate) compiler_options: BuildOptions,
+
+    #[structopt(long, help = "Print the constraints of every function, loop iteration and statement")]
+    pub(crate) profile: bool,
 }
 
 impl Command for Build {
@@ -126,7 +155,9 @@ impl Command for Build {
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
         let path = context.dir()?;
-        let package_name = context.manifest()?.get_package_name();
+        let manifest = context.manifest()?;
+        let package_name = manifest.get_package_name();
+        let compiler_options = self.compiler_options.with_manifest(&manifest);
 
         // Sanitize the package path to the root directory.
         let mut package_path = path.clone();
@@ -159,6 +190,9 @@ impl Command for Build {
         // Load the state file at `package_name.in`
         let (state_string, state_path) = StateFile::new(&package_name).read_from(&path)?;
 
+        // Resolve the dependencies declared in the manifest
+        let imports_map = context.imports_map()?;
+
         // Log compilation of files to console
         tracing::info!("Compiling main program... ({:?})", main_file_path);
 
@@ -167,49 +201,64 @@ impl Command for Build {
             package_name.clone(),
             main_file_path,
             output_directory,
+            imports_map,
             &input_string,
             &input_path,
             &state_string,
             &state_path,
             thread_leaked_context(),
-            Some(self.compiler_options.clone().into()),
-            Some(self.compiler_options.into()),
+            Some(compiler_options.clone().into()),
+            Some(compiler_options.into()),
         )?;
 
-        // Compute the current program checksum
-        let program_checksum = program.checksum()?;
-
         // Generate the program on the constraint system and verify correctness
-        {
-            let mut cs = CircuitSynthesizer::<Bls12_377> {
-                constraints: Default::default(),
-                public_variables: Default::default(),
-                private_variables: Default::default(),
-                namespaces: Default::default(),
-            };
+        let circuit_shape = {
+            let mut cs = CircuitSynthesizer::<Bls12_377>::default();
             let temporary_program = program.clone();
-            let output = temporary_program.compile_constraints(&mut cs)?;
+            let output = if self.profile {
+                let (output, profile) = temporary_program.compile_constraints_with_profile(&mut cs)?;
+
+                // Print where the constraints of the circuit come from
+                for line in profile.to_table().lines() {
+                    tracing::info!("{}", line);
+                }
+
+                // Write the profile to a folded stack file, to be rendered as a flamegraph
+                let profile_file = ProfileFile::new(&package_name);
+                profile_file.write_to(&path, profile.to_folded())?;
+
+                tracing::info!("Profile saved ({:?})", path);
+
+                output
+            } else {
+                temporary_program.compile_constraints(&mut cs)?
+            };
 
             tracing::debug!("Compiled output - {:#?}", output);
             tracing::info!("Number of constraints - {:#?}", cs.num_constraints());
 
+            let circuit_shape = CircuitShape {
+                num_constraints: cs.num_constraints(),
+                num_public_variables: cs.num_public_variables(),
+                num_private_variables: cs.num_private_variables(),
+            };
+
             // Serialize the circuit
             let circuit_object = SerializedCircuit::from(cs);
-            let json = circuit_object.to_json_string().unwrap();
-            // println!("json: {}", json);
 
             // Write serialized circuit to circuit `.json` file.
-            let circuit_file = CircuitFile::new(&package_name);
-            circuit_file.write_to(&path, json)?;
+            let json = circuit_object.to_json_string()?;
+            CircuitFile::new(&package_name).write_to(&path, json)?;
 
-            // Check that we can read the serialized circuit file
-            // let serialized = circuit_file.read_from(&package_path)?;
+            // Write serialized circuit to the compact binary `.circuit` file.
+            let bytes = circuit_object.to_bytes()?;
+            BinaryCircuitFile::new(&package_name).write_to(&path, &bytes)?;
 
-            // Deserialize the circuit
-            // let deserialized = SerializedCircuit::from_json_string(&serialized).unwrap();
-            // let _circuit_synthesizer = CircuitSynthesizer::<Bls12_377>::try_from(deserialized).unwrap();
-            // println!("deserialized {:?}", circuit_synthesizer.num_constraints());
-        }
+            circuit_shape
+        };
+
+        // Compute the checksum of the sources, options and circuit of this build
+        let program_checksum = program.checksum(Some(&circuit_shape))?;
 
         // If a checksum file exists, check if it differs from the new checksum
         let checksum_file = ChecksumFile::new(&package_name);
@@ -221,8 +270,12 @@ impl Command for Build {
             true
         };
 
-        // If checksum differs, compile the program
+        // If checksum differs, the keys in the output directory belong to another circuit
         if checksum_differs {
+            // Remove the stale keys, so that the next setup generates them again
+            ProvingKeyFile::new(&package_name).remove(&path)?;
+            VerificationKeyFile::new(&package_name).remove(&path)?;
+
             // Write the new checksum to the output directory
             checksum_file.write_to(&path, program_checksum)?;
 
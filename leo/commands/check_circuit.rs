@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_package::outputs::{BinaryCircuitFile, CircuitFile, CIRCUIT_FILE_EXTENSION};
+use leo_synthesizer::{CircuitSynthesizer, SerializedCircuit};
+
+use anyhow::{anyhow, Result};
+use snarkvm_curves::bls12_377::Bls12_377;
+use snarkvm_r1cs::ConstraintSystem;
+use std::{convert::TryFrom, fs, path::PathBuf};
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Reload a serialized circuit and check that its assignment satisfies every constraint
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct CheckCircuit {
+    #[structopt(
+        parse(from_os_str),
+        help = "Circuit file to check, `.json` or binary [default: the circuit of the last build]"
+    )]
+    pub(crate) file: Option<PathBuf>,
+}
+
+impl Command for CheckCircuit {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Checking")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let serialized = match self.file {
+            Some(file) => {
+                tracing::info!("Loading circuit... ({:?})", file);
+                let is_json = file.to_string_lossy().ends_with(CIRCUIT_FILE_EXTENSION);
+                let bytes = fs::read(&file).map_err(|_| anyhow!("Cannot read the circuit file {:?}", file))?;
+                match is_json {
+                    true => SerializedCircuit::from_json_string(&String::from_utf8(bytes)?)?,
+                    false => SerializedCircuit::from_bytes(&bytes)?,
+                }
+            }
+            None => {
+                let path = context.dir()?;
+                let package_name = context.manifest()?.get_package_name();
+
+                // Prefer the compact binary circuit, older builds only wrote the JSON one
+                let binary_circuit_file = BinaryCircuitFile::new(&package_name);
+                let circuit_file = CircuitFile::new(&package_name);
+                if binary_circuit_file.exists_at(&path) {
+                    tracing::info!("Loading circuit...");
+                    SerializedCircuit::from_bytes(&binary_circuit_file.read_from(&path)?)?
+                } else if circuit_file.exists_at(&path) {
+                    tracing::info!("Loading circuit...");
+                    SerializedCircuit::from_json_string(&circuit_file.read_from(&path)?)?
+                } else {
+                    return Err(anyhow!("Circuit not found. Use `leo build` to generate it first"));
+                }
+            }
+        };
+
+        let num_constraints = serialized.num_constraints;
+        let num_public_variables = serialized.num_public_variables;
+        let num_private_variables = serialized.num_private_variables;
+
+        let circuit = CircuitSynthesizer::<Bls12_377>::try_from(serialized)
+            .map_err(|error| anyhow!("Invalid field element in the circuit: {}", error))?;
+
+        // The recorded counts must match what was read back
+        if circuit.num_constraints() != num_constraints
+            || circuit.num_public_variables() != num_public_variables
+            || circuit.num_private_variables() != num_private_variables
+        {
+            return Err(anyhow!(
+                "The circuit is malformed, its recorded sizes do not match its contents"
+            ));
+        }
+
+        tracing::info!("Number of constraints - {:#?}", num_constraints);
+
+        match circuit.which_is_unsatisfied() {
+            None => tracing::info!("Circuit is satisfied"),
+            Some(index) => return Err(anyhow!("Constraint {} is not satisfied", index)),
+        }
+
+        Ok(())
+    }
+}
@@ -15,8 +15,16 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{commands::Command, context::Context};
-use leo_compiler::OutputFile;
-use leo_package::outputs::{ChecksumFile, CircuitFile, ProofFile, ProvingKeyFile, VerificationKeyFile};
+use leo_compiler::{OutputFile, PublicInputsFile};
+use leo_package::outputs::{
+    BinaryCircuitFile,
+    ChecksumFile,
+    CircuitFile,
+    ProfileFile,
+    ProofFile,
+    ProvingKeyFile,
+    VerificationKeyFile,
+};
 
 use anyhow::Result;
 use structopt::StructOpt;
@@ -46,12 +54,19 @@ impl Command for Clean {
         // Remove the checksum from the output directory
         ChecksumFile::new(&package_name).remove(&path)?;
 
-        // Remove the serialized circuit from the output directory
+        // Remove the serialized circuits from the output directory
         CircuitFile::new(&package_name).remove(&path)?;
+        BinaryCircuitFile::new(&package_name).remove(&path)?;
+
+        // Remove the constraint profile from the output directory
+        ProfileFile::new(&package_name).remove(&path)?;
 
         // Remove the program output file from the output directory
         OutputFile::new(&package_name).remove(&path)?;
 
+        // Remove the public inputs file from the output directory
+        PublicInputsFile::new(&package_name).remove(&path)?;
+
         // Remove the proving key from the output directory
         ProvingKeyFile::new(&package_name).remove(&path)?;
 
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_package::source::{SOURCE_DIRECTORY_NAME, SOURCE_FILE_EXTENSION};
+
+use anyhow::{anyhow, Result};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Format Leo code command
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Fmt {
+    #[structopt(long, help = "Exit with an error if a file is not formatted, without rewriting it")]
+    pub(crate) check: bool,
+}
+
+impl Command for Fmt {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Formatting")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Make sure we are in a package
+        context.manifest()?;
+
+        // Sanitize the package path to the root directory
+        let mut package_path = context.dir()?;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        let mut files = Vec::new();
+        source_files(&package_path.join(SOURCE_DIRECTORY_NAME), &mut files)?;
+        files.sort();
+
+        let mut unformatted = 0;
+        for file in files.iter() {
+            let source = fs::read_to_string(file)?;
+            let formatted = leo_parser::format(&file.to_string_lossy(), &source)?;
+            if formatted == source {
+                continue;
+            }
+
+            unformatted += 1;
+            match self.check {
+                true => tracing::warn!("{} is not formatted", file.display()),
+                false => {
+                    fs::write(file, formatted)?;
+                    tracing::info!("Formatted {}", file.display());
+                }
+            }
+        }
+
+        if self.check && unformatted > 0 {
+            return Err(anyhow!("{} of {} files are not formatted", unformatted, files.len()));
+        }
+
+        tracing::info!("{} files checked; {} unformatted;", files.len(), unformatted);
+
+        Ok(())
+    }
+}
+
+/// Collects the Leo files in `directory` and its module subdirectories.
+fn source_files(directory: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            source_files(&path, files)?;
+        } else if path.extension() == Some(SOURCE_FILE_EXTENSION.trim_start_matches('.').as_ref()) {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
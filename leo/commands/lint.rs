@@ -15,8 +15,19 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{commands::Command, context::Context};
+use leo_compiler::{
+    compiler::{thread_leaked_context, Compiler},
+    group::targets::edwards_bls12::EdwardsGroupType,
+    CompilerOptions,
+};
+use leo_linter::{LintConfig, Linter};
+use leo_package::{
+    outputs::OUTPUTS_DIRECTORY_NAME,
+    source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use snarkvm_curves::edwards_bls12::Fq;
 use structopt::StructOpt;
 use tracing::span::Span;
 
@@ -37,7 +48,68 @@ impl Command for Lint {
         Ok(())
     }
 
-    fn apply(self, _: Context, _: Self::Input) -> Result<Self::Output> {
-        unimplemented!("Lint command has not been implemented yet");
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let manifest = context.manifest()?;
+        let package_name = manifest.get_package_name();
+
+        // Sanitize the package path to the root directory
+        let mut package_path = context.dir()?;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        if !MainFile::exists_at(&package_path) {
+            return Err(anyhow!("File main.leo not found in src/ directory"));
+        }
+
+        // Load the rule levels from the `[lint]` section of the manifest
+        let linter = Linter::new(LintConfig::from_pairs(manifest.get_lint_levels())?)?;
+
+        let mut main_file_path = package_path.clone();
+        main_file_path.push(SOURCE_DIRECTORY_NAME);
+        main_file_path.push(MAIN_FILENAME);
+
+        let mut output_directory = package_path;
+        output_directory.push(OUTPUTS_DIRECTORY_NAME);
+
+        // Resolve the dependencies declared in the manifest
+        let imports_map = context.imports_map()?;
+
+        tracing::info!("Linting main program... ({:?})", main_file_path);
+
+        // Lint the program as written, optimizations would remove the code some rules look for
+        let program = Compiler::<Fq, EdwardsGroupType>::parse_program_without_input(
+            package_name,
+            main_file_path,
+            output_directory,
+            imports_map,
+            thread_leaked_context(),
+            Some(CompilerOptions {
+                canonicalization_enabled: true,
+                constant_folding_enabled: false,
+                dead_code_elimination_enabled: false,
+                ..Default::default()
+            }),
+            None,
+        )?;
+
+        let asg = program.asg().ok_or_else(|| anyhow!("Program ASG was not generated"))?;
+        let lints = linter.lint(program.ast(), asg);
+
+        let denied = lints.iter().filter(|lint| lint.is_denied()).count();
+        for lint in lints.iter() {
+            match lint.is_denied() {
+                true => tracing::error!("{}\n", lint),
+                false => tracing::warn!("{}\n", lint),
+            }
+        }
+
+        tracing::info!("{} warnings; {} errors;", lints.len() - denied, denied);
+
+        if denied > 0 {
+            return Err(anyhow!("Linting failed with {} errors", denied));
+        }
+
+        Ok(())
     }
 }
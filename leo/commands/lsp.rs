@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_imports::ImportsMap;
+use leo_lsp::LanguageServer;
+
+use anyhow::Result;
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Start the language server on stdin and stdout
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Lsp {}
+
+impl Command for Lsp {
+    type Input = ImportsMap;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Language server")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        // The dependencies of the package are resolved if the editor opened one
+        match context.manifest() {
+            Ok(_) => context.imports_map(),
+            Err(_) => Ok(ImportsMap::default()),
+        }
+    }
+
+    fn apply(self, _context: Context, imports_map: Self::Input) -> Result<Self::Output> {
+        LanguageServer::stdio(imports_map)?;
+        Ok(())
+    }
+}
@@ -24,18 +24,27 @@ use tracing::span::Span;
 pub mod build;
 pub use build::Build;
 
+pub mod check_circuit;
+pub use check_circuit::CheckCircuit;
+
 pub mod clean;
 pub use clean::Clean;
 
 pub mod deploy;
 pub use deploy::Deploy;
 
+pub mod fmt;
+pub use fmt::Fmt;
+
 pub mod init;
 pub use init::Init;
 
 pub mod lint;
 pub use lint::Lint;
 
+pub mod lsp;
+pub use lsp::Lsp;
+
 pub mod new;
 pub use new::New;
 
@@ -54,6 +63,9 @@ pub use test::Test;
 pub mod update;
 pub use update::{Automatic as UpdateAutomatic, Update};
 
+pub mod verify;
+pub use verify::Verify;
+
 pub mod watch;
 pub use watch::Watch;
 
@@ -20,7 +20,8 @@ use leo_package::imports::{ImportsDirectory, IMPORTS_DIRECTORY_NAME};
 use anyhow::{anyhow, Result};
 use std::{
     fs::{create_dir_all, File},
-    io::{Read, Write},
+    io::{Read, Seek, Write},
+    path::Path,
 };
 use structopt::StructOpt;
 use tracing::Span;
@@ -118,35 +119,42 @@ impl Command for Add {
             create_dir_all(&path)?;
         };
 
-        // Proceed to unzip and parse the fetched bytes.
-        let mut zip_archive = match zip::ZipArchive::new(reader) {
-            Ok(zip) => zip,
+        extract_package(reader, &path)?;
+
+        tracing::info!("Successfully added a package");
+
+        Ok(())
+    }
+}
+
+/// Unzip a package fetched from the Aleo Package Manager into the given directory.
+pub(crate) fn extract_package<R: Read + Seek>(reader: R, path: &Path) -> Result<()> {
+    // Proceed to unzip and parse the fetched bytes.
+    let mut zip_archive = match zip::ZipArchive::new(reader) {
+        Ok(zip) => zip,
+        Err(error) => return Err(anyhow!(error)),
+    };
+    for i in 0..zip_archive.len() {
+        let file = match zip_archive.by_index(i) {
+            Ok(file) => file,
             Err(error) => return Err(anyhow!(error)),
         };
-        for i in 0..zip_archive.len() {
-            let file = match zip_archive.by_index(i) {
-                Ok(file) => file,
-                Err(error) => return Err(anyhow!(error)),
-            };
 
-            let file_name = file.name();
+        let file_name = file.name();
 
-            let mut file_path = path.clone();
-            file_path.push(file_name);
+        let mut file_path = path.to_path_buf();
+        file_path.push(file_name);
 
-            if file_name.ends_with('/') {
-                create_dir_all(file_path)?;
-            } else {
-                if let Some(parent_directory) = path.parent() {
-                    create_dir_all(parent_directory)?;
-                }
-
-                File::create(file_path)?.write_all(&file.bytes().map(|e| e.unwrap()).collect::<Vec<u8>>())?;
+        if file_name.ends_with('/') {
+            create_dir_all(file_path)?;
+        } else {
+            if let Some(parent_directory) = path.parent() {
+                create_dir_all(parent_directory)?;
             }
-        }
-
-        tracing::info!("Successfully added a package");
 
-        Ok(())
+            File::create(file_path)?.write_all(&file.bytes().map(|e| e.unwrap()).collect::<Vec<u8>>())?;
+        }
     }
+
+    Ok(())
 }
@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::add::extract_package;
+use crate::{api::Fetch, commands::Command, context::Context};
+use leo_package::{
+    imports::{DependencyResolver, ImportsDirectory, IMPORTS_DIRECTORY_NAME},
+    root::{Dependency, LockFile, Manifest},
+};
+
+use anyhow::{anyhow, Result};
+use std::{
+    collections::HashSet,
+    convert::TryFrom,
+    fs,
+    path::{Path, PathBuf},
+};
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Fetch the dependencies declared in the manifest and lock their versions
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Install {}
+
+impl Install {
+    /// Fetch the registry dependencies of the package at `directory` and of its dependencies
+    fn install(
+        context: &Context,
+        root: &Path,
+        directory: &Path,
+        lock_file: Option<&LockFile>,
+        visited: &mut HashSet<PathBuf>,
+    ) -> Result<()> {
+        let directory = directory.canonicalize()?;
+        if !visited.insert(directory.clone()) {
+            return Ok(());
+        }
+
+        let manifest = Manifest::try_from(directory.as_path())?;
+        for (name, dependency) in manifest.get_package_dependencies() {
+            let dependency_directory = match dependency {
+                Dependency { path: Some(path), .. } => directory.join(path),
+                Dependency {
+                    author: Some(author),
+                    package: Some(package),
+                    version,
+                    ..
+                } => {
+                    // Locked versions take precedence over fetching the latest version.
+                    let version = version.or_else(|| {
+                        lock_file
+                            .and_then(|lock_file| lock_file.find(&author, &package))
+                            .map(|locked| locked.version.clone())
+                    });
+
+                    match version {
+                        Some(version)
+                            if DependencyResolver::install_directory(root, &author, &package, &version).exists() =>
+                        {
+                            DependencyResolver::install_directory(root, &author, &package, &version)
+                        }
+                        version => Self::fetch(context, root, author, package, version)?,
+                    }
+                }
+                _ => {
+                    return Err(anyhow!(
+                        "dependency `{}` must declare either a `path` or an `author` and a `package`",
+                        name
+                    ))
+                }
+            };
+
+            Self::install(context, root, &dependency_directory, lock_file, visited)?;
+        }
+
+        Ok(())
+    }
+
+    /// Fetch a package from the Aleo Package Manager into `imports/author-package@version`
+    fn fetch(
+        context: &Context,
+        root: &Path,
+        author: String,
+        package: String,
+        version: Option<String>,
+    ) -> Result<PathBuf> {
+        tracing::info!("Fetching {}/{}", author, package);
+
+        let reader = {
+            let fetch = Fetch {
+                author: author.clone(),
+                package_name: package.clone(),
+                version,
+            };
+            let bytes = context.api.run_route(fetch)?.bytes()?;
+            std::io::Cursor::new(bytes)
+        };
+
+        // The fetched version is only known once the package manifest is unpacked.
+        ImportsDirectory::create(root)?;
+        let staging = root
+            .join(IMPORTS_DIRECTORY_NAME)
+            .join(format!(".{}-{}", author, package));
+        if staging.exists() {
+            fs::remove_dir_all(&staging)?;
+        }
+        fs::create_dir_all(&staging)?;
+        extract_package(reader, &staging)?;
+
+        let manifest = Manifest::try_from(staging.as_path())?;
+        let directory = DependencyResolver::install_directory(root, &author, &package, &manifest.get_package_version());
+        if directory.exists() {
+            fs::remove_dir_all(&directory)?;
+        }
+        fs::rename(&staging, &directory)?;
+
+        Ok(directory)
+    }
+}
+
+impl Command for Install {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Installing")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let root = context.dir()?;
+        let lock_file = context.lock_file()?;
+
+        Self::install(&context, &root, &root, lock_file.as_ref(), &mut HashSet::new())?;
+
+        // Check the installed packages against the lock file and pin their versions.
+        context.imports_map()?;
+
+        tracing::info!("Successfully installed dependencies");
+
+        Ok(())
+    }
+}
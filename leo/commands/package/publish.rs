@@ -37,6 +37,7 @@ impl Command for Publish {
     fn prelude(&self, context: Context) -> Result<Self::Input> {
         (Build {
             compiler_options: Default::default(),
+            profile: false,
         })
         .execute(context)
     }
@@ -14,13 +14,25 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use super::{build::BuildOptions, prove::Prove};
+use super::{build::BuildOptions, prove::Prove, verify::read_public_inputs};
 use crate::{commands::Command, context::Context};
-use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
+use leo_compiler::{
+    compiler::{thread_leaked_context, Compiler},
+    group::targets::edwards_bls12::EdwardsGroupType,
+    OutputFile,
+};
+use leo_package::{
+    inputs::*,
+    outputs::{OutputsDirectory, OUTPUTS_DIRECTORY_NAME},
+    source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use snarkvm_algorithms::{snark::groth16::Groth16, traits::SNARK};
-use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+use snarkvm_curves::{
+    bls12_377::{Bls12_377, Fr},
+    edwards_bls12::Fq,
+};
 use structopt::StructOpt;
 use tracing::span::Span;
 
@@ -31,12 +43,18 @@ pub struct Run {
     #[structopt(long = "skip-key-check", help = "Skip key verification on Setup stage")]
     pub(crate) skip_key_check: bool,
 
+    #[structopt(
+        long,
+        help = "Evaluate the program on its inputs without generating constraints or a proof"
+    )]
+    pub(crate) interpret: bool,
+
     #[structopt(flatten)]
     pub(crate) compiler_options: BuildOptions,
 }
 
 impl Command for Run {
-    type Input = <Prove as Command>::Output;
+    type Input = Option<<Prove as Command>::Output>;
     type Output = ();
 
     fn log_span(&self) -> Span {
@@ -44,22 +62,35 @@ impl Command for Run {
     }
 
     fn prelude(&self, context: Context) -> Result<Self::Input> {
+        if self.interpret {
+            return Ok(None);
+        }
+
         (Prove {
             skip_key_check: self.skip_key_check,
             compiler_options: self.compiler_options.clone(),
         })
         .execute(context)
+        .map(Some)
     }
 
-    fn apply(self, _context: Context, input: Self::Input) -> Result<Self::Output> {
-        let (proof, prepared_verifying_key) = input;
+    fn apply(self, context: Context, input: Self::Input) -> Result<Self::Output> {
+        let (proof, prepared_verifying_key) = match input {
+            Some(input) => input,
+            None => return self.interpret(context),
+        };
+
+        // Read the public inputs collected while proving
+        let path = context.dir()?;
+        let package_name = context.manifest()?.get_package_name();
+        let public_inputs = read_public_inputs(&path, &package_name)?;
 
         tracing::info!("Starting...");
 
         // Run the verifier
         let is_success = Groth16::<Bls12_377, Compiler<Fr, EdwardsGroupType>, Vec<Fr>>::verify(
             &prepared_verifying_key,
-            &vec![],
+            &public_inputs,
             &proof,
         )?;
 
@@ -72,3 +103,60 @@ impl Command for Run {
         Ok(())
     }
 }
+
+impl Run {
+    /// Evaluates the main function on the package inputs without proving it.
+    fn interpret(self, context: Context) -> Result<()> {
+        let path = context.dir()?;
+        let manifest = context.manifest()?;
+        let package_name = manifest.get_package_name();
+        let compiler_options = self.compiler_options.with_manifest(&manifest);
+
+        // Sanitize the package path to the root directory
+        let mut package_path = path.clone();
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        if !MainFile::exists_at(&package_path) {
+            return Err(anyhow!("File main.leo not found in src/ directory"));
+        }
+
+        // Construct the path to the output directory and create it
+        let mut output_directory = package_path.clone();
+        output_directory.push(OUTPUTS_DIRECTORY_NAME);
+        OutputsDirectory::create(&package_path)?;
+
+        let mut main_file_path = package_path;
+        main_file_path.push(SOURCE_DIRECTORY_NAME);
+        main_file_path.push(MAIN_FILENAME);
+
+        let (input_string, input_path) = InputFile::new(&package_name).read_from(&path)?;
+        let (state_string, state_path) = StateFile::new(&package_name).read_from(&path)?;
+
+        tracing::info!("Interpreting main program... ({:?})", main_file_path);
+
+        let program = Compiler::<Fq, EdwardsGroupType>::parse_program_with_input(
+            package_name.clone(),
+            main_file_path,
+            output_directory.clone(),
+            context.imports_map()?,
+            &input_string,
+            &input_path,
+            &state_string,
+            &state_path,
+            thread_leaked_context(),
+            Some(compiler_options.clone().into()),
+            Some(compiler_options.into()),
+        )?;
+
+        let output = program.interpret()?;
+
+        // Write the output registers the same way a proven run does
+        OutputFile::new(&package_name).write(&output_directory, output.to_string().as_bytes())?;
+
+        tracing::info!("Output {}", output);
+
+        Ok(())
+    }
+}
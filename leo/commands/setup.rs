@@ -56,6 +56,7 @@ impl Command for Setup {
     fn prelude(&self, context: Context) -> Result<Self::Input> {
         (Build {
             compiler_options: self.compiler_options.clone(),
+            profile: false,
         })
         .execute(context)
     }
@@ -39,6 +39,9 @@ pub struct Test {
     #[structopt(short = "f", long = "file", name = "file")]
     pub(crate) files: Vec<PathBuf>,
 
+    #[structopt(long, help = "Evaluate the tests without generating constraints")]
+    pub(crate) interpret: bool,
+
     #[structopt(flatten)]
     pub(crate) compiler_options: BuildOptions,
 }
@@ -57,7 +60,9 @@ impl Command for Test {
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
         // Get the package name
-        let package_name = context.manifest()?.get_package_name();
+        let manifest = context.manifest()?;
+        let package_name = manifest.get_package_name();
+        let compiler_options = self.compiler_options.clone().with_manifest(&manifest);
 
         // Sanitize the package path to the root directory
         let mut package_path = context.dir()?;
@@ -93,6 +98,9 @@ impl Command for Test {
         // Create the output directory
         OutputsDirectory::create(&package_path)?;
 
+        // Resolve the dependencies declared in the manifest
+        let imports_map = context.imports_map()?;
+
         // Finally test every passed file
         for file_path in to_test {
             tracing::info!("Running tests in file {:?}", file_path);
@@ -110,13 +118,17 @@ impl Command for Test {
                 package_name.clone(),
                 file_path,
                 output_directory.clone(),
+                imports_map.clone(),
                 thread_leaked_context(),
-                Some(self.compiler_options.clone().into()),
-                Some(self.compiler_options.clone().into()),
+                Some(compiler_options.clone().into()),
+                Some(compiler_options.clone().into()),
             )?;
 
-            let temporary_program = program;
-            let (passed, failed) = temporary_program.compile_test_constraints(input_pairs)?;
+            let (passed, failed) = if self.interpret {
+                program.interpret_tests(input_pairs)?
+            } else {
+                program.compile_test_constraints(input_pairs)?
+            };
             let time_taken = timer.elapsed().as_millis();
 
             if failed == 0 {
@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType, PublicInputsFile};
+use leo_package::outputs::{ProofFile, VerificationKeyFile};
+
+use snarkvm_algorithms::{
+    snark::groth16::{Groth16, PreparedVerifyingKey, Proof, VerifyingKey},
+    traits::SNARK,
+};
+use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+
+use anyhow::{anyhow, Result};
+use std::path::Path;
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Verify a previously generated proof without rebuilding the program
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Verify {}
+
+impl Command for Verify {
+    type Input = ();
+    type Output = bool;
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Verifying")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let path = context.dir()?;
+        let package_name = context.manifest()?.get_package_name();
+
+        // Read the verification key file from the output directory
+        let verification_key_file = VerificationKeyFile::new(&package_name);
+        if !verification_key_file.exists_at(&path) {
+            return Err(anyhow!(
+                "Verification key not found. Use `leo setup` to generate it first"
+            ));
+        }
+
+        tracing::info!("Loading verification key...");
+        let verifying_key_bytes = verification_key_file.read_from(&path)?;
+        let verifying_key = VerifyingKey::<Bls12_377>::read(verifying_key_bytes.as_slice())?;
+        let prepared_verifying_key = PreparedVerifyingKey::<Bls12_377>::from(verifying_key);
+
+        // Read the proof file from the output directory
+        let proof_file = ProofFile::new(&package_name);
+        if !proof_file.exists_at(&path) {
+            return Err(anyhow!("Proof not found. Use `leo prove` to generate it first"));
+        }
+
+        tracing::info!("Loading proof...");
+        let proof_bytes = proof_file.read_from(&path)?;
+        let proof = Proof::<Bls12_377>::read(proof_bytes.as_slice())?;
+
+        // Read the public inputs the proof was generated with
+        let public_inputs = read_public_inputs(&path, &package_name)?;
+
+        tracing::info!("Starting...");
+
+        // Run the verifier
+        let is_success = Groth16::<Bls12_377, Compiler<Fr, EdwardsGroupType>, Vec<Fr>>::verify(
+            &prepared_verifying_key,
+            &public_inputs,
+            &proof,
+        )?;
+
+        match is_success {
+            true => tracing::info!("Proof is valid"),
+            false => return Err(anyhow!("Proof is invalid")),
+        };
+
+        Ok(is_success)
+    }
+}
+
+/// Reads the public inputs written to the output directory by the last proof.
+pub(crate) fn read_public_inputs(path: &Path, package_name: &str) -> Result<Vec<Fr>> {
+    let public_inputs_file = PublicInputsFile::new(package_name);
+    if !public_inputs_file.exists_at(path) {
+        return Err(anyhow!(
+            "Public inputs not found. Use `leo prove` to generate them first"
+        ));
+    }
+
+    Ok(public_inputs_file.read_values(path)?)
+}
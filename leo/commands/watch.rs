@@ -69,6 +69,7 @@ impl Command for Watch {
                 Ok(DebouncedEvent::Write(_write)) => {
                     match (Build {
                         compiler_options: self.compiler_options.clone(),
+                        profile: false,
                     })
                     .execute(context.clone())
                     {
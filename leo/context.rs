@@ -15,7 +15,11 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{api::Api, config};
-use leo_package::root::Manifest;
+use leo_imports::ImportsMap;
+use leo_package::{
+    imports::DependencyResolver,
+    root::{LockFile, Manifest},
+};
 
 use anyhow::Result;
 use std::{convert::TryFrom, env::current_dir, path::PathBuf};
@@ -45,6 +49,40 @@ impl Context {
     pub fn manifest(&self) -> Result<Manifest> {
         Ok(Manifest::try_from(self.dir()?.as_path())?)
     }
+
+    /// Get the lock file for current context, if there is one
+    pub fn lock_file(&self) -> Result<Option<LockFile>> {
+        let path = self.dir()?;
+        match LockFile::exists_at(&path) {
+            true => Ok(Some(LockFile::read_from(&path)?)),
+            false => Ok(None),
+        }
+    }
+
+    /// Resolve the dependencies declared in the manifest, update `Leo.lock`
+    /// and return the directories their imports are read from
+    pub fn imports_map(&self) -> Result<ImportsMap> {
+        let path = self.dir()?;
+        let lock_file = self.lock_file()?;
+        let resolution = DependencyResolver::resolve(&path, lock_file.as_ref())?;
+
+        // Packages without dependencies don't need a lock file.
+        let resolved_lock_file = resolution.lock_file();
+        let up_to_date = match &lock_file {
+            Some(lock_file) => lock_file == &resolved_lock_file,
+            None => resolved_lock_file.packages.is_empty(),
+        };
+        if !up_to_date {
+            resolved_lock_file.write_to(&path)?;
+        }
+
+        let mut imports_map = ImportsMap::default();
+        for (directory, dependencies) in resolution.imports() {
+            imports_map.insert(directory.clone(), dependencies.clone().into_iter().collect());
+        }
+
+        Ok(imports_map)
+    }
 }
 
 /// Create a new context for the current directory.
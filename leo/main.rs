@@ -22,19 +22,23 @@ pub mod logger;
 pub mod updater;
 
 use commands::{
-    package::{Add, Clone, Login, Logout, Publish, Remove},
+    package::{Add, Clone, Install, Login, Logout, Publish, Remove},
     Build,
+    CheckCircuit,
     Clean,
     Command,
     Deploy,
+    Fmt,
     Init,
     Lint,
+    Lsp,
     New,
     Prove,
     Run,
     Setup,
     Test,
     Update,
+    Verify,
     Watch,
 };
 
@@ -107,6 +111,18 @@ enum CommandOpts {
         command: Run,
     },
 
+    #[structopt(about = "Verify a saved proof against the saved verification key")]
+    Verify {
+        #[structopt(flatten)]
+        command: Verify,
+    },
+
+    #[structopt(about = "Check that a serialized circuit is satisfied by its recorded assignment")]
+    CheckCircuit {
+        #[structopt(flatten)]
+        command: CheckCircuit,
+    },
+
     #[structopt(about = "Clean the output directory")]
     Clean {
         #[structopt(flatten)]
@@ -143,6 +159,12 @@ enum CommandOpts {
         command: Clone,
     },
 
+    #[structopt(about = "Fetch the dependencies declared in the manifest")]
+    Install {
+        #[structopt(flatten)]
+        command: Install,
+    },
+
     #[structopt(about = "Login to the Aleo Package Manager")]
     Login {
         #[structopt(flatten)]
@@ -167,12 +189,24 @@ enum CommandOpts {
         command: Remove,
     },
 
-    #[structopt(about = "Lints the Leo files in the package (*)")]
+    #[structopt(about = "Lints the Leo files in the package")]
     Lint {
         #[structopt(flatten)]
         command: Lint,
     },
 
+    #[structopt(about = "Starts a language server for editors on stdin and stdout")]
+    Lsp {
+        #[structopt(flatten)]
+        command: Lsp,
+    },
+
+    #[structopt(about = "Formats the Leo files in the package")]
+    Fmt {
+        #[structopt(flatten)]
+        command: Fmt,
+    },
+
     #[structopt(about = "Deploy the current package as a program to the network (*)")]
     Deploy {
         #[structopt(flatten)]
@@ -186,7 +220,8 @@ fn main() {
 
 /// Run command with custom build arguments.
 fn run_with_args(opt: Opt) -> Result<(), Error> {
-    if !opt.quiet {
+    // The language server speaks its protocol on stdout, where the logger writes.
+    if !opt.quiet && !matches!(opt.command, CommandOpts::Lsp { .. }) {
         // Init logger with optional debug flag.
         logger::init_logger("leo", match opt.debug {
             false => 1,
@@ -209,18 +244,23 @@ fn run_with_args(opt: Opt) -> Result<(), Error> {
         CommandOpts::Prove { command } => command.try_execute(context),
         CommandOpts::Test { command } => command.try_execute(context),
         CommandOpts::Run { command } => command.try_execute(context),
+        CommandOpts::Verify { command } => command.try_execute(context),
+        CommandOpts::CheckCircuit { command } => command.try_execute(context),
         CommandOpts::Clean { command } => command.try_execute(context),
         CommandOpts::Watch { command } => command.try_execute(context),
         CommandOpts::Update { command } => command.try_execute(context),
 
         CommandOpts::Add { command } => command.try_execute(context),
         CommandOpts::Clone { command } => command.try_execute(context),
+        CommandOpts::Install { command } => command.try_execute(context),
         CommandOpts::Login { command } => command.try_execute(context),
         CommandOpts::Logout { command } => command.try_execute(context),
         CommandOpts::Publish { command } => command.try_execute(context),
         CommandOpts::Remove { command } => command.try_execute(context),
 
         CommandOpts::Lint { command } => command.try_execute(context),
+        CommandOpts::Fmt { command } => command.try_execute(context),
+        CommandOpts::Lsp { command } => command.try_execute(context),
         CommandOpts::Deploy { command } => command.try_execute(context),
     }
 }
@@ -315,10 +355,38 @@ mod cli_tests {
     #[test]
     #[should_panic]
     fn unimplemented() {
-        assert!(run_cmd("leo lint", &None).is_err());
         assert!(run_cmd("leo deploy", &None).is_err());
     }
 
+    #[test]
+    fn lint() {
+        let path = &Some(PathBuf::from("examples/pedersen-hash"));
+
+        assert!(run_cmd("leo lint", path).is_ok());
+        assert!(run_cmd("leo lint", &None).is_err());
+    }
+
+    #[test]
+    fn fmt() {
+        let dir = testdir("fmt");
+        let path = dir.path("fmt");
+
+        assert!(run_cmd("leo new fmt-test", &Some(path.clone())).is_ok());
+
+        let package_path = &Some(path.join("fmt-test"));
+        let main_path = path.join("fmt-test/src/main.leo");
+        std::fs::write(&main_path, "function main(a:u32,b:u32)->u32{return a+b; // sum\n}").unwrap();
+
+        assert!(run_cmd("leo fmt --check", package_path).is_err());
+        assert!(run_cmd("leo fmt", package_path).is_ok());
+        assert!(run_cmd("leo fmt --check", package_path).is_ok());
+        assert_eq!(
+            std::fs::read_to_string(&main_path).unwrap(),
+            "function main(a: u32, b: u32) -> u32 {\n    return a + b; // sum\n}\n"
+        );
+        assert!(run_cmd("leo fmt", &None).is_err());
+    }
+
     #[test]
     fn clean() {
         let path = &Some(PathBuf::from("examples/pedersen-hash"));
@@ -339,6 +407,27 @@ mod cli_tests {
         assert!(run_cmd("leo build --disable-all-optimizations", build_path).is_ok());
         assert!(run_cmd("leo build --disable-code-elimination", build_path).is_ok());
         assert!(run_cmd("leo build --disable-constant-folding", build_path).is_ok());
+        assert!(run_cmd("leo build --inline-limit 8", build_path).is_ok());
+    }
+
+    #[test]
+    fn build_profile() {
+        let dir = testdir("test");
+        let path = dir.path("test");
+
+        assert!(run_cmd("leo new profile", &Some(path.clone())).is_ok());
+
+        let package_path = path.join("profile");
+        let package = &Some(package_path.clone());
+
+        assert!(run_cmd("leo build --profile", package).is_ok());
+
+        let folded = std::fs::read_to_string(package_path.join("outputs/profile.folded")).unwrap();
+        assert!(folded.contains("function main (main.leo:2:1);"));
+        assert!(folded.contains(";definition statement (main.leo:3:5) "));
+
+        assert!(run_cmd("leo clean", package).is_ok());
+        assert!(!package_path.join("outputs/profile.folded").exists());
     }
 
     #[test]
@@ -350,12 +439,139 @@ mod cli_tests {
 
         let setup_path = &Some(path.join("setup-test"));
 
+        assert!(run_cmd("leo verify", setup_path).is_err());
         assert!(run_cmd("leo setup", setup_path).is_ok());
         assert!(run_cmd("leo setup", setup_path).is_ok());
         assert!(run_cmd("leo setup --skip-key-check", setup_path).is_ok());
         assert!(run_cmd("leo prove --skip-key-check", setup_path).is_ok());
         assert!(run_cmd("leo run --skip-key-check", setup_path).is_ok());
+        assert!(run_cmd("leo verify", setup_path).is_ok());
         assert!(run_cmd("leo clean", setup_path).is_ok());
+        assert!(run_cmd("leo verify", setup_path).is_err());
+    }
+
+    #[test]
+    fn run_and_test_interpreted() {
+        let dir = testdir("test");
+        let path = dir.path("test");
+
+        assert!(run_cmd("leo new interpret", &Some(path.clone())).is_ok());
+
+        let package_path = path.join("interpret");
+        let main = "function main(a: u32, b: u32) -> u32 {\n    return a + b;\n}\n\n\
+                    @test\nfunction test_add() {\n    console.assert(main(1, 2) == 3);\n}\n";
+        std::fs::write(package_path.join("src/main.leo"), main).unwrap();
+
+        let package = &Some(package_path.clone());
+
+        assert!(run_cmd("leo run --interpret", package).is_ok());
+
+        // The output is written without generating any keys or proofs
+        let output = std::fs::read_to_string(package_path.join("outputs/interpret.out")).unwrap();
+        assert!(output.contains("r0: u32 = 3;"));
+        assert!(!package_path.join("outputs/interpret.lpk").exists());
+
+        assert!(run_cmd("leo test --interpret", package).is_ok());
+
+        // Errors found while interpreting fail the command
+        let overflow = "function main(a: u32, b: u32) -> u32 {\n    return a - b - 4;\n}\n";
+        std::fs::write(package_path.join("src/main.leo"), overflow).unwrap();
+
+        assert!(run_cmd("leo run --interpret", package).is_err());
+    }
+
+    #[test]
+    fn build_checksum() {
+        let dir = testdir("test");
+        let path = dir.path("test");
+
+        assert!(run_cmd("leo new checksum", &Some(path.clone())).is_ok());
+
+        let package_path = path.join("checksum");
+        let main =
+            "mod math;\n\nimport math.add;\n\nfunction main(a: u32, b: u32) -> u32 {\n    return add(a, b);\n}\n";
+        let math = "pub function add(a: u32, b: u32) -> u32 {\n    return a + b;\n}\n";
+        std::fs::write(package_path.join("src/main.leo"), main).unwrap();
+        std::fs::write(package_path.join("src/math.leo"), math).unwrap();
+
+        let package = &Some(package_path.clone());
+        let checksum = || std::fs::read_to_string(package_path.join("outputs/checksum.sum")).unwrap();
+        let proving_key_path = package_path.join("outputs/checksum.lpk");
+
+        assert!(run_cmd("leo setup", package).is_ok());
+        let initial = checksum();
+        assert!(proving_key_path.exists());
+
+        // The keys are kept as long as nothing the circuit is built from changes.
+        assert!(run_cmd("leo build", package).is_ok());
+        assert_eq!(checksum(), initial);
+        assert!(proving_key_path.exists());
+
+        // Editing a module invalidates the keys.
+        std::fs::write(package_path.join("src/math.leo"), math.replace("a + b", "b + a")).unwrap();
+        assert!(run_cmd("leo build", package).is_ok());
+        let edited = checksum();
+        assert_ne!(edited, initial);
+        assert!(!proving_key_path.exists());
+
+        // So do the input files and the compiler options.
+        let input_path = package_path.join("inputs/checksum.in");
+        let input = std::fs::read_to_string(&input_path).unwrap();
+        std::fs::write(&input_path, input.replace("a: u32 = 1;", "a: u32 = 3;")).unwrap();
+        assert!(run_cmd("leo build", package).is_ok());
+        let with_input = checksum();
+        assert_ne!(with_input, edited);
+
+        assert!(run_cmd("leo build --disable-constant-folding", package).is_ok());
+        assert_ne!(checksum(), with_input);
+
+        assert!(run_cmd("leo setup", package).is_ok());
+        assert!(proving_key_path.exists());
+    }
+
+    #[test]
+    fn verify_public_inputs() {
+        let dir = testdir("test");
+        let path = dir.path("test");
+
+        assert!(run_cmd("leo new public-inputs", &Some(path.clone())).is_ok());
+
+        let package_path = path.join("public-inputs");
+        let program = "function main(a: u32, b: u32) -> u32 {\n    return a + b + input.registers.r0;\n}\n";
+        std::fs::write(package_path.join("src/main.leo"), program).unwrap();
+
+        let package = &Some(package_path.clone());
+
+        assert!(run_cmd("leo run", package).is_ok());
+        assert!(run_cmd("leo verify", package).is_ok());
+
+        // The proof must not verify against different public inputs.
+        let public_inputs_path = package_path.join("outputs/public-inputs.public");
+        let public_inputs = std::fs::read_to_string(&public_inputs_path).unwrap();
+        assert!(!public_inputs.is_empty());
+        std::fs::write(&public_inputs_path, public_inputs.replacen('0', "1", 1)).unwrap();
+
+        assert!(run_cmd("leo verify", package).is_err());
+    }
+
+    #[test]
+    fn verify_large_field_public_inputs() {
+        let dir = testdir("test");
+        let path = dir.path("test");
+
+        assert!(run_cmd("leo new large-field", &Some(path.clone())).is_ok());
+
+        let package_path = path.join("large-field");
+        let program = "function main(a: field) -> field {\n    return a + input.registers.r0;\n}\n";
+        let input = "[main]\na: field = 1;\n\n[registers]\nr0: field = 340282366920938463463374607431768211457;\n";
+        std::fs::write(package_path.join("src/main.leo"), program).unwrap();
+        std::fs::write(package_path.join("inputs/large-field.in"), input).unwrap();
+
+        let package = &Some(package_path);
+
+        // Public inputs wider than one 64-bit limb must read back unchanged.
+        assert!(run_cmd("leo run", package).is_ok());
+        assert!(run_cmd("leo verify", package).is_ok());
     }
 
     #[test]
@@ -375,6 +591,41 @@ mod cli_tests {
         assert!(run_cmd("leo remove u8u32", import_path).is_err());
     }
 
+    #[test]
+    fn test_path_dependency() {
+        let dir = testdir("test");
+        let path = dir.path("test");
+
+        assert!(run_cmd("leo new app", &Some(path.clone())).is_ok());
+        assert!(run_cmd("leo new math", &Some(path.clone())).is_ok());
+
+        let app_path = path.join("app");
+        let math_path = path.join("math");
+
+        let library = "function double(a: u32) -> u32 {\n    return a + a;\n}\n";
+        std::fs::write(math_path.join("src/main.leo"), library).unwrap();
+
+        // Import the library under a different name than its package name.
+        let mut manifest = std::fs::read_to_string(app_path.join("Leo.toml")).unwrap();
+        manifest.push_str("\n[dependencies]\narith = { path = \"../math\" }\n");
+        std::fs::write(app_path.join("Leo.toml"), manifest).unwrap();
+
+        let program = "import arith.double;\n\nfunction main(a: u32, b: u32) -> u32 {\n    return double(a) + b;\n}\n";
+        std::fs::write(app_path.join("src/main.leo"), program).unwrap();
+
+        let app = &Some(app_path.clone());
+
+        assert!(run_cmd("leo build", app).is_ok());
+        assert!(app_path.join("Leo.lock").exists());
+
+        // A dependency that imports the package back is rejected.
+        let mut manifest = std::fs::read_to_string(math_path.join("Leo.toml")).unwrap();
+        manifest.push_str("\n[dependencies]\napp = { path = \"../app\" }\n");
+        std::fs::write(math_path.join("Leo.toml"), manifest).unwrap();
+
+        assert!(run_cmd("leo build", app).is_err());
+    }
+
     #[test]
     fn test_missing_file() {
         let dir = testdir("test");
@@ -15,12 +15,14 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use anyhow::Result;
+use leo_synthesizer::{SerializedCircuit, SerializedField};
 use std::path::PathBuf;
 
 use crate::{
     commands::{
         package::{Login, Logout},
         Build,
+        CheckCircuit,
         Command,
         Prove,
         Run,
@@ -40,15 +42,46 @@ const PEDERSEN_HASH_PATH: &str = "./examples/pedersen-hash/";
 pub fn build_pedersen_hash() -> Result<()> {
     (Build {
         compiler_options: Default::default(),
+        profile: false,
     })
     .apply(context()?, ())?;
     Ok(())
 }
 
+#[test]
+pub fn check_circuit_pedersen_hash() -> Result<()> {
+    (Build {
+        compiler_options: Default::default(),
+        profile: false,
+    })
+    .apply(context()?, ())?;
+    (CheckCircuit { file: None }).apply(context()?, ())?;
+
+    // The JSON circuit is checked too, and an assignment that was tampered with fails
+    let mut json_file = PathBuf::from(PEDERSEN_HASH_PATH);
+    json_file.push("outputs/pedersen-hash.json");
+    (CheckCircuit {
+        file: Some(json_file.clone()),
+    })
+    .apply(context()?, ())?;
+
+    let mut circuit = SerializedCircuit::from_json_string(&std::fs::read_to_string(&json_file)?)?;
+    circuit.private_variables[0] = SerializedField("12345".to_string());
+    let tampered_file = std::env::temp_dir().join("pedersen-hash-tampered.circuit");
+    std::fs::write(&tampered_file, circuit.to_bytes()?)?;
+    let check = (CheckCircuit {
+        file: Some(tampered_file),
+    })
+    .apply(context()?, ());
+    assert!(check.is_err());
+    Ok(())
+}
+
 #[test]
 pub fn setup_pedersen_hash() -> Result<()> {
     let build = (Build {
         compiler_options: Default::default(),
+        profile: false,
     })
     .apply(context()?, ())?;
     (Setup {
@@ -68,6 +101,7 @@ pub fn setup_pedersen_hash() -> Result<()> {
 pub fn prove_pedersen_hash() -> Result<()> {
     let build = (Build {
         compiler_options: Default::default(),
+        profile: false,
     })
     .apply(context()?, ())?;
     let setup = (Setup {
@@ -92,6 +126,7 @@ pub fn prove_pedersen_hash() -> Result<()> {
 pub fn run_pedersen_hash() -> Result<()> {
     let build = (Build {
         compiler_options: Default::default(),
+        profile: false,
     })
     .apply(context()?, ())?;
     let setup = (Setup {
@@ -106,14 +141,22 @@ pub fn run_pedersen_hash() -> Result<()> {
     .apply(context()?, setup)?;
     (Run {
         skip_key_check: false,
+        interpret: false,
         compiler_options: Default::default(),
     })
-    .apply(context()?, prove.clone())?;
+    .apply(context()?, Some(prove.clone()))?;
     (Run {
         skip_key_check: true,
+        interpret: false,
+        compiler_options: Default::default(),
+    })
+    .apply(context()?, Some(prove))?;
+    (Run {
+        skip_key_check: false,
+        interpret: true,
         compiler_options: Default::default(),
     })
-    .apply(context()?, prove)?;
+    .apply(context()?, None)?;
     Ok(())
 }
 
@@ -124,11 +167,19 @@ pub fn test_pedersen_hash() -> Result<()> {
 
     (Test {
         files: vec![],
+        interpret: false,
+        compiler_options: Default::default(),
+    })
+    .apply(context()?, ())?;
+    (Test {
+        files: vec![main_file.clone()],
+        interpret: false,
         compiler_options: Default::default(),
     })
     .apply(context()?, ())?;
     (Test {
         files: vec![main_file],
+        interpret: true,
         compiler_options: Default::default(),
     })
     .apply(context()?, ())?;
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{LintError, LintLevel};
+
+use indexmap::IndexMap;
+
+/// Maps lint rule names to the level they are reported with.
+///
+/// Rules that are not configured use their default level.
+#[derive(Clone, Debug, Default)]
+pub struct LintConfig {
+    levels: IndexMap<String, LintLevel>,
+}
+
+impl LintConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Returns a new `LintConfig` from `rule = "level"` pairs, e.g. the `[lint]` section of `Leo.toml`.
+    ///
+    pub fn from_pairs<I, K, V>(pairs: I) -> Result<Self, LintError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut config = Self::new();
+        for (rule, level) in pairs {
+            let (rule, level) = (rule.as_ref(), level.as_ref());
+            let parsed = LintLevel::from_name(level)
+                .ok_or_else(|| LintError::InvalidLevel(rule.to_string(), level.to_string()))?;
+
+            config.set_level(rule, parsed);
+        }
+
+        Ok(config)
+    }
+
+    pub fn set_level(&mut self, rule: &str, level: LintLevel) {
+        self.levels.insert(rule.to_string(), level);
+    }
+
+    pub fn level(&self, rule: &str) -> Option<LintLevel> {
+        self.levels.get(rule).copied()
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = &str> {
+        self.levels.keys().map(|rule| rule.as_str())
+    }
+}
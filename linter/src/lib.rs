@@ -0,0 +1,40 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The linter for Leo programs.
+//!
+//! The [`Linter`] runs a set of [`LintRule`]s over the abstract semantic graph of a program
+//! and reports every finding as a [`Lint`] pointing at the offending source span.
+
+#![allow(clippy::module_inception)]
+
+#[macro_use]
+extern crate thiserror;
+
+pub mod config;
+pub use config::*;
+
+pub mod errors;
+pub use errors::*;
+
+pub mod lint;
+pub use lint::*;
+
+pub mod linter;
+pub use linter::*;
+
+pub mod rules;
+pub use rules::*;
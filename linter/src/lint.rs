@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{FormattedError, Span};
+
+use std::fmt;
+
+/// Specifies how the findings of a lint rule are reported.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LintLevel {
+    /// The rule is not run.
+    Allow,
+
+    /// Findings are reported as warnings.
+    Warn,
+
+    /// Findings are reported as errors and fail the lint run.
+    Deny,
+}
+
+impl LintLevel {
+    /// Returns the level with the given name as it is written in `Leo.toml`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "allow" => Some(LintLevel::Allow),
+            "warn" => Some(LintLevel::Warn),
+            "deny" => Some(LintLevel::Deny),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LintLevel::Allow => write!(f, "allow"),
+            LintLevel::Warn => write!(f, "warning"),
+            LintLevel::Deny => write!(f, "error"),
+        }
+    }
+}
+
+/// A single finding of a lint rule.
+#[derive(Clone, Debug)]
+pub struct Lint {
+    /// The name of the rule that reported this lint.
+    pub rule: &'static str,
+
+    /// The level the rule is configured with.
+    pub level: LintLevel,
+
+    /// The message and source location of the finding.
+    pub error: FormattedError,
+}
+
+impl Lint {
+    pub fn new(rule: &'static str, message: String, span: &Span) -> Self {
+        Self {
+            rule,
+            level: LintLevel::Warn,
+            error: FormattedError::new_from_span(message, span),
+        }
+    }
+
+    pub fn is_denied(&self) -> bool {
+        self.level == LintLevel::Deny
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}[{}]", self.level, self.rule)?;
+        write!(f, "{}", self.error)
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{rules::*, Lint, LintConfig, LintError, LintLevel};
+use leo_asg::Program;
+
+/// The parts of a program that lint rules inspect.
+pub struct LintContext<'a, 'b> {
+    /// The program AST, used for declarations that are not kept in the ASG, such as imports.
+    pub ast: &'b leo_ast::Program,
+
+    /// The program ASG.
+    pub asg: &'b Program<'a>,
+}
+
+/// Runs a set of lint rules over a program.
+pub struct Linter {
+    rules: Vec<Box<dyn LintRule>>,
+    config: LintConfig,
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Self {
+            rules: vec![
+                Box::new(UnusedVariables),
+                Box::new(UnusedImports),
+                Box::new(ShadowedBindings),
+                Box::new(ConstantConditions),
+                Box::new(RedundantCasts),
+                Box::new(UnusedMut),
+            ],
+            config: LintConfig::default(),
+        }
+    }
+}
+
+impl Linter {
+    ///
+    /// Returns a new `Linter` with all built-in rules and the given configuration.
+    ///
+    /// Returns an error if the configuration refers to a rule that does not exist.
+    ///
+    pub fn new(config: LintConfig) -> Result<Self, LintError> {
+        let mut linter = Self::default();
+        linter.set_config(config)?;
+
+        Ok(linter)
+    }
+
+    ///
+    /// Adds a rule to the linter.
+    ///
+    pub fn add_rule<R: LintRule + 'static>(&mut self, rule: R) {
+        self.rules.push(Box::new(rule));
+    }
+
+    ///
+    /// Replaces the rule configuration of the linter.
+    ///
+    pub fn set_config(&mut self, config: LintConfig) -> Result<(), LintError> {
+        if let Some(unknown) = config
+            .rules()
+            .find(|name| !self.rules.iter().any(|rule| rule.name() == *name))
+        {
+            return Err(LintError::UnknownRule(unknown.to_string()));
+        }
+        self.config = config;
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the names of all rules known to the linter.
+    ///
+    pub fn rule_names(&self) -> Vec<&'static str> {
+        self.rules.iter().map(|rule| rule.name()).collect()
+    }
+
+    ///
+    /// Runs every rule that is not allowed over the program and returns the findings in source order.
+    ///
+    pub fn lint<'a>(&self, ast: &leo_ast::Program, asg: &Program<'a>) -> Vec<Lint> {
+        let context = LintContext { ast, asg };
+
+        let mut lints = vec![];
+        for rule in self.rules.iter() {
+            let level = self.config.level(rule.name()).unwrap_or_else(|| rule.default_level());
+            if level == LintLevel::Allow {
+                continue;
+            }
+
+            let mut found = vec![];
+            rule.check(&context, &mut found);
+            lints.extend(found.into_iter().map(|mut lint| {
+                lint.level = level;
+                lint
+            }));
+        }
+
+        lints.sort_by(|a, b| {
+            (&a.error.path, a.error.line_start, a.error.col_start).cmp(&(
+                &b.error.path,
+                b.error.line_start,
+                b.error.col_start,
+            ))
+        });
+        // Generic functions and circuits are checked once per instance, which repeats their findings.
+        lints.dedup_by(|a, b| a.rule == b.rule && a.error == b.error);
+
+        lints
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{rules::visit_local_program, Lint, LintContext, LintRule};
+use leo_asg::*;
+
+use std::cell::Cell;
+
+/// Reports conditions that always evaluate to the same value.
+pub struct ConstantConditions;
+
+impl LintRule for ConstantConditions {
+    fn name(&self) -> &'static str {
+        "constant_conditions"
+    }
+
+    fn check<'a>(&self, context: &LintContext<'a, '_>, lints: &mut Vec<Lint>) {
+        lints.extend(visit_local_program(context.asg, ConstantConditionVisitor::default()).lints);
+    }
+}
+
+#[derive(Default)]
+struct ConstantConditionVisitor {
+    lints: Vec<Lint>,
+}
+
+impl ConstantConditionVisitor {
+    fn check_condition<'a>(&mut self, condition: &Cell<&'a Expression<'a>>) {
+        let condition = condition.get();
+        if let (Some(ConstValue::Boolean(value)), Some(span)) = (condition.const_value(), condition.span()) {
+            self.lints.push(Lint::new(
+                ConstantConditions.name(),
+                format!("condition is always `{}`", value),
+                span,
+            ));
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for ConstantConditionVisitor {
+    fn visit_ternary_expression(&mut self, input: &TernaryExpression<'a>) -> VisitResult {
+        self.check_condition(&input.condition);
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> StatementVisitor<'a> for ConstantConditionVisitor {
+    fn visit_conditional_statement(&mut self, input: &ConditionalStatement<'a>) -> VisitResult {
+        self.check_condition(&input.condition);
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for ConstantConditionVisitor {}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The built-in lint rules.
+
+pub mod constant_conditions;
+pub use constant_conditions::*;
+
+pub mod redundant_casts;
+pub use redundant_casts::*;
+
+pub mod shadowed_bindings;
+pub use shadowed_bindings::*;
+
+pub mod unused_imports;
+pub use unused_imports::*;
+
+pub mod unused_mut;
+pub use unused_mut::*;
+
+pub mod unused_variables;
+pub use unused_variables::*;
+
+use crate::{Lint, LintContext, LintLevel};
+use leo_asg::{Program, ProgramVisitor, VisitorDirector};
+
+/// A check over a program that reports findings as lints.
+pub trait LintRule {
+    /// The name of the rule, as used in the `[lint]` section of `Leo.toml`.
+    fn name(&self) -> &'static str;
+
+    /// The level used when the package does not configure the rule.
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    /// Appends every finding of the rule to `lints`.
+    fn check<'a>(&self, context: &LintContext<'a, '_>, lints: &mut Vec<Lint>);
+}
+
+///
+/// Visits the functions, circuits and global constants declared in the program itself.
+///
+/// Imported modules are skipped, they are linted as part of their own package.
+///
+pub(crate) fn visit_local_program<'a, R: ProgramVisitor<'a>>(program: &Program<'a>, visitor: R) -> R {
+    let mut director = VisitorDirector::new(visitor);
+    for (_, function) in program.functions.iter() {
+        director.visit_function(function).ok();
+    }
+    for (_, circuit) in program.circuits.iter() {
+        director.visit_circuit(circuit).ok();
+    }
+    for (_, global_const) in program.global_consts.iter() {
+        director.visit_global_const(global_const).ok();
+    }
+
+    director.visitor()
+}
@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{rules::visit_local_program, Lint, LintContext, LintRule};
+use leo_asg::*;
+
+/// Reports casts of a value to the type it already has.
+pub struct RedundantCasts;
+
+impl LintRule for RedundantCasts {
+    fn name(&self) -> &'static str {
+        "redundant_casts"
+    }
+
+    fn check<'a>(&self, context: &LintContext<'a, '_>, lints: &mut Vec<Lint>) {
+        lints.extend(visit_local_program(context.asg, RedundantCastVisitor::default()).lints);
+    }
+}
+
+#[derive(Default)]
+struct RedundantCastVisitor {
+    lints: Vec<Lint>,
+}
+
+impl<'a> ExpressionVisitor<'a> for RedundantCastVisitor {
+    fn visit_cast_expression(&mut self, input: &CastExpression<'a>) -> VisitResult {
+        if input.inner.get().get_type().as_ref() == Some(&input.target_type) {
+            if let Some(span) = input.span() {
+                self.lints.push(Lint::new(
+                    RedundantCasts.name(),
+                    format!("redundant cast, the value already has type `{}`", input.target_type),
+                    span,
+                ));
+            }
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> StatementVisitor<'a> for RedundantCastVisitor {}
+
+impl<'a> ProgramVisitor<'a> for RedundantCastVisitor {}
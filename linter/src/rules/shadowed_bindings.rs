@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{rules::visit_local_program, Lint, LintContext, LintRule};
+use leo_asg::*;
+
+use indexmap::IndexMap;
+
+/// Reports variable, loop and match bindings that shadow an earlier binding with the same name.
+pub struct ShadowedBindings;
+
+impl LintRule for ShadowedBindings {
+    fn name(&self) -> &'static str {
+        "shadowed_bindings"
+    }
+
+    fn check<'a>(&self, context: &LintContext<'a, '_>, lints: &mut Vec<Lint>) {
+        lints.extend(visit_local_program(context.asg, ShadowedBindingVisitor::default()).lints);
+    }
+}
+
+#[derive(Default)]
+struct ShadowedBindingVisitor {
+    lints: Vec<Lint>,
+}
+
+impl ShadowedBindingVisitor {
+    fn report(&mut self, variable: &InnerVariable, shadowed: &InnerVariable) {
+        self.lints.push(Lint::new(
+            ShadowedBindings.name(),
+            format!(
+                "`{}` shadows the binding declared on line {}",
+                variable.name.name, shadowed.name.span.line_start
+            ),
+            &variable.name.span,
+        ));
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for ShadowedBindingVisitor {}
+
+impl<'a> StatementVisitor<'a> for ShadowedBindingVisitor {
+    fn visit_block(&mut self, input: &BlockStatement<'a>) -> VisitResult {
+        // Bindings declared so far in this block. The block scope itself only keeps the last
+        // binding of each name, so earlier ones are tracked here.
+        let mut declared: IndexMap<String, &'a Variable<'a>> = IndexMap::new();
+
+        for statement in input.statements.iter() {
+            let variables = match statement.get() {
+                Statement::Definition(definition) => definition.variables.clone(),
+                Statement::Iteration(iteration) => vec![iteration.variable],
+                _ => continue,
+            };
+
+            for variable in variables {
+                let inner = variable.borrow();
+                let name = inner.name.name.to_string();
+
+                if let Some(shadowed) = declared.get(&name) {
+                    self.report(&inner, &shadowed.borrow());
+                } else if let Some(shadowed) = input
+                    .scope
+                    .parent_scope
+                    .get()
+                    .and_then(|parent| parent.resolve_variable(&name))
+                {
+                    // Bindings of enclosing blocks declared after this one are not shadowed.
+                    let shadowed = shadowed.borrow();
+                    if shadowed.id < inner.id {
+                        self.report(&inner, &shadowed);
+                    }
+                }
+
+                declared.insert(name, variable);
+            }
+        }
+
+        VisitResult::VisitChildren
+    }
+
+    fn visit_match(&mut self, input: &MatchStatement<'a>) -> VisitResult {
+        for arm in input.arms.iter() {
+            let bindings = match &arm.pattern {
+                MatchPattern::Variant { bindings, .. } => bindings,
+                MatchPattern::Wildcard => continue,
+            };
+            // The arm body is declared in the scope of its bindings, which is declared in the scope of the match.
+            let outer = match arm.body.get() {
+                Statement::Block(block) => block
+                    .scope
+                    .parent_scope
+                    .get()
+                    .and_then(|bindings| bindings.parent_scope.get()),
+                _ => None,
+            };
+
+            for variable in bindings.iter().flatten() {
+                let inner = variable.borrow();
+                if let Some(shadowed) = outer.and_then(|outer| outer.resolve_variable(&inner.name.name)) {
+                    let shadowed = shadowed.borrow();
+                    if shadowed.id < inner.id {
+                        self.report(&inner, &shadowed);
+                    }
+                }
+            }
+        }
+
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for ShadowedBindingVisitor {}
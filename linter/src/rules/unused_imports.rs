@@ -0,0 +1,245 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{rules::visit_local_program, Lint, LintContext, LintRule};
+use leo_asg::*;
+use leo_ast::{PackageAccess, PackageOrPackages};
+
+use std::collections::HashSet;
+
+/// Reports imported symbols, and star imports of packages, that are never used.
+pub struct UnusedImports;
+
+impl LintRule for UnusedImports {
+    fn name(&self) -> &'static str {
+        "unused_imports"
+    }
+
+    fn check<'a>(&self, context: &LintContext<'a, '_>, lints: &mut Vec<Lint>) {
+        let used = visit_local_program(context.asg, UsedSymbols::default());
+
+        let mut imports = vec![];
+        for import in context.ast.imports.iter() {
+            collect_package(&mut imports, vec![], &import.package_or_packages);
+        }
+
+        for (package, symbol, span) in imports {
+            match symbol {
+                Some(name) => {
+                    let import_scope = context.asg.scope.parent_scope.get().unwrap_or(context.asg.scope);
+                    if !used.uses_scope_symbol(import_scope, &name) {
+                        lints.push(Lint::new(self.name(), format!("unused import `{}`", name), &span));
+                    }
+                }
+                None => {
+                    let package = package.join(".");
+                    let is_used = context
+                        .asg
+                        .imported_modules
+                        .get(&package)
+                        .map(|module| used.uses_module(module))
+                        .unwrap_or(true);
+                    if !is_used {
+                        lints.push(Lint::new(self.name(), format!("unused import `{}.*`", package), &span));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// An imported package, the local name of the imported symbol or `None` for a star import, and its span.
+type ImportedName = (Vec<String>, Option<String>, Span);
+
+fn collect_package(output: &mut Vec<ImportedName>, mut package_segments: Vec<String>, package: &PackageOrPackages) {
+    match package {
+        PackageOrPackages::Package(package) => {
+            package_segments.push(package.name.name.to_string());
+            collect_package_access(output, package_segments, &package.access);
+        }
+        PackageOrPackages::Packages(packages) => {
+            package_segments.push(packages.name.name.to_string());
+            for access in packages.accesses.iter() {
+                collect_package_access(output, package_segments.clone(), access);
+            }
+        }
+    }
+}
+
+fn collect_package_access(output: &mut Vec<ImportedName>, mut package_segments: Vec<String>, access: &PackageAccess) {
+    match access {
+        PackageAccess::Star { span } => output.push((package_segments, None, span.clone())),
+        PackageAccess::SubPackage(package) => {
+            collect_package(output, package_segments, &PackageOrPackages::Package(*package.clone()))
+        }
+        PackageAccess::Symbol(symbol) => {
+            let local = symbol.alias.as_ref().unwrap_or(&symbol.symbol);
+            output.push((package_segments, Some(local.name.to_string()), local.span.clone()));
+        }
+        PackageAccess::Multiple(packages) => {
+            package_segments.push(packages.name.name.to_string());
+            for access in packages.accesses.iter() {
+                collect_package_access(output, package_segments.clone(), access);
+            }
+        }
+    }
+}
+
+/// Collects the ids of all functions, circuits, enums and variables referenced by a program.
+#[derive(Default)]
+struct UsedSymbols {
+    functions: HashSet<u32>,
+    circuits: HashSet<u32>,
+    enums: HashSet<u32>,
+    variables: HashSet<u32>,
+}
+
+impl UsedSymbols {
+    fn use_type(&mut self, type_: &Type) {
+        match type_ {
+            Type::Circuit(circuit) => {
+                self.circuits.insert(circuit.id);
+            }
+            Type::Enum(enum_) => {
+                self.enums.insert(enum_.id);
+            }
+            Type::Array(element, _) => self.use_type(element),
+            Type::Tuple(elements) => elements.iter().for_each(|element| self.use_type(element)),
+            _ => (),
+        }
+    }
+
+    fn uses_global_const(&self, global_const: &DefinitionStatement) -> bool {
+        global_const
+            .variables
+            .iter()
+            .any(|variable| self.variables.contains(&variable.borrow().id))
+    }
+
+    /// Generic functions are used through their instances.
+    fn uses_function(&self, function: &Function) -> bool {
+        self.functions.contains(&function.id)
+            || function
+                .instances
+                .borrow()
+                .values()
+                .any(|instance| self.uses_function(instance))
+    }
+
+    fn uses_circuit(&self, circuit: &Circuit) -> bool {
+        self.circuits.contains(&circuit.id)
+            || circuit
+                .instances
+                .borrow()
+                .values()
+                .any(|instance| self.uses_circuit(instance))
+    }
+
+    fn uses_scope_symbol(&self, scope: &Scope, name: &str) -> bool {
+        if let Some(function) = scope.functions.borrow().get(name) {
+            self.uses_function(function)
+        } else if let Some(circuit) = scope.circuits.borrow().get(name) {
+            self.uses_circuit(circuit)
+        } else if let Some(enum_) = scope.enums.borrow().get(name) {
+            self.enums.contains(&enum_.id)
+        } else if let Some(global_const) = scope.global_consts.borrow().get(name) {
+            self.uses_global_const(global_const)
+        } else {
+            true
+        }
+    }
+
+    fn uses_module(&self, module: &Program) -> bool {
+        module.functions.values().any(|function| self.uses_function(function))
+            || module.circuits.values().any(|circuit| self.uses_circuit(circuit))
+            || module.enums.values().any(|enum_| self.enums.contains(&enum_.id))
+            || module
+                .global_consts
+                .values()
+                .any(|global_const| self.uses_global_const(global_const))
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for UsedSymbols {
+    fn visit_call(&mut self, input: &CallExpression<'a>) -> VisitResult {
+        let function = input.function.get();
+        self.functions.insert(function.id);
+        if let Some(circuit) = function.circuit.get() {
+            self.circuits.insert(circuit.id);
+        }
+        VisitResult::VisitChildren
+    }
+
+    fn visit_cast_expression(&mut self, input: &CastExpression<'a>) -> VisitResult {
+        self.use_type(&input.target_type);
+        VisitResult::VisitChildren
+    }
+
+    fn visit_circuit_access(&mut self, input: &CircuitAccessExpression<'a>) -> VisitResult {
+        self.circuits.insert(input.circuit.get().id);
+        VisitResult::VisitChildren
+    }
+
+    fn visit_circuit_init(&mut self, input: &CircuitInitExpression<'a>) -> VisitResult {
+        self.circuits.insert(input.circuit.get().id);
+        VisitResult::VisitChildren
+    }
+
+    fn visit_enum_init(&mut self, input: &EnumInitExpression<'a>) -> VisitResult {
+        self.enums.insert(input.enum_.get().id);
+        VisitResult::VisitChildren
+    }
+
+    fn visit_variable_ref(&mut self, input: &VariableRef<'a>) -> VisitResult {
+        let variable = input.variable.borrow();
+        self.variables.insert(variable.id);
+        self.use_type(&variable.type_);
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> StatementVisitor<'a> for UsedSymbols {
+    fn visit_definition(&mut self, input: &DefinitionStatement<'a>) -> VisitResult {
+        for variable in input.variables.iter() {
+            self.use_type(&variable.borrow().type_);
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for UsedSymbols {
+    fn visit_function(&mut self, input: &'a Function<'a>) -> VisitResult {
+        self.use_type(&input.output);
+        for (_, argument) in input.arguments.iter() {
+            self.use_type(&argument.get().borrow().type_);
+        }
+        VisitResult::VisitChildren
+    }
+
+    fn visit_circuit_member(&mut self, input: &CircuitMember<'a>) -> VisitResult {
+        if let CircuitMember::Variable(type_) = input {
+            self.use_type(type_);
+        }
+        VisitResult::VisitChildren
+    }
+
+    fn visit_global_const(&mut self, input: &'a DefinitionStatement<'a>) -> VisitResult {
+        for variable in input.variables.iter() {
+            self.use_type(&variable.borrow().type_);
+        }
+        VisitResult::VisitChildren
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{rules::visit_local_program, Lint, LintContext, LintRule};
+use leo_asg::*;
+
+use std::collections::HashSet;
+
+/// Reports mutable bindings that are never mutated.
+///
+/// This covers circuit functions taking `mut self` that never mutate `self`, and `let`
+/// bindings of constant values that are never assigned to and could be declared `const`.
+pub struct UnusedMut;
+
+impl LintRule for UnusedMut {
+    fn name(&self) -> &'static str {
+        "unused_mut"
+    }
+
+    fn check<'a>(&self, context: &LintContext<'a, '_>, lints: &mut Vec<Lint>) {
+        let visitor = visit_local_program(context.asg, MutatedVariables::default());
+
+        for function in visitor.mut_self_functions {
+            let self_variable = function.scope.variables.borrow().get("self").copied();
+            if let (Some(self_variable), Some(span)) = (self_variable, function.span.as_ref()) {
+                if !visitor.mutated.contains(&self_variable.borrow().id) {
+                    lints.push(Lint::new(
+                        self.name(),
+                        format!(
+                            "function `{}` takes `mut self` but never mutates it",
+                            function.name.borrow().name
+                        ),
+                        span,
+                    ));
+                }
+            }
+        }
+
+        for (variable, value) in visitor.definitions {
+            let variable = variable.borrow();
+            if variable.mutable && value.is_consty() && !visitor.mutated.contains(&variable.id) {
+                lints.push(Lint::new(
+                    self.name(),
+                    format!(
+                        "variable `{}` is never mutated, consider declaring it with `const`",
+                        variable.name.name
+                    ),
+                    &variable.name.span,
+                ));
+            }
+        }
+    }
+}
+
+/// Collects `let` definitions, `mut self` functions and the ids of all variables that are
+/// assigned to or mutated through a `mut self` call.
+#[derive(Default)]
+struct MutatedVariables<'a> {
+    definitions: Vec<(&'a Variable<'a>, &'a Expression<'a>)>,
+    mut_self_functions: Vec<&'a Function<'a>>,
+    mutated: HashSet<u32>,
+}
+
+///
+/// Returns the variable at the root of a chain of member, index and range accesses.
+///
+fn root_variable<'a>(mut expression: &'a Expression<'a>) -> Option<&'a Variable<'a>> {
+    loop {
+        expression = match expression {
+            Expression::VariableRef(reference) => return Some(reference.variable),
+            Expression::CircuitAccess(access) => access.target.get()?,
+            Expression::ArrayAccess(access) => access.array.get(),
+            Expression::ArrayRangeAccess(access) => access.array.get(),
+            Expression::TupleAccess(access) => access.tuple_ref.get(),
+            _ => return None,
+        };
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for MutatedVariables<'a> {
+    fn visit_call(&mut self, input: &CallExpression<'a>) -> VisitResult {
+        if input.function.get().qualifier == FunctionQualifier::MutSelfRef {
+            if let Some(variable) = input.target.get().and_then(root_variable) {
+                self.mutated.insert(variable.borrow().id);
+            }
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> StatementVisitor<'a> for MutatedVariables<'a> {
+    fn visit_assign(&mut self, input: &AssignStatement<'a>) -> VisitResult {
+        self.mutated.insert(input.target_variable.get().borrow().id);
+        VisitResult::VisitChildren
+    }
+
+    fn visit_definition(&mut self, input: &DefinitionStatement<'a>) -> VisitResult {
+        let value = input.value.get();
+        self.definitions
+            .extend(input.variables.iter().map(|variable| (*variable, value)));
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for MutatedVariables<'a> {
+    fn visit_function(&mut self, input: &'a Function<'a>) -> VisitResult {
+        if input.qualifier == FunctionQualifier::MutSelfRef {
+            self.mut_self_functions.push(input);
+        }
+        VisitResult::VisitChildren
+    }
+}
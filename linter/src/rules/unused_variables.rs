@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{rules::visit_local_program, Lint, LintContext, LintRule};
+use leo_asg::*;
+
+use std::collections::HashSet;
+
+/// Reports variables and function parameters that are never read.
+///
+/// Parameters of `main` are bound by the program input file and are ignored.
+pub struct UnusedVariables;
+
+impl LintRule for UnusedVariables {
+    fn name(&self) -> &'static str {
+        "unused_variables"
+    }
+
+    fn check<'a>(&self, context: &LintContext<'a, '_>, lints: &mut Vec<Lint>) {
+        let main_parameters = context
+            .asg
+            .functions
+            .get("main")
+            .map(|main| {
+                main.arguments
+                    .iter()
+                    .map(|(_, argument)| argument.get().borrow().id)
+                    .collect::<HashSet<_>>()
+            })
+            .unwrap_or_default();
+        let declared = visit_local_program(context.asg, DeclaredVariables::default());
+
+        for variable in declared.variables {
+            let variable = variable.borrow();
+            if variable.references.is_empty() && !main_parameters.contains(&variable.id) {
+                lints.push(Lint::new(
+                    self.name(),
+                    format!("unused variable `{}`", variable.name.name),
+                    &variable.name.span,
+                ));
+            }
+        }
+    }
+}
+
+/// Collects the variables declared by definitions, loops and function parameters.
+#[derive(Default)]
+struct DeclaredVariables<'a> {
+    variables: Vec<&'a Variable<'a>>,
+}
+
+impl<'a> ExpressionVisitor<'a> for DeclaredVariables<'a> {}
+
+impl<'a> StatementVisitor<'a> for DeclaredVariables<'a> {
+    fn visit_definition(&mut self, input: &DefinitionStatement<'a>) -> VisitResult {
+        self.variables.extend(input.variables.iter().copied());
+        VisitResult::VisitChildren
+    }
+
+    fn visit_iteration(&mut self, input: &IterationStatement<'a>) -> VisitResult {
+        self.variables.push(input.variable);
+        VisitResult::VisitChildren
+    }
+
+    fn visit_match(&mut self, input: &MatchStatement<'a>) -> VisitResult {
+        for arm in input.arms.iter() {
+            if let MatchPattern::Variant { bindings, .. } = &arm.pattern {
+                self.variables.extend(bindings.iter().flatten().copied());
+            }
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for DeclaredVariables<'a> {
+    fn visit_function(&mut self, input: &'a Function<'a>) -> VisitResult {
+        self.variables
+            .extend(input.arguments.iter().map(|(_, argument)| argument.get()));
+        VisitResult::VisitChildren
+    }
+}
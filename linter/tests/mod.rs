@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_asg::*;
+use leo_linter::{Lint, LintConfig, LintLevel, Linter};
+use leo_parser::parse_ast;
+
+const TESTING_FILEPATH: &str = "input.leo";
+
+//convenience function for tests, leaks memory
+fn make_test_context() -> AsgContext<'static> {
+    let allocator = Box::leak(Box::new(new_alloc_context()));
+    new_context(allocator)
+}
+
+fn lint_with_imports<'a, T: ImportResolver<'a>>(
+    context: AsgContext<'a>,
+    linter: &Linter,
+    program_string: &str,
+    imports: &mut T,
+) -> Vec<Lint> {
+    let ast = parse_ast(TESTING_FILEPATH, program_string).unwrap();
+    let asg = Program::new(context, ast.as_repr(), imports).unwrap();
+    linter.lint(ast.as_repr(), &asg)
+}
+
+fn lint(program_string: &str) -> Vec<Lint> {
+    lint_with_imports(
+        make_test_context(),
+        &Linter::default(),
+        program_string,
+        &mut NullImportResolver,
+    )
+}
+
+fn lint_rule(program_string: &str, rule: &str) -> Vec<Lint> {
+    lint(program_string)
+        .into_iter()
+        .filter(|lint| lint.rule == rule)
+        .collect()
+}
+
+#[test]
+fn test_clean_program() {
+    let program_string = include_str!("clean.leo");
+    assert!(lint(program_string).is_empty());
+}
+
+#[test]
+fn test_unused_variables() {
+    let program_string = include_str!("unused_variables.leo");
+    let lints = lint_rule(program_string, "unused_variables");
+
+    assert_eq!(lints.len(), 3);
+    assert_eq!(lints[0].error.line_start, 1);
+    assert_eq!(lints[1].error.line_start, 6);
+    assert_eq!(lints[2].error.line_start, 7);
+}
+
+#[test]
+fn test_unused_imports() {
+    let context = make_test_context();
+    let mut imports = MockedImportResolver {
+        packages: indexmap::IndexMap::new(),
+    };
+    let library = load_asg(context, include_str!("library.leo"), &mut NullImportResolver).unwrap();
+    imports.packages.insert("library".to_string(), library);
+
+    let program_string = include_str!("unused_imports.leo");
+    let lints = lint_with_imports(context, &Linter::default(), program_string, &mut imports);
+
+    let lints: Vec<_> = lints.into_iter().filter(|lint| lint.rule == "unused_imports").collect();
+
+    assert_eq!(lints.len(), 1);
+    assert!(lints[0].error.message.contains("Point"));
+}
+
+#[test]
+fn test_shadowed_bindings() {
+    let program_string = include_str!("shadowed_bindings.leo");
+    let lints = lint_rule(program_string, "shadowed_bindings");
+
+    assert_eq!(lints.len(), 2);
+    assert_eq!(lints[0].error.line_start, 3);
+    assert_eq!(lints[1].error.line_start, 6);
+}
+
+#[test]
+fn test_constant_conditions() {
+    let program_string = include_str!("constant_conditions.leo");
+    let lints = lint_rule(program_string, "constant_conditions");
+
+    assert_eq!(lints.len(), 2);
+}
+
+#[test]
+fn test_redundant_casts() {
+    let program_string = include_str!("redundant_casts.leo");
+    let lints = lint_rule(program_string, "redundant_casts");
+
+    assert_eq!(lints.len(), 1);
+    assert_eq!(lints[0].error.line_start, 2);
+}
+
+#[test]
+fn test_unused_mut() {
+    let program_string = include_str!("unused_mut.leo");
+    let lints = lint_rule(program_string, "unused_mut");
+
+    assert_eq!(lints.len(), 2);
+    assert!(lints[0].error.message.contains("`get`"));
+    assert!(lints[1].error.message.contains("`b`"));
+}
+
+#[test]
+fn test_config_levels() {
+    let program_string = include_str!("unused_variables.leo");
+    let config = LintConfig::from_pairs(vec![("unused_variables", "deny")]).unwrap();
+    let linter = Linter::new(config).unwrap();
+    let lints = lint_with_imports(make_test_context(), &linter, program_string, &mut NullImportResolver);
+
+    assert!(lints
+        .iter()
+        .all(|lint| (lint.rule == "unused_variables") == (lint.level == LintLevel::Deny)));
+
+    let config = LintConfig::from_pairs(vec![("unused_variables", "allow")]).unwrap();
+    let linter = Linter::new(config).unwrap();
+    let lints = lint_with_imports(make_test_context(), &linter, program_string, &mut NullImportResolver);
+
+    assert!(lints.iter().all(|lint| lint.rule != "unused_variables"));
+}
+
+#[test]
+fn test_config_fail() {
+    assert!(LintConfig::from_pairs(vec![("unused_variables", "sometimes")]).is_err());
+
+    let config = LintConfig::from_pairs(vec![("no_such_rule", "deny")]).unwrap();
+    assert!(Linter::new(config).is_err());
+}
@@ -0,0 +1,352 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    span_contains,
+    symbols::symbol_at,
+    CircuitSymbols,
+    MemberKind,
+    MemberSymbol,
+    Symbol,
+    SymbolCollector,
+    SymbolKind,
+};
+use leo_asg::{new_alloc_context, new_context, AsgContext, AsgConvertError, Program, VisitorDirector};
+use leo_ast::{AstError, FormattedError, ReducerError, Span};
+use leo_imports::{ImportParser, ImportParserError, ImportsMap};
+use leo_parser::{DeprecatedError, SyntaxError, TokenError};
+
+use indexmap::{IndexMap, IndexSet};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+static SOURCE_DIRECTORY_NAME: &str = "src";
+static MAIN_FILENAME: &str = "main.leo";
+
+///
+/// The text and syntax trees of the files read by an analysis.
+///
+/// Files are read from the unsaved buffers of the editor before the file system.
+///
+pub(crate) struct Sources<'b> {
+    buffers: &'b IndexMap<PathBuf, String>,
+    texts: IndexMap<String, Option<String>>,
+    asts: IndexMap<String, Option<leo_ast::Program>>,
+}
+
+impl<'b> Sources<'b> {
+    fn new(buffers: &'b IndexMap<PathBuf, String>) -> Self {
+        Self {
+            buffers,
+            texts: IndexMap::new(),
+            asts: IndexMap::new(),
+        }
+    }
+
+    pub(crate) fn text(&mut self, path: &str) -> Option<&str> {
+        let buffers = self.buffers;
+        self.texts
+            .entry(path.to_string())
+            .or_insert_with(|| {
+                buffers
+                    .get(Path::new(path))
+                    .cloned()
+                    .or_else(|| fs::read_to_string(path).ok())
+            })
+            .as_deref()
+    }
+
+    pub(crate) fn line(&mut self, path: &str, line: usize) -> Option<String> {
+        self.text(path)?.lines().nth(line.checked_sub(1)?).map(str::to_string)
+    }
+
+    pub(crate) fn ast(&mut self, path: &str) -> Option<&leo_ast::Program> {
+        if !self.asts.contains_key(path) {
+            let ast = self.text(path).and_then(|text| leo_parser::parse(path, text).ok());
+            self.asts.insert(path.to_string(), ast);
+        }
+
+        self.asts[path].as_ref()
+    }
+
+    fn into_texts(self) -> IndexMap<String, String> {
+        self.texts
+            .into_iter()
+            .filter_map(|(path, text)| Some((path, text?)))
+            .collect()
+    }
+}
+
+///
+/// The errors and symbols of the program a document belongs to.
+///
+#[derive(Clone, Debug, Default)]
+pub struct Analysis {
+    /// The text of every file read by the analysis, by path.
+    pub sources: IndexMap<String, String>,
+
+    /// The errors of the program, the parser and the ASG stop at the first one.
+    pub errors: Vec<FormattedError>,
+
+    /// The symbols of the program and of the programs it imports, empty if it has errors.
+    pub symbols: Vec<Symbol>,
+
+    /// The members of the circuits, by circuit id.
+    pub circuits: IndexMap<u32, CircuitSymbols>,
+
+    circuit_functions: Vec<(Span, u32)>,
+}
+
+impl Analysis {
+    ///
+    /// Analyzes the document at `path`, reading the files in `buffers` from memory.
+    ///
+    /// A document in the source directory of a package is analyzed as part of the main file of
+    /// the package, so that its modules and imports are resolved the way the compiler resolves them.
+    ///
+    pub fn new(path: &Path, buffers: &IndexMap<PathBuf, String>, imports_map: &ImportsMap) -> Self {
+        if let Some(main) = package_main(path, buffers) {
+            let (analysis, files) = Self::analyze(&main, buffers, imports_map);
+            if files.contains(path) {
+                return analysis;
+            }
+        }
+
+        Self::analyze(path, buffers, imports_map).0
+    }
+
+    /// Analyzes the program in the file at `path`, returns the analysis and the files the program reads.
+    fn analyze(
+        path: &Path,
+        buffers: &IndexMap<PathBuf, String>,
+        imports_map: &ImportsMap,
+    ) -> (Self, IndexSet<PathBuf>) {
+        let path_string = path.to_string_lossy().to_string();
+        let mut sources = Sources::new(buffers);
+        let mut files = IndexSet::new();
+        files.insert(path.to_path_buf());
+
+        let arena = new_alloc_context();
+        let context = new_context(&arena);
+
+        let mut analysis = Analysis::default();
+        let program = match sources.text(&path_string).map(str::to_string) {
+            Some(text) => convert(context, path, &text, buffers, imports_map, &mut files),
+            None => Err(unspanned(format!("failed to read {}", path_string), &path_string)),
+        };
+        match program {
+            Ok(program) => {
+                let mut director = VisitorDirector::new(SymbolCollector::new(&mut sources));
+                director.visit_program(&program).ok();
+                let collector = director.visitor();
+                analysis.symbols = collector.symbols;
+                analysis.circuits = collector.circuits;
+                analysis.circuit_functions = collector.circuit_functions;
+            }
+            Err(mut error) => {
+                // Errors without a location, such as unresolved imports, are reported on the document.
+                if error.path.is_empty() {
+                    error.path = Arc::new(path_string.clone());
+                }
+                sources.text(&error.path);
+                analysis.errors.push(error);
+            }
+        }
+        analysis.sources = sources.into_texts();
+
+        (analysis, files)
+    }
+
+    ///
+    /// Returns the symbol at the 1-based `line` and byte `column` of the file at `path`, the innermost one if they are nested.
+    ///
+    pub fn symbol(&self, path: &str, position: (usize, usize)) -> Option<&Symbol> {
+        symbol_at(&self.symbols, path, position)
+    }
+
+    ///
+    /// Returns the members of the circuit of the value before the `.` or `::` that precedes
+    /// `position` in `source`, the current text of the file at `path`.
+    ///
+    /// The value is written as a path of names, e.g. `self.origin.`, and is looked up by name as
+    /// the symbols may have been collected from an earlier version of the text.
+    ///
+    pub fn completions(&self, path: &str, source: &str, position: (usize, usize)) -> Vec<(&str, &MemberSymbol)> {
+        let line = source.lines().nth(position.0.saturating_sub(1)).unwrap_or_default();
+        let before = line.get(..position.1.saturating_sub(1)).unwrap_or(line);
+        let before = before.trim_end_matches(is_identifier_character);
+
+        let (receiver, kinds) = if let Some(receiver) = before.strip_suffix("::") {
+            (receiver, &[MemberKind::Static][..])
+        } else if let Some(receiver) = before.strip_suffix('.') {
+            (receiver, &[MemberKind::Variable, MemberKind::Method][..])
+        } else {
+            return Vec::new();
+        };
+        let start = receiver
+            .trim_end_matches(|character| is_identifier_character(character) || character == '.')
+            .len();
+        let mut segments = receiver[start..].split('.');
+
+        let mut circuit = match segments.next() {
+            Some("self") | Some("Self") => self
+                .circuit_functions
+                .iter()
+                .filter(|(span, _)| *span.path == path && span_contains(span, position))
+                .map(|(_, circuit)| *circuit)
+                .last(),
+            Some(name) if kinds.contains(&MemberKind::Static) => self.named(path, name, SymbolKind::Circuit, position),
+            Some(name) => self.named(path, name, SymbolKind::Variable, position),
+            None => None,
+        };
+        for segment in segments {
+            circuit = circuit
+                .and_then(|circuit| self.circuits.get(&circuit)?.members.get(segment))
+                .filter(|member| member.kind == MemberKind::Variable)
+                .and_then(|member| member.circuit);
+        }
+
+        match circuit.and_then(|circuit| self.circuits.get(&circuit)) {
+            Some(circuit) => circuit
+                .members
+                .iter()
+                .filter(|(_, member)| kinds.contains(&member.kind))
+                .map(|(name, member)| (name.as_str(), member))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    ///
+    /// Returns the circuit of the last symbol of `kind` written as `name` before `position`, or of the first after it.
+    ///
+    fn named(&self, path: &str, name: &str, kind: SymbolKind, position: (usize, usize)) -> Option<u32> {
+        let mut symbols = self
+            .symbols
+            .iter()
+            .filter(|symbol| symbol.kind == kind && symbol.circuit.is_some() && *symbol.span.path == path)
+            .filter(|symbol| self.text(&symbol.span) == Some(name))
+            .collect::<Vec<_>>();
+        symbols.sort_by_key(|symbol| (symbol.span.line_start, symbol.span.col_start));
+
+        symbols
+            .iter()
+            .rev()
+            .find(|symbol| (symbol.span.line_start, symbol.span.col_start) < position)
+            .or_else(|| symbols.first())
+            .and_then(|symbol| symbol.circuit)
+    }
+
+    /// Returns the text of the single line `span`.
+    fn text(&self, span: &Span) -> Option<&str> {
+        self.sources
+            .get(&*span.path)?
+            .lines()
+            .nth(span.line_start.checked_sub(1)?)?
+            .get(span.col_start.checked_sub(1)?..span.col_stop.checked_sub(1)?)
+    }
+}
+
+fn is_identifier_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || character == '_'
+}
+
+///
+/// Returns the main file of the package whose source directory contains the file at `path`.
+///
+fn package_main(path: &Path, buffers: &IndexMap<PathBuf, String>) -> Option<PathBuf> {
+    path.ancestors()
+        .skip(1)
+        .find(|directory| directory.file_name().map(|name| name == SOURCE_DIRECTORY_NAME) == Some(true))
+        .map(|directory| directory.join(MAIN_FILENAME))
+        .filter(|main| buffers.contains_key(main) || main.exists())
+}
+
+/// Converts the program in `text`, read from `path`, into an ASG, recording the files it reads in `files`.
+fn convert<'a>(
+    context: AsgContext<'a>,
+    path: &Path,
+    text: &str,
+    buffers: &IndexMap<PathBuf, String>,
+    imports_map: &ImportsMap,
+    files: &mut IndexSet<PathBuf>,
+) -> Result<Program<'a>, FormattedError> {
+    let path_string = path.to_string_lossy();
+    let mut ast = leo_parser::parse_ast(&path_string, text).map_err(syntax_error)?;
+    ast.canonicalize().map_err(|error| ast_error(error, &path_string))?;
+    let ast = ast.into_repr();
+
+    let mut import_parser = ImportParser::new(path.to_path_buf(), imports_map.clone()).with_sources(buffers.clone());
+    let program = match path.parent() {
+        Some(directory) => import_parser
+            .declare_modules(directory, &ast)
+            .map_err(|error| import_error(error, &path_string)),
+        None => Ok(()),
+    }
+    .and_then(|_| Program::new(context, &ast, &mut import_parser).map_err(|error| asg_error(error, &path_string)));
+    files.extend(import_parser.resolved_files().cloned());
+
+    program
+}
+
+/// Returns an error at the start of the file at `path`.
+fn unspanned(message: String, path: &str) -> FormattedError {
+    FormattedError::new_from_span(message, &Span {
+        line_start: 1,
+        line_stop: 1,
+        col_start: 1,
+        col_stop: 1,
+        path: Arc::new(path.to_string()),
+        content: Default::default(),
+    })
+}
+
+fn syntax_error(error: SyntaxError) -> FormattedError {
+    match error {
+        SyntaxError::Error(error)
+        | SyntaxError::TokenError(TokenError::Error(error))
+        | SyntaxError::DeprecatedError(DeprecatedError::Error(error)) => error,
+    }
+}
+
+fn ast_error(error: AstError, path: &str) -> FormattedError {
+    match error {
+        AstError::Error(error) => error,
+        AstError::ReducerError(ReducerError::Error(error))
+        | AstError::ReducerError(ReducerError::CanonicalizeError(leo_ast::CanonicalizeError::Error(error)))
+        | AstError::ReducerError(ReducerError::CombinerError(leo_ast::CombinerError::Error(error))) => error,
+        error => unspanned(error.to_string(), path),
+    }
+}
+
+fn asg_error(error: AsgConvertError, path: &str) -> FormattedError {
+    match error {
+        AsgConvertError::Error(error) | AsgConvertError::ImportError(error) => error,
+        AsgConvertError::SyntaxError(error) => syntax_error(error),
+        error => unspanned(error.to_string(), path),
+    }
+}
+
+fn import_error(error: ImportParserError, path: &str) -> FormattedError {
+    match error {
+        ImportParserError::Error(error) => error,
+        ImportParserError::SyntaxError(error) => syntax_error(error),
+        ImportParserError::AsgConvertError(error) => asg_error(error, path),
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The language server for Leo programs.
+//!
+//! An [`Analysis`] parses a document and converts the program it belongs to into an ASG, reading
+//! unsaved documents from memory. The [`LanguageServer`] publishes the errors of every analysis
+//! as diagnostics, and answers hovers, definitions and circuit member completions from the
+//! symbols collected from the ASG.
+
+#[macro_use]
+extern crate thiserror;
+
+pub mod analysis;
+pub use analysis::*;
+
+pub mod errors;
+pub use errors::*;
+
+pub mod position;
+pub use position::*;
+
+pub mod server;
+pub use server::*;
+
+pub mod symbols;
+pub use symbols::*;
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Conversions between source positions of Leo, a 1-based line and byte column, and of the
+//! Language Server Protocol, a 0-based line and UTF-16 column.
+
+use leo_ast::{FormattedError, Span};
+use lsp_types::{Position, Range};
+
+/// Returns the text of the 1-based `line` of `source`, without its line break.
+fn line_text(source: &str, line: usize) -> &str {
+    source.lines().nth(line.saturating_sub(1)).unwrap_or_default()
+}
+
+///
+/// Returns the LSP position of the 1-based `line` and byte `column` in `source`.
+///
+pub fn lsp_position(source: &str, line: usize, column: usize) -> Position {
+    let text = line_text(source, line);
+    let prefix = text.get(..column.saturating_sub(1).min(text.len())).unwrap_or(text);
+
+    Position::new(line.saturating_sub(1) as u32, prefix.encode_utf16().count() as u32)
+}
+
+///
+/// Returns the 1-based line and byte column of the LSP `position` in `source`.
+///
+pub fn source_position(source: &str, position: Position) -> (usize, usize) {
+    let line = position.line as usize + 1;
+    let text = line_text(source, line);
+
+    let mut units = 0;
+    let column = text
+        .char_indices()
+        .find(|(_, character)| {
+            let found = units >= position.character as usize;
+            units += character.len_utf16();
+            found
+        })
+        .map(|(index, _)| index)
+        .unwrap_or_else(|| text.len());
+
+    (line, column + 1)
+}
+
+///
+/// Returns the LSP range of `span` in `source`.
+///
+pub fn span_range(source: &str, span: &Span) -> Range {
+    Range::new(
+        lsp_position(source, span.line_start, span.col_start),
+        lsp_position(source, span.line_stop, span.col_stop),
+    )
+}
+
+///
+/// Returns the LSP range of the span `error` points at in `source`.
+///
+pub fn error_range(source: &str, error: &FormattedError) -> Range {
+    Range::new(
+        lsp_position(source, error.line_start, error.col_start),
+        lsp_position(source, error.line_stop, error.col_stop),
+    )
+}
+
+///
+/// Returns `true` if the 1-based `line` and byte `column` are within `span`.
+///
+pub fn span_contains(span: &Span, (line, column): (usize, usize)) -> bool {
+    (span.line_start, span.col_start) <= (line, column) && (line, column) < (span.line_stop, span.col_stop)
+}
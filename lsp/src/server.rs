@@ -0,0 +1,302 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{error_range, source_position, span_range, Analysis, LanguageServerError, MemberKind};
+use leo_imports::ImportsMap;
+
+use indexmap::{IndexMap, IndexSet};
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument,
+        DidCloseTextDocument,
+        DidOpenTextDocument,
+        Notification as _,
+        PublishDiagnostics,
+    },
+    request::{Completion, GotoDefinition, HoverRequest, Request as _},
+    CompletionItem,
+    CompletionItemKind,
+    CompletionOptions,
+    CompletionParams,
+    CompletionResponse,
+    Diagnostic,
+    DiagnosticSeverity,
+    DidChangeTextDocumentParams,
+    DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams,
+    GotoDefinitionParams,
+    GotoDefinitionResponse,
+    Hover,
+    HoverContents,
+    HoverParams,
+    HoverProviderCapability,
+    Location,
+    MarkupContent,
+    MarkupKind,
+    OneOf,
+    PublishDiagnosticsParams,
+    ServerCapabilities,
+    TextDocumentPositionParams,
+    TextDocumentSyncCapability,
+    TextDocumentSyncKind,
+    Url,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::PathBuf;
+
+///
+/// A language server for Leo.
+///
+/// Every change to a document analyzes it again and publishes its errors as diagnostics. Hovers,
+/// definitions and completions are answered from the latest analysis without errors, so that
+/// they keep working while the document is being edited.
+///
+pub struct LanguageServer {
+    connection: Connection,
+    imports_map: ImportsMap,
+
+    /// The unsaved text of the open documents.
+    buffers: IndexMap<PathBuf, String>,
+
+    /// The latest analysis of each open document, without errors if there was one.
+    analyses: IndexMap<PathBuf, Analysis>,
+
+    /// The files diagnostics were last published for.
+    diagnosed: IndexSet<Url>,
+}
+
+impl LanguageServer {
+    pub fn new(connection: Connection, imports_map: ImportsMap) -> Self {
+        Self {
+            connection,
+            imports_map,
+            buffers: IndexMap::new(),
+            analyses: IndexMap::new(),
+            diagnosed: IndexSet::new(),
+        }
+    }
+
+    ///
+    /// Runs a language server over the standard input and output until the client exits.
+    ///
+    pub fn stdio(imports_map: ImportsMap) -> Result<(), LanguageServerError> {
+        let (connection, io_threads) = Connection::stdio();
+        Self::new(connection, imports_map).run()?;
+        io_threads.join()?;
+
+        Ok(())
+    }
+
+    ///
+    /// Initializes the connection and handles the messages of the client until it shuts the server down.
+    ///
+    pub fn run(mut self) -> Result<(), LanguageServerError> {
+        let capabilities = ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            definition_provider: Some(OneOf::Left(true)),
+            completion_provider: Some(CompletionOptions {
+                trigger_characters: Some(vec![".".to_string(), ":".to_string()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        self.connection.initialize(serde_json::to_value(capabilities)?)?;
+
+        while let Ok(message) = self.connection.receiver.recv() {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        break;
+                    }
+                    let response = self.respond(request);
+                    self.send(response)?;
+                }
+                Message::Notification(notification) => self.notify(notification)?,
+                Message::Response(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn send(&self, message: impl Into<Message>) -> Result<(), LanguageServerError> {
+        self.connection
+            .sender
+            .send(message.into())
+            .map_err(|_| LanguageServerError::Disconnected)
+    }
+
+    fn respond(&self, request: Request) -> Response {
+        match request.method.as_str() {
+            HoverRequest::METHOD => respond(request, |params: HoverParams| {
+                self.hover(params.text_document_position_params)
+            }),
+            GotoDefinition::METHOD => respond(request, |params: GotoDefinitionParams| {
+                self.definition(params.text_document_position_params)
+            }),
+            Completion::METHOD => respond(request, |params: CompletionParams| {
+                self.completion(params.text_document_position)
+            }),
+            method => Response::new_err(
+                request.id.clone(),
+                ErrorCode::MethodNotFound as i32,
+                format!("unsupported request `{}`", method),
+            ),
+        }
+    }
+
+    fn notify(&mut self, notification: Notification) -> Result<(), LanguageServerError> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams = serde_json::from_value(notification.params)?;
+                self.update(params.text_document.uri, params.text_document.text)
+            }
+            DidChangeTextDocument::METHOD => {
+                // The server asks for the full text of the document on every change.
+                let mut params: DidChangeTextDocumentParams = serde_json::from_value(notification.params)?;
+                match params.content_changes.pop() {
+                    Some(change) => self.update(params.text_document.uri, change.text),
+                    None => Ok(()),
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams = serde_json::from_value(notification.params)?;
+                if let Ok(path) = params.text_document.uri.to_file_path() {
+                    self.buffers.remove(&path);
+                    self.analyses.remove(&path);
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Stores the new `text` of the document at `uri`, analyzes it and publishes its errors.
+    fn update(&mut self, uri: Url, text: String) -> Result<(), LanguageServerError> {
+        let path = match uri.to_file_path() {
+            Ok(path) => path,
+            Err(_) => return Ok(()),
+        };
+        self.buffers.insert(path.clone(), text);
+
+        let analysis = Analysis::new(&path, &self.buffers, &self.imports_map);
+        self.publish(&analysis)?;
+        if analysis.errors.is_empty() || !self.analyses.contains_key(&path) {
+            self.analyses.insert(path, analysis);
+        }
+
+        Ok(())
+    }
+
+    /// Publishes the errors of `analysis`, and clears the diagnostics published before.
+    fn publish(&mut self, analysis: &Analysis) -> Result<(), LanguageServerError> {
+        let mut diagnostics = self
+            .diagnosed
+            .drain(..)
+            .map(|uri| (uri, Vec::new()))
+            .collect::<IndexMap<_, _>>();
+        for error in analysis.errors.iter() {
+            let source = analysis
+                .sources
+                .get(&*error.path)
+                .map(String::as_str)
+                .unwrap_or_default();
+            if let Ok(uri) = Url::from_file_path(&*error.path) {
+                diagnostics.entry(uri).or_default().push(Diagnostic {
+                    range: error_range(source, error),
+                    severity: Some(DiagnosticSeverity::ERROR),
+                    source: Some("leo".to_string()),
+                    message: error.message.clone(),
+                    ..Default::default()
+                });
+            }
+        }
+
+        for (uri, diagnostics) in diagnostics {
+            if !diagnostics.is_empty() {
+                self.diagnosed.insert(uri.clone());
+            }
+            self.send(Notification::new(
+                PublishDiagnostics::METHOD.to_string(),
+                PublishDiagnosticsParams::new(uri, diagnostics, None),
+            ))?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the path, the analysis and the position in the source of a request.
+    fn locate(&self, params: &TextDocumentPositionParams) -> Option<(String, &Analysis, &str, (usize, usize))> {
+        let path = params.text_document.uri.to_file_path().ok()?;
+        let analysis = self.analyses.get(&path)?;
+        let source = self.buffers.get(&path)?;
+        let position = source_position(source, params.position);
+
+        Some((path.to_string_lossy().to_string(), analysis, source, position))
+    }
+
+    fn hover(&self, params: TextDocumentPositionParams) -> Option<Hover> {
+        let (path, analysis, _, position) = self.locate(&params)?;
+        let symbol = analysis.symbol(&path, position)?;
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```leo\n{}\n```", symbol.detail),
+            }),
+            range: Some(span_range(analysis.sources.get(&path)?, &symbol.span)),
+        })
+    }
+
+    fn definition(&self, params: TextDocumentPositionParams) -> Option<GotoDefinitionResponse> {
+        let (path, analysis, _, position) = self.locate(&params)?;
+        let definition = analysis.symbol(&path, position)?.definition.as_ref()?;
+        let uri = Url::from_file_path(&*definition.path).ok()?;
+        let range = span_range(analysis.sources.get(&*definition.path)?, definition);
+
+        Some(GotoDefinitionResponse::Scalar(Location::new(uri, range)))
+    }
+
+    fn completion(&self, params: TextDocumentPositionParams) -> Option<CompletionResponse> {
+        let (path, analysis, source, position) = self.locate(&params)?;
+        let items = analysis
+            .completions(&path, source, position)
+            .into_iter()
+            .map(|(name, member)| CompletionItem {
+                label: name.to_string(),
+                kind: Some(match member.kind {
+                    MemberKind::Variable => CompletionItemKind::FIELD,
+                    MemberKind::Method => CompletionItemKind::METHOD,
+                    MemberKind::Static => CompletionItemKind::FUNCTION,
+                }),
+                detail: Some(member.detail.clone()),
+                ..Default::default()
+            })
+            .collect();
+
+        Some(CompletionResponse::Array(items))
+    }
+}
+
+/// Answers `request` with the result of `handler` on its parameters.
+fn respond<P: DeserializeOwned, R: Serialize>(request: Request, handler: impl FnOnce(P) -> R) -> Response {
+    match serde_json::from_value::<P>(request.params) {
+        Ok(params) => Response::new_ok(request.id, handler(params)),
+        Err(error) => Response::new_err(request.id, ErrorCode::InvalidParams as i32, error.to_string()),
+    }
+}
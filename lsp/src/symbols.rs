@@ -0,0 +1,592 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Collects what an editor can ask about the names and expressions of a program from its ASG.
+
+use crate::{analysis::Sources, span_contains};
+use leo_asg::*;
+
+use indexmap::IndexMap;
+use std::{cell::Cell, collections::HashSet};
+
+/// What a symbol names.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SymbolKind {
+    Variable,
+    Function,
+    Circuit,
+    Member,
+    Expression,
+}
+
+/// A name or expression in the source, with its type or declaration.
+#[derive(Clone, Debug)]
+pub struct Symbol {
+    pub kind: SymbolKind,
+
+    /// Where the name or expression is written.
+    pub span: Span,
+
+    /// The type of a value, or the declaration of a function or circuit.
+    pub detail: String,
+
+    /// Where the named variable, function, circuit or member is declared.
+    pub definition: Option<Span>,
+
+    /// The id of the circuit the symbol names or of the type of its value.
+    pub circuit: Option<u32>,
+}
+
+/// How a circuit member is accessed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MemberKind {
+    /// A variable, accessed as `value.member`.
+    Variable,
+
+    /// A function taking `self`, called as `value.member()`.
+    Method,
+
+    /// A function without `self`, called as `Circuit::member()`.
+    Static,
+}
+
+/// A member of a circuit, as offered for completion.
+#[derive(Clone, Debug)]
+pub struct MemberSymbol {
+    pub kind: MemberKind,
+    pub detail: String,
+    pub definition: Option<Span>,
+
+    /// The id of the circuit of the type of a member variable.
+    pub circuit: Option<u32>,
+}
+
+/// The members of a circuit.
+#[derive(Clone, Debug)]
+pub struct CircuitSymbols {
+    pub name: String,
+    pub members: IndexMap<String, MemberSymbol>,
+}
+
+/// Returns the id of the circuit of values of type `type_`.
+fn circuit_id(type_: Option<&Type>) -> Option<u32> {
+    match type_ {
+        Some(Type::Circuit(circuit)) => Some(circuit.id),
+        _ => None,
+    }
+}
+
+/// Returns the declaration of `function`, e.g. `function add(mut self, other: Point) -> u32`.
+pub(crate) fn signature(function: &Function) -> String {
+    let mut inputs = match function.qualifier {
+        FunctionQualifier::SelfRef => vec!["self".to_string()],
+        FunctionQualifier::ConstSelfRef => vec!["const self".to_string()],
+        FunctionQualifier::MutSelfRef => vec!["mut self".to_string()],
+        FunctionQualifier::Static => vec![],
+    };
+    for (_, argument) in function.arguments.iter() {
+        let argument = argument.get().borrow();
+        inputs.push(format!(
+            "{}{}: {}",
+            if argument.const_ { "const " } else { "" },
+            argument.name.name,
+            argument.type_
+        ));
+    }
+    let output = match &function.output {
+        Type::Tuple(types) if types.is_empty() => String::new(),
+        output => format!(" -> {}", output),
+    };
+
+    format!(
+        "function {}({}){}",
+        function.name.borrow().name,
+        inputs.join(", "),
+        output
+    )
+}
+
+/// Returns the declaration of `variable`, e.g. `let a: u32`.
+fn declaration(variable: &InnerVariable) -> String {
+    match variable.declaration {
+        VariableDeclaration::Definition if variable.mutable => {
+            format!("let {}: {}", variable.name.name, variable.type_)
+        }
+        VariableDeclaration::Definition => format!("const {}: {}", variable.name.name, variable.type_),
+        _ => format!("{}: {}", variable.name.name, variable.type_),
+    }
+}
+
+///
+/// Collects the symbols of a program and of the programs it imports.
+///
+/// Circuit member variables are not part of the ASG, their declarations are looked up in the
+/// syntax tree of the file declaring the circuit.
+///
+pub(crate) struct SymbolCollector<'s, 'b> {
+    sources: &'s mut Sources<'b>,
+    programs: HashSet<u32>,
+    pub(crate) symbols: Vec<Symbol>,
+    pub(crate) circuits: IndexMap<u32, CircuitSymbols>,
+
+    /// The span of every circuit function, and the id of its circuit.
+    pub(crate) circuit_functions: Vec<(Span, u32)>,
+}
+
+impl<'s, 'b> SymbolCollector<'s, 'b> {
+    pub(crate) fn new(sources: &'s mut Sources<'b>) -> Self {
+        Self {
+            sources,
+            programs: HashSet::new(),
+            symbols: Vec::new(),
+            circuits: IndexMap::new(),
+            circuit_functions: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, kind: SymbolKind, span: &Span, detail: String, definition: Option<&Span>, circuit: Option<u32>) {
+        self.symbols.push(Symbol {
+            kind,
+            span: span.clone(),
+            detail,
+            definition: definition.cloned(),
+            circuit,
+        });
+    }
+
+    fn push_variable(&mut self, variable: &Variable) {
+        let variable = variable.borrow();
+        let span = &variable.name.span;
+        self.push(
+            SymbolKind::Variable,
+            span,
+            declaration(&variable),
+            Some(span),
+            circuit_id(Some(&variable.type_)),
+        );
+    }
+
+    fn push_circuit_name(&mut self, span: &Span, circuit: &Circuit) {
+        let name = circuit.name.borrow();
+        let span = self.name_at(span, &name.name).or_else(|| self.name_at(span, "Self"));
+        if let Some(span) = span {
+            self.push(
+                SymbolKind::Circuit,
+                &span,
+                format!("circuit {}", name.name),
+                Some(&name.span),
+                Some(circuit.id),
+            );
+        }
+    }
+
+    fn push_member(&mut self, span: &Span, circuit: &Circuit, member: &str) {
+        if let Some(symbol) = self.member(circuit, member) {
+            self.push(
+                SymbolKind::Member,
+                span,
+                symbol.detail,
+                symbol.definition.as_ref(),
+                symbol.circuit,
+            );
+        }
+    }
+
+    /// Returns the member `name` of `circuit`, recording the members of the circuit on first use.
+    fn member(&mut self, circuit: &Circuit, name: &str) -> Option<MemberSymbol> {
+        if !self.circuits.contains_key(&circuit.id) {
+            let members = self.members(circuit);
+            self.circuits.insert(circuit.id, CircuitSymbols {
+                name: circuit.name.borrow().name.to_string(),
+                members,
+            });
+        }
+
+        self.circuits[&circuit.id].members.get(name).cloned()
+    }
+
+    fn members(&mut self, circuit: &Circuit) -> IndexMap<String, MemberSymbol> {
+        let circuit_name = circuit.name.borrow();
+        let declared = self
+            .sources
+            .ast(&circuit_name.span.path)
+            .and_then(|ast| {
+                ast.circuits
+                    .values()
+                    .find(|ast| ast.circuit_name.name == circuit_name.name)
+            })
+            .map(|ast| {
+                ast.members
+                    .iter()
+                    .filter_map(|member| match member {
+                        leo_ast::CircuitMember::CircuitVariable(name, _) => {
+                            Some((name.name.to_string(), name.span.clone()))
+                        }
+                        leo_ast::CircuitMember::CircuitFunction(_) => None,
+                    })
+                    .collect::<IndexMap<_, _>>()
+            })
+            .unwrap_or_default();
+
+        circuit
+            .members
+            .borrow()
+            .iter()
+            .map(|(name, member)| {
+                let member = match member {
+                    CircuitMember::Variable(type_) => MemberSymbol {
+                        kind: MemberKind::Variable,
+                        detail: format!("{}: {}", name, type_),
+                        definition: declared.get(name).cloned(),
+                        circuit: circuit_id(Some(type_)),
+                    },
+                    CircuitMember::Function(function) => MemberSymbol {
+                        kind: match function.qualifier {
+                            FunctionQualifier::Static => MemberKind::Static,
+                            _ => MemberKind::Method,
+                        },
+                        detail: signature(function),
+                        definition: Some(function.name.borrow().span.clone()),
+                        circuit: circuit_id(Some(&function.output)),
+                    },
+                };
+                (name.clone(), member)
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the span of `name` if it is written at the start of `span`.
+    ///
+    fn name_at(&mut self, span: &Span, name: &str) -> Option<Span> {
+        let line = self.sources.line(&span.path, span.line_start)?;
+        let start = span.col_start - 1;
+        match line.get(start..start + name.len()) == Some(name) {
+            true => Some(Span {
+                line_start: span.line_start,
+                line_stop: span.line_start,
+                col_start: span.col_start,
+                col_stop: span.col_start + name.len(),
+                path: span.path.clone(),
+                content: line.into(),
+            }),
+            false => None,
+        }
+    }
+
+    ///
+    /// Returns the span of the function `name` called by the call expression at `span`, which is
+    /// written before the first parenthesis after `after`.
+    ///
+    fn called_name(&mut self, span: &Span, after: Option<&Span>, name: &str) -> Option<Span> {
+        let (line_number, column) = match after {
+            Some(after) => (after.line_stop, after.col_stop),
+            None => (span.line_start, span.col_start),
+        };
+        let line = self.sources.line(&span.path, line_number)?;
+        let parenthesis = column - 1 + line.get(column - 1..)?.find('(')?;
+        let stop = line[..parenthesis].trim_end().len();
+        let start = stop.checked_sub(name.len())?;
+
+        match &line[start..stop] == name {
+            true => Some(Span {
+                line_start: line_number,
+                line_stop: line_number,
+                col_start: start + 1,
+                col_stop: stop + 1,
+                path: span.path.clone(),
+                content: line.into(),
+            }),
+            false => None,
+        }
+    }
+
+    ///
+    /// Adds the symbols imported by the program declared in the file at `path`, resolved through
+    /// the scope of its imports.
+    ///
+    fn push_imports(&mut self, path: &str, program: &Program) {
+        let imports = match (self.sources.ast(path), program.scope.parent_scope.get()) {
+            (Some(ast), Some(scope)) => {
+                let mut symbols = Vec::new();
+                for import in ast.imports.iter() {
+                    imported_symbols(&import.package_or_packages, &mut symbols);
+                }
+                (symbols, scope)
+            }
+            _ => return,
+        };
+
+        let (symbols, scope) = imports;
+        for symbol in symbols {
+            let local = symbol.alias.as_ref().unwrap_or(&symbol.symbol);
+            let names = std::iter::once(&symbol.symbol).chain(symbol.alias.as_ref());
+
+            if let Some(function) = scope.functions.borrow().get(local.name.as_ref()) {
+                let definition = function.name.borrow().span.clone();
+                for name in names {
+                    self.push(
+                        SymbolKind::Function,
+                        &name.span,
+                        signature(function),
+                        Some(&definition),
+                        None,
+                    );
+                }
+            } else if let Some(circuit) = scope.circuits.borrow().get(local.name.as_ref()) {
+                let definition = circuit.name.borrow().span.clone();
+                for name in names {
+                    self.push(
+                        SymbolKind::Circuit,
+                        &name.span,
+                        format!("circuit {}", circuit.name.borrow().name),
+                        Some(&definition),
+                        Some(circuit.id),
+                    );
+                }
+            } else if let Some(variable) = scope.variables.borrow().get(local.name.as_ref()) {
+                let variable = variable.borrow();
+                for name in names {
+                    self.push(
+                        SymbolKind::Variable,
+                        &name.span,
+                        declaration(&variable),
+                        Some(&variable.name.span),
+                        circuit_id(Some(&variable.type_)),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Appends the symbols named by an import statement to `output`.
+fn imported_symbols(package: &leo_ast::PackageOrPackages, output: &mut Vec<leo_ast::ImportSymbol>) {
+    match package {
+        leo_ast::PackageOrPackages::Package(package) => imported_access(&package.access, output),
+        leo_ast::PackageOrPackages::Packages(packages) => {
+            for access in packages.accesses.iter() {
+                imported_access(access, output);
+            }
+        }
+    }
+}
+
+fn imported_access(access: &leo_ast::PackageAccess, output: &mut Vec<leo_ast::ImportSymbol>) {
+    match access {
+        leo_ast::PackageAccess::Star { .. } => {}
+        leo_ast::PackageAccess::SubPackage(package) => imported_access(&package.access, output),
+        leo_ast::PackageAccess::Symbol(symbol) => output.push(symbol.clone()),
+        leo_ast::PackageAccess::Multiple(packages) => {
+            for access in packages.accesses.iter() {
+                imported_access(access, output);
+            }
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for SymbolCollector<'_, '_> {
+    fn visit_expression(&mut self, input: &Cell<&'a Expression<'a>>) -> VisitResult {
+        let expression = input.get();
+        if let (Some(span), Some(type_)) = (expression.span(), expression.get_type()) {
+            if !matches!(expression, Expression::VariableRef(_)) {
+                self.push(
+                    SymbolKind::Expression,
+                    span,
+                    type_.to_string(),
+                    None,
+                    circuit_id(Some(&type_)),
+                );
+            }
+        }
+        VisitResult::VisitChildren
+    }
+
+    fn visit_call(&mut self, input: &CallExpression<'a>) -> VisitResult {
+        let function = input.function.get();
+        let target = input.target.get().and_then(|target| target.span());
+        let name = function.name.borrow();
+        if let Some(span) = input.span.as_ref() {
+            if let Some(name_span) = self.called_name(span, target, &name.name) {
+                self.push(
+                    SymbolKind::Function,
+                    &name_span,
+                    signature(function),
+                    Some(&name.span),
+                    None,
+                );
+            }
+            if let (None, Some(circuit)) = (target, function.circuit.get()) {
+                self.push_circuit_name(span, circuit);
+            }
+        }
+        VisitResult::VisitChildren
+    }
+
+    fn visit_circuit_access(&mut self, input: &CircuitAccessExpression<'a>) -> VisitResult {
+        let circuit = input.circuit.get();
+        self.push_member(&input.member.span, circuit, &input.member.name);
+        if let (None, Some(span)) = (input.target.get(), input.span.as_ref()) {
+            self.push_circuit_name(span, circuit);
+        }
+        VisitResult::VisitChildren
+    }
+
+    fn visit_circuit_init(&mut self, input: &CircuitInitExpression<'a>) -> VisitResult {
+        let circuit = input.circuit.get();
+        if let Some(span) = input.span.as_ref() {
+            self.push_circuit_name(span, circuit);
+        }
+        for (name, _) in input.values.iter() {
+            self.push_member(&name.span, circuit, &name.name);
+        }
+        VisitResult::VisitChildren
+    }
+
+    fn visit_variable_ref(&mut self, input: &VariableRef<'a>) -> VisitResult {
+        let variable = input.variable.borrow();
+        if let Some(span) = input.span.as_ref() {
+            let definition = match variable.declaration {
+                VariableDeclaration::Input => None,
+                _ => Some(&variable.name.span),
+            };
+            self.push(
+                SymbolKind::Variable,
+                span,
+                declaration(&variable),
+                definition,
+                circuit_id(Some(&variable.type_)),
+            );
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> StatementVisitor<'a> for SymbolCollector<'_, '_> {
+    fn visit_definition(&mut self, input: &DefinitionStatement<'a>) -> VisitResult {
+        for variable in input.variables.iter() {
+            self.push_variable(variable);
+        }
+        VisitResult::VisitChildren
+    }
+
+    fn visit_iteration(&mut self, input: &IterationStatement<'a>) -> VisitResult {
+        self.push_variable(input.variable);
+        VisitResult::VisitChildren
+    }
+
+    fn visit_match(&mut self, input: &MatchStatement<'a>) -> VisitResult {
+        for arm in input.arms.iter() {
+            if let MatchPattern::Variant { bindings, .. } = &arm.pattern {
+                for variable in bindings.iter().flatten() {
+                    self.push_variable(variable);
+                }
+            }
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for SymbolCollector<'_, '_> {
+    fn visit_function(&mut self, input: &'a Function<'a>) -> VisitResult {
+        let name = input.name.borrow();
+        self.push(
+            SymbolKind::Function,
+            &name.span,
+            signature(input),
+            Some(&name.span),
+            None,
+        );
+        for (_, argument) in input.arguments.iter() {
+            self.push_variable(argument.get());
+        }
+        if let (Some(circuit), Some(span)) = (input.circuit.get(), input.span.as_ref()) {
+            self.circuit_functions.push((span.clone(), circuit.id));
+        }
+        VisitResult::VisitChildren
+    }
+
+    fn visit_circuit(&mut self, input: &'a Circuit<'a>) -> VisitResult {
+        let name = input.name.borrow();
+        self.push(
+            SymbolKind::Circuit,
+            &name.span,
+            format!("circuit {}", name.name),
+            Some(&name.span),
+            Some(input.id),
+        );
+        drop(name);
+
+        let member_names = input.members.borrow().keys().cloned().collect::<Vec<_>>();
+        for member in member_names {
+            if let Some(MemberSymbol {
+                kind: MemberKind::Variable,
+                definition: Some(span),
+                detail,
+                circuit,
+            }) = self.member(input, &member)
+            {
+                self.push(SymbolKind::Member, &span, detail, Some(&span), circuit);
+            }
+        }
+        VisitResult::VisitChildren
+    }
+
+    fn visit_global_const(&mut self, input: &'a DefinitionStatement<'a>) -> VisitResult {
+        self.visit_definition(input)
+    }
+
+    fn visit_program(&mut self, input: &Program<'a>) -> VisitResult {
+        if !self.programs.insert(input.id) {
+            return VisitResult::SkipChildren;
+        }
+
+        // The file of a program is found through the spans of its declarations.
+        let path = input
+            .functions
+            .values()
+            .filter_map(|function| function.span.as_ref())
+            .chain(input.circuits.values().filter_map(|circuit| circuit.span.as_ref()))
+            .chain(
+                input
+                    .global_consts
+                    .values()
+                    .filter_map(|global_const| global_const.span.as_ref()),
+            )
+            .map(|span| span.path.clone())
+            .next();
+        if let Some(path) = path {
+            self.push_imports(&path, input);
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+///
+/// Returns the smallest of `symbols` in the file at `path` that contains `position`.
+///
+pub(crate) fn symbol_at<'s>(symbols: &'s [Symbol], path: &str, position: (usize, usize)) -> Option<&'s Symbol> {
+    symbols
+        .iter()
+        .rev()
+        .filter(|symbol| *symbol.span.path == path && span_contains(&symbol.span, position))
+        .min_by_key(|symbol| {
+            (
+                symbol.span.line_stop - symbol.span.line_start,
+                symbol.span.col_stop as isize - symbol.span.col_start as isize,
+            )
+        })
+}
@@ -0,0 +1,271 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_imports::ImportsMap;
+use leo_lsp::{Analysis, LanguageServer, MemberKind};
+
+use indexmap::IndexMap;
+use lsp_server::{Connection, Message, Notification, Request, RequestId};
+use serde_json::json;
+use std::path::{Path, PathBuf};
+
+const MAIN_PATH: &str = "/virtual/package/src/main.leo";
+const GEOMETRY_PATH: &str = "/virtual/package/src/geometry.leo";
+
+/// Returns the unsaved buffers of a package that only exists in memory.
+fn buffers(main: &str, geometry: &str) -> IndexMap<PathBuf, String> {
+    let mut buffers = IndexMap::new();
+    buffers.insert(PathBuf::from(MAIN_PATH), main.to_string());
+    buffers.insert(PathBuf::from(GEOMETRY_PATH), geometry.to_string());
+    buffers
+}
+
+fn analyze(path: &str, buffers: &IndexMap<PathBuf, String>) -> Analysis {
+    Analysis::new(Path::new(path), buffers, &ImportsMap::default())
+}
+
+/// Returns the 1-based line and byte column of the `nth` occurrence of `needle` in `source`, plus `offset` bytes.
+fn position(source: &str, needle: &str, nth: usize, offset: usize) -> (usize, usize) {
+    let index = source.match_indices(needle).nth(nth).unwrap().0 + offset;
+    let line_start = source[..index].rfind('\n').map(|newline| newline + 1).unwrap_or(0);
+
+    (source[..index].matches('\n').count() + 1, index - line_start + 1)
+}
+
+#[test]
+fn test_diagnostics() {
+    let main = include_str!("main.leo");
+    let geometry = include_str!("geometry.leo");
+    assert!(analyze(MAIN_PATH, &buffers(main, geometry)).errors.is_empty());
+
+    let analysis = analyze(
+        MAIN_PATH,
+        &buffers(&main.replace("return line", "return lin"), geometry),
+    );
+    assert_eq!(analysis.errors.len(), 1);
+    assert_eq!(*analysis.errors[0].path, MAIN_PATH);
+    assert_eq!(analysis.errors[0].line_start, 18);
+
+    // The module is analyzed as part of the package, its errors are reported in its own file.
+    let analysis = analyze(GEOMETRY_PATH, &buffers(main, &geometry.replace("x: 0,", "x: 0u8,")));
+    assert_eq!(analysis.errors.len(), 1);
+    assert_eq!(*analysis.errors[0].path, GEOMETRY_PATH);
+    assert_eq!(analysis.errors[0].line_start, 6);
+}
+
+#[test]
+fn test_unsaved_buffers() {
+    let main = include_str!("main.leo");
+    let geometry = include_str!("geometry.leo").replace("y: u32,", "z: u32,");
+
+    let analysis = analyze(MAIN_PATH, &buffers(main, &geometry));
+    assert_eq!(analysis.errors.len(), 1);
+    assert_eq!(*analysis.errors[0].path, GEOMETRY_PATH);
+}
+
+#[test]
+fn test_hover() {
+    let main = include_str!("main.leo");
+    let analysis = analyze(MAIN_PATH, &buffers(main, include_str!("geometry.leo")));
+
+    let hover = |needle, nth, offset| {
+        analysis
+            .symbol(MAIN_PATH, position(main, needle, nth, offset))
+            .map(|symbol| symbol.detail.clone())
+    };
+    assert_eq!(hover("line.", 0, 0).unwrap(), "let line: Line");
+    assert_eq!(
+        hover("length_squared()", 0, 0).unwrap(),
+        "function length_squared(self) -> u32"
+    );
+    assert_eq!(hover("dx * dx", 0, 3).unwrap(), "u32");
+    assert_eq!(hover("a: u32", 0, 0).unwrap(), "a: u32");
+    assert_eq!(hover("Point::", 0, 0).unwrap(), "circuit Point");
+    assert_eq!(hover("start.x", 0, 6).unwrap(), "x: u32");
+    assert!(hover("mod", 0, 0).is_none());
+}
+
+#[test]
+fn test_definition() {
+    let main = include_str!("main.leo");
+    let geometry = include_str!("geometry.leo");
+    let analysis = analyze(MAIN_PATH, &buffers(main, geometry));
+
+    let definition = |needle, nth, offset| {
+        let span = analysis
+            .symbol(MAIN_PATH, position(main, needle, nth, offset))?
+            .definition
+            .clone()?;
+        Some((span.path.to_string(), span.line_start, span.col_start))
+    };
+
+    // Variables, functions and circuits of the document.
+    assert_eq!(definition("line.", 0, 0).unwrap(), (MAIN_PATH.to_string(), 17, 9));
+    assert_eq!(
+        definition("length_squared()", 0, 0).unwrap(),
+        (MAIN_PATH.to_string(), 9, 14)
+    );
+    assert_eq!(definition("Line {", 1, 0).unwrap(), (MAIN_PATH.to_string(), 5, 9));
+
+    // Symbols of the module, resolved by the import parser.
+    assert_eq!(definition("Point;", 0, 0).unwrap(), (GEOMETRY_PATH.to_string(), 1, 13));
+    assert_eq!(definition("origin", 0, 0).unwrap(), (GEOMETRY_PATH.to_string(), 5, 14));
+    assert_eq!(definition("end.x", 0, 4).unwrap(), (GEOMETRY_PATH.to_string(), 2, 5));
+    assert_eq!(definition("y: a", 0, 0).unwrap(), (GEOMETRY_PATH.to_string(), 3, 5));
+}
+
+#[test]
+fn test_completion() {
+    let main = include_str!("main.leo");
+    let analysis = analyze(MAIN_PATH, &buffers(main, include_str!("geometry.leo")));
+
+    // The symbols of the last analysis complete members in text that does not parse.
+    let completions = |edited: &str, needle| {
+        let position = position(edited, needle, 0, needle.len());
+        analysis
+            .completions(MAIN_PATH, edited, position)
+            .into_iter()
+            .map(|(name, member)| (name.to_string(), member.kind))
+            .collect::<Vec<_>>()
+    };
+
+    let edited = main.replace("return line.length_squared();", "return line.");
+    assert_eq!(completions(&edited, "return line."), vec![
+        ("start".to_string(), MemberKind::Variable),
+        ("end".to_string(), MemberKind::Variable),
+        ("length_squared".to_string(), MemberKind::Method),
+    ]);
+
+    let edited = main.replace("let dy = self.end.y", "let dy = self.end.");
+    assert_eq!(completions(&edited, "dy = self.end."), vec![
+        ("x".to_string(), MemberKind::Variable),
+        ("y".to_string(), MemberKind::Variable),
+    ]);
+
+    let edited = main.replace("Point::origin()", "Point::or");
+    assert_eq!(completions(&edited, "Point::or"), vec![(
+        "origin".to_string(),
+        MemberKind::Static
+    )]);
+
+    assert!(completions(main, "return ").is_empty());
+}
+
+fn request(id: i32, method: &str, params: serde_json::Value) -> Message {
+    Message::Request(Request::new(RequestId::from(id), method.to_string(), params))
+}
+
+fn notification(method: &str, params: serde_json::Value) -> Message {
+    Message::Notification(Notification::new(method.to_string(), params))
+}
+
+#[test]
+fn test_server() {
+    let (server, client) = Connection::memory();
+    let server = std::thread::spawn(move || LanguageServer::new(server, ImportsMap::default()).run());
+
+    let main = include_str!("main.leo");
+    let uri = |path: &str| format!("file://{}", path);
+    let open = |path: &str, text: &str| {
+        notification(
+            "textDocument/didOpen",
+            json!({ "textDocument": { "uri": uri(path), "languageId": "leo", "version": 1, "text": text } }),
+        )
+    };
+    let receive = || client.receiver.recv().unwrap();
+
+    client
+        .sender
+        .send(request(1, "initialize", json!({ "capabilities": {} })))
+        .unwrap();
+    match receive() {
+        Message::Response(response) => assert!(response.result.unwrap()["capabilities"]["hoverProvider"] == true),
+        message => panic!("unexpected message {:?}", message),
+    }
+    client.sender.send(notification("initialized", json!({}))).unwrap();
+
+    // A broken document is reported, fixing it clears the report.
+    client
+        .sender
+        .send(open(GEOMETRY_PATH, include_str!("geometry.leo")))
+        .unwrap();
+    client
+        .sender
+        .send(open(MAIN_PATH, &main.replace("return line", "return lin")))
+        .unwrap();
+    match receive() {
+        Message::Notification(notification) => {
+            assert_eq!(notification.method, "textDocument/publishDiagnostics");
+            assert_eq!(notification.params["uri"], uri(MAIN_PATH));
+            assert_eq!(notification.params["diagnostics"][0]["range"]["start"]["line"], 17);
+        }
+        message => panic!("unexpected message {:?}", message),
+    }
+    client
+        .sender
+        .send(notification(
+            "textDocument/didChange",
+            json!({ "textDocument": { "uri": uri(MAIN_PATH), "version": 2 }, "contentChanges": [{ "text": main }] }),
+        ))
+        .unwrap();
+    match receive() {
+        Message::Notification(notification) => {
+            assert_eq!(notification.params["uri"], uri(MAIN_PATH));
+            assert_eq!(notification.params["diagnostics"], json!([]));
+        }
+        message => panic!("unexpected message {:?}", message),
+    }
+
+    let (line, column) = position(main, "origin", 0, 0);
+    let position = json!({ "line": line - 1, "character": column - 1 });
+    let document = json!({ "uri": uri(MAIN_PATH) });
+    client
+        .sender
+        .send(request(
+            2,
+            "textDocument/hover",
+            json!({ "textDocument": document, "position": position }),
+        ))
+        .unwrap();
+    match receive() {
+        Message::Response(response) => assert_eq!(
+            response.result.unwrap()["contents"]["value"],
+            "```leo\nfunction origin() -> Point\n```"
+        ),
+        message => panic!("unexpected message {:?}", message),
+    }
+    client
+        .sender
+        .send(request(
+            3,
+            "textDocument/definition",
+            json!({ "textDocument": document, "position": position }),
+        ))
+        .unwrap();
+    match receive() {
+        Message::Response(response) => {
+            let result = response.result.unwrap();
+            assert_eq!(result["uri"], uri(GEOMETRY_PATH));
+            assert_eq!(result["range"]["start"], json!({ "line": 4, "character": 13 }));
+        }
+        message => panic!("unexpected message {:?}", message),
+    }
+
+    client.sender.send(request(4, "shutdown", json!(null))).unwrap();
+    assert!(matches!(receive(), Message::Response(_)));
+    client.sender.send(notification("exit", json!(null))).unwrap();
+    server.join().unwrap().unwrap();
+}
@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::ManifestError;
+
+use std::{io, path::PathBuf};
+
+#[derive(Debug, Error)]
+pub enum ResolverError {
+    #[error(
+        "checksum of `{}` does not match {}, the package was modified after it was locked",
+        _0,
+        _1
+    )]
+    ChecksumMismatch(String, &'static str),
+
+    #[error("dependency `{}` must declare either a `path` or an `author` and a `package`", _0)]
+    InvalidDependency(String),
+
+    #[error("{}", _0)]
+    ManifestError(#[from] ManifestError),
+
+    #[error("dependency `{}` points to {:?}, which is not a Leo package", _0, _1)]
+    MissingPackage(String, PathBuf),
+
+    #[error("dependency `{}` ({}) is not installed, run `leo install` to fetch it", _0, _1)]
+    NotInstalled(String, String),
+
+    #[error("dependency `{}` expects package `{}`, found `{}`", _0, _1, _2)]
+    PackageMismatch(String, String, String),
+
+    #[error("reading {:?}: {}", _0, _1)]
+    Reading(PathBuf, io::Error),
+
+    #[error("recursive dependency: {}", _0)]
+    RecursiveDependency(String),
+
+    #[error(
+        "dependency `{}` has no version and is not locked, run `leo install` to fetch the latest version",
+        _0
+    )]
+    UnversionedDependency(String),
+
+    #[error("dependency `{}` expects version {}, found {}", _0, _1, _2)]
+    VersionMismatch(String, String, String),
+}
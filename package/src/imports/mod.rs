@@ -16,3 +16,6 @@
 
 pub mod directory;
 pub use directory::*;
+
+pub mod resolver;
+pub use resolver::*;
@@ -0,0 +1,345 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Resolves the packages declared in the `[dependencies]` section of a manifest.
+
+use crate::{
+    errors::ResolverError,
+    imports::IMPORTS_DIRECTORY_NAME,
+    root::{package_id, Dependency, LockFile, LockedPackage, Manifest, LOCK_FILENAME, MANIFEST_FILENAME},
+    source::SOURCE_DIRECTORY_NAME,
+};
+
+use sha2::{Digest, Sha256};
+use std::{
+    collections::BTreeMap,
+    convert::TryFrom,
+    fs,
+    path::{Path, PathBuf},
+};
+use walkdir::WalkDir;
+
+/// A package reached from the `[dependencies]` section of the root package or of another dependency.
+#[derive(Clone, Debug)]
+pub struct ResolvedPackage {
+    pub name: String,
+    pub version: String,
+    /// The author of a package fetched from the Aleo Package Manager.
+    pub author: Option<String>,
+    /// The directory of a local package, relative to the root package.
+    pub path: Option<String>,
+    /// The SHA-256 checksum of the package manifest and source files.
+    pub checksum: String,
+    /// The canonical directory of the package.
+    pub directory: PathBuf,
+    /// The packages this package imports, keyed by import name.
+    pub dependencies: BTreeMap<String, PathBuf>,
+}
+
+/// The dependency tree of a package.
+#[derive(Clone, Debug, Default)]
+pub struct Resolution {
+    /// The canonical directory of the root package.
+    pub root: PathBuf,
+    /// The packages the root package imports, keyed by import name.
+    pub dependencies: BTreeMap<String, PathBuf>,
+    /// Every package of the tree except the root, keyed by canonical directory.
+    pub packages: BTreeMap<PathBuf, ResolvedPackage>,
+}
+
+impl Resolution {
+    /// Returns the import names declared by each package of the tree, including the root,
+    /// keyed by package directory.
+    pub fn imports(&self) -> impl Iterator<Item = (&PathBuf, &BTreeMap<String, PathBuf>)> {
+        std::iter::once((&self.root, &self.dependencies)).chain(
+            self.packages
+                .iter()
+                .map(|(directory, package)| (directory, &package.dependencies)),
+        )
+    }
+
+    /// Returns the lock file pinning every package of the tree.
+    pub fn lock_file(&self) -> LockFile {
+        let packages = self.packages.values().map(|package| LockedPackage {
+            name: package.name.clone(),
+            version: package.version.clone(),
+            author: package.author.clone(),
+            path: package.path.clone(),
+            checksum: package.checksum.clone(),
+            dependencies: package
+                .dependencies
+                .iter()
+                .map(|(name, directory)| (name.clone(), self.packages[directory].id()))
+                .collect(),
+        });
+
+        let mut packages: Vec<_> = packages.collect();
+        packages.sort_by_key(LockedPackage::id);
+
+        LockFile { packages }
+    }
+}
+
+impl ResolvedPackage {
+    pub fn id(&self) -> String {
+        package_id(self.author.as_deref(), &self.name, &self.version)
+    }
+}
+
+///
+/// Walks the `[dependencies]` sections of a package and of every package it depends on.
+///
+/// Local packages are read from their `path`, packages of the Aleo Package Manager from
+/// `imports/author-package@version` in the root package. The versions and checksums of
+/// registry packages are checked against the lock file, if there is one.
+///
+pub struct DependencyResolver<'a> {
+    root: PathBuf,
+    lock_file: Option<&'a LockFile>,
+    packages: BTreeMap<PathBuf, ResolvedPackage>,
+}
+
+impl<'a> DependencyResolver<'a> {
+    pub fn resolve(root: &Path, lock_file: Option<&'a LockFile>) -> Result<Resolution, ResolverError> {
+        let root = root
+            .canonicalize()
+            .map_err(|error| ResolverError::Reading(root.to_path_buf(), error))?;
+        let manifest = Manifest::try_from(root.as_path())?;
+
+        let mut resolver = Self {
+            root: root.clone(),
+            lock_file,
+            packages: BTreeMap::new(),
+        };
+
+        let mut stack = vec![(manifest.get_package_name(), root.clone())];
+        let dependencies = resolver.resolve_dependencies(&root, &manifest, &mut stack)?;
+
+        Ok(Resolution {
+            root,
+            dependencies,
+            packages: resolver.packages,
+        })
+    }
+
+    ///
+    /// Returns the directory the registry package `author/package` is installed to.
+    ///
+    pub fn install_directory(root: &Path, author: &str, package: &str, version: &str) -> PathBuf {
+        root.join(IMPORTS_DIRECTORY_NAME)
+            .join(package_id(Some(author), package, version))
+    }
+
+    fn resolve_dependencies(
+        &mut self,
+        directory: &Path,
+        manifest: &Manifest,
+        stack: &mut Vec<(String, PathBuf)>,
+    ) -> Result<BTreeMap<String, PathBuf>, ResolverError> {
+        let mut dependencies = BTreeMap::new();
+
+        for (name, dependency) in manifest.get_package_dependencies() {
+            let dependency_directory = self.locate(directory, &name, &dependency)?;
+
+            if let Some(position) = stack
+                .iter()
+                .position(|(_, directory)| directory == &dependency_directory)
+            {
+                let cycle = stack[position..]
+                    .iter()
+                    .map(|(package, _)| package.as_str())
+                    .chain(std::iter::once(stack[position].0.as_str()))
+                    .collect::<Vec<_>>();
+                return Err(ResolverError::RecursiveDependency(cycle.join(" -> ")));
+            }
+
+            if !self.packages.contains_key(&dependency_directory) {
+                self.resolve_package(&name, &dependency, &dependency_directory, stack)?;
+            }
+
+            dependencies.insert(name, dependency_directory);
+        }
+
+        Ok(dependencies)
+    }
+
+    fn resolve_package(
+        &mut self,
+        name: &str,
+        dependency: &Dependency,
+        directory: &Path,
+        stack: &mut Vec<(String, PathBuf)>,
+    ) -> Result<(), ResolverError> {
+        let manifest = Manifest::try_from(directory)?;
+
+        if let Some(package) = &dependency.package {
+            if package != &manifest.get_package_name() {
+                return Err(ResolverError::PackageMismatch(
+                    name.to_string(),
+                    package.clone(),
+                    manifest.get_package_name(),
+                ));
+            }
+        }
+        if let Some(version) = &dependency.version {
+            if version != &manifest.get_package_version() {
+                return Err(ResolverError::VersionMismatch(
+                    name.to_string(),
+                    version.clone(),
+                    manifest.get_package_version(),
+                ));
+            }
+        }
+
+        stack.push((manifest.get_package_name(), directory.to_path_buf()));
+        let dependencies = self.resolve_dependencies(directory, &manifest, stack)?;
+        stack.pop();
+
+        let package = ResolvedPackage {
+            name: manifest.get_package_name(),
+            version: manifest.get_package_version(),
+            author: match dependency.path {
+                Some(_) => None,
+                None => dependency.author.clone(),
+            },
+            path: dependency.path.as_ref().map(|_| self.relative_path(directory)),
+            checksum: checksum(directory)?,
+            directory: directory.to_path_buf(),
+            dependencies,
+        };
+
+        // Local packages are expected to change, registry packages must match what was locked.
+        if package.author.is_some() {
+            if let Some(locked) = self.lock_file.and_then(|lock_file| lock_file.get(&package.id())) {
+                if locked.checksum != package.checksum {
+                    return Err(ResolverError::ChecksumMismatch(package.id(), LOCK_FILENAME));
+                }
+            }
+        }
+
+        self.packages.insert(directory.to_path_buf(), package);
+
+        Ok(())
+    }
+
+    /// Returns the canonical directory of the package declared as `name` by the package at `directory`.
+    fn locate(&self, directory: &Path, name: &str, dependency: &Dependency) -> Result<PathBuf, ResolverError> {
+        let location = match (&dependency.path, &dependency.author, &dependency.package) {
+            (Some(path), _, _) => directory.join(path),
+            (None, Some(author), Some(package)) => {
+                let version = match &dependency.version {
+                    Some(version) => version.clone(),
+                    None => self
+                        .lock_file
+                        .and_then(|lock_file| lock_file.find(author, package))
+                        .map(|locked| locked.version.clone())
+                        .or_else(|| self.installed_version(author, package))
+                        .ok_or_else(|| ResolverError::UnversionedDependency(name.to_string()))?,
+                };
+
+                let location = Self::install_directory(&self.root, author, package, &version);
+                if !location.join(MANIFEST_FILENAME).exists() {
+                    return Err(ResolverError::NotInstalled(
+                        name.to_string(),
+                        format!("{}/{}@{}", author, package, version),
+                    ));
+                }
+                location
+            }
+            _ => return Err(ResolverError::InvalidDependency(name.to_string())),
+        };
+
+        if !location.join(MANIFEST_FILENAME).exists() {
+            return Err(ResolverError::MissingPackage(name.to_string(), location));
+        }
+
+        location
+            .canonicalize()
+            .map_err(|error| ResolverError::Reading(location, error))
+    }
+
+    /// Returns the version of the registry package `author/package`, if exactly one version is installed.
+    fn installed_version(&self, author: &str, package: &str) -> Option<String> {
+        let prefix = format!("{}-{}@", author, package);
+        let versions = fs::read_dir(self.root.join(IMPORTS_DIRECTORY_NAME))
+            .ok()?
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter_map(|name| name.strip_prefix(&prefix).map(str::to_string))
+            .collect::<Vec<_>>();
+
+        match versions.as_slice() {
+            [version] => Some(version.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the path of a local package relative to the root package, with `/` separators.
+    fn relative_path(&self, directory: &Path) -> String {
+        let root = self.root.components().collect::<Vec<_>>();
+        let directory = directory.components().collect::<Vec<_>>();
+        let common = root.iter().zip(&directory).take_while(|(a, b)| a == b).count();
+
+        let mut components = vec![".."; root.len() - common];
+        components.extend(
+            directory[common..]
+                .iter()
+                .map(|component| component.as_os_str().to_str().unwrap_or_default()),
+        );
+
+        match components.is_empty() {
+            true => ".".to_string(),
+            false => components.join("/"),
+        }
+    }
+}
+
+///
+/// Returns the SHA-256 checksum of the manifest and source files of the package at `directory`.
+///
+/// Files are hashed in path order along with their paths, so renaming a file changes the checksum.
+///
+pub fn checksum(directory: &Path) -> Result<String, ResolverError> {
+    let mut files = vec![PathBuf::from(MANIFEST_FILENAME)];
+    for entry in WalkDir::new(directory.join(SOURCE_DIRECTORY_NAME)).sort_by(|a, b| a.file_name().cmp(b.file_name())) {
+        let entry = entry.map_err(|error| ResolverError::Reading(directory.to_path_buf(), error.into()))?;
+        if entry.file_type().is_file() {
+            files.push(
+                entry
+                    .path()
+                    .strip_prefix(directory)
+                    .unwrap_or(entry.path())
+                    .to_path_buf(),
+            );
+        }
+    }
+
+    let mut hasher = Sha256::new();
+    for file in files {
+        let path = directory.join(&file);
+        let bytes = fs::read(&path).map_err(|error| ResolverError::Reading(path, error))?;
+
+        let name = file
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        hasher.update(name.as_bytes());
+        hasher.update((bytes.len() as u64).to_le_bytes());
+        hasher.update(&bytes);
+    }
+
+    Ok(hex::encode(hasher.finalize()))
+}
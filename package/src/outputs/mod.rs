@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod binary_circuit;
+pub use self::binary_circuit::*;
+
 pub mod circuit;
 pub use self::circuit::*;
 
@@ -31,3 +34,6 @@ pub use self::proving_key::*;
 
 pub mod verification_key;
 pub use self::verification_key::*;
+
+pub mod profile;
+pub use self::profile::*;
@@ -49,11 +49,10 @@ impl ProofFile {
     }
 
     /// Reads the proof from the given file path if it exists.
-    pub fn read_from(&self, path: &Path) -> Result<String, ProofFileError> {
+    pub fn read_from(&self, path: &Path) -> Result<Vec<u8>, ProofFileError> {
         let path = self.setup_file_path(path);
 
-        let proof = fs::read_to_string(&path).map_err(|_| ProofFileError::FileReadError(path.into_owned()))?;
-        Ok(proof)
+        fs::read(&path).map_err(|_| ProofFileError::FileReadError(path.into_owned()))
     }
 
     /// Writes the given proof to a file.
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The `Leo.lock` file.
+
+use crate::errors::LockFileError;
+
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::BTreeMap, fs, path::Path};
+
+pub const LOCK_FILENAME: &str = "Leo.lock";
+
+const LOCK_FILE_HEADER: &str =
+    "# This file is automatically generated by Leo.\n# It is not intended for manual editing.\n";
+
+/// The exact version and checksum of every package a program depends on.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct LockFile {
+    #[serde(default, rename = "package")]
+    pub packages: Vec<LockedPackage>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: String,
+    /// The author of a package fetched from the Aleo Package Manager.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// The directory of a local package, relative to the root package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    pub checksum: String,
+    /// The packages this package imports, keyed by import name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub dependencies: BTreeMap<String, String>,
+}
+
+/// Returns the identifier of a package, `author-package@version` for packages
+/// of the Aleo Package Manager and `package@version` for local packages.
+pub fn package_id(author: Option<&str>, name: &str, version: &str) -> String {
+    match author {
+        Some(author) => format!("{}-{}@{}", author, name, version),
+        None => format!("{}@{}", name, version),
+    }
+}
+
+impl LockedPackage {
+    pub fn id(&self) -> String {
+        package_id(self.author.as_deref(), &self.name, &self.version)
+    }
+}
+
+impl LockFile {
+    pub fn filename() -> String {
+        LOCK_FILENAME.to_string()
+    }
+
+    pub fn exists_at(path: &Path) -> bool {
+        Self::setup_file_path(path).exists()
+    }
+
+    /// Returns the locked package with the given identifier.
+    pub fn get(&self, id: &str) -> Option<&LockedPackage> {
+        self.packages.iter().find(|package| package.id() == id)
+    }
+
+    /// Returns the locked package of the Aleo Package Manager with the given author and name.
+    pub fn find(&self, author: &str, name: &str) -> Option<&LockedPackage> {
+        self.packages
+            .iter()
+            .find(|package| package.author.as_deref() == Some(author) && package.name == name)
+    }
+
+    pub fn read_from(path: &Path) -> Result<Self, LockFileError> {
+        let path = Self::setup_file_path(path);
+
+        let string = fs::read_to_string(&path).map_err(|error| LockFileError::Reading(LOCK_FILENAME, error))?;
+        toml::from_str(&string).map_err(|error| LockFileError::Parsing(LOCK_FILENAME, error))
+    }
+
+    pub fn write_to(&self, path: &Path) -> Result<(), LockFileError> {
+        let path = Self::setup_file_path(path);
+
+        let string = toml::to_string(self).map_err(|error| LockFileError::Serializing(LOCK_FILENAME, error))?;
+        fs::write(&path, format!("{}\n{}", LOCK_FILE_HEADER, string))
+            .map_err(|error| LockFileError::Writing(LOCK_FILENAME, error))
+    }
+
+    fn setup_file_path(path: &Path) -> Cow<'_, Path> {
+        let mut path = Cow::from(path);
+        if path.is_dir() {
+            path.to_mut().push(LOCK_FILENAME);
+        }
+        path
+    }
+}
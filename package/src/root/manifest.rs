@@ -19,10 +19,11 @@ use crate::{errors::ManifestError, package::Package};
 use serde::Deserialize;
 use std::{
     borrow::Cow,
+    collections::BTreeMap,
     convert::TryFrom,
     fs::File,
     io::{Read, Write},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 pub const MANIFEST_FILENAME: &str = "Leo.toml";
@@ -33,10 +34,32 @@ pub struct Remote {
     pub author: String,
 }
 
+/// A package declared in the `[dependencies]` section, keyed by the name it is imported with.
+///
+/// Either `author` and `package` name a package of the Aleo Package Manager,
+/// or `path` points to a local package directory, relative to the manifest.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Dependency {
+    pub author: Option<String>,
+    pub package: Option<String>,
+    pub version: Option<String>,
+    pub path: Option<PathBuf>,
+}
+
+/// Settings of the `[compiler]` section.
+#[derive(Clone, Deserialize)]
+pub struct CompilerSettings {
+    /// The maximum depth of inlined function calls, bounds recursion.
+    pub inline_limit: Option<u32>,
+}
+
 #[derive(Deserialize)]
 pub struct Manifest {
     pub project: Package,
     pub remote: Option<Remote>,
+    pub lint: Option<BTreeMap<String, String>>,
+    pub compiler: Option<CompilerSettings>,
+    pub dependencies: Option<BTreeMap<String, Dependency>>,
 }
 
 impl Manifest {
@@ -44,6 +67,9 @@ impl Manifest {
         Ok(Self {
             project: Package::new(package_name)?,
             remote: author.map(|author| Remote { author }),
+            lint: None,
+            compiler: None,
+            dependencies: None,
         })
     }
 
@@ -79,6 +105,21 @@ impl Manifest {
         self.remote.clone()
     }
 
+    /// Returns the lint rule levels configured in the `[lint]` section.
+    pub fn get_lint_levels(&self) -> BTreeMap<String, String> {
+        self.lint.clone().unwrap_or_default()
+    }
+
+    /// Returns the inline limit configured in the `[compiler]` section.
+    pub fn get_inline_limit(&self) -> Option<u32> {
+        self.compiler.as_ref().and_then(|compiler| compiler.inline_limit)
+    }
+
+    /// Returns the packages declared in the `[dependencies]` section, keyed by import name.
+    pub fn get_package_dependencies(&self) -> BTreeMap<String, Dependency> {
+        self.dependencies.clone().unwrap_or_default()
+    }
+
     pub fn write_to(self, path: &Path) -> Result<(), ManifestError> {
         let mut path = Cow::from(path);
         if path.is_dir() {
@@ -145,7 +186,7 @@ impl TryFrom<&Path> for Manifest {
         // Read each individual line of the toml file
         for line in buffer.lines() {
             // Determine if the old remote format is being used
-            if line.starts_with("remote") {
+            if line.split('=').next().map(str::trim) == Some("remote") {
                 let remote = line
                     .split('=') // Split the line as 'remote' = '"{author}/{package_name}"'
                     .collect::<Vec<&str>>()[1]; // Fetch just '"{author}/{package_name}"'
@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+// Tests for dependency resolution and the lock file
+
+use crate::test_dir;
+use leo_package::{
+    errors::ResolverError,
+    imports::DependencyResolver,
+    root::{LockFile, LOCK_FILENAME},
+};
+
+use std::{fs, path::Path};
+
+/// Create a package with the given name and `[dependencies]` section.
+fn create_package(path: &Path, name: &str, dependencies: &str) {
+    fs::create_dir_all(path.join("src")).unwrap();
+    fs::write(
+        path.join("Leo.toml"),
+        format!(
+            "[project]\nname = \"{}\"\nversion = \"0.1.0\"\n\n[dependencies]\n{}",
+            name, dependencies
+        ),
+    )
+    .unwrap();
+    fs::write(
+        path.join("src/main.leo"),
+        "function main() -> u8 {\n    return 1u8;\n}\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_resolve_path_dependencies() {
+    let test_directory = test_dir();
+    create_package(&test_directory.join("app"), "app", "lib = { path = \"../lib\" }\n");
+    create_package(&test_directory.join("lib"), "lib", "util = { path = \"../util\" }\n");
+    create_package(&test_directory.join("util"), "util", "");
+
+    let resolution = DependencyResolver::resolve(&test_directory.join("app"), None).unwrap();
+    let lib = test_directory.join("lib").canonicalize().unwrap();
+    let util = test_directory.join("util").canonicalize().unwrap();
+
+    assert_eq!(resolution.dependencies.get("lib"), Some(&lib));
+    assert_eq!(resolution.packages[&lib].dependencies.get("util"), Some(&util));
+    assert_eq!(resolution.imports().count(), 3);
+
+    let lock_file = resolution.lock_file();
+    assert_eq!(lock_file.packages.len(), 2);
+    assert_eq!(lock_file.get("lib@0.1.0").unwrap().path.as_deref(), Some("../lib"));
+    assert_eq!(
+        lock_file
+            .get("lib@0.1.0")
+            .unwrap()
+            .dependencies
+            .get("util")
+            .map(String::as_str),
+        Some("util@0.1.0")
+    );
+
+    // The lock file must survive a round trip.
+    lock_file.write_to(&test_directory.join("app")).unwrap();
+    assert_eq!(LockFile::read_from(&test_directory.join("app")).unwrap(), lock_file);
+}
+
+#[test]
+fn test_resolve_recursive_dependency() {
+    let test_directory = test_dir();
+    create_package(&test_directory.join("app"), "app", "a = { path = \"../a\" }\n");
+    create_package(&test_directory.join("a"), "a", "b = { path = \"../b\" }\n");
+    create_package(&test_directory.join("b"), "b", "a = { path = \"../a\" }\n");
+
+    match DependencyResolver::resolve(&test_directory.join("app"), None) {
+        Err(ResolverError::RecursiveDependency(cycle)) => assert_eq!(cycle, "a -> b -> a"),
+        _ => panic!("expected a recursive dependency error"),
+    }
+}
+
+#[test]
+fn test_resolve_missing_path_dependency() {
+    let test_directory = test_dir();
+    create_package(&test_directory.join("app"), "app", "lib = { path = \"../lib\" }\n");
+
+    assert!(matches!(
+        DependencyResolver::resolve(&test_directory.join("app"), None),
+        Err(ResolverError::MissingPackage(..))
+    ));
+}
+
+#[test]
+fn test_resolve_registry_dependency() {
+    let test_directory = test_dir();
+    let app = test_directory.join("app");
+    create_package(
+        &app,
+        "app",
+        "sudoku = { author = \"howard\", package = \"silly-sudoku\" }\n",
+    );
+
+    // Registry packages have to be installed first.
+    assert!(matches!(
+        DependencyResolver::resolve(&app, None),
+        Err(ResolverError::UnversionedDependency(..))
+    ));
+
+    let installed = DependencyResolver::install_directory(&app, "howard", "silly-sudoku", "0.1.0");
+    create_package(&installed, "silly-sudoku", "");
+
+    let lock_file = DependencyResolver::resolve(&app, None).unwrap().lock_file();
+    let locked = lock_file.get("howard-silly-sudoku@0.1.0").unwrap();
+    assert_eq!(locked.author.as_deref(), Some("howard"));
+    assert!(DependencyResolver::resolve(&app, Some(&lock_file)).is_ok());
+
+    // Modifying an installed package must not go unnoticed.
+    fs::write(
+        installed.join("src/main.leo"),
+        "function main() -> u8 {\n    return 2u8;\n}\n",
+    )
+    .unwrap();
+
+    match DependencyResolver::resolve(&app, Some(&lock_file)) {
+        Err(ResolverError::ChecksumMismatch(id, filename)) => {
+            assert_eq!(id, "howard-silly-sudoku@0.1.0");
+            assert_eq!(filename, LOCK_FILENAME);
+        }
+        _ => panic!("expected a checksum mismatch"),
+    }
+}
+
+#[test]
+fn test_resolve_version_mismatch() {
+    let test_directory = test_dir();
+    create_package(
+        &test_directory.join("app"),
+        "app",
+        "lib = { path = \"../lib\", version = \"1.0.0\" }\n",
+    );
+    create_package(&test_directory.join("lib"), "lib", "");
+
+    assert!(matches!(
+        DependencyResolver::resolve(&test_directory.join("app"), None),
+        Err(ResolverError::VersionMismatch(..))
+    ));
+}
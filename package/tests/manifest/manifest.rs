@@ -167,3 +167,28 @@ fn test_manifest_refactors() {
     // Check that the manifest file remote has been updated.
     assert!(remote_is_updated(&manifest_path));
 }
+
+#[test]
+fn test_manifest_lint_levels() {
+    let test_directory = test_dir();
+    let manifest_path = test_directory.join(MANIFEST_FILENAME);
+
+    let mut file = File::create(&manifest_path).unwrap();
+    file.write_all(
+        br#"[project]
+name = "test-package"
+version = "0.1.0"
+
+[lint]
+unused_variables = "allow"
+shadowed_bindings = "deny"
+"#,
+    )
+    .unwrap();
+
+    let manifest = Manifest::try_from(manifest_path.as_path()).unwrap();
+    let levels = manifest.get_lint_levels();
+
+    assert_eq!(levels.get("unused_variables").map(String::as_str), Some("allow"));
+    assert_eq!(levels.get("shadowed_bindings").map(String::as_str), Some("deny"));
+}
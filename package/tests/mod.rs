@@ -16,6 +16,7 @@
 
 #![allow(clippy::module_inception)]
 
+pub mod dependencies;
 pub mod initialize;
 pub mod manifest;
 
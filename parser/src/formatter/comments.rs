@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Tracks the source positions of the comments and literals that the AST does not keep.
+
+use crate::{SpannedToken, Token};
+use leo_ast::Span;
+
+use std::collections::{BTreeMap, HashMap};
+
+/// A `(line, column)` position in the source text.
+pub(crate) type Position = (usize, usize);
+
+pub(crate) fn start(span: &Span) -> Position {
+    (span.line_start, span.col_start)
+}
+
+pub(crate) fn end(span: &Span) -> Position {
+    (span.line_stop, span.col_stop)
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Comment {
+    pub(crate) start: Position,
+    pub(crate) line_stop: usize,
+    pub(crate) text: String,
+}
+
+///
+/// The comments and literal tokens of a source file, in source order.
+///
+pub(crate) struct SourceMap {
+    comments: Vec<Comment>,
+    next_comment: usize,
+    /// The source text of string and char literals, by their start position.
+    literals: HashMap<Position, String>,
+    /// The position of the matching `}` of every `{`.
+    braces: BTreeMap<Position, Position>,
+}
+
+impl SourceMap {
+    pub(crate) fn new(source: &str, tokens: &[SpannedToken]) -> Self {
+        let mut line_offsets = vec![0];
+        line_offsets.extend(source.match_indices('\n').map(|(index, _)| index + 1));
+
+        let mut comments = Vec::new();
+        let mut literals = HashMap::new();
+        let mut braces = BTreeMap::new();
+        let mut open_braces = Vec::new();
+
+        for SpannedToken { token, span } in tokens {
+            match token {
+                Token::CommentLine(text) | Token::CommentBlock(text) => comments.push(Comment {
+                    start: start(span),
+                    line_stop: span.line_stop,
+                    text: text.trim_end().to_string(),
+                }),
+                Token::StringLit(_) | Token::CharLit(_) => {
+                    let offset = line_offsets[span.line_start - 1];
+                    if let Some(literal) = source.get(offset + span.col_start - 1..offset + span.col_stop - 1) {
+                        literals.insert(start(span), literal.to_string());
+                    }
+                }
+                Token::LeftCurly => open_braces.push(start(span)),
+                Token::RightCurly => {
+                    if let Some(open) = open_braces.pop() {
+                        braces.insert(open, start(span));
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        Self {
+            comments,
+            next_comment: 0,
+            literals,
+            braces,
+        }
+    }
+
+    ///
+    /// Returns the comments that start before `position` and were not returned yet.
+    ///
+    pub(crate) fn comments_before(&mut self, position: Position) -> Vec<Comment> {
+        let first = self.next_comment;
+        while self
+            .comments
+            .get(self.next_comment)
+            .map(|comment| comment.start < position)
+            == Some(true)
+        {
+            self.next_comment += 1;
+        }
+        self.comments[first..self.next_comment].to_vec()
+    }
+
+    ///
+    /// Returns the comments that were not returned yet and start on `line`, after the code on it.
+    ///
+    pub(crate) fn comments_on_line(&mut self, line: usize) -> Vec<Comment> {
+        let first = self.next_comment;
+        while self
+            .comments
+            .get(self.next_comment)
+            .map(|comment| comment.start.0 == line)
+            == Some(true)
+        {
+            self.next_comment += 1;
+        }
+        self.comments[first..self.next_comment].to_vec()
+    }
+
+    ///
+    /// Returns the source text of the string or char literal starting at `position`.
+    ///
+    pub(crate) fn literal(&self, position: Position) -> Option<&str> {
+        self.literals.get(&position).map(String::as_str)
+    }
+
+    ///
+    /// Returns the position of the `}` closing the first `{` after `position`.
+    ///
+    pub(crate) fn closing_brace(&self, position: Position) -> Option<Position> {
+        self.braces.range(position..).next().map(|(_, close)| *close)
+    }
+}
@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A small pretty printing document in the style of Wadler's "prettier printer".
+//!
+//! A [`Doc`] describes text together with the places where it may be broken into lines.
+//! Every [`Doc::Group`] is laid out on a single line if it fits in the remaining width,
+//! otherwise each of its line breaks is taken.
+
+use super::{INDENT_WIDTH, MAX_WIDTH};
+
+#[derive(Clone, Debug)]
+pub(crate) enum Doc {
+    /// Literal text, never broken.
+    Text(String),
+    /// A space when the enclosing group is flat, a line break otherwise.
+    Line,
+    /// Nothing when the enclosing group is flat, a line break otherwise.
+    SoftLine,
+    /// A line break that also forces every enclosing group to break.
+    HardLine,
+    /// Text that is only printed when the enclosing group is broken, e.g. a trailing comma.
+    IfBreak(&'static str),
+    /// Indents every line break in the inner document by one level.
+    Nest(Box<Doc>),
+    /// Lays out the inner document flat if it fits.
+    Group(Box<Doc>),
+    Concat(Vec<Doc>),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Flat,
+    Break,
+}
+
+pub(crate) fn text<T: Into<String>>(text: T) -> Doc {
+    Doc::Text(text.into())
+}
+
+pub(crate) fn concat(docs: Vec<Doc>) -> Doc {
+    Doc::Concat(docs)
+}
+
+pub(crate) fn nest(doc: Doc) -> Doc {
+    Doc::Nest(Box::new(doc))
+}
+
+pub(crate) fn group(doc: Doc) -> Doc {
+    Doc::Group(Box::new(doc))
+}
+
+///
+/// Returns a comma separated list between `open` and `close`.
+///
+/// The list is kept on one line if it fits, otherwise every element gets its own line
+/// and the last one a trailing comma.
+///
+pub(crate) fn list(open: &str, elements: Vec<Doc>, close: &str) -> Doc {
+    if elements.is_empty() {
+        return text(format!("{}{}", open, close));
+    }
+
+    let mut inner = vec![Doc::SoftLine];
+    let count = elements.len();
+    for (i, element) in elements.into_iter().enumerate() {
+        inner.push(element);
+        if i + 1 < count {
+            inner.push(text(","));
+            inner.push(Doc::Line);
+        }
+    }
+    inner.push(Doc::IfBreak(","));
+
+    group(concat(vec![
+        text(open),
+        nest(concat(inner)),
+        Doc::SoftLine,
+        text(close),
+    ]))
+}
+
+impl Doc {
+    fn has_hard_line(&self) -> bool {
+        match self {
+            Doc::HardLine => true,
+            Doc::Nest(doc) | Doc::Group(doc) => doc.has_hard_line(),
+            Doc::Concat(docs) => docs.iter().any(Doc::has_hard_line),
+            _ => false,
+        }
+    }
+
+    ///
+    /// Renders the document, breaking the groups that do not fit in [`MAX_WIDTH`] columns.
+    ///
+    pub(crate) fn render(&self) -> String {
+        let mut out = String::new();
+        let mut column = 0usize;
+        let mut stack: Vec<(usize, Mode, &Doc)> = vec![(0, Mode::Break, self)];
+
+        while let Some((indent, mode, doc)) = stack.pop() {
+            match doc {
+                Doc::Text(text) => {
+                    out.push_str(text);
+                    column = match text.rfind('\n') {
+                        Some(newline) => text.len() - newline - 1,
+                        None => column + text.chars().count(),
+                    };
+                }
+                Doc::Line | Doc::SoftLine if mode == Mode::Flat => {
+                    if let Doc::Line = doc {
+                        out.push(' ');
+                        column += 1;
+                    }
+                }
+                Doc::Line | Doc::SoftLine | Doc::HardLine => {
+                    out.push('\n');
+                    out.push_str(&" ".repeat(indent));
+                    column = indent;
+                }
+                Doc::IfBreak(text) => {
+                    if mode == Mode::Break {
+                        out.push_str(text);
+                        column += text.len();
+                    }
+                }
+                Doc::Nest(doc) => stack.push((indent + INDENT_WIDTH, mode, doc)),
+                Doc::Group(doc) => {
+                    let flat = mode == Mode::Flat
+                        || (!doc.has_hard_line() && fits(MAX_WIDTH as isize - column as isize, doc, &stack));
+                    stack.push((indent, if flat { Mode::Flat } else { Mode::Break }, doc));
+                }
+                Doc::Concat(docs) => stack.extend(docs.iter().rev().map(|doc| (indent, mode, doc))),
+            }
+        }
+
+        // Blank lines are rendered with the indentation of the line that follows them, which is dropped.
+        let mut lines = out.lines().map(str::trim_end).collect::<Vec<_>>().join("\n");
+        if !lines.is_empty() {
+            lines.push('\n');
+        }
+        lines
+    }
+}
+
+///
+/// Returns `true` if `doc` laid out flat, followed by the rest of the document up to its next
+/// line break, fits in `remaining` columns.
+///
+fn fits(mut remaining: isize, doc: &Doc, rest: &[(usize, Mode, &Doc)]) -> bool {
+    let mut stack = vec![(Mode::Flat, doc)];
+    let mut rest = rest.iter().rev();
+
+    while remaining >= 0 {
+        let (mode, doc) = match stack.pop() {
+            Some(next) => next,
+            None => match rest.next() {
+                Some((_, mode, doc)) => (*mode, *doc),
+                None => return true,
+            },
+        };
+
+        match doc {
+            Doc::Text(text) => remaining -= text.chars().count() as isize,
+            Doc::Line if mode == Mode::Flat => remaining -= 1,
+            Doc::SoftLine if mode == Mode::Flat => {}
+            Doc::Line | Doc::SoftLine | Doc::HardLine => return true,
+            Doc::IfBreak(text) => {
+                if mode == Mode::Break {
+                    remaining -= text.len() as isize;
+                }
+            }
+            Doc::Nest(doc) | Doc::Group(doc) => stack.push((mode, doc)),
+            Doc::Concat(docs) => stack.extend(docs.iter().rev().map(|doc| (mode, doc))),
+        }
+    }
+
+    false
+}
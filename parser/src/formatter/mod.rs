@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The formatter to print Leo code text in one canonical layout.
+//!
+//! This module contains the [`format()`] method which parses the code text and prints its
+//! [`Program`] AST back, together with the comments the parser skips.
+//!
+//! [`Program`]: leo_ast::Program
+
+mod comments;
+mod doc;
+mod printer;
+
+use crate::{tokenize, ParserContext, SyntaxResult};
+use comments::SourceMap;
+use printer::Printer;
+
+/// The number of columns the formatter fits lines into.
+pub const MAX_WIDTH: usize = 100;
+
+/// The number of spaces of one level of indentation.
+pub const INDENT_WIDTH: usize = 4;
+
+/// Returns the given Leo source code text in the canonical layout.
+pub fn format(path: &str, source: &str) -> SyntaxResult<String> {
+    let tokens = tokenize(path, source.into())?;
+    let program = ParserContext::new(tokens.clone()).parse_program()?;
+
+    Ok(Printer::new(SourceMap::new(source, &tokens)).program(&program).render())
+}
@@ -0,0 +1,904 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Converts a [`Program`] AST into a [`Doc`] in the canonical layout.
+
+use super::{
+    comments::{end, start, Comment, Position, SourceMap},
+    doc::{concat, group, list, nest, text, Doc},
+};
+use leo_ast::*;
+
+// Binding strength of the expressions, following the precedence levels of the parser.
+const TERNARY: u8 = 0;
+const OR: u8 = 1;
+const AND: u8 = 2;
+const BIT_OR: u8 = 3;
+const BIT_XOR: u8 = 4;
+const BIT_AND: u8 = 5;
+const EQUALITY: u8 = 6;
+const ORDERING: u8 = 7;
+const SHIFT: u8 = 8;
+const ADDITIVE: u8 = 9;
+const MULTIPLICATIVE: u8 = 10;
+const POW: u8 = 11;
+const CAST: u8 = 12;
+const UNARY: u8 = 13;
+const POSTFIX: u8 = 14;
+
+/// A top-level declaration of a program.
+enum Item<'a> {
+    Import(&'a ImportStatement),
+    Module(&'a ModuleDeclaration),
+    Circuit(&'a Circuit),
+    Enum(&'a Enum),
+    Function(&'a Function),
+    Const(&'a DefinitionStatement),
+}
+
+/// A printed top-level declaration with the comments attached to it.
+struct Chunk {
+    doc: Doc,
+    line_start: usize,
+    line_stop: usize,
+    is_const: bool,
+    /// The import without its comments or the module name, which they are sorted by.
+    key: String,
+}
+
+pub(crate) struct Printer {
+    source: SourceMap,
+}
+
+impl Printer {
+    pub(crate) fn new(source: SourceMap) -> Self {
+        Self { source }
+    }
+
+    ///
+    /// Returns the document of a whole program.
+    ///
+    /// Imports are sorted and grouped at the top of the file, followed by the sorted module
+    /// declarations and then every other declaration in source order.
+    ///
+    pub(crate) fn program(mut self, program: &Program) -> Doc {
+        let mut items = program
+            .imports
+            .iter()
+            .map(Item::Import)
+            .chain(program.modules.iter().map(Item::Module))
+            .chain(program.circuits.values().map(Item::Circuit))
+            .chain(program.enums.values().map(Item::Enum))
+            .chain(program.functions.values().map(Item::Function))
+            .chain(program.global_consts.values().map(Item::Const))
+            .collect::<Vec<_>>();
+        items.sort_by_key(|item| item.start());
+
+        let mut imports = Vec::new();
+        let mut modules = Vec::new();
+        let mut declarations = Vec::new();
+        let mut header = Vec::new();
+        for (index, item) in items.iter().enumerate() {
+            let mut leading = self.source.comments_before(item.start());
+
+            // Comments at the start of the file that are not attached to the first declaration stay there.
+            if index == 0 {
+                let mut next_line = item.start().0;
+                let mut attached = 0;
+                for comment in leading.iter().rev() {
+                    if comment.line_stop + 1 < next_line {
+                        break;
+                    }
+                    next_line = comment.start.0;
+                    attached += 1;
+                }
+                header = leading.drain(..leading.len() - attached).collect();
+            }
+
+            // Comments inside an import or module declaration move above it.
+            if let Item::Import(_) | Item::Module(_) = item {
+                let end = self.item_end(item);
+                leading.extend(self.source.comments_before(end));
+            }
+
+            let body = self.item(item, program);
+            let key = match item {
+                Item::Module(module) => module.name.name.to_string(),
+                _ => body.render(),
+            };
+            let line_stop = self.item_end(item).0;
+            let trailing = self.source.comments_on_line(line_stop);
+
+            let mut docs = Vec::new();
+            let mut last_line = None;
+            push_comments(&mut docs, &leading, &mut last_line);
+            separate(&mut docs, &mut last_line, item.start().0);
+            docs.push(body);
+            push_trailing(&mut docs, &trailing);
+
+            let chunk = Chunk {
+                doc: concat(docs),
+                line_start: leading.first().map(|comment| comment.start.0).unwrap_or(item.start().0),
+                line_stop,
+                is_const: matches!(item, Item::Const(_)),
+                key,
+            };
+            match item {
+                Item::Import(_) => imports.push(chunk),
+                Item::Module(_) => modules.push(chunk),
+                _ => declarations.push(chunk),
+            }
+        }
+        let remaining = self.source.comments_before((usize::MAX, 0));
+
+        imports.sort_by(|a, b| a.key.cmp(&b.key));
+        modules.sort_by(|a, b| a.key.cmp(&b.key));
+
+        let mut docs = Vec::new();
+        let mut last_line = None;
+        push_comments(&mut docs, &header, &mut last_line);
+
+        for (group_index, chunks) in [imports, modules, declarations].iter().enumerate() {
+            for (index, chunk) in chunks.iter().enumerate() {
+                if !docs.is_empty() {
+                    docs.push(Doc::HardLine);
+
+                    // Imports and modules are not separated, consecutive constants only if they were.
+                    let adjacent = index > 0
+                        && (group_index < 2 || {
+                            let previous = &chunks[index - 1];
+                            previous.is_const && chunk.is_const && chunk.line_start <= previous.line_stop + 1
+                        });
+                    if !adjacent {
+                        docs.push(Doc::HardLine);
+                    }
+                }
+                docs.push(chunk.doc.clone());
+            }
+        }
+
+        last_line = items.last().map(|item| self.item_end(item).0);
+        push_comments(&mut docs, &remaining, &mut last_line);
+
+        concat(docs)
+    }
+
+    fn item(&mut self, item: &Item, program: &Program) -> Doc {
+        match item {
+            Item::Import(import) => concat(vec![
+                text("import "),
+                self.package_or_packages(&import.package_or_packages),
+                text(";"),
+            ]),
+            Item::Module(module) => text(format!(
+                "{}mod {};",
+                if module.public { "pub " } else { "" },
+                module.name.name
+            )),
+            Item::Circuit(circuit) => {
+                let public = program.public.contains(circuit.circuit_name.name.as_ref());
+                self.circuit(circuit, public)
+            }
+            Item::Enum(enum_) => {
+                let public = program.public.contains(enum_.enum_name.name.as_ref());
+                self.enum_(enum_, public)
+            }
+            Item::Function(function) => {
+                let public = program.public.contains(function.identifier.name.as_ref());
+                self.function(function, public)
+            }
+            Item::Const(definition) => {
+                let public = definition
+                    .variable_names
+                    .iter()
+                    .any(|variable| program.public.contains(variable.identifier.name.as_ref()));
+                let definition = self.definition(definition);
+                match public {
+                    true => concat(vec![text("pub "), definition]),
+                    false => definition,
+                }
+            }
+        }
+    }
+
+    fn item_end(&self, item: &Item) -> Position {
+        match item {
+            Item::Import(import) => end(&import.span),
+            Item::Module(module) => end(&module.span),
+            Item::Circuit(circuit) => self
+                .source
+                .closing_brace(start(&circuit.circuit_name.span))
+                .unwrap_or_else(|| end(&circuit.circuit_name.span)),
+            Item::Enum(enum_) => self
+                .source
+                .closing_brace(start(&enum_.enum_name.span))
+                .unwrap_or_else(|| end(&enum_.enum_name.span)),
+            Item::Function(function) => end(&function.span),
+            Item::Const(definition) => end(&definition.span),
+        }
+    }
+
+    fn package_or_packages(&self, package_or_packages: &PackageOrPackages) -> Doc {
+        match package_or_packages {
+            PackageOrPackages::Package(package) => self.package(package),
+            PackageOrPackages::Packages(packages) => self.packages(packages),
+        }
+    }
+
+    fn package(&self, package: &Package) -> Doc {
+        concat(vec![
+            text(format!("{}.", package.name.name)),
+            self.package_access(&package.access),
+        ])
+    }
+
+    fn packages(&self, packages: &Packages) -> Doc {
+        let mut accesses = packages
+            .accesses
+            .iter()
+            .map(|access| self.package_access(access))
+            .collect::<Vec<_>>();
+        accesses.sort_by_cached_key(Doc::render);
+
+        concat(vec![text(format!("{}.", packages.name.name)), list("(", accesses, ")")])
+    }
+
+    fn package_access(&self, access: &PackageAccess) -> Doc {
+        match access {
+            PackageAccess::Star { .. } => text("*"),
+            PackageAccess::SubPackage(package) => self.package(package),
+            PackageAccess::Symbol(symbol) => match &symbol.alias {
+                Some(alias) => text(format!("{} as {}", symbol.symbol.name, alias.name)),
+                None => text(symbol.symbol.name.to_string()),
+            },
+            PackageAccess::Multiple(packages) => self.packages(packages),
+        }
+    }
+
+    fn circuit(&mut self, circuit: &Circuit, public: bool) -> Doc {
+        let header = format!(
+            "{}circuit {}{} {{",
+            if public { "pub " } else { "" },
+            circuit.circuit_name.name,
+            const_parameters_string(&circuit.const_parameters)
+        );
+        let close = self
+            .source
+            .closing_brace(start(&circuit.circuit_name.span))
+            .unwrap_or((usize::MAX, 0));
+
+        let mut body = Vec::new();
+        let mut last_line = None;
+        for member in circuit.members.iter() {
+            let (member_start, member_stop) = match member {
+                CircuitMember::CircuitVariable(name, _) => (start(&name.span), name.span.line_stop),
+                CircuitMember::CircuitFunction(function) => (function_start(function), function.span.line_stop),
+            };
+
+            let leading = self.source.comments_before(member_start);
+            // Functions are always separated from the other members by a blank line.
+            if matches!(member, CircuitMember::CircuitFunction(_)) && !body.is_empty() {
+                body.push(Doc::HardLine);
+                last_line = None;
+            }
+            push_comments(&mut body, &leading, &mut last_line);
+            separate(&mut body, &mut last_line, member_start.0);
+
+            match member {
+                CircuitMember::CircuitVariable(name, type_) => {
+                    body.push(text(format!("{}: ", name.name)));
+                    body.push(type_doc(type_));
+                    body.push(text(","));
+                }
+                CircuitMember::CircuitFunction(function) => {
+                    body.push(self.function(function, false));
+                }
+            }
+            last_line = Some(member_stop);
+            push_trailing(&mut body, &self.source.comments_on_line(member_stop));
+        }
+        push_comments(&mut body, &self.source.comments_before(close), &mut last_line);
+
+        close_braces(header, body)
+    }
+
+    fn enum_(&mut self, enum_: &Enum, public: bool) -> Doc {
+        let header = format!("{}enum {} {{", if public { "pub " } else { "" }, enum_.enum_name.name);
+        let close = self
+            .source
+            .closing_brace(start(&enum_.enum_name.span))
+            .unwrap_or((usize::MAX, 0));
+
+        let mut body = Vec::new();
+        let mut last_line = None;
+        for variant in enum_.variants.iter() {
+            let variant_start = start(&variant.name.span);
+            let leading = self.source.comments_before(variant_start);
+            push_comments(&mut body, &leading, &mut last_line);
+            separate(&mut body, &mut last_line, variant_start.0);
+
+            body.push(text(variant.name.name.to_string()));
+            if !variant.payload.is_empty() {
+                body.push(list("(", variant.payload.iter().map(type_doc).collect(), ")"));
+            }
+            body.push(text(","));
+
+            let line_stop = variant.name.span.line_stop;
+            last_line = Some(line_stop);
+            push_trailing(&mut body, &self.source.comments_on_line(line_stop));
+        }
+        push_comments(&mut body, &self.source.comments_before(close), &mut last_line);
+
+        close_braces(header, body)
+    }
+
+    fn function(&mut self, function: &Function, public: bool) -> Doc {
+        let mut docs = Vec::new();
+        if public {
+            docs.push(text("pub "));
+        }
+        for annotation in function.annotations.iter() {
+            docs.push(match annotation.arguments.is_empty() {
+                true => text(format!("@{}", annotation.name.name)),
+                false => text(format!(
+                    "@{}({})",
+                    annotation.name.name,
+                    annotation
+                        .arguments
+                        .iter()
+                        .map(|argument| argument.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            });
+            docs.push(Doc::HardLine);
+        }
+
+        let inputs = function
+            .input
+            .iter()
+            .map(|input| match input {
+                FunctionInput::SelfKeyword(_) => text("self"),
+                FunctionInput::ConstSelfKeyword(_) => text("const self"),
+                FunctionInput::MutSelfKeyword(_) => text("mut self"),
+                FunctionInput::Variable(variable) => concat(vec![
+                    text(format!(
+                        "{}{}: ",
+                        if variable.const_ { "const " } else { "" },
+                        variable.identifier.name
+                    )),
+                    type_doc(&variable.type_),
+                ]),
+            })
+            .collect();
+
+        docs.push(text(format!(
+            "function {}{}",
+            function.identifier.name,
+            const_parameters_string(&function.const_parameters)
+        )));
+        docs.push(list("(", inputs, ")"));
+        if let Some(output) = &function.output {
+            docs.push(text(" -> "));
+            docs.push(type_doc(output));
+        }
+        docs.push(text(" "));
+        docs.push(self.block(&function.block));
+
+        concat(docs)
+    }
+
+    fn block(&mut self, block: &Block) -> Doc {
+        let close = (block.span.line_stop, block.span.col_stop - 1);
+
+        let mut body = Vec::new();
+        let mut last_line = None;
+        for statement in block.statements.iter() {
+            let leading = self.source.comments_before(statement_head(statement));
+            push_comments(&mut body, &leading, &mut last_line);
+            separate(&mut body, &mut last_line, statement.span().line_start);
+
+            body.push(self.statement(statement));
+
+            let line_stop = statement.span().line_stop;
+            last_line = Some(line_stop);
+            push_trailing(&mut body, &self.source.comments_on_line(line_stop));
+        }
+        push_comments(&mut body, &self.source.comments_before(close), &mut last_line);
+
+        close_braces("{".to_string(), body)
+    }
+
+    fn statement(&mut self, statement: &Statement) -> Doc {
+        match statement {
+            Statement::Return(statement) => concat(vec![
+                text("return "),
+                self.expression(&statement.expression, false),
+                text(";"),
+            ]),
+            Statement::Definition(statement) => self.definition(statement),
+            Statement::Assign(statement) => {
+                let mut docs = vec![text(statement.assignee.identifier.name.to_string())];
+                for access in statement.assignee.accesses.iter() {
+                    docs.push(match access {
+                        AssigneeAccess::ArrayRange(left, right) => self.range(left.as_ref(), right.as_ref()),
+                        AssigneeAccess::ArrayIndex(index) => {
+                            concat(vec![text("["), self.expression(index, false), text("]")])
+                        }
+                        AssigneeAccess::Tuple(index, _) => text(format!(".{}", index)),
+                        AssigneeAccess::Member(name) => text(format!(".{}", name.name)),
+                    });
+                }
+                docs.push(text(format!(" {} ", statement.operation.as_ref())));
+                docs.push(self.expression(&statement.value, false));
+                docs.push(text(";"));
+                concat(docs)
+            }
+            Statement::Conditional(statement) => self.conditional(statement),
+            Statement::Iteration(statement) => concat(vec![
+                text(format!("for {} in ", statement.variable.name)),
+                self.expression(&statement.start, false),
+                text(".."),
+                self.expression(&statement.stop, true),
+                text(" "),
+                self.block(&statement.block),
+            ]),
+            Statement::Break(_) => text("break;"),
+            Statement::Continue(_) => text("continue;"),
+            Statement::Console(statement) => {
+                let (function, arguments) = match &statement.function {
+                    ConsoleFunction::Assert(expression) => ("assert", vec![self.expression(expression, false)]),
+                    ConsoleFunction::Debug(string) => ("debug", self.format_string(string)),
+                    ConsoleFunction::Error(string) => ("error", self.format_string(string)),
+                    ConsoleFunction::Log(string) => ("log", self.format_string(string)),
+                };
+                concat(vec![
+                    text(format!("console.{}", function)),
+                    list("(", arguments, ")"),
+                    text(";"),
+                ])
+            }
+            Statement::Expression(statement) => concat(vec![self.expression(&statement.expression, false), text(";")]),
+            Statement::Block(block) => self.block(block),
+            Statement::Match(statement) => self.match_(statement),
+        }
+    }
+
+    fn match_(&mut self, statement: &MatchStatement) -> Doc {
+        let header = concat(vec![
+            text("match "),
+            self.expression(&statement.value, true),
+            text(" {"),
+        ]);
+        let close = (statement.span.line_stop, statement.span.col_stop - 1);
+
+        let mut body = Vec::new();
+        let mut last_line = None;
+        for arm in statement.arms.iter() {
+            let arm_start = start(&arm.span);
+            let leading = self.source.comments_before(arm_start);
+            push_comments(&mut body, &leading, &mut last_line);
+            separate(&mut body, &mut last_line, arm_start.0);
+
+            body.push(text(format!("{} => ", arm.pattern)));
+            body.push(self.block(&arm.block));
+
+            let line_stop = arm.span.line_stop;
+            last_line = Some(line_stop);
+            push_trailing(&mut body, &self.source.comments_on_line(line_stop));
+        }
+        push_comments(&mut body, &self.source.comments_before(close), &mut last_line);
+
+        match body.is_empty() {
+            true => concat(vec![header, text("}")]),
+            false => concat(vec![
+                header,
+                nest(concat(vec![Doc::HardLine, concat(body)])),
+                Doc::HardLine,
+                text("}"),
+            ]),
+        }
+    }
+
+    fn conditional(&mut self, statement: &ConditionalStatement) -> Doc {
+        let mut docs = vec![
+            text("if "),
+            self.expression(&statement.condition, true),
+            text(" "),
+            self.block(&statement.block),
+        ];
+        if let Some(next) = &statement.next {
+            docs.push(text(" else "));
+            docs.push(self.statement(next));
+        }
+        concat(docs)
+    }
+
+    fn definition(&mut self, statement: &DefinitionStatement) -> Doc {
+        let names = statement
+            .variable_names
+            .iter()
+            .map(|variable| variable.identifier.name.to_string())
+            .collect::<Vec<_>>();
+
+        let mut docs = vec![text(format!("{} ", statement.declaration_type))];
+        docs.push(match names.len() {
+            1 => text(names[0].clone()),
+            _ => text(format!("({})", names.join(", "))),
+        });
+        if let Some(type_) = &statement.type_ {
+            docs.push(text(": "));
+            docs.push(type_doc(type_));
+        }
+        docs.push(text(" = "));
+        docs.push(self.expression(&statement.value, false));
+        docs.push(text(";"));
+        concat(docs)
+    }
+
+    fn format_string(&mut self, string: &FormatString) -> Vec<Doc> {
+        let literal = match self.source.literal(start(&string.span)) {
+            Some(literal) => literal.to_string(),
+            None => {
+                let parts = string
+                    .parts
+                    .iter()
+                    .map(|part| match part {
+                        FormatStringPart::Const(text) => text.escape_debug().to_string(),
+                        FormatStringPart::Container => "{}".to_string(),
+                    })
+                    .collect::<String>();
+                format!("\"{}\"", parts)
+            }
+        };
+
+        let mut arguments = vec![text(literal)];
+        arguments.extend(
+            string
+                .parameters
+                .iter()
+                .map(|parameter| self.expression(parameter, false)),
+        );
+        arguments
+    }
+
+    fn range(&mut self, left: Option<&Expression>, right: Option<&Expression>) -> Doc {
+        let mut docs = vec![text("[")];
+        if let Some(left) = left {
+            docs.push(self.expression(left, false));
+        }
+        docs.push(text(".."));
+        if let Some(right) = right {
+            docs.push(self.expression(right, false));
+        }
+        docs.push(text("]"));
+        concat(docs)
+    }
+
+    ///
+    /// Returns the document of an expression, parenthesized if it binds weaker than `precedence`.
+    ///
+    fn operand(&mut self, expression: &Expression, precedence: u8, no_circuit_init: bool) -> Doc {
+        if expression_precedence(expression) < precedence {
+            concat(vec![text("("), self.expression(expression, false), text(")")])
+        } else {
+            self.expression(expression, no_circuit_init)
+        }
+    }
+
+    ///
+    /// Returns the document of an expression.
+    ///
+    /// The conditions of `if` and `for` statements cannot contain unparenthesized circuit
+    /// initializations, `no_circuit_init` is set while printing them.
+    ///
+    fn expression(&mut self, expression: &Expression, no_circuit_init: bool) -> Doc {
+        match expression {
+            Expression::Identifier(identifier) => text(identifier.name.to_string()),
+            Expression::Value(value) => self.value(value),
+            Expression::Binary(binary) => {
+                let precedence = binary_precedence(&binary.op);
+                let (left, right) = match binary.op {
+                    BinaryOperation::Pow => (precedence + 1, precedence),
+                    _ => (precedence, precedence + 1),
+                };
+                concat(vec![
+                    self.operand(&binary.left, left, no_circuit_init),
+                    text(format!(" {} ", binary.op.as_ref())),
+                    self.operand(&binary.right, right, no_circuit_init),
+                ])
+            }
+            Expression::Unary(unary) => concat(vec![
+                text(unary.op.as_ref()),
+                self.operand(&unary.inner, UNARY, no_circuit_init),
+            ]),
+            Expression::Ternary(ternary) => concat(vec![
+                self.operand(&ternary.condition, OR, no_circuit_init),
+                text(" ? "),
+                self.expression(&ternary.if_true, false),
+                text(" : "),
+                self.operand(&ternary.if_false, TERNARY, no_circuit_init),
+            ]),
+            Expression::Cast(cast) => concat(vec![
+                self.operand(&cast.inner, CAST, no_circuit_init),
+                text(if cast.wrapping { " as wrapping " } else { " as " }),
+                type_doc(&cast.target_type),
+            ]),
+            Expression::ArrayInline(array) => {
+                let elements = array
+                    .elements
+                    .iter()
+                    .map(|element| match element {
+                        SpreadOrExpression::Spread(expression) => {
+                            concat(vec![text("..."), self.expression(expression, false)])
+                        }
+                        SpreadOrExpression::Expression(expression) => self.expression(expression, false),
+                    })
+                    .collect();
+                list("[", elements, "]")
+            }
+            Expression::ArrayInit(array) => concat(vec![
+                text("["),
+                self.expression(&array.element, false),
+                text(format!("; {}]", array.dimensions)),
+            ]),
+            Expression::ArrayAccess(access) => concat(vec![
+                self.operand(&access.array, POSTFIX, no_circuit_init),
+                text("["),
+                self.expression(&access.index, false),
+                text("]"),
+            ]),
+            Expression::ArrayRangeAccess(access) => concat(vec![
+                self.operand(&access.array, POSTFIX, no_circuit_init),
+                self.range(access.left.as_deref(), access.right.as_deref()),
+            ]),
+            Expression::TupleInit(tuple) => {
+                let elements = tuple
+                    .elements
+                    .iter()
+                    .map(|element| self.expression(element, false))
+                    .collect();
+                list("(", elements, ")")
+            }
+            Expression::TupleAccess(access) => concat(vec![
+                self.operand(&access.tuple, POSTFIX, no_circuit_init),
+                text(format!(".{}", access.index)),
+            ]),
+            Expression::CircuitInit(init) => {
+                let mut members = Vec::new();
+                let count = init.members.len();
+                for (i, member) in init.members.iter().enumerate() {
+                    members.push(match &member.expression {
+                        Some(expression) => concat(vec![
+                            text(format!("{}: ", member.identifier.name)),
+                            self.expression(expression, false),
+                        ]),
+                        None => text(member.identifier.name.to_string()),
+                    });
+                    if i + 1 < count {
+                        members.push(text(","));
+                        members.push(Doc::Line);
+                    }
+                }
+
+                let doc = match count {
+                    0 => text(format!("{} {{}}", init.name.name)),
+                    _ => group(concat(vec![
+                        text(format!("{} {{", init.name.name)),
+                        nest(concat(vec![Doc::Line, concat(members), Doc::IfBreak(",")])),
+                        Doc::Line,
+                        text("}"),
+                    ])),
+                };
+                match no_circuit_init {
+                    true => concat(vec![text("("), doc, text(")")]),
+                    false => doc,
+                }
+            }
+            Expression::CircuitMemberAccess(access) => concat(vec![
+                self.operand(&access.circuit, POSTFIX, no_circuit_init),
+                text(format!(".{}", access.name.name)),
+            ]),
+            Expression::CircuitStaticFunctionAccess(access) => concat(vec![
+                self.operand(&access.circuit, POSTFIX, no_circuit_init),
+                text(format!("::{}", access.name.name)),
+            ]),
+            Expression::Call(call) => {
+                let arguments = call
+                    .arguments
+                    .iter()
+                    .map(|argument| self.expression(argument, false))
+                    .collect();
+                concat(vec![
+                    self.operand(&call.function, POSTFIX, no_circuit_init),
+                    list("(", arguments, ")"),
+                ])
+            }
+        }
+    }
+
+    fn value(&self, value: &ValueExpression) -> Doc {
+        text(match value {
+            ValueExpression::Address(address, _) => address.to_string(),
+            ValueExpression::Boolean(boolean, _) => boolean.to_string(),
+            ValueExpression::Char(character) => match self.source.literal(start(&character.span)) {
+                Some(literal) => literal.to_string(),
+                None => format!("'{}'", character.character.to_string().escape_debug()),
+            },
+            ValueExpression::Field(field, _) => format!("{}field", field),
+            ValueExpression::Group(group) => match group.as_ref() {
+                GroupValue::Single(value, _) => format!("{}group", value),
+                GroupValue::Tuple(tuple) => format!("({}, {})group", tuple.x, tuple.y),
+            },
+            ValueExpression::Implicit(value, _) => value.to_string(),
+            ValueExpression::Integer(type_, value, _) => format!("{}{}", value, type_),
+            ValueExpression::String(string, span) => match self.source.literal(start(span)) {
+                Some(literal) => literal.to_string(),
+                None => format!(
+                    "\"{}\"",
+                    string
+                        .iter()
+                        .map(|character| character.to_string())
+                        .collect::<String>()
+                        .escape_debug()
+                ),
+            },
+        })
+    }
+}
+
+impl Item<'_> {
+    fn start(&self) -> Position {
+        match self {
+            Item::Import(import) => start(&import.span),
+            Item::Module(module) => start(&module.span),
+            Item::Circuit(circuit) => start(&circuit.circuit_name.span),
+            Item::Enum(enum_) => start(&enum_.enum_name.span),
+            Item::Function(function) => function_start(function),
+            Item::Const(definition) => start(&definition.span),
+        }
+    }
+}
+
+fn function_start(function: &Function) -> Position {
+    match function.annotations.first() {
+        Some(annotation) => start(&annotation.span),
+        None => start(&function.span),
+    }
+}
+
+///
+/// Returns the position up to which the comments of a statement are printed before it.
+///
+/// Comments inside of the blocks of a statement stay in those blocks.
+///
+fn statement_head(statement: &Statement) -> Position {
+    match statement {
+        Statement::Conditional(statement) => start(&statement.block.span),
+        Statement::Iteration(statement) => start(&statement.block.span),
+        Statement::Block(block) => start(&block.span),
+        Statement::Match(statement) => end(statement.value.span()),
+        statement => end(statement.span()),
+    }
+}
+
+fn type_doc(type_: &Type) -> Doc {
+    text(type_string(type_))
+}
+
+fn type_string(type_: &Type) -> String {
+    match type_ {
+        Type::Circuit(name) => name.name.to_string(),
+        Type::GenericCircuit(name, arguments) => format!(
+            "{}<{}>",
+            name.name,
+            arguments.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ")
+        ),
+        Type::SelfType => "Self".to_string(),
+        Type::Array(element, dimensions) => format!("[{}; {}]", type_string(element), dimensions),
+        Type::Tuple(types) => format!("({})", types.iter().map(type_string).collect::<Vec<_>>().join(", ")),
+        type_ => type_.to_string(),
+    }
+}
+
+fn const_parameters_string(const_parameters: &[ConstParameter]) -> String {
+    if const_parameters.is_empty() {
+        return String::new();
+    }
+    let const_parameters = const_parameters
+        .iter()
+        .map(|parameter| format!("const {}: {}", parameter.identifier.name, type_string(&parameter.type_)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("<{}>", const_parameters)
+}
+
+fn binary_precedence(operation: &BinaryOperation) -> u8 {
+    match operation {
+        BinaryOperation::Or => OR,
+        BinaryOperation::And => AND,
+        BinaryOperation::BitOr => BIT_OR,
+        BinaryOperation::BitXor => BIT_XOR,
+        BinaryOperation::BitAnd => BIT_AND,
+        BinaryOperation::Eq | BinaryOperation::Ne => EQUALITY,
+        BinaryOperation::Lt | BinaryOperation::Le | BinaryOperation::Gt | BinaryOperation::Ge => ORDERING,
+        BinaryOperation::Shl | BinaryOperation::Shr | BinaryOperation::ShrSigned => SHIFT,
+        BinaryOperation::Add | BinaryOperation::Sub => ADDITIVE,
+        BinaryOperation::Mul | BinaryOperation::Div | BinaryOperation::Mod => MULTIPLICATIVE,
+        BinaryOperation::Pow => POW,
+    }
+}
+
+fn expression_precedence(expression: &Expression) -> u8 {
+    match expression {
+        Expression::Ternary(_) => TERNARY,
+        Expression::Binary(binary) => binary_precedence(&binary.op),
+        Expression::Cast(_) => CAST,
+        Expression::Unary(_) => UNARY,
+        // Negative literals are parsed as unary expressions.
+        Expression::Value(ValueExpression::Implicit(value, _))
+        | Expression::Value(ValueExpression::Integer(_, value, _))
+            if value.starts_with('-') =>
+        {
+            UNARY
+        }
+        _ => POSTFIX,
+    }
+}
+
+///
+/// Starts a new line, preceded by a blank line if the source had one before `line`.
+///
+fn separate(docs: &mut Vec<Doc>, last_line: &mut Option<usize>, line: usize) {
+    if let Some(last) = *last_line {
+        if line > last + 1 {
+            docs.push(Doc::HardLine);
+        }
+    }
+    if !docs.is_empty() || last_line.is_some() {
+        docs.push(Doc::HardLine);
+    }
+    *last_line = Some(line);
+}
+
+fn push_comments(docs: &mut Vec<Doc>, comments: &[Comment], last_line: &mut Option<usize>) {
+    for comment in comments {
+        separate(docs, last_line, comment.start.0);
+        docs.push(text(comment.text.clone()));
+        *last_line = Some(comment.line_stop);
+    }
+}
+
+fn push_trailing(docs: &mut Vec<Doc>, comments: &[Comment]) {
+    for comment in comments {
+        docs.push(text(format!(" {}", comment.text)));
+    }
+}
+
+///
+/// Returns `header` followed by the indented `body` and a closing brace on its own line.
+///
+fn close_braces(header: String, body: Vec<Doc>) -> Doc {
+    match body.is_empty() {
+        true => text(format!("{}}}", header)),
+        false => concat(vec![
+            text(header),
+            nest(concat(vec![Doc::HardLine, concat(body)])),
+            Doc::HardLine,
+            text("}"),
+        ]),
+    }
+}
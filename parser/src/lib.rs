@@ -33,6 +33,9 @@ pub use errors::*;
 pub mod parser;
 pub use parser::*;
 
+pub mod formatter;
+pub use formatter::*;
+
 use leo_ast::Ast;
 
 #[cfg(test)]
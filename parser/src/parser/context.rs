@@ -70,7 +70,11 @@ impl ParserContext {
     /// Returns a reference to the next next token or error if it does not exist.
     ///
     pub fn peek_next(&self) -> SyntaxResult<&SpannedToken> {
-        self.tokens.get(self.tokens.len() - 2).ok_or_else(|| self.eof())
+        self.tokens
+            .len()
+            .checked_sub(2)
+            .and_then(|index| self.tokens.get(index))
+            .ok_or_else(|| self.eof())
     }
 
     ///
@@ -106,9 +106,9 @@ impl ParserContext {
     /// Otherwise, tries to parse the next token using [`parse_bit_or_expression`].
     ///
     pub fn parse_conjunctive_expression(&mut self) -> SyntaxResult<Expression> {
-        let mut expr = self.parse_equality_expression()?;
+        let mut expr = self.parse_bit_or_expression()?;
         while self.eat(Token::And).is_some() {
-            let right = self.parse_equality_expression()?;
+            let right = self.parse_bit_or_expression()?;
             expr = Expression::Binary(BinaryExpression {
                 span: expr.span() + right.span(),
                 op: BinaryOperation::And,
@@ -125,19 +125,19 @@ impl ParserContext {
     ///
     /// Otherwise, tries to parse the next token using [`parse_bit_xor_expression`].
     ///
-    // pub fn parse_bit_or_expression(&mut self) -> SyntaxResult<Expression> {
-    //     let mut expr = self.parse_bit_xor_expression()?;
-    //     while self.eat(Token::BitOr).is_some() {
-    //         let right = self.parse_bit_xor_expression()?;
-    //         expr = Expression::Binary(BinaryExpression {
-    //             span: expr.span() + right.span(),
-    //             op: BinaryOperation::BitOr,
-    //             left: Box::new(expr),
-    //             right: Box::new(right),
-    //         })
-    //     }
-    //     Ok(expr)
-    // }
+    pub fn parse_bit_or_expression(&mut self) -> SyntaxResult<Expression> {
+        let mut expr = self.parse_bit_xor_expression()?;
+        while self.eat(Token::BitOr).is_some() {
+            let right = self.parse_bit_xor_expression()?;
+            expr = Expression::Binary(BinaryExpression {
+                span: expr.span() + right.span(),
+                op: BinaryOperation::BitOr,
+                left: Box::new(expr),
+                right: Box::new(right),
+            })
+        }
+        Ok(expr)
+    }
 
     ///
     /// Returns an [`Expression`] AST node if the next tokens represent a
@@ -145,19 +145,19 @@ impl ParserContext {
     ///
     /// Otherwise, tries to parse the next token using [`parse_bit_and_expression`].
     ///
-    // pub fn parse_bit_xor_expression(&mut self) -> SyntaxResult<Expression> {
-    //     let mut expr = self.parse_bit_and_expression()?;
-    //     while self.eat(Token::BitXor).is_some() {
-    //         let right = self.parse_bit_and_expression()?;
-    //         expr = Expression::Binary(BinaryExpression {
-    //             span: expr.span() + right.span(),
-    //             op: BinaryOperation::BitXor,
-    //             left: Box::new(expr),
-    //             right: Box::new(right),
-    //         })
-    //     }
-    //     Ok(expr)
-    // }
+    pub fn parse_bit_xor_expression(&mut self) -> SyntaxResult<Expression> {
+        let mut expr = self.parse_bit_and_expression()?;
+        while self.eat(Token::BitXor).is_some() {
+            let right = self.parse_bit_and_expression()?;
+            expr = Expression::Binary(BinaryExpression {
+                span: expr.span() + right.span(),
+                op: BinaryOperation::BitXor,
+                left: Box::new(expr),
+                right: Box::new(right),
+            })
+        }
+        Ok(expr)
+    }
 
     ///
     /// Returns an [`Expression`] AST node if the next tokens represent a
@@ -165,19 +165,19 @@ impl ParserContext {
     ///
     /// Otherwise, tries to parse the next token using [`parse_equality_expression`].
     ///
-    // pub fn parse_bit_and_expression(&mut self) -> SyntaxResult<Expression> {
-    //     let mut expr = self.parse_equality_expression()?;
-    //     while self.eat(Token::BitAnd).is_some() {
-    //         let right = self.parse_equality_expression()?;
-    //         expr = Expression::Binary(BinaryExpression {
-    //             span: expr.span() + right.span(),
-    //             op: BinaryOperation::BitAnd,
-    //             left: Box::new(expr),
-    //             right: Box::new(right),
-    //         })
-    //     }
-    //     Ok(expr)
-    // }
+    pub fn parse_bit_and_expression(&mut self) -> SyntaxResult<Expression> {
+        let mut expr = self.parse_equality_expression()?;
+        while self.eat(Token::BitAnd).is_some() {
+            let right = self.parse_equality_expression()?;
+            expr = Expression::Binary(BinaryExpression {
+                span: expr.span() + right.span(),
+                op: BinaryOperation::BitAnd,
+                left: Box::new(expr),
+                right: Box::new(right),
+            })
+        }
+        Ok(expr)
+    }
 
     ///
     /// Returns an [`Expression`] AST node if the next tokens represent a
@@ -210,10 +210,10 @@ impl ParserContext {
     /// Otherwise, tries to parse the next token using [`parse_shift_expression`].
     ///    
     pub fn parse_ordering_expression(&mut self) -> SyntaxResult<Expression> {
-        let mut expr = self.parse_additive_expression()?;
+        let mut expr = self.parse_shift_expression()?;
         while let Some(SpannedToken { token: op, .. }) = self.eat_any(&[Token::Lt, Token::LtEq, Token::Gt, Token::GtEq])
         {
-            let right = self.parse_additive_expression()?;
+            let right = self.parse_shift_expression()?;
             expr = Expression::Binary(BinaryExpression {
                 span: expr.span() + right.span(),
                 op: match op {
@@ -236,24 +236,24 @@ impl ParserContext {
     ///
     /// Otherwise, tries to parse the next token using [`parse_additive_expression`].
     ///
-    // pub fn parse_shift_expression(&mut self) -> SyntaxResult<Expression> {
-    //     let mut expr = self.parse_additive_expression()?;
-    //     while let Some(SpannedToken { token: op, .. }) = self.eat_any(&[Token::Shl, Token::Shr, Token::ShrSigned]) {
-    //         let right = self.parse_additive_expression()?;
-    //         expr = Expression::Binary(BinaryExpression {
-    //             span: expr.span() + right.span(),
-    //             op: match op {
-    //                 Token::Shl => BinaryOperation::Shl,
-    //                 Token::Shr => BinaryOperation::Shr,
-    //                 Token::ShrSigned => BinaryOperation::ShrSigned,
-    //                 _ => unimplemented!(),
-    //             },
-    //             left: Box::new(expr),
-    //             right: Box::new(right),
-    //         })
-    //     }
-    //     Ok(expr)
-    // }
+    pub fn parse_shift_expression(&mut self) -> SyntaxResult<Expression> {
+        let mut expr = self.parse_additive_expression()?;
+        while let Some(SpannedToken { token: op, .. }) = self.eat_any(&[Token::Shl, Token::Shr, Token::ShrSigned]) {
+            let right = self.parse_additive_expression()?;
+            expr = Expression::Binary(BinaryExpression {
+                span: expr.span() + right.span(),
+                op: match op {
+                    Token::Shl => BinaryOperation::Shl,
+                    Token::Shr => BinaryOperation::Shr,
+                    Token::ShrSigned => BinaryOperation::ShrSigned,
+                    _ => unimplemented!(),
+                },
+                left: Box::new(expr),
+                right: Box::new(right),
+            })
+        }
+        Ok(expr)
+    }
 
     ///
     /// Returns an [`Expression`] AST node if the next tokens represent a
@@ -287,14 +287,14 @@ impl ParserContext {
     ///
     pub fn parse_multiplicative_expression(&mut self) -> SyntaxResult<Expression> {
         let mut expr = self.parse_exponential_expression()?;
-        while let Some(SpannedToken { token: op, .. }) = self.eat_any(&[Token::Mul, Token::Div]) {
+        while let Some(SpannedToken { token: op, .. }) = self.eat_any(&[Token::Mul, Token::Div, Token::Mod]) {
             let right = self.parse_exponential_expression()?;
             expr = Expression::Binary(BinaryExpression {
                 span: expr.span() + right.span(),
                 op: match op {
                     Token::Mul => BinaryOperation::Mul,
                     Token::Div => BinaryOperation::Div,
-                    // Token::Mod => BinaryOperation::Mod,
+                    Token::Mod => BinaryOperation::Mod,
                     _ => unimplemented!(),
                 },
                 left: Box::new(expr),
@@ -337,11 +337,18 @@ impl ParserContext {
     pub fn parse_cast_expression(&mut self) -> SyntaxResult<Expression> {
         let mut expr = self.parse_unary_expression()?;
         while self.eat(Token::As).is_some() {
+            // `wrapping` only marks a wrapping cast if a type follows, otherwise it is the name of a circuit.
+            let wrapping = matches!(&*self.peek_token(), Token::Ident(name) if name.as_ref() == "wrapping")
+                && matches!(self.peek_next(), Ok(next) if Self::token_starts_type(&next.token));
+            if wrapping {
+                self.eat_identifier();
+            }
             let (type_, type_span) = self.parse_type()?;
             expr = Expression::Cast(CastExpression {
                 span: expr.span() + &type_span,
                 inner: Box::new(expr),
                 target_type: type_,
+                wrapping,
             })
         }
         Ok(expr)
@@ -355,7 +362,7 @@ impl ParserContext {
     ///
     pub fn parse_unary_expression(&mut self) -> SyntaxResult<Expression> {
         let mut ops = Vec::new();
-        while let Some(token) = self.eat_any(&[Token::Not, Token::Minus]) {
+        while let Some(token) = self.eat_any(&[Token::Not, Token::Minus, Token::BitNot]) {
             ops.push(token);
         }
         let mut inner = self.parse_postfix_expression()?;
@@ -363,7 +370,7 @@ impl ParserContext {
             let operation = match op.token {
                 Token::Not => UnaryOperation::Not,
                 Token::Minus => UnaryOperation::Negate,
-                // Token::BitNot => UnaryOperation::BitNot,
+                Token::BitNot => UnaryOperation::BitNot,
                 _ => unimplemented!(),
             };
             // hack for const signed integer overflow issues
@@ -26,23 +26,48 @@ impl ParserContext {
     ///
     pub fn parse_program(&mut self) -> SyntaxResult<Program> {
         let mut imports = Vec::new();
+        let mut modules = Vec::new();
+        let mut public = IndexSet::new();
         let mut circuits = IndexMap::new();
+        let mut enums = IndexMap::new();
         let mut functions = IndexMap::new();
         let mut global_consts = IndexMap::new();
         // let mut tests = IndexMap::new();
 
         while self.has_next() {
+            // `pub` is only a keyword in front of a top-level declaration.
+            let is_public = matches!(&self.peek()?.token, Token::Ident(ident) if ident.as_ref() == "pub");
+            if is_public {
+                self.expect_ident()?;
+            }
+
             let token = self.peek()?;
             match &token.token {
-                Token::Import => {
+                Token::Import if !is_public => {
                     imports.push(self.parse_import()?);
                 }
+                Token::Ident(ident) if ident.as_ref() == "mod" => {
+                    modules.push(self.parse_module_declaration(is_public)?);
+                }
                 Token::Circuit => {
                     let (id, circuit) = self.parse_circuit()?;
+                    if is_public {
+                        public.insert(id.name.to_string());
+                    }
                     circuits.insert(id, circuit);
                 }
+                Token::Ident(ident) if ident.as_ref() == "enum" => {
+                    let (id, enum_) = self.parse_enum()?;
+                    if is_public {
+                        public.insert(id.name.to_string());
+                    }
+                    enums.insert(id, enum_);
+                }
                 Token::Function | Token::At => {
                     let (id, function) = self.parse_function_declaration()?;
+                    if is_public {
+                        public.insert(id.name.to_string());
+                    }
                     functions.insert(id, function);
                 }
                 Token::Ident(ident) if ident.as_ref() == "test" => {
@@ -58,14 +83,38 @@ impl ParserContext {
                 }
                 Token::Const => {
                     let (name, global_const) = self.parse_global_const_declaration()?;
+                    if is_public {
+                        public.extend(
+                            global_const
+                                .variable_names
+                                .iter()
+                                .map(|variable_name| variable_name.identifier.name.to_string()),
+                        );
+                    }
                     global_consts.insert(name, global_const);
                 }
+                _ if is_public => {
+                    return Err(SyntaxError::unexpected(
+                        &token.token,
+                        &[
+                            Token::Ident("mod".into()),
+                            Token::Circuit,
+                            Token::Ident("enum".into()),
+                            Token::Function,
+                            Token::At,
+                            Token::Const,
+                        ],
+                        &token.span,
+                    ));
+                }
                 _ => {
                     return Err(SyntaxError::unexpected(
                         &token.token,
                         &[
                             Token::Import,
+                            Token::Ident("mod".into()),
                             Token::Circuit,
+                            Token::Ident("enum".into()),
                             Token::Function,
                             Token::Ident("test".into()),
                             Token::At,
@@ -79,12 +128,30 @@ impl ParserContext {
             name: String::new(),
             expected_input: Vec::new(),
             imports,
+            modules,
+            public,
             circuits,
+            enums,
             functions,
             global_consts,
         })
     }
 
+    ///
+    /// Returns a [`ModuleDeclaration`] AST node if the next tokens represent a module declaration.
+    ///
+    pub fn parse_module_declaration(&mut self, public: bool) -> SyntaxResult<ModuleDeclaration> {
+        let start = self.expect_ident()?;
+        let name = self.expect_ident()?;
+        let end = self.expect(Token::Semicolon)?;
+
+        Ok(ModuleDeclaration {
+            name,
+            public,
+            span: start.span + end,
+        })
+    }
+
     ///
     /// Returns an [`Annotation`] AST node if the next tokens represent a supported annotation.
     ///
@@ -371,15 +438,88 @@ impl ParserContext {
     pub fn parse_circuit(&mut self) -> SyntaxResult<(Identifier, Circuit)> {
         self.expect(Token::Circuit)?;
         let name = self.expect_ident()?;
+        let const_parameters = self.parse_const_parameters()?;
         self.expect(Token::LeftCurly)?;
         let members = self.parse_circuit_declaration()?;
 
         Ok((name.clone(), Circuit {
             circuit_name: name,
+            const_parameters,
             members,
         }))
     }
 
+    ///
+    /// Returns an [`(Identifier, Enum)`] tuple of AST nodes if the next tokens represent an enum.
+    ///
+    pub fn parse_enum(&mut self) -> SyntaxResult<(Identifier, Enum)> {
+        self.expect_ident()?;
+        let name = self.expect_ident()?;
+        self.expect(Token::LeftCurly)?;
+
+        let mut variants = Vec::new();
+        while self.eat(Token::RightCurly).is_none() {
+            variants.push(self.parse_enum_variant()?);
+            if self.eat(Token::Comma).is_none() {
+                self.expect(Token::RightCurly)?;
+                break;
+            }
+        }
+
+        Ok((name.clone(), Enum {
+            enum_name: name,
+            variants,
+        }))
+    }
+
+    ///
+    /// Returns an [`EnumVariant`] AST node if the next tokens represent an enum variant.
+    ///
+    pub fn parse_enum_variant(&mut self) -> SyntaxResult<EnumVariant> {
+        let name = self.expect_ident()?;
+
+        let mut payload = Vec::new();
+        if self.eat(Token::LeftParen).is_some() {
+            while self.eat(Token::RightParen).is_none() {
+                payload.push(self.parse_type()?.0);
+                if self.eat(Token::Comma).is_none() {
+                    self.expect(Token::RightParen)?;
+                    break;
+                }
+            }
+        }
+
+        Ok(EnumVariant { name, payload })
+    }
+
+    ///
+    /// Returns a list of [`ConstParameter`] AST nodes if the next tokens represent the const parameters
+    /// of a generic function or circuit, as in `<const N: u32>`.
+    ///
+    pub fn parse_const_parameters(&mut self) -> SyntaxResult<Vec<ConstParameter>> {
+        let mut const_parameters = Vec::new();
+        if self.eat(Token::Lt).is_none() {
+            return Ok(const_parameters);
+        }
+        while self.eat(Token::Gt).is_none() {
+            let start = self.expect(Token::Const)?;
+            let identifier = self.expect_ident()?;
+            self.expect(Token::Colon)?;
+            let (type_, end) = self.parse_type()?;
+            const_parameters.push(ConstParameter {
+                identifier,
+                type_,
+                span: start + end,
+            });
+            if self.eat(Token::Comma).is_none() {
+                self.expect(Token::Gt)?;
+                break;
+            }
+        }
+
+        Ok(const_parameters)
+    }
+
     ///
     /// Returns a [`FunctionInput`] AST node if the next tokens represent a function parameter.
     ///
@@ -438,6 +578,7 @@ impl ParserContext {
         }
         let start = self.expect(Token::Function)?;
         let name = self.expect_ident()?;
+        let const_parameters = self.parse_const_parameters()?;
         self.expect(Token::LeftParen)?;
         let mut inputs = Vec::new();
         while self.eat(Token::RightParen).is_none() {
@@ -457,6 +598,7 @@ impl ParserContext {
         Ok((name.clone(), Function {
             annotations,
             identifier: name,
+            const_parameters,
             input: inputs,
             output,
             span: start + block.span.clone(),
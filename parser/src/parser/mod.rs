@@ -30,7 +30,7 @@ pub mod type_;
 use std::unimplemented;
 
 use crate::{errors::assert_no_whitespace, tokenizer::*, DeprecatedError, SyntaxError, Token};
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use leo_ast::*;
 
 pub type SyntaxResult<T> = Result<T, SyntaxError>;
@@ -23,13 +23,13 @@ const ASSIGN_TOKENS: &[Token] = &[
     Token::MulEq,
     Token::DivEq,
     Token::ExpEq,
-    // Token::BitAndEq,
-    // Token::BitOrEq,
-    // Token::BitXorEq,
-    // Token::ShlEq,
-    // Token::ShrEq,
-    // Token::ShrSignedEq,
-    // Token::ModEq,
+    Token::BitAndEq,
+    Token::BitOrEq,
+    Token::BitXorEq,
+    Token::ShlEq,
+    Token::ShrEq,
+    Token::ShrSignedEq,
+    Token::ModEq,
     // Token::OrEq,
     // Token::AndEq,
 ];
@@ -90,9 +90,21 @@ impl ParserContext {
             Token::Return => Ok(Statement::Return(self.parse_return_statement()?)),
             Token::If => Ok(Statement::Conditional(self.parse_conditional_statement()?)),
             Token::For => Ok(Statement::Iteration(self.parse_loop_statement()?)),
+            Token::Break => Ok(Statement::Break(self.parse_break_statement()?)),
+            Token::Continue => Ok(Statement::Continue(self.parse_continue_statement()?)),
             Token::Console => Ok(Statement::Console(self.parse_console_statement()?)),
             Token::Let | Token::Const => Ok(Statement::Definition(self.parse_definition_statement()?)),
             Token::LeftCurly => Ok(Statement::Block(self.parse_block()?)),
+            // `match` is only a keyword when followed by the value it matches on.
+            Token::Ident(ident)
+                if ident.as_ref() == "match"
+                    && matches!(
+                        self.peek_next()?.token,
+                        Token::Ident(_) | Token::Input | Token::LittleSelf
+                    ) =>
+            {
+                Ok(Statement::Match(self.parse_match_statement()?))
+            }
             _ => Ok(self.parse_assign_statement()?),
         }
     }
@@ -119,13 +131,13 @@ impl ParserContext {
                     Token::ExpEq => AssignOperation::Pow,
                     // Token::OrEq => AssignOperation::Or,
                     // Token::AndEq => AssignOperation::And,
-                    // Token::BitOrEq => AssignOperation::BitOr,
-                    // Token::BitAndEq => AssignOperation::BitAnd,
-                    // Token::BitXorEq => AssignOperation::BitXor,
-                    // Token::ShrEq => AssignOperation::Shr,
-                    // Token::ShrSignedEq => AssignOperation::ShrSigned,
-                    // Token::ShlEq => AssignOperation::Shl,
-                    // Token::ModEq => AssignOperation::Mod,
+                    Token::BitOrEq => AssignOperation::BitOr,
+                    Token::BitAndEq => AssignOperation::BitAnd,
+                    Token::BitXorEq => AssignOperation::BitXor,
+                    Token::ShrEq => AssignOperation::Shr,
+                    Token::ShrSignedEq => AssignOperation::ShrSigned,
+                    Token::ShlEq => AssignOperation::Shl,
+                    Token::ModEq => AssignOperation::Mod,
                     _ => unimplemented!(),
                 },
                 value,
@@ -175,6 +187,26 @@ impl ParserContext {
         })
     }
 
+    ///
+    /// Returns a [`BreakStatement`] AST node if the next tokens represent a break statement.
+    ///
+    pub fn parse_break_statement(&mut self) -> SyntaxResult<BreakStatement> {
+        let span = self.expect(Token::Break)?;
+        self.expect(Token::Semicolon)?;
+
+        Ok(BreakStatement { span })
+    }
+
+    ///
+    /// Returns a [`ContinueStatement`] AST node if the next tokens represent a continue statement.
+    ///
+    pub fn parse_continue_statement(&mut self) -> SyntaxResult<ContinueStatement> {
+        let span = self.expect(Token::Continue)?;
+        self.expect(Token::Semicolon)?;
+
+        Ok(ContinueStatement { span })
+    }
+
     ///
     /// Returns a [`ConditionalStatement`] AST node if the next tokens represent a conditional statement.
     ///
@@ -198,6 +230,83 @@ impl ParserContext {
         })
     }
 
+    ///
+    /// Returns a [`MatchStatement`] AST node if the next tokens represent a match statement.
+    ///
+    pub fn parse_match_statement(&mut self) -> SyntaxResult<MatchStatement> {
+        let start = self.expect_ident()?.span;
+        self.fuzzy_struct_state = true;
+        let value = self.parse_conditional_expression()?;
+        self.fuzzy_struct_state = false;
+        self.expect(Token::LeftCurly)?;
+
+        let mut arms = Vec::new();
+        let end = loop {
+            if let Some(end) = self.eat(Token::RightCurly) {
+                break end.span;
+            }
+            arms.push(self.parse_match_arm()?);
+            self.eat(Token::Comma);
+        };
+
+        Ok(MatchStatement {
+            span: start + end,
+            value,
+            arms,
+        })
+    }
+
+    ///
+    /// Returns a [`MatchArm`] AST node if the next tokens represent an arm of a match statement.
+    ///
+    pub fn parse_match_arm(&mut self) -> SyntaxResult<MatchArm> {
+        let pattern = if let Some(token) = self.eat(Token::Underscore) {
+            MatchPattern::Wildcard(token.span)
+        } else {
+            let enum_name = self.expect_ident()?;
+            self.expect(Token::DoubleColon)?;
+            let variant = self.expect_ident()?;
+            let mut span = &enum_name.span + &variant.span;
+
+            let mut bindings = Vec::new();
+            if self.eat(Token::LeftParen).is_some() {
+                loop {
+                    if let Some(end) = self.eat(Token::RightParen) {
+                        span = span + end.span;
+                        break;
+                    }
+                    bindings.push(if let Some(token) = self.eat(Token::Underscore) {
+                        Identifier {
+                            name: token.token.to_string().into(),
+                            span: token.span,
+                        }
+                    } else {
+                        self.expect_ident()?
+                    });
+                    if self.eat(Token::Comma).is_none() {
+                        span = span + self.expect(Token::RightParen)?;
+                        break;
+                    }
+                }
+            }
+
+            MatchPattern::Variant {
+                enum_name,
+                variant,
+                bindings,
+                span,
+            }
+        };
+        self.expect(Token::BigArrow)?;
+        let block = self.parse_block()?;
+
+        Ok(MatchArm {
+            span: pattern.span() + &block.span,
+            pattern,
+            block,
+        })
+    }
+
     ///
     /// Returns an [`IterationStatement`] AST node if the next tokens represent an iteration statement.
     ///
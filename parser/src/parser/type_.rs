@@ -54,18 +54,40 @@ impl ParserContext {
         })
     }
 
+    ///
+    /// Returns `true` if a type can start with the given token.
+    ///
+    pub fn token_starts_type(token: &Token) -> bool {
+        TYPE_TOKENS.contains(token)
+            || matches!(
+                token,
+                Token::Ident(_) | Token::BigSelf | Token::LeftParen | Token::LeftSquare
+            )
+    }
+
+    ///
+    /// Returns a [`Dimension`] AST node if the next token is an int or the name of a const parameter.
+    ///
+    pub fn eat_dimension(&mut self) -> Option<Dimension> {
+        if let Some((int, _)) = self.eat_int() {
+            Some(Dimension::Number(int))
+        } else {
+            self.eat_identifier().map(Dimension::Parameter)
+        }
+    }
+
     ///
     /// Returns an [`ArrayDimensions`] AST node if the next tokens represent dimensions for an array type.
     ///
     pub fn parse_array_dimensions(&mut self) -> SyntaxResult<ArrayDimensions> {
-        Ok(if let Some((int, _)) = self.eat_int() {
-            ArrayDimensions(vec![int])
+        Ok(if let Some(dimension) = self.eat_dimension() {
+            ArrayDimensions(vec![dimension])
         } else {
             self.expect(Token::LeftParen)?;
             let mut dimensions = Vec::new();
             loop {
-                if let Some((int, _)) = self.eat_int() {
-                    dimensions.push(int);
+                if let Some(dimension) = self.eat_dimension() {
+                    dimensions.push(dimension);
                 } else {
                     let token = self.peek()?;
                     return Err(SyntaxError::unexpected_str(&token.token, "int", &token.span));
@@ -79,6 +101,27 @@ impl ParserContext {
         })
     }
 
+    ///
+    /// Returns the const arguments of a generic circuit type and the span of the closing `>`.
+    ///
+    pub fn parse_const_arguments(&mut self) -> SyntaxResult<(Vec<Dimension>, Span)> {
+        let mut arguments = Vec::new();
+        loop {
+            if let Some(end) = self.eat(Token::Gt) {
+                return Ok((arguments, end.span));
+            }
+            if let Some(dimension) = self.eat_dimension() {
+                arguments.push(dimension);
+            } else {
+                let token = self.peek()?;
+                return Err(SyntaxError::unexpected_str(&token.token, "int", &token.span));
+            }
+            if self.eat(Token::Comma).is_none() {
+                return Ok((arguments, self.expect(Token::Gt)?));
+            }
+        }
+    }
+
     ///
     /// Returns a [`(Type, Span)`] tuple of AST nodes if the next token represents a type. Also
     /// returns the span of the parsed token.
@@ -88,7 +131,12 @@ impl ParserContext {
             (Type::SelfType, token.span)
         } else if let Some(ident) = self.eat_identifier() {
             let span = ident.span.clone();
-            (Type::Circuit(ident), span)
+            if self.eat(Token::Lt).is_some() {
+                let (arguments, end_span) = self.parse_const_arguments()?;
+                (Type::GenericCircuit(ident, arguments), span + end_span)
+            } else {
+                (Type::Circuit(ident), span)
+            }
         } else if let Some(token) = self.eat(Token::LeftParen) {
             let mut types = Vec::new();
             let end_span;
@@ -133,11 +133,76 @@ impl Namespace for ParseNamespace {
     }
 }
 
+/// Removes the source positions from a serialized AST, identifiers are serialized with their span.
+/// The imports and modules are sorted, as the formatter reorders them.
+fn strip_spans(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    fn strip_identifier(string: String) -> String {
+        match serde_json::from_str::<Value>(&string) {
+            Ok(Value::Object(identifier)) if identifier.contains_key("span") => identifier["name"].to_string(),
+            _ => string,
+        }
+    }
+
+    match value {
+        Value::Object(object) if object.contains_key("line_start") => Value::Null,
+        Value::Object(object) => Value::Object(
+            object
+                .into_iter()
+                .filter(|(key, _)| key != "span")
+                .map(|(key, value)| {
+                    let value = match (key.as_str(), strip_spans(value)) {
+                        ("imports" | "modules" | "accesses", Value::Array(mut array)) => {
+                            array.sort_by_cached_key(Value::to_string);
+                            Value::Array(array)
+                        }
+                        (_, value) => value,
+                    };
+                    (strip_identifier(key), value)
+                })
+                .collect(),
+        ),
+        Value::Array(array) => Value::Array(array.into_iter().map(strip_spans).collect()),
+        Value::String(string) => Value::String(strip_identifier(string)),
+        value => value,
+    }
+}
+
+struct FormatNamespace;
+
+impl Namespace for FormatNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        let formatted = crate::format("test", &test.content).map_err(|x| x.to_string())?;
+
+        if crate::format("test", &formatted).map_err(|x| x.to_string())? != formatted {
+            return Err(format!("formatting is not idempotent:\n{}", formatted));
+        }
+
+        let ast = |source: &str| {
+            let program = crate::parse("test", source).map_err(|x| x.to_string())?;
+            Ok::<_, String>(strip_spans(
+                serde_json::to_value(&program).expect("serialization failed"),
+            ))
+        };
+        if ast(&test.content)? != ast(&formatted)? {
+            return Err(format!("formatting changed the program:\n{}", formatted));
+        }
+
+        Ok(Value::String(formatted))
+    }
+}
+
 struct TestRunner;
 
 impl Runner for TestRunner {
     fn resolve_namespace(&self, name: &str) -> Option<Box<dyn Namespace>> {
         Some(match name {
+            "Format" => Box::new(FormatNamespace),
             "Parse" => Box::new(ParseNamespace),
             "ParseStatement" => Box::new(ParseStatementNamespace),
             "ParseExpression" => Box::new(ParseExpressionNamespace),
@@ -348,11 +348,10 @@ impl Token {
                     //     return (len + inner_len, Some(Token::AndEq));
                     // }
                     return (len, Some(Token::And));
+                } else if let Some(len) = eat(input, "&=") {
+                    return (len, Some(Token::BitAndEq));
                 }
-                // else if let Some(len) = eat(input, "&=") {
-                //     return (len, Some(Token::BitAndEq));
-                // }
-                // return (1, Some(Token::BitAnd));
+                return (1, Some(Token::BitAnd));
             }
             b'(' => return (1, Some(Token::LeftParen)),
             b')' => return (1, Some(Token::RightParen)),
@@ -419,35 +418,35 @@ impl Token {
             b'<' => {
                 if let Some(len) = eat(input, "<=") {
                     return (len, Some(Token::LtEq));
+                } else if let Some(len) = eat(input, "<<") {
+                    if let Some(inner_len) = eat(&input[len..], "=") {
+                        return (len + inner_len, Some(Token::ShlEq));
+                    }
+                    return (len, Some(Token::Shl));
                 }
-                // else if let Some(len) = eat(input, "<<") {
-                //     if let Some(inner_len) = eat(&input[len..], "=") {
-                //         return (len + inner_len, Some(Token::ShlEq));
-                //     }
-                //     return (len, Some(Token::Shl));
-                // }
                 return (1, Some(Token::Lt));
             }
             b'>' => {
                 if let Some(len) = eat(input, ">=") {
                     return (len, Some(Token::GtEq));
+                } else if let Some(len) = eat(input, ">>") {
+                    if let Some(inner_len) = eat(&input[len..], "=") {
+                        return (len + inner_len, Some(Token::ShrEq));
+                    } else if let Some(inner_len) = eat(&input[len..], ">") {
+                        if let Some(eq_len) = eat(&input[len + inner_len..], "=") {
+                            return (len + inner_len + eq_len, Some(Token::ShrSignedEq));
+                        }
+                        return (len + inner_len, Some(Token::ShrSigned));
+                    }
+                    return (len, Some(Token::Shr));
                 }
-                // else if let Some(len) = eat(input, ">>") {
-                //     if let Some(inner_len) = eat(&input[len..], "=") {
-                //         return (len + inner_len, Some(Token::ShrEq));
-                //     } else if let Some(inner_len) = eat(&input[len..], ">") {
-                //         if let Some(eq_len) = eat(&input[len + inner_len..], "=") {
-                //             return (len + inner_len + eq_len, Some(Token::ShrSignedEq));
-                //         }
-                //         return (len + inner_len, Some(Token::ShrSigned));
-                //     }
-                //     return (len, Some(Token::Shr));
-                // }
                 return (1, Some(Token::Gt));
             }
             b'=' => {
                 if let Some(len) = eat(input, "==") {
                     return (len, Some(Token::Eq));
+                } else if let Some(len) = eat(input, "=>") {
+                    return (len, Some(Token::BigArrow));
                 }
                 return (1, Some(Token::Assign));
             }
@@ -462,25 +461,24 @@ impl Token {
                     //     return (len + inner_len, Some(Token::OrEq));
                     // }
                     return (len, Some(Token::Or));
+                } else if let Some(len) = eat(input, "|=") {
+                    return (len, Some(Token::BitOrEq));
+                }
+                return (1, Some(Token::BitOr));
+            }
+            b'^' => {
+                if let Some(len) = eat(input, "^=") {
+                    return (len, Some(Token::BitXorEq));
+                }
+                return (1, Some(Token::BitXor));
+            }
+            b'~' => return (1, Some(Token::BitNot)),
+            b'%' => {
+                if let Some(len) = eat(input, "%=") {
+                    return (len, Some(Token::ModEq));
                 }
-                // else if let Some(len) = eat(input, "|=") {
-                //     return (len, Some(Token::BitOrEq));
-                // }
-                // return (1, Some(Token::BitOr));
+                return (1, Some(Token::Mod));
             }
-            // b'^' => {
-            //     if let Some(len) = eat(input, "^=") {
-            //         return (len, Some(Token::BitXorEq));
-            //     }
-            //     return (1, Some(Token::BitXor));
-            // }
-            // b'~' => return (1, Some(Token::BitNot)),
-            // b'%' => {
-            //     if let Some(len) = eat(input, "%=") {
-            //         return (len, Some(Token::ModEq));
-            //     }
-            //     return (1, Some(Token::Mod));
-            // }
             _ => (),
         }
         if let Some(ident) = eat_identifier(&input_tendril) {
@@ -491,10 +489,12 @@ impl Token {
                     "address" => Token::Address,
                     "as" => Token::As,
                     "bool" => Token::Bool,
+                    "break" => Token::Break,
                     "char" => Token::Char,
                     "circuit" => Token::Circuit,
                     "console" => Token::Console,
                     "const" => Token::Const,
+                    "continue" => Token::Continue,
                     "else" => Token::Else,
                     "false" => Token::False,
                     "field" => Token::Field,
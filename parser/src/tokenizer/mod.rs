@@ -110,21 +110,6 @@ mod tests {
 
     #[test]
     fn test_tokenizer() {
-        // &
-        // &=
-        // |
-        // |=
-        // ^
-        // ^=
-        // ~
-        // <<
-        // <<=
-        // >>
-        // >>=
-        // >>>
-        // >>>=
-        // %
-        // %=
         // ||=
         // &&=
 
@@ -146,8 +131,10 @@ mod tests {
         address
         as
         bool
+        break
         circuit
         const
+        continue
         else
         false
         field
@@ -206,6 +193,7 @@ mod tests {
         <=
         =
         ==
+        =>
         >
         >=
         @
@@ -215,6 +203,21 @@ mod tests {
         }}
         ||
         ?
+        &
+        &=
+        |
+        |=
+        ^
+        ^=
+        ~
+        <<
+        <<=
+        >>
+        >>=
+        >>>
+        >>>=
+        %
+        %=
         // test
         /* test */
         //"#
@@ -225,10 +228,10 @@ mod tests {
         for SpannedToken { token, .. } in tokens.iter() {
             output += &format!("{} ", token.to_string());
         }
-        // & &= | |= ^ ^= ~ << <<= >> >>= >>> >>>= % %= ||= &&=
+        // ||= &&=
         assert_eq!(
             output,
-            r#""test" "test{}test" "test{}" "{}test" "test{" "test}" "test{test" "test}test" "te{{}}" aleo1qnr4dkkvkgfqph0vzc3y6z2eu975wnpz2925ntjccd5cfqxtyu8sta57j8 test_ident 12345 address as bool circuit const else false field for function group i128 i64 i32 i16 i8 if import in input let mut return static string test true u128 u64 u32 u16 u8 self Self console ! != && ( ) * ** **= *= + += , - -= -> _ . .. ... / /= : :: ; < <= = == > >= @ [ ] { { } } || ? // test
+            r#""test" "test{}test" "test{}" "{}test" "test{" "test}" "test{test" "test}test" "te{{}}" aleo1qnr4dkkvkgfqph0vzc3y6z2eu975wnpz2925ntjccd5cfqxtyu8sta57j8 test_ident 12345 address as bool break circuit const continue else false field for function group i128 i64 i32 i16 i8 if import in input let mut return static string test true u128 u64 u32 u16 u8 self Self console ! != && ( ) * ** **= *= + += , - -= -> _ . .. ... / /= : :: ; < <= = == => > >= @ [ ] { { } } || ? & &= | |= ^ ^= ~ << <<= >> >>= >>> >>>= % %= // test
  /* test */ // "#
         );
     }
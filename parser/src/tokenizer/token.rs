@@ -81,6 +81,21 @@ pub enum Token {
     MulEq,
     DivEq,
     ExpEq,
+    BitAnd,
+    BitAndEq,
+    BitOr,
+    BitOrEq,
+    BitXor,
+    BitXorEq,
+    BitNot,
+    Shl,
+    ShlEq,
+    Shr,
+    ShrEq,
+    ShrSigned,
+    ShrSignedEq,
+    Mod,
+    ModEq,
     LeftParen,
     RightParen,
     LeftSquare,
@@ -96,6 +111,7 @@ pub enum Token {
     DoubleColon,
     Question,
     Arrow,
+    BigArrow,
     Underscore,
 
     // Syntactic Grammr
@@ -126,9 +142,11 @@ pub enum Token {
 
     // Regular Keywords
     As,
+    Break,
     Circuit,
     Console,
     Const,
+    Continue,
     Else,
     For,
     Function,
@@ -140,21 +158,6 @@ pub enum Token {
     Static,
     String,
     // Not yet in ABNF
-    // BitAnd,
-    // BitAndEq,
-    // BitOr,
-    // BitOrEq,
-    // BitXor,
-    // BitXorEq,
-    // BitNot,
-    // Shl,
-    // ShlEq,
-    // Shr,
-    // ShrEq,
-    // ShrSigned,
-    // ShrSignedEq,
-    // Mod,
-    // ModEq,
     // OrEq,
     // AndEq,
 
@@ -167,10 +170,12 @@ pub const KEYWORD_TOKENS: &[Token] = &[
     Token::Address,
     Token::As,
     Token::Bool,
+    Token::Break,
     Token::Char,
     Token::Circuit,
     Token::Console,
     Token::Const,
+    Token::Continue,
     Token::Else,
     Token::False,
     Token::Field,
@@ -252,6 +257,21 @@ impl fmt::Display for Token {
             MulEq => write!(f, "*="),
             DivEq => write!(f, "/="),
             ExpEq => write!(f, "**="),
+            BitAnd => write!(f, "&"),
+            BitAndEq => write!(f, "&="),
+            BitOr => write!(f, "|"),
+            BitOrEq => write!(f, "|="),
+            BitXor => write!(f, "^"),
+            BitXorEq => write!(f, "^="),
+            BitNot => write!(f, "~"),
+            Shl => write!(f, "<<"),
+            ShlEq => write!(f, "<<="),
+            Shr => write!(f, ">>"),
+            ShrEq => write!(f, ">>="),
+            ShrSigned => write!(f, ">>>"),
+            ShrSignedEq => write!(f, ">>>="),
+            Mod => write!(f, "%"),
+            ModEq => write!(f, "%="),
             LeftParen => write!(f, "("),
             RightParen => write!(f, ")"),
             LeftSquare => write!(f, "["),
@@ -267,6 +287,7 @@ impl fmt::Display for Token {
             DoubleColon => write!(f, "::"),
             Question => write!(f, "?"),
             Arrow => write!(f, "->"),
+            BigArrow => write!(f, "=>"),
             Underscore => write!(f, "_"),
 
             U8 => write!(f, "u8"),
@@ -292,9 +313,11 @@ impl fmt::Display for Token {
             Import => write!(f, "import"),
 
             As => write!(f, "as"),
+            Break => write!(f, "break"),
             Circuit => write!(f, "circuit"),
             Console => write!(f, "console"),
             Const => write!(f, "const"),
+            Continue => write!(f, "continue"),
             Else => write!(f, "else"),
             For => write!(f, "for"),
             Function => write!(f, "function"),
@@ -306,21 +329,6 @@ impl fmt::Display for Token {
             Static => write!(f, "static"),
             String => write!(f, "string"),
             Eof => write!(f, ""),
-            // BitAnd => write!(f, "&"),
-            // BitAndEq => write!(f, "&="),
-            // BitOr => write!(f, "|"),
-            // BitOrEq => write!(f, "|="),
-            // BitXor => write!(f, "^"),
-            // BitXorEq => write!(f, "^="),
-            // BitNot => write!(f, "~"),
-            // Shl => write!(f, "<<"),
-            // ShlEq => write!(f, "<<="),
-            // Shr => write!(f, ">>"),
-            // ShrEq => write!(f, ">>="),
-            // ShrSigned => write!(f, ">>>"),
-            // ShrSignedEq => write!(f, ">>>="),
-            // Mod => write!(f, "%"),
-            // ModEq => write!(f, "%="),
             // OrEq => write!(f, "||="),
             // AndEq => write!(f, "&&="),
         }
@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_curves::traits::PairingEngine;
-use snarkvm_fields::Field;
+use snarkvm_fields::{Field, One, Zero};
 use snarkvm_r1cs::{ConstraintSystem, Index, LinearCombination, OptionalVec, SynthesisError, Variable};
 
 #[derive(Default)]
@@ -55,15 +55,54 @@ pub struct CircuitSynthesizer<E: PairingEngine> {
 
 impl<E: PairingEngine> Default for CircuitSynthesizer<E> {
     fn default() -> Self {
+        // The first public variable is the constant one, as in the constraint systems of the proving systems.
+        let mut public_variables = OptionalVec::default();
+        public_variables.insert(E::Fr::one());
+
         Self {
             constraints: Default::default(),
-            public_variables: Default::default(),
+            public_variables,
             private_variables: Default::default(),
             namespaces: Default::default(),
         }
     }
 }
 
+impl<E: PairingEngine> CircuitSynthesizer<E> {
+    /// Returns the position of the first constraint that the assigned variables do not satisfy, if any.
+    pub fn which_is_unsatisfied(&self) -> Option<usize> {
+        self.constraints.iter().position(|constraint| {
+            match (
+                self.evaluate(&constraint.at),
+                self.evaluate(&constraint.bt),
+                self.evaluate(&constraint.ct),
+            ) {
+                (Some(a), Some(b), Some(c)) => a * b != c,
+                _ => true,
+            }
+        })
+    }
+
+    pub fn is_satisfied(&self) -> bool {
+        self.which_is_unsatisfied().is_none()
+    }
+
+    /// Evaluates a linear combination on the assigned variables.
+    /// Returns `None` if it refers to a variable that does not exist.
+    fn evaluate(&self, terms: &[(E::Fr, Index)]) -> Option<E::Fr> {
+        let mut sum = E::Fr::zero();
+        for (coefficient, index) in terms {
+            let value = match *index {
+                Index::Public(i) if i < self.public_variables.len() => self.public_variables[i],
+                Index::Private(i) if i < self.private_variables.len() => self.private_variables[i],
+                _ => return None,
+            };
+            sum += *coefficient * value;
+        }
+        Some(sum)
+    }
+}
+
 impl<E: PairingEngine> ConstraintSystem<E::Fr> for CircuitSynthesizer<E> {
     type Root = Self;
 
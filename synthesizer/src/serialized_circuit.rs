@@ -45,6 +45,16 @@ impl SerializedCircuit {
     pub fn from_json_string(json: &str) -> Result<Self, serde_json::Error> {
         serde_json::from_str(json)
     }
+
+    /// Encodes the circuit in the compact binary format.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, bincode::Error> {
+        bincode::serialize(&self)
+    }
+
+    /// Decodes a circuit from the compact binary format.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, bincode::Error> {
+        bincode::deserialize(bytes)
+    }
 }
 
 impl<E: PairingEngine> From<CircuitSynthesizer<E>> for SerializedCircuit {
@@ -153,15 +163,16 @@ impl TryFrom<SerializedCircuit> for CircuitSynthesizer<Bls12_377> {
 
         let mut constraints = OptionalVec::with_capacity(serialized.num_constraints);
 
-        for i in 0..serialized.num_constraints {
+        let constraint_sets = serialized.at.iter().zip(&serialized.bt).zip(&serialized.ct);
+        for ((at, bt), ct) in constraint_sets.take(serialized.num_constraints) {
             // Deserialize at[i]
-            let a_constraints = get_deserialized_constraints(&serialized.at[i])?;
+            let a_constraints = get_deserialized_constraints(at)?;
 
             // Deserialize bt[i]
-            let b_constraints = get_deserialized_constraints(&serialized.bt[i])?;
+            let b_constraints = get_deserialized_constraints(bt)?;
 
             // Deserialize ct[i]
-            let c_constraints = get_deserialized_constraints(&serialized.ct[i])?;
+            let c_constraints = get_deserialized_constraints(ct)?;
 
             constraints.insert(ConstraintSet {
                 at: a_constraints,